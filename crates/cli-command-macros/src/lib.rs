@@ -228,12 +228,9 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 		let id = &entry.id;
 		let ty = &entry.ty;
 		let canonical = &entry.canonical;
-		let interactive = entry.interactive;
-		let batch = entry.batch;
 		quote! {
 			CommandId::#id => {
 				type Cmd = #ty;
-				use crate::commands::def::ExecMode;
 
 				let canonical = #canonical;
 				debug_assert_eq!(
@@ -241,28 +238,9 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 					canonical,
 					"command graph canonical name must match CommandDef::NAME"
 				);
-				let interactive_only = #interactive || <Cmd as crate::commands::def::CommandDef>::INTERACTIVE_ONLY;
-				let batch_enabled = #batch;
-
-				if exec.mode == ExecMode::Batch {
-					if !batch_enabled {
-						return Err(crate::error::PwError::UnsupportedMode(format!(
-							"command '{}' is not available in batch/ndjson mode",
-							canonical,
-						)));
-					}
-					if interactive_only {
-						return Err(crate::error::PwError::UnsupportedMode(format!(
-							"command '{}' is interactive-only and cannot run in batch/ndjson mode",
-							canonical,
-						)));
-					}
-				}
-
-				let raw: <Cmd as crate::commands::def::CommandDef>::Raw = serde_json::from_value(args)
-					.map_err(|err| crate::error::PwError::Context(format!("INVALID_INPUT: {}", err)))?;
-
-				<Cmd as crate::commands::def::CommandDef>::validate_mode(&raw, exec.mode)?;
+				let explain_mode = exec.explain;
+				let raw_input = (explain_mode != crate::commands::def::ExplainMode::Off).then(|| args.clone());
+				let raw = parse_input::<Cmd>(id, args, exec.mode)?;
 				let resolved = {
 					let env = crate::target::ResolveEnv::new(
 						&*exec.ctx_state,
@@ -272,12 +250,32 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 					<<Cmd as crate::commands::def::CommandDef>::Raw as crate::commands::def::Resolve>::resolve(raw, &env)?
 				};
 
+				let explanation = raw_input.map(|input| {
+					<Cmd as crate::commands::def::CommandDef>::explain(&resolved)
+						.unwrap_or_else(|| crate::commands::def::Explanation::generic(canonical, input))
+				});
+				if explain_mode == crate::commands::def::ExplainMode::Only {
+					if let Some(explanation) = explanation {
+						return Ok(crate::commands::def::ErasedOutcome::unexecuted(canonical, explanation));
+					}
+				}
+
 				let outcome = <Cmd as crate::commands::def::CommandDef>::execute(&resolved, exec).await?;
-				outcome.erase(canonical)
+				let mut erased = outcome.erase(canonical)?;
+				erased.explanation = explanation.map(|explanation| explanation.summary);
+				Ok(erased)
 			}
 		}
 	});
 
+	let validate_arms = catalog.entries.iter().map(|entry| {
+		let id = &entry.id;
+		let ty = &entry.ty;
+		quote! {
+			CommandId::#id => parse_input::<#ty>(id, args, mode).map(drop)
+		}
+	});
+
 	Ok(TokenStream::from(quote! {
 		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 		pub enum CommandId {
@@ -321,6 +319,44 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 			}
 		}
 
+		/// Rejects commands `mode` does not allow, then deserializes `args` into the command's raw input.
+		fn parse_input<Cmd: crate::commands::def::CommandDef>(
+			id: CommandId,
+			args: serde_json::Value,
+			mode: crate::commands::def::ExecMode,
+		) -> crate::error::Result<Cmd::Raw> {
+			let meta = command_meta(id);
+			if mode == crate::commands::def::ExecMode::Batch {
+				if !meta.batch_enabled {
+					return Err(crate::error::PwError::UnsupportedMode(format!(
+						"command '{}' is not available in batch/ndjson mode",
+						meta.canonical,
+					)));
+				}
+				if meta.interactive_only || Cmd::INTERACTIVE_ONLY {
+					return Err(crate::error::PwError::UnsupportedMode(format!(
+						"command '{}' is interactive-only and cannot run in batch/ndjson mode",
+						meta.canonical,
+					)));
+				}
+			}
+			let raw: Cmd::Raw = serde_json::from_value(args)
+				.map_err(|err| crate::error::PwError::Context(format!("INVALID_INPUT: {}", err)))?;
+			Cmd::validate_mode(&raw, mode)?;
+			Ok(raw)
+		}
+
+		/// Checks `args` deserialize into the command's raw input and suit `mode`, without resolving or running it.
+		pub fn validate_input(
+			id: CommandId,
+			args: serde_json::Value,
+			mode: crate::commands::def::ExecMode,
+		) -> crate::error::Result<()> {
+			match id {
+				#(#validate_arms),*
+			}
+		}
+
 		pub async fn run_command(
 			id: CommandId,
 			args: serde_json::Value,
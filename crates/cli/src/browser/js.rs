@@ -2,48 +2,121 @@ pub fn escape_selector(selector: &str) -> String {
 	selector.replace('\\', "\\\\").replace('\'', "\\'")
 }
 
-pub fn console_capture_injection_js() -> &'static str {
-	r#"() => {
-            window.__consoleMessages = [];
-            const originalConsole = { ...console };
-            ['log', 'warn', 'error', 'info', 'debug'].forEach(method => {
-                console[method] = (...args) => {
-                    window.__consoleMessages.push({
-                        type: method,
-                        text: args.map(a => String(a)).join(' ')
-                    });
-                    originalConsole[method](...args);
-                };
-            });
-            window.onerror = (msg, src, line, col, error) => {
-                window.__consoleMessages.push({
-                    type: 'pageerror',
-                    text: String(msg),
-                    stack: error?.stack || null
-                });
-            };
-        }"#
-}
+/// Stylesheet zeroing animation and transition timing, used by `screenshot --freeze-animations`.
+pub const FREEZE_ANIMATIONS_CSS: &str = "*, *::before, *::after { animation-duration: 0s !important; animation-delay: 0s !important; transition-duration: 0s !important; transition-delay: 0s !important; }";
 
-pub fn get_element_coords_js(selector: &str) -> String {
-	let escaped = escape_selector(selector);
+/// Script installing [`FREEZE_ANIMATIONS_CSS`] into the document.
+///
+/// Usable both as an init script (runs before page scripts, waiting for the
+/// document element when needed) and as an expression against an already
+/// loaded page. Installation is idempotent.
+pub fn freeze_animations_js() -> String {
 	format!(
 		r#"(() => {{
-                const el = document.querySelector('{escaped}');
-                if (!el) return 'null';
-                const rect = el.getBoundingClientRect();
-                return JSON.stringify({{
-                    x: Math.round(rect.x + rect.width / 2),
-                    y: Math.round(rect.y + rect.height / 2),
-                    width: Math.round(rect.width),
-                    height: Math.round(rect.height),
-                    text: el.textContent?.trim().substring(0, 100) || null,
-                    href: el.getAttribute('href')
-                }});
+                const install = () => {{
+                    if (document.getElementById('__pw_freeze_animations')) return;
+                    const style = document.createElement('style');
+                    style.id = '__pw_freeze_animations';
+                    style.textContent = '{FREEZE_ANIMATIONS_CSS}';
+                    (document.head || document.documentElement).appendChild(style);
+                }};
+                if (document.documentElement) install();
+                else document.addEventListener('DOMContentLoaded', install, {{ once: true }});
             }})()"#
 	)
 }
 
+/// Script resolving once the DOM has gone `quiet_ms` without mutations, or
+/// after `max_ms` at the latest.
+///
+/// Observes the whole document (subtree, attributes, and character data) with
+/// a `MutationObserver`. Evaluates to a JSON object `{ settled, waitedMs }`
+/// where `settled` is false when `max_ms` elapsed first.
+pub fn settle_dom_js(quiet_ms: u64, max_ms: u64) -> String {
+	format!(
+		r#"new Promise((resolve) => {{
+                const start = performance.now();
+                let quietTimer = null;
+                const finish = (settled) => {{
+                    observer.disconnect();
+                    clearTimeout(quietTimer);
+                    clearTimeout(maxTimer);
+                    resolve(JSON.stringify({{ settled, waitedMs: Math.round(performance.now() - start) }}));
+                }};
+                const arm = () => {{
+                    clearTimeout(quietTimer);
+                    quietTimer = setTimeout(() => finish(true), {quiet_ms});
+                }};
+                const observer = new MutationObserver(arm);
+                observer.observe(document, {{ subtree: true, childList: true, attributes: true, characterData: true }});
+                const maxTimer = setTimeout(() => finish(false), {max_ms});
+                arm();
+            }})"#
+	)
+}
+
+/// Element function returning the rendered text of the element it is called on.
+///
+/// Skips text under `display: none`, `visibility: hidden`/`collapse`, and
+/// zero-size parents, and separates block-level runs with newlines the way
+/// `innerText` does. Run it through [`pw_rs::Locator::evaluate`] so the
+/// element is resolved by any Playwright selector, not just CSS.
+pub const VISIBLE_TEXT_JS: &str = r#"root => {
+                const ignoreTags = new Set(['SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE']);
+                const blockOf = (el) => {
+                    while (el && el !== root && window.getComputedStyle(el).display.startsWith('inline')) el = el.parentElement;
+                    return el;
+                };
+                const parts = [];
+                let lastBlock = null;
+                const walker = document.createTreeWalker(root, NodeFilter.SHOW_TEXT);
+                while (walker.nextNode()) {
+                    const node = walker.currentNode;
+                    const parent = node.parentElement;
+                    if (!parent || ignoreTags.has(parent.tagName)) continue;
+                    if (window.getComputedStyle(parent).visibility !== 'visible') continue;
+                    const rect = parent.getBoundingClientRect();
+                    if (rect.width === 0 || rect.height === 0) continue;
+                    const text = node.textContent.replace(/\s+/g, ' ');
+                    if (!text.trim()) continue;
+                    const block = blockOf(parent);
+                    if (lastBlock !== null && block !== lastBlock) parts.push('\n');
+                    parts.push(text);
+                    lastBlock = block;
+                }
+                return parts.join('').split('\n').map(line => line.trim()).join('\n');
+            }"#;
+
+/// Element function collecting the `innerText` of same-origin frames nested
+/// in the element it is called on, depth first in document order.
+///
+/// Returns `{ texts, skippedFrames }` where `skippedFrames` lists URLs of
+/// frames whose document is not accessible (cross-origin). Like
+/// [`VISIBLE_TEXT_JS`], run it through [`pw_rs::Locator::evaluate`].
+pub const FRAME_TEXTS_JS: &str = r#"root => {
+                const texts = [];
+                const skippedFrames = [];
+                const visit = (scope) => {
+                    scope.querySelectorAll('iframe, frame').forEach(frame => {
+                        let doc = null;
+                        try { doc = frame.contentDocument; } catch (e) {}
+                        if (!doc || !doc.documentElement) {
+                            skippedFrames.push(frame.src || 'about:blank');
+                            return;
+                        }
+                        const body = doc.body || doc.documentElement;
+                        const text = (body.innerText || '').trim();
+                        if (text) texts.push(text);
+                        visit(doc);
+                    });
+                };
+                visit(root);
+                return { texts, skippedFrames };
+            }"#;
+
+/// Element function returning the text preview and `href` reported by `page.coords`.
+pub const ELEMENT_LABEL_JS: &str = "el => ({ text: el.textContent?.trim().substring(0, 100) || null, href: el.getAttribute('href') })";
+
 pub fn get_all_element_coords_js(selector: &str) -> String {
 	let escaped = escape_selector(selector);
 	format!(
@@ -23,6 +23,8 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 		har,
 		block,
 		download,
+		launch,
+		emulation,
 	} = config;
 
 	debug!(
@@ -33,7 +35,11 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 		"starting Playwright..."
 	);
 
-	let mut playwright = Playwright::launch().await.map_err(|e| PwError::BrowserLaunch(e.to_string()))?;
+	let har = har.with_expanded_path(preferred_url.as_deref(), std::time::SystemTime::now());
+
+	let mut playwright = Playwright::launch_with_config(crate::debug_stats::connection_config())
+		.await
+		.map_err(|e| PwError::BrowserLaunch(e.to_string()))?;
 	let context_build = build_browser_context(
 		&mut playwright,
 		ContextFactoryInput {
@@ -45,6 +51,8 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 			needs_custom_context,
 			har: &har,
 			download: &download,
+			launch: &launch,
+			emulation: &emulation,
 		},
 	)
 	.await?;
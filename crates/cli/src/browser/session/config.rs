@@ -1,7 +1,7 @@
 use pw_rs::{StorageState, WaitUntil};
 
-use crate::context::{BlockConfig, DownloadConfig, HarConfig};
-use crate::types::BrowserKind;
+use crate::context::{BlockConfig, DownloadConfig, EmulationConfig, HarConfig, LaunchConfig};
+use crate::types::{BrowserKind, ProtectedUrl};
 
 /// Fully owned browser-session configuration.
 ///
@@ -22,7 +22,7 @@ pub struct SessionConfig {
 	/// Whether to launch Playwright browser server mode.
 	pub launch_server: bool,
 	/// URL patterns excluded from page-reuse candidates.
-	pub protected_urls: Vec<String>,
+	pub protected_urls: Vec<ProtectedUrl>,
 	/// Preferred URL for page-reuse candidate selection.
 	pub preferred_url: Option<String>,
 	/// HAR recording configuration.
@@ -31,6 +31,10 @@ pub struct SessionConfig {
 	pub block: BlockConfig,
 	/// Download-tracking configuration.
 	pub download: DownloadConfig,
+	/// Browser launch tuning (slow-mo, devtools, channel, default args).
+	pub launch: LaunchConfig,
+	/// Browser context emulation (locale, timezone).
+	pub emulation: EmulationConfig,
 }
 
 impl SessionConfig {
@@ -48,12 +52,14 @@ impl SessionConfig {
 			har: HarConfig::default(),
 			block: BlockConfig::default(),
 			download: DownloadConfig::default(),
+			launch: LaunchConfig::default(),
+			emulation: EmulationConfig::default(),
 		}
 	}
 
 	/// Returns true when context creation must use explicit options.
 	pub(crate) fn needs_custom_context(&self) -> bool {
-		self.storage_state.is_some() || self.har.is_enabled() || self.download.is_enabled()
+		self.storage_state.is_some() || self.har.is_enabled() || self.download.is_enabled() || self.emulation.is_enabled()
 	}
 }
 
@@ -87,4 +93,11 @@ mod tests {
 		dl_cfg.download.dir = Some("downloads".into());
 		assert!(dl_cfg.needs_custom_context());
 	}
+
+	#[test]
+	fn session_config_requires_custom_context_for_emulation() {
+		let mut cfg = SessionConfig::new(WaitUntil::NetworkIdle);
+		cfg.emulation.timezone_id = Some("Europe/Berlin".to_string());
+		assert!(cfg.needs_custom_context());
+	}
 }
@@ -2,7 +2,7 @@ use pw_rs::{BrowserContextOptions, Playwright, StorageState};
 use tracing::debug;
 
 use super::types::SessionEndpoints;
-use crate::context::{DownloadConfig, HarConfig};
+use crate::context::{DownloadConfig, EmulationConfig, HarConfig, LaunchConfig};
 use crate::error::{PwError, Result};
 use crate::types::BrowserKind;
 
@@ -16,6 +16,8 @@ pub(crate) struct ContextFactoryInput<'a> {
 	pub(crate) needs_custom_context: bool,
 	pub(crate) har: &'a HarConfig,
 	pub(crate) download: &'a DownloadConfig,
+	pub(crate) launch: &'a LaunchConfig,
+	pub(crate) emulation: &'a EmulationConfig,
 }
 
 /// Browser/context build output used by session assembly.
@@ -38,6 +40,8 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		needs_custom_context,
 		har,
 		download,
+		launch,
+		emulation,
 	} = input;
 
 	if let Some(endpoint) = cdp_endpoint {
@@ -54,7 +58,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		let browser = connect_result.browser;
 		let mut reuse_existing_page = false;
 		let context = if needs_custom_context {
-			let options = build_context_options(storage_state, har, download);
+			let options = build_context_options(storage_state, har, download, emulation);
 			browser.new_context_with_options(options).await?
 		} else if let Some(default_ctx) = connect_result.default_context {
 			reuse_existing_page = true;
@@ -77,10 +81,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 
 	if launch_server {
 		playwright.keep_server_running();
-		let launch_options = pw_rs::LaunchOptions {
-			headless: Some(headless),
-			..Default::default()
-		};
+		let launch_options = launch.launch_options(headless);
 		let launched = match browser_kind {
 			BrowserKind::Chromium => playwright
 				.chromium()
@@ -101,7 +102,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 
 		let browser = launched.browser().clone();
 		let context = if needs_custom_context {
-			let options = build_context_options(storage_state, har, download);
+			let options = build_context_options(storage_state, har, download, emulation);
 			browser.new_context_with_options(options).await?
 		} else {
 			browser.new_context().await?
@@ -119,17 +120,14 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		});
 	}
 
-	let launch_options = pw_rs::LaunchOptions {
-		headless: Some(headless),
-		..Default::default()
-	};
+	let launch_options = launch.launch_options(headless);
 	let browser = match browser_kind {
 		BrowserKind::Chromium => playwright.chromium().launch_with_options(launch_options).await?,
 		BrowserKind::Firefox => playwright.firefox().launch_with_options(launch_options).await?,
 		BrowserKind::Webkit => playwright.webkit().launch_with_options(launch_options).await?,
 	};
 	let context = if needs_custom_context {
-		let options = build_context_options(storage_state, har, download);
+		let options = build_context_options(storage_state, har, download, emulation);
 		browser.new_context_with_options(options).await?
 	} else {
 		browser.new_context().await?
@@ -144,9 +142,45 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 	})
 }
 
-fn build_context_options(storage_state: Option<StorageState>, har_config: &HarConfig, download_config: &DownloadConfig) -> BrowserContextOptions {
+pub(super) fn build_context_options(
+	storage_state: Option<StorageState>,
+	har_config: &HarConfig,
+	download_config: &DownloadConfig,
+	emulation: &EmulationConfig,
+) -> BrowserContextOptions {
 	let mut builder = BrowserContextOptions::builder();
 
+	if let Some(locale) = &emulation.locale {
+		builder = builder.locale(locale.clone());
+	}
+	if let Some(timezone_id) = &emulation.timezone_id {
+		builder = builder.timezone_id(timezone_id.clone());
+	}
+	if let Some(scheme) = emulation.color_scheme {
+		builder = builder.color_scheme(scheme.as_str().to_string());
+	}
+	if let Some(motion) = emulation.reduced_motion {
+		builder = builder.reduced_motion(motion.as_str().to_string());
+	}
+	if let Some(viewport) = &emulation.viewport {
+		builder = builder.viewport(viewport.clone());
+	}
+	if let Some(user_agent) = &emulation.user_agent {
+		builder = builder.user_agent(user_agent.clone());
+	}
+	if let Some(scale) = emulation.device_scale_factor {
+		builder = builder.device_scale_factor(scale);
+	}
+	if let Some(is_mobile) = emulation.is_mobile {
+		builder = builder.is_mobile(is_mobile);
+	}
+	if let Some(has_touch) = emulation.has_touch {
+		builder = builder.has_touch(has_touch);
+	}
+	if let Some(geolocation) = &emulation.geolocation {
+		builder = builder.geolocation(geolocation.clone()).permissions(vec!["geolocation".to_string()]);
+	}
+
 	if let Some(state) = storage_state {
 		builder = builder.storage_state(state);
 	}
@@ -182,9 +216,58 @@ fn build_context_options(storage_state: Option<StorageState>, har_config: &HarCo
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::types::{ColorScheme, ReducedMotion};
 
 	#[test]
 	fn endpoint_bundle_reports_empty_for_default() {
 		assert!(SessionEndpoints::default().is_empty());
 	}
+
+	#[test]
+	fn context_options_carry_emulation() {
+		let emulation = EmulationConfig {
+			locale: Some("fr-FR".to_string()),
+			timezone_id: Some("America/New_York".to_string()),
+			color_scheme: Some(ColorScheme::Dark),
+			reduced_motion: Some(ReducedMotion::Reduce),
+			viewport: Some(pw_rs::Viewport { width: 390, height: 844 }),
+			..Default::default()
+		};
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), &emulation);
+		assert_eq!(options.locale.as_deref(), Some("fr-FR"));
+		assert_eq!(options.timezone_id.as_deref(), Some("America/New_York"));
+		assert_eq!(options.color_scheme.as_deref(), Some("dark"));
+		assert_eq!(options.reduced_motion.as_deref(), Some("reduce"));
+		assert_eq!(options.viewport, Some(pw_rs::Viewport { width: 390, height: 844 }));
+	}
+
+	#[test]
+	fn context_options_carry_device_preset() {
+		let mut emulation = EmulationConfig {
+			viewport: Some(pw_rs::Viewport { width: 1280, height: 720 }),
+			..Default::default()
+		};
+		emulation.apply_device(&crate::devices::lookup("iPhone 13").unwrap());
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), &emulation);
+		assert_eq!(options.viewport, Some(pw_rs::Viewport { width: 390, height: 664 }));
+		assert!(options.user_agent.as_deref().is_some_and(|ua| ua.contains("iPhone")));
+		assert_eq!(options.device_scale_factor, Some(3.0));
+		assert_eq!(options.is_mobile, Some(true));
+		assert_eq!(options.has_touch, Some(true));
+	}
+
+	#[test]
+	fn context_options_grant_permission_for_geolocation() {
+		let emulation = EmulationConfig {
+			geolocation: Some(pw_rs::Geolocation {
+				latitude: 52.37,
+				longitude: 4.89,
+				accuracy: None,
+			}),
+			..Default::default()
+		};
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), &emulation);
+		assert_eq!(options.geolocation, emulation.geolocation);
+		assert_eq!(options.permissions, Some(vec!["geolocation".to_string()]));
+	}
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pw_rs::Subscription;
 use tracing::debug;
 
@@ -5,9 +7,34 @@ use crate::context::BlockConfig;
 use crate::error::{PwError, Result};
 
 /// Installs request-blocking routes and returns RAII subscriptions.
+///
+/// Bare patterns share a single catch-all route that aborts matching
+/// requests and continues the rest. URL globs get one aborting route each,
+/// registered afterwards so they take precedence over the catch-all.
 pub(crate) async fn install_routes(page: &pw_rs::Page, block_config: &BlockConfig) -> Result<Vec<Subscription>> {
-	let mut route_subscriptions = Vec::with_capacity(block_config.patterns.len());
-	for pattern in &block_config.patterns {
+	let (globs, bare): (Vec<&String>, Vec<&String>) = block_config.patterns.iter().partition(|p| BlockConfig::is_url_glob(p));
+
+	let mut route_subscriptions = Vec::with_capacity(globs.len() + 1);
+	if !bare.is_empty() {
+		debug!(target = "pw", patterns = ?bare, "blocking bare patterns");
+		let matcher = Arc::new(bare_pattern_matcher(&bare)?);
+		let subscription = page
+			.route("**/*", move |route| {
+				let matcher = Arc::clone(&matcher);
+				async move {
+					if matcher.is_match(route.request().url()) {
+						route.abort(None).await
+					} else {
+						route.continue_(None).await
+					}
+				}
+			})
+			.await
+			.map_err(|e| PwError::BrowserLaunch(format!("route setup failed: {e}")))?;
+		route_subscriptions.push(subscription);
+	}
+
+	for pattern in globs {
 		debug!(target = "pw", %pattern, "blocking pattern");
 		let subscription = page
 			.route(pattern, |route| async move { route.abort(None).await })
@@ -15,5 +42,76 @@ pub(crate) async fn install_routes(page: &pw_rs::Page, block_config: &BlockConfi
 			.map_err(|e| PwError::BrowserLaunch(format!("route setup failed: {e}")))?;
 		route_subscriptions.push(subscription);
 	}
+
 	Ok(route_subscriptions)
 }
+
+/// Compiles bare patterns into one regex over the whole URL.
+///
+/// The syntax is Playwright's glob syntax and stays case-sensitive: `?` is
+/// any one character, `{a,b}` is an alternative, and `\` escapes the next
+/// character. The one difference is that `*` also spans `/`, since a bare
+/// pattern has no path segments to stop at.
+fn bare_pattern_matcher(patterns: &[&String]) -> Result<regex_lite::Regex> {
+	let alternatives: Vec<String> = patterns.iter().map(|pattern| glob_to_regex(pattern)).collect();
+	regex_lite::Regex::new(&format!("^(?:{})$", alternatives.join("|"))).map_err(|e| PwError::Context(format!("invalid block pattern in {patterns:?}: {e}")))
+}
+
+fn glob_to_regex(glob: &str) -> String {
+	let mut regex = String::with_capacity(glob.len() * 2);
+	let mut in_group = false;
+	let mut chars = glob.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => match chars.next() {
+				Some(escaped) => regex.push_str(&regex_lite::escape(&escaped.to_string())),
+				None => regex.push_str(r"\\"),
+			},
+			'*' => regex.push_str(".*"),
+			'?' => regex.push('.'),
+			'{' => {
+				in_group = true;
+				regex.push_str("(?:");
+			}
+			'}' if in_group => {
+				in_group = false;
+				regex.push(')');
+			}
+			',' if in_group => regex.push('|'),
+			other => regex.push_str(&regex_lite::escape(&other.to_string())),
+		}
+	}
+	regex
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn matcher(patterns: &[&str]) -> regex_lite::Regex {
+		let owned: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+		bare_pattern_matcher(&owned.iter().collect::<Vec<_>>()).unwrap()
+	}
+
+	#[test]
+	fn star_spans_the_whole_url() {
+		let matcher = matcher(&["*.png", "*googletagmanager*"]);
+		assert!(matcher.is_match("https://example.com/img/logo.png"));
+		assert!(matcher.is_match("https://www.googletagmanager.com/gtm.js"));
+		assert!(!matcher.is_match("https://example.com/logo.png?v=1"));
+		assert!(!matcher.is_match("https://example.com/page"));
+	}
+
+	#[test]
+	fn keeps_glob_syntax_and_case() {
+		let matcher = matcher(&["*.{jpg,webp}", "*-v?.js"]);
+		assert!(matcher.is_match("https://example.com/photo.webp"));
+		assert!(matcher.is_match("https://example.com/app-v2.js"));
+		assert!(!matcher.is_match("https://example.com/photo.JPG"));
+		assert!(!matcher.is_match("https://example.com/app-v10.js"));
+
+		let escaped = self::matcher(&[r"*\?x=1"]);
+		assert!(escaped.is_match("https://example.com/?x=1"));
+		assert!(!escaped.is_match("https://example.com/ax=1"));
+	}
+}
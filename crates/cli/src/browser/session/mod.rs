@@ -2,6 +2,7 @@ mod builder;
 mod config;
 mod context_factory;
 mod features;
+mod navigation;
 mod page_selection;
 mod shutdown;
 mod types;
@@ -10,12 +11,13 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 pub use config::SessionConfig;
-use pw_rs::{BrowserContextOptions, GotoOptions, Playwright, StorageState, Subscription, WaitUntil};
+use pw_rs::{GotoOptions, Playwright, StorageState, Subscription, WaitUntil};
 pub use shutdown::ShutdownMode;
 use tracing::debug;
 pub use types::{AuthInjectionReport, DownloadInfo, SessionEndpoints};
 
 use self::features::har::HarRecording;
+use crate::context::{DownloadConfig, EmulationConfig, HarConfig, LaunchConfig};
 use crate::error::{PwError, Result};
 use crate::types::BrowserKind;
 
@@ -80,12 +82,21 @@ impl BrowserSession {
 	}
 
 	/// Creates a session in browser-server mode.
-	pub async fn launch_server_session(wait_until: WaitUntil, storage_state: Option<StorageState>, headless: bool, browser_kind: BrowserKind) -> Result<Self> {
+	pub async fn launch_server_session(
+		wait_until: WaitUntil,
+		storage_state: Option<StorageState>,
+		headless: bool,
+		browser_kind: BrowserKind,
+		launch: &LaunchConfig,
+		emulation: &EmulationConfig,
+	) -> Result<Self> {
 		let mut config = SessionConfig::new(wait_until);
 		config.storage_state = storage_state;
 		config.headless = headless;
 		config.browser_kind = browser_kind;
 		config.launch_server = true;
+		config.launch = launch.clone();
+		config.emulation = emulation.clone();
 		Self::with_config(config).await
 	}
 
@@ -96,6 +107,8 @@ impl BrowserSession {
 		headless: bool,
 		remote_debugging_port: u16,
 		keep_browser_running: bool,
+		launch: &LaunchConfig,
+		emulation: &EmulationConfig,
 	) -> Result<Self> {
 		debug!(
 			target = "pw",
@@ -105,27 +118,24 @@ impl BrowserSession {
 			"launching persistent session..."
 		);
 
-		let mut playwright = Playwright::launch().await.map_err(|e| PwError::BrowserLaunch(e.to_string()))?;
+		let mut playwright = Playwright::launch_with_config(crate::debug_stats::connection_config())
+			.await
+			.map_err(|e| PwError::BrowserLaunch(e.to_string()))?;
 		if keep_browser_running {
 			playwright.keep_server_running();
 		}
 
 		let launch_options = pw_rs::LaunchOptions {
-			headless: Some(headless),
 			remote_debugging_port: Some(remote_debugging_port),
 			handle_sighup: Some(!keep_browser_running),
 			handle_sigint: Some(!keep_browser_running),
 			handle_sigterm: Some(!keep_browser_running),
-			..Default::default()
+			..launch.launch_options(headless)
 		};
 
 		let browser = playwright.chromium().launch_with_options(launch_options).await?;
-		let context = if let Some(state) = storage_state {
-			let options = BrowserContextOptions::builder().storage_state(state).build();
-			browser.new_context_with_options(options).await?
-		} else {
-			browser.new_context().await?
-		};
+		let options = context_factory::build_context_options(storage_state, &HarConfig::default(), &DownloadConfig::default(), emulation);
+		let context = browser.new_context_with_options(options).await?;
 		let page = context.new_page().await?;
 
 		Ok(Self {
@@ -157,7 +167,15 @@ impl BrowserSession {
 	}
 
 	/// Navigates the active page to a URL with optional timeout.
-	pub async fn goto(&self, url: &str, timeout_ms: Option<u64>) -> Result<()> {
+	///
+	/// A navigation interrupted by a client-side redirect succeeds once the
+	/// page settles on its final URL; see [`navigation`] for the settle and
+	/// bounded-retry rules.
+	///
+	/// Returns the main resource response, or `None` for targets without one
+	/// (`data:` URLs, same-document navigations) and for navigations that
+	/// settled after such a redirect.
+	pub async fn goto(&self, url: &str, timeout_ms: Option<u64>) -> Result<Option<pw_rs::Response>> {
 		let mut goto_opts = GotoOptions {
 			wait_until: Some(self.wait_until),
 			..Default::default()
@@ -166,10 +184,35 @@ impl BrowserSession {
 			goto_opts.timeout = Some(std::time::Duration::from_millis(ms));
 		}
 
-		self.page.goto(url, Some(goto_opts)).await.map(|_| ()).map_err(|e| PwError::Navigation {
-			url: url.to_string(),
-			source: anyhow::Error::new(e),
-		})
+		let before_url = self.page.url();
+		let mut attempt = 1;
+		loop {
+			let err = match self.page.goto(url, Some(goto_opts.clone())).await {
+				Ok(response) => return Ok(response),
+				Err(err) => err,
+			};
+
+			let aborted = err.is_navigation_aborted();
+			if !aborted && !err.is_navigation_interrupted() {
+				return Err(navigation_error(url, err));
+			}
+
+			if let Some(settled_url) = navigation::wait_for_settled_url(&self.page, self.wait_until).await {
+				// A bare abort is only a redirect if the page ended up somewhere new.
+				if aborted && !navigation::moved_elsewhere(&settled_url, url, &before_url) {
+					return Err(navigation_error(url, err));
+				}
+				debug!(target = "pw", url, settled_url = %settled_url, "navigation interrupted by redirect; page settled");
+				return Ok(None);
+			}
+
+			if attempt >= navigation::MAX_INTERRUPTED_ATTEMPTS {
+				return Err(navigation_error(url, err));
+			}
+
+			debug!(target = "pw", url, attempt, error = %err, "navigation interrupted; retrying");
+			attempt += 1;
+		}
 	}
 
 	/// Returns the active page handle.
@@ -251,22 +294,31 @@ impl BrowserSession {
 		features::har::export_if_active(&self.context, self.har_recording.as_ref()).await;
 		let _ = self.context.close().await;
 
-		match mode {
-			ShutdownMode::CloseSessionOnly => {
-				self.browser.close().await?;
-			}
-			ShutdownMode::KeepBrowserAlive => {}
+		let result = match mode {
+			ShutdownMode::CloseSessionOnly => self.browser.close().await,
+			ShutdownMode::KeepBrowserAlive => Ok(()),
 			ShutdownMode::ShutdownServer => {
 				if let Some(server) = self.launched_server.take() {
-					server.close().await?;
-					self._playwright.enable_server_shutdown();
+					let closed = server.close().await;
+					if closed.is_ok() {
+						self._playwright.enable_server_shutdown();
+					}
+					closed
 				} else {
-					self.browser.close().await?;
+					self.browser.close().await
 				}
 			}
-		}
+		};
+		crate::debug_stats::record(&self._playwright);
+
+		Ok(result?)
+	}
+}
 
-		Ok(())
+fn navigation_error(url: &str, err: pw_rs::Error) -> PwError {
+	PwError::Navigation {
+		url: url.to_string(),
+		source: anyhow::Error::new(err),
 	}
 }
 
@@ -0,0 +1,91 @@
+//! Navigation resilience for [`super::BrowserSession::goto`].
+//!
+//! Pages that redirect client-side before the awaited lifecycle event fires
+//! (e.g. `location.replace` in an inline script) make Playwright reject the
+//! original `goto` with a "navigation interrupted" error even though the
+//! browser ends up on a perfectly good page. Instead of surfacing that error,
+//! the session polls the page until its URL and `document.readyState` settle
+//! and treats a settled page as a completed redirect. Bare aborts
+//! (`net::ERR_ABORTED`, `NS_BINDING_ABORTED`) only count when the page settled
+//! on a URL other than the one requested and the one it left, since downloads
+//! and `204` responses abort the same way. Only when the page never settles is
+//! the navigation retried, up to [`MAX_INTERRUPTED_ATTEMPTS`] times.
+
+use std::time::Duration;
+
+use pw_rs::WaitUntil;
+
+/// Total `goto` attempts when every attempt is interrupted without settling.
+pub(super) const MAX_INTERRUPTED_ATTEMPTS: u32 = 3;
+
+/// Delay between settle probes.
+const SETTLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Settle probes per interrupted attempt (~3s with [`SETTLE_POLL_INTERVAL`]).
+const SETTLE_MAX_POLLS: u32 = 30;
+
+const PROBE_JS: &str = "[window.location.href, document.readyState]";
+
+/// Returns true when `ready_state` satisfies the lifecycle `wait_until` awaits.
+fn ready_state_satisfies(ready_state: &str, wait_until: WaitUntil) -> bool {
+	match wait_until {
+		WaitUntil::Commit => true,
+		WaitUntil::DomContentLoaded => ready_state != "loading",
+		WaitUntil::Load | WaitUntil::NetworkIdle => ready_state == "complete",
+	}
+}
+
+/// Polls `page` until it reports the same URL on two consecutive probes with a
+/// ready state satisfying `wait_until`, returning that URL.
+///
+/// Probes that fail (the execution context is torn down mid-redirect) count
+/// as unsettled. Returns `None` when the page does not settle in time.
+pub(super) async fn wait_for_settled_url(page: &pw_rs::Page, wait_until: WaitUntil) -> Option<String> {
+	let mut last_ready_url: Option<String> = None;
+
+	for _ in 0..SETTLE_MAX_POLLS {
+		tokio::time::sleep(SETTLE_POLL_INTERVAL).await;
+
+		let probe = page.evaluate_typed::<(String, String)>(PROBE_JS).await.ok();
+		let ready_url = probe.and_then(|(href, ready_state)| ready_state_satisfies(&ready_state, wait_until).then_some(href));
+
+		match (&last_ready_url, ready_url) {
+			(Some(previous), Some(current)) if *previous == current => return Some(current),
+			(_, current) => last_ready_url = current,
+		}
+	}
+
+	None
+}
+
+/// Returns true when `settled_url` is neither the URL `goto` requested nor the
+/// one the page showed before it.
+pub(super) fn moved_elsewhere(settled_url: &str, requested_url: &str, before_url: &str) -> bool {
+	let same = |a: &str, b: &str| match (url::Url::parse(a), url::Url::parse(b)) {
+		(Ok(a), Ok(b)) => a == b,
+		_ => a == b,
+	};
+	!same(settled_url, requested_url) && !same(settled_url, before_url)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ready_state_follows_wait_until() {
+		assert!(ready_state_satisfies("loading", WaitUntil::Commit));
+		assert!(!ready_state_satisfies("loading", WaitUntil::DomContentLoaded));
+		assert!(ready_state_satisfies("interactive", WaitUntil::DomContentLoaded));
+		assert!(!ready_state_satisfies("interactive", WaitUntil::Load));
+		assert!(ready_state_satisfies("complete", WaitUntil::NetworkIdle));
+	}
+
+	#[test]
+	fn moved_elsewhere_ignores_pages_that_stayed_put() {
+		assert!(moved_elsewhere("https://b.test/final", "https://a.test/start", "about:blank"));
+		assert!(!moved_elsewhere("https://a.test/start", "https://a.test/start", "about:blank"));
+		assert!(!moved_elsewhere("https://a.test/", "https://a.test", "about:blank"));
+		assert!(!moved_elsewhere("https://a.test/list", "https://a.test/export.csv", "https://a.test/list"));
+	}
+}
@@ -1,12 +1,13 @@
 use tracing::debug;
 
 use crate::error::Result;
+use crate::types::{ProtectedUrl, is_protected_url};
 
 /// Picks a page for command execution, optionally reusing an existing page.
 pub(crate) async fn select_page(
 	context: &pw_rs::BrowserContext,
 	reuse_existing_page: bool,
-	protected_urls: &[String],
+	protected_urls: &[ProtectedUrl],
 	preferred_url: Option<&str>,
 ) -> Result<pw_rs::Page> {
 	if !reuse_existing_page {
@@ -49,11 +50,6 @@ pub(crate) async fn select_page(
 	}
 }
 
-pub(crate) fn is_protected_url(url: &str, protected_patterns: &[String]) -> bool {
-	let url_lower = url.to_lowercase();
-	protected_patterns.iter().any(|pattern| url_lower.contains(&pattern.to_lowercase()))
-}
-
 pub(crate) fn is_preferred_match(url: &str, preferred: &str) -> bool {
 	url.starts_with(preferred) || preferred.starts_with(url) || urls_match_loosely(url, preferred)
 }
@@ -74,14 +70,6 @@ fn urls_match_loosely(a: &str, b: &str) -> bool {
 mod tests {
 	use super::*;
 
-	#[test]
-	fn protected_url_matching_is_case_insensitive() {
-		let patterns = vec!["Admin".to_string(), "settings".to_string()];
-		assert!(is_protected_url("https://example.com/admin/panel", &patterns));
-		assert!(is_protected_url("https://example.com/SETTINGS", &patterns));
-		assert!(!is_protected_url("https://example.com/public", &patterns));
-	}
-
 	#[test]
 	fn preferred_match_accepts_prefix_and_same_host() {
 		assert!(is_preferred_match("https://example.com/dashboard", "https://example.com"));
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use crate::output::OutputFormat;
+use crate::runtime::RuntimeOverrides;
 use crate::styles::cli_styles;
 use crate::types::BrowserKind;
 
@@ -20,10 +21,34 @@ pub struct Cli {
 	#[arg(short, long, global = true, action = clap::ArgAction::Count)]
 	pub verbose: u8,
 
-	/// Output format: toon (default), json, ndjson, or text
+	/// Output format: toon (default), json, ndjson, text, or csv
 	#[arg(short = 'f', long, global = true, value_enum, default_value = "toon")]
 	pub format: OutputFormat,
 
+	/// After the command runs, keep a headed browser open until Enter is pressed
+	#[arg(long, global = true)]
+	pub pause: bool,
+
+	/// Describe what the command will do in the response, then run it
+	#[arg(long, global = true)]
+	pub explain: bool,
+
+	/// Describe what the command will do without running it
+	#[arg(long, global = true)]
+	pub explain_only: bool,
+
+	/// Timeout in milliseconds for navigation and element actions (overrides runtime.overrides.timeoutMs)
+	#[arg(long = "timeout", global = true, value_name = "MS")]
+	pub timeout_ms: Option<u64>,
+
+	/// Emulate a device preset's viewport, user agent, scale factor and touch support
+	#[arg(long, global = true, value_name = "NAME", ignore_case = true, value_parser = clap::builder::PossibleValuesParser::new(crate::devices::names()))]
+	pub device: Option<String>,
+
+	/// After the command finishes, print driver request counts and latencies to stderr
+	#[arg(long, global = true)]
+	pub debug_stats: bool,
+
 	#[command(subcommand)]
 	pub command: Commands,
 }
@@ -32,12 +57,16 @@ pub struct Cli {
 pub enum Commands {
 	/// Execute a single canonical operation.
 	Exec(ExecArgs),
-	/// Stream request envelopes over stdin/stdout (NDJSON).
+	/// Stream request envelopes over stdin/stdout, or replay them from a file (NDJSON).
 	Batch(BatchArgs),
+	/// Re-run the command recorded in a saved result envelope.
+	Replay(ReplayArgs),
 	/// Manage profile-scoped runtime configuration.
 	Profile(ProfileArgs),
 	/// Manage daemon lifecycle.
 	Daemon(DaemonArgs),
+	/// Install or verify the Playwright driver's browsers.
+	Install(InstallArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -61,6 +90,12 @@ pub struct ExecArgs {
 	/// Directory for failure artifacts.
 	#[arg(long, value_name = "DIR")]
 	pub artifacts_dir: Option<PathBuf>,
+
+	#[command(flatten)]
+	pub launch: LaunchArgs,
+
+	#[command(flatten)]
+	pub emulation: EmulationArgs,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -68,6 +103,154 @@ pub struct BatchArgs {
 	/// Runtime profile name.
 	#[arg(long, value_name = "NAME", default_value = "default")]
 	pub profile: String,
+
+	/// Run up to N independent read requests at once (responses may arrive out of order).
+	#[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+	pub max_concurrency: u16,
+
+	/// Read request envelopes from an NDJSON file instead of stdin.
+	#[arg(long, value_name = "FILE")]
+	pub input: Option<PathBuf>,
+
+	/// Keep replaying an input file after a request fails (default: stop at the first failure).
+	#[arg(long, requires = "input")]
+	pub continue_on_error: bool,
+
+	/// Re-run a request up to N times after a timeout or lost connection (per-request `retry` overrides).
+	#[arg(long, value_name = "N", default_value_t = 0)]
+	pub retries: u32,
+
+	/// Check every request's op and input without running anything, reporting all invalid lines.
+	#[arg(long)]
+	pub validate: bool,
+
+	#[command(flatten)]
+	pub launch: LaunchArgs,
+
+	#[command(flatten)]
+	pub emulation: EmulationArgs,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ReplayArgs {
+	/// Result envelope JSON captured with `pw -f json exec ...`.
+	#[arg(value_name = "RESULT")]
+	pub file: PathBuf,
+
+	/// Runtime profile name (defaults to the profile recorded in the envelope).
+	#[arg(long, value_name = "NAME")]
+	pub profile: Option<String>,
+
+	/// Directory for failure artifacts.
+	#[arg(long, value_name = "DIR")]
+	pub artifacts_dir: Option<PathBuf>,
+
+	#[command(flatten)]
+	pub launch: LaunchArgs,
+
+	#[command(flatten)]
+	pub emulation: EmulationArgs,
+}
+
+/// Browser launch flags mapped onto request runtime overrides.
+///
+/// Flags take precedence over `runtime.overrides` in the request envelope,
+/// which in turn take precedence over the profile's persisted `launch` config.
+/// They only affect sessions that `pw` launches itself.
+#[derive(Args, Debug, Clone, Default)]
+pub struct LaunchArgs {
+	/// Slow down every browser operation by N milliseconds.
+	#[arg(long = "slow-mo", value_name = "MS")]
+	pub slow_mo: Option<f64>,
+
+	/// Open DevTools for each tab (Chromium only, forces headful).
+	#[arg(long)]
+	pub devtools: bool,
+
+	/// Browser distribution channel: chrome, chrome-beta, chrome-dev, chrome-canary,
+	/// msedge, msedge-beta, msedge-dev, msedge-canary, or chromium.
+	#[arg(long, value_name = "CHANNEL")]
+	pub channel: Option<String>,
+
+	/// Comma-separated default browser arguments to drop (for example --mute-audio).
+	#[arg(long, value_name = "ARGS", value_delimiter = ',', allow_hyphen_values = true)]
+	pub ignore_default_args: Vec<String>,
+
+	/// Custom browser executable for the selected browser kind.
+	#[arg(long, value_name = "PATH")]
+	pub executable_path: Option<PathBuf>,
+
+	/// Storage-state file to load; repeat to merge several (later files win).
+	#[arg(long = "auth", value_name = "FILE")]
+	pub auth: Vec<PathBuf>,
+}
+
+impl LaunchArgs {
+	/// Writes flag values into `overrides`, replacing envelope-provided values.
+	pub fn apply_to(&self, overrides: &mut RuntimeOverrides) {
+		if let Some(ms) = self.slow_mo {
+			overrides.slow_mo_ms = Some(ms);
+		}
+		if self.devtools {
+			overrides.devtools = Some(true);
+		}
+		if let Some(channel) = &self.channel {
+			overrides.channel = Some(channel.clone());
+		}
+		if !self.ignore_default_args.is_empty() {
+			overrides.ignore_default_args = Some(pw_rs::IgnoreDefaultArgs::Array(self.ignore_default_args.clone()));
+		}
+		if let Some(path) = &self.executable_path {
+			overrides.executable_path = Some(path.clone());
+		}
+		if !self.auth.is_empty() {
+			overrides.auth_files = self.auth.clone();
+		}
+	}
+}
+
+/// Browser context emulation flags mapped onto request runtime overrides.
+#[derive(Args, Debug, Clone, Default)]
+pub struct EmulationArgs {
+	/// IANA timezone for the browser context (for example America/New_York).
+	#[arg(long, value_name = "TZ")]
+	pub timezone: Option<String>,
+
+	/// Locale for the browser context (for example fr-FR).
+	#[arg(long, value_name = "LOCALE")]
+	pub locale: Option<String>,
+
+	/// Geolocation reported to the page, as latitude,longitude (for example 52.37,4.89).
+	#[arg(long, value_name = "LAT,LNG", value_parser = parse_geolocation, allow_hyphen_values = true)]
+	pub geolocation: Option<pw_rs::Geolocation>,
+}
+
+impl EmulationArgs {
+	/// Writes flag values into `overrides`, replacing envelope-provided values.
+	pub fn apply_to(&self, overrides: &mut RuntimeOverrides) {
+		if let Some(tz) = &self.timezone {
+			overrides.timezone_id = Some(tz.clone());
+		}
+		if let Some(locale) = &self.locale {
+			overrides.locale = Some(locale.clone());
+		}
+		if let Some(geolocation) = &self.geolocation {
+			overrides.geolocation = Some(geolocation.clone());
+		}
+	}
+}
+
+/// Parses `lat,lng` into a geolocation, rejecting out-of-range coordinates.
+fn parse_geolocation(value: &str) -> std::result::Result<pw_rs::Geolocation, String> {
+	let (lat, lng) = value.split_once(',').ok_or("expected latitude,longitude")?;
+	let parse = |part: &str, what: &str| part.trim().parse::<f64>().map_err(|_| format!("invalid {what} '{}'", part.trim()));
+	let geolocation = pw_rs::Geolocation {
+		latitude: parse(lat, "latitude")?,
+		longitude: parse(lng, "longitude")?,
+		accuracy: None,
+	};
+	crate::context::validate_geolocation(&geolocation).map_err(|e| e.to_string())?;
+	Ok(geolocation)
 }
 
 #[derive(Args, Debug, Clone)]
@@ -97,6 +280,24 @@ pub enum ProfileAction {
 		#[arg(value_name = "NAME")]
 		name: String,
 	},
+	/// Archive a profile's config and auth files into a tar file.
+	Export {
+		#[arg(value_name = "NAME")]
+		name: String,
+		#[arg(value_name = "OUTPUT")]
+		output: PathBuf,
+	},
+	/// Restore a profile from a tar file written by `profile export`.
+	Import {
+		#[arg(value_name = "FILE")]
+		file: PathBuf,
+		/// Profile to import into (default: the exported profile's name).
+		#[arg(value_name = "NAME")]
+		name: Option<String>,
+		/// Replace an existing profile with the same name.
+		#[arg(long)]
+		force: bool,
+	},
 }
 
 #[derive(Args, Debug, Clone)]
@@ -110,11 +311,29 @@ pub enum DaemonAction {
 	Start {
 		#[arg(long)]
 		foreground: bool,
+		/// Close browsers nobody holds after this many seconds unused.
+		#[arg(long = "idle-timeout", value_name = "SECS")]
+		idle_timeout: Option<u64>,
 	},
 	Stop,
 	Status,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct InstallArgs {
+	/// Browsers to install, comma-separated (default: chromium).
+	#[arg(long, value_enum, value_delimiter = ',', value_name = "BROWSERS")]
+	pub browsers: Vec<BrowserKind>,
+
+	/// Directory to install browsers into (sets PLAYWRIGHT_BROWSERS_PATH).
+	#[arg(long, value_name = "DIR")]
+	pub cache_dir: Option<PathBuf>,
+
+	/// Only verify and report installed versions; download nothing.
+	#[arg(long)]
+	pub check: bool,
+}
+
 /// HAR content policy (CLI wrapper for pw_rs::HarContentPolicy)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -158,6 +377,44 @@ impl From<CliHarMode> for pw_rs::HarMode {
 	}
 }
 
+/// Screenshot animation handling (CLI wrapper for pw_rs::ScreenshotAnimations)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CliScreenshotAnimations {
+	/// Stop CSS animations and transitions while capturing
+	Disabled,
+	/// Leave animations running
+	Allow,
+}
+
+impl From<CliScreenshotAnimations> for pw_rs::ScreenshotAnimations {
+	fn from(animations: CliScreenshotAnimations) -> Self {
+		match animations {
+			CliScreenshotAnimations::Disabled => pw_rs::ScreenshotAnimations::Disabled,
+			CliScreenshotAnimations::Allow => pw_rs::ScreenshotAnimations::Allow,
+		}
+	}
+}
+
+/// PDF paper format (CLI wrapper for pw_rs::PdfFormat)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CliPdfFormat {
+	/// 210mm x 297mm
+	A4,
+	/// 8.5in x 11in
+	Letter,
+}
+
+impl From<CliPdfFormat> for pw_rs::PdfFormat {
+	fn from(format: CliPdfFormat) -> Self {
+		match format {
+			CliPdfFormat::A4 => pw_rs::PdfFormat::A4,
+			CliPdfFormat::Letter => pw_rs::PdfFormat::Letter,
+		}
+	}
+}
+
 /// Project template type for init command.
 #[derive(Clone, Debug, ValueEnum, Default, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
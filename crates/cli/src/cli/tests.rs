@@ -30,6 +30,18 @@ fn parse_exec_with_file() {
 	}
 }
 
+#[test]
+fn parse_replay() {
+	let cli = Cli::try_parse_from(["pw", "replay", "result.json", "--profile", "ci"]).unwrap();
+	match cli.command {
+		Commands::Replay(args) => {
+			assert_eq!(args.file, PathBuf::from("result.json"));
+			assert_eq!(args.profile.as_deref(), Some("ci"));
+		}
+		_ => panic!("expected replay"),
+	}
+}
+
 #[test]
 fn parse_batch() {
 	let cli = Cli::try_parse_from(["pw", "batch", "--profile", "ci"]).unwrap();
@@ -39,6 +51,51 @@ fn parse_batch() {
 	}
 }
 
+#[test]
+fn parse_batch_input_file() {
+	let cli = Cli::try_parse_from(["pw", "batch", "--input", "script.ndjson", "--continue-on-error"]).unwrap();
+	let Commands::Batch(args) = cli.command else {
+		panic!("expected batch");
+	};
+	assert_eq!(args.input, Some(PathBuf::from("script.ndjson")));
+	assert!(args.continue_on_error);
+
+	assert!(Cli::try_parse_from(["pw", "batch", "--continue-on-error"]).is_err());
+}
+
+#[test]
+fn parse_batch_retries() {
+	let cli = Cli::try_parse_from(["pw", "batch", "--retries", "2"]).unwrap();
+	let Commands::Batch(args) = cli.command else {
+		panic!("expected batch");
+	};
+	assert_eq!(args.retries, 2);
+}
+
+#[test]
+fn parse_batch_validate() {
+	let cli = Cli::try_parse_from(["pw", "batch", "--validate", "--input", "script.ndjson"]).unwrap();
+	let Commands::Batch(args) = cli.command else {
+		panic!("expected batch");
+	};
+	assert!(args.validate);
+}
+
+#[test]
+fn parse_profile_import() {
+	let cli = Cli::try_parse_from(["pw", "profile", "import", "work.tar", "ci", "--force"]).unwrap();
+	match cli.command {
+		Commands::Profile(ProfileArgs {
+			action: ProfileAction::Import { file, name, force },
+		}) => {
+			assert_eq!(file, PathBuf::from("work.tar"));
+			assert_eq!(name.as_deref(), Some("ci"));
+			assert!(force);
+		}
+		_ => panic!("expected profile import"),
+	}
+}
+
 #[test]
 fn parse_profile_set() {
 	let cli = Cli::try_parse_from(["pw", "profile", "set", "default", "--file", "cfg.json"]).unwrap();
@@ -58,13 +115,170 @@ fn parse_daemon_start_foreground() {
 	let cli = Cli::try_parse_from(["pw", "daemon", "start", "--foreground"]).unwrap();
 	match cli.command {
 		Commands::Daemon(DaemonArgs {
-			action: DaemonAction::Start { foreground },
-		}) => assert!(foreground),
+			action: DaemonAction::Start { foreground, idle_timeout },
+		}) => {
+			assert!(foreground);
+			assert_eq!(idle_timeout, None);
+		}
 		_ => panic!("expected daemon start"),
 	}
 }
 
+#[test]
+fn parse_daemon_start_idle_timeout() {
+	let cli = Cli::try_parse_from(["pw", "daemon", "start", "--idle-timeout", "600"]).unwrap();
+	match cli.command {
+		Commands::Daemon(DaemonArgs {
+			action: DaemonAction::Start { foreground, idle_timeout },
+		}) => {
+			assert!(!foreground);
+			assert_eq!(idle_timeout, Some(600));
+		}
+		_ => panic!("expected daemon start"),
+	}
+}
+
+#[test]
+fn parse_install_browsers_and_cache_dir() {
+	let cli = Cli::try_parse_from(["pw", "install", "--browsers", "chromium,firefox", "--cache-dir", "/tmp/pw-browsers", "--check"]).unwrap();
+	match cli.command {
+		Commands::Install(args) => {
+			assert_eq!(args.browsers, vec![BrowserKind::Chromium, BrowserKind::Firefox]);
+			assert_eq!(args.cache_dir, Some(PathBuf::from("/tmp/pw-browsers")));
+			assert!(args.check);
+		}
+		_ => panic!("expected install"),
+	}
+}
+
 #[test]
 fn invalid_command_fails() {
 	assert!(Cli::try_parse_from(["pw", "navigate", "https://example.com"]).is_err());
 }
+
+#[test]
+fn parse_exec_launch_flags_into_overrides() {
+	let cli = Cli::try_parse_from([
+		"pw",
+		"exec",
+		"navigate",
+		"--slow-mo",
+		"250",
+		"--devtools",
+		"--channel",
+		"chrome",
+		"--ignore-default-args",
+		"--mute-audio,--hide-scrollbars",
+	])
+	.unwrap();
+	let Commands::Exec(args) = cli.command else {
+		panic!("expected exec");
+	};
+
+	let mut overrides = RuntimeOverrides {
+		channel: Some("msedge".to_string()),
+		..Default::default()
+	};
+	args.launch.apply_to(&mut overrides);
+
+	assert_eq!(overrides.slow_mo_ms, Some(250.0));
+	assert_eq!(overrides.devtools, Some(true));
+	assert_eq!(overrides.channel.as_deref(), Some("chrome"));
+	assert_eq!(
+		overrides.ignore_default_args,
+		Some(pw_rs::IgnoreDefaultArgs::Array(vec![
+			"--mute-audio".to_string(),
+			"--hide-scrollbars".to_string()
+		]))
+	);
+}
+
+#[test]
+fn absent_launch_flags_keep_envelope_overrides() {
+	let cli = Cli::try_parse_from(["pw", "batch"]).unwrap();
+	let Commands::Batch(args) = cli.command else {
+		panic!("expected batch");
+	};
+
+	let mut overrides = RuntimeOverrides {
+		slow_mo_ms: Some(50.0),
+		channel: Some("msedge".to_string()),
+		..Default::default()
+	};
+	args.launch.apply_to(&mut overrides);
+
+	assert_eq!(overrides.slow_mo_ms, Some(50.0));
+	assert_eq!(overrides.devtools, None);
+	assert_eq!(overrides.channel.as_deref(), Some("msedge"));
+	assert!(overrides.ignore_default_args.is_none());
+}
+
+#[test]
+fn parse_batch_executable_path_into_overrides() {
+	let cli = Cli::try_parse_from(["pw", "batch", "--executable-path", "/opt/firefox/firefox"]).unwrap();
+	let Commands::Batch(args) = cli.command else {
+		panic!("expected batch");
+	};
+
+	let mut overrides = RuntimeOverrides::default();
+	args.launch.apply_to(&mut overrides);
+	assert_eq!(overrides.executable_path, Some(PathBuf::from("/opt/firefox/firefox")));
+}
+
+#[test]
+fn parse_device_flag_ignores_case_and_rejects_unknown_presets() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--device", "pixel 5", "--input", "{}"]).unwrap();
+	assert_eq!(cli.device.as_deref(), Some("pixel 5"));
+
+	let err = Cli::try_parse_from(["pw", "exec", "navigate", "--device", "Nokia 3310"]).unwrap_err();
+	assert!(err.to_string().contains("iPhone 13"), "{err}");
+}
+
+#[test]
+fn parse_exec_emulation_flags_into_overrides() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--timezone", "America/New_York", "--locale", "fr-FR", "--input", "{}"]).unwrap();
+	let Commands::Exec(args) = cli.command else {
+		panic!("expected exec");
+	};
+
+	let mut overrides = RuntimeOverrides::default();
+	args.emulation.apply_to(&mut overrides);
+	assert_eq!(overrides.timezone_id.as_deref(), Some("America/New_York"));
+	assert_eq!(overrides.locale.as_deref(), Some("fr-FR"));
+}
+
+#[test]
+fn parse_geolocation_flag() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--geolocation", "-33.87, 151.21", "--input", "{}"]).unwrap();
+	let Commands::Exec(args) = cli.command else {
+		panic!("expected exec");
+	};
+	let geolocation = args.emulation.geolocation.unwrap();
+	assert_eq!((geolocation.latitude, geolocation.longitude), (-33.87, 151.21));
+
+	for bad in ["52.37", "north,4.89", "52.37,200"] {
+		assert!(Cli::try_parse_from(["pw", "exec", "navigate", "--geolocation", bad]).is_err(), "{bad}");
+	}
+}
+
+#[test]
+fn repeated_auth_flags_replace_envelope_auth_files() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--auth", "a.json", "--auth", "b.json", "--input", "{}"]).unwrap();
+	let Commands::Exec(args) = cli.command else {
+		panic!("expected exec");
+	};
+
+	let mut overrides: RuntimeOverrides = serde_json::from_str(r#"{"authFile":"envelope.json"}"#).unwrap();
+	assert_eq!(overrides.auth_files, [PathBuf::from("envelope.json")]);
+	args.launch.apply_to(&mut overrides);
+	assert_eq!(overrides.auth_files, [PathBuf::from("a.json"), PathBuf::from("b.json")]);
+}
+
+#[test]
+fn global_timeout_flag_parses_before_and_after_subcommand() {
+	let cli = Cli::try_parse_from(["pw", "--timeout", "500", "exec", "click", "--input", "{}"]).unwrap();
+	assert_eq!(cli.timeout_ms, Some(500));
+
+	let cli = Cli::try_parse_from(["pw", "batch", "--timeout", "750"]).unwrap();
+	assert_eq!(cli.timeout_ms, Some(750));
+}
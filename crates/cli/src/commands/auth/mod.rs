@@ -13,7 +13,7 @@ use std::path::{Path, PathBuf};
 
 use clap::Args;
 pub use listen::listen;
-use pw_rs::{StorageState, WaitUntil};
+use pw_rs::{Cookie, StorageState, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
@@ -110,12 +110,60 @@ pub struct CookiesRaw {
 	#[arg(short, long, default_value = "table")]
 	#[serde(default)]
 	pub format: Option<String>,
+	/// Only cookies whose name contains this substring
+	#[arg(long, value_name = "SUBSTR")]
+	#[serde(default)]
+	pub name: Option<String>,
+	/// Only cookies whose domain contains this substring (case-insensitive)
+	#[arg(long, value_name = "SUBSTR")]
+	#[serde(default)]
+	pub domain: Option<String>,
+	/// Only cookies with the Secure attribute
+	#[arg(long)]
+	#[serde(default, alias = "secure_only")]
+	pub secure_only: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
 pub struct CookiesResolved {
 	pub target: ResolvedTarget,
 	pub format: String,
+	pub filter: CookieFilter,
+}
+
+/// Narrows the cookies `auth.cookies` returns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CookieFilter {
+	pub name: Option<String>,
+	pub domain: Option<String>,
+	pub secure_only: bool,
+}
+
+impl CookieFilter {
+	pub fn matches(&self, cookie: &Cookie) -> bool {
+		let name = self.name.as_ref().is_none_or(|name| cookie.name.contains(name.as_str()));
+		let domain = self.domain.as_ref().is_none_or(|domain| {
+			cookie
+				.domain
+				.as_ref()
+				.is_some_and(|cookie_domain| cookie_domain.to_ascii_lowercase().contains(&domain.to_ascii_lowercase()))
+		});
+		let secure = !self.secure_only || cookie.secure == Some(true);
+		name && domain && secure
+	}
+
+	/// Echoes the set filters into the command's `extra` inputs.
+	fn record(&self, extra: &mut serde_json::Value) {
+		if let Some(name) = &self.name {
+			extra["name"] = name.as_str().into();
+		}
+		if let Some(domain) = &self.domain {
+			extra["domain"] = domain.as_str().into();
+		}
+		if self.secure_only {
+			extra["secureOnly"] = true.into();
+		}
+	}
 }
 
 impl CookiesResolved {
@@ -130,8 +178,13 @@ impl Resolve for CookiesRaw {
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<CookiesResolved> {
 		let target = env.resolve_target(self.url, TargetPolicy::AllowCurrentPage)?;
 		let format = self.format.unwrap_or_else(|| "table".to_string());
+		let filter = CookieFilter {
+			name: self.name,
+			domain: self.domain,
+			secure_only: self.secure_only.unwrap_or(false),
+		};
 
-		Ok(CookiesResolved { target, format })
+		Ok(CookiesResolved { target, format, filter })
 	}
 }
 
@@ -151,11 +204,13 @@ impl CommandDef for CookiesCommand {
 	{
 		Box::pin(async move {
 			let data = cookies_resolved(args, exec.ctx, exec.session, exec.last_url).await?;
+			let mut extra = serde_json::json!({ "format": args.format });
+			args.filter.record(&mut extra);
 
 			Ok(CommandOutcome {
 				inputs: CommandInputs {
 					url: args.target.url_str().map(str::to_string),
-					extra: Some(serde_json::json!({ "format": args.format })),
+					extra: Some(extra),
 					..Default::default()
 				},
 				data,
@@ -304,7 +359,7 @@ async fn login_resolved(
 		.session(
 			SessionRequest::from_context(WaitUntil::Load, ctx)
 				.with_headless(false)
-				.with_auth_file(None)
+				.with_auth_files(&[])
 				.with_preferred_url(preferred_url),
 		)
 		.await?;
@@ -353,7 +408,7 @@ async fn login_resolved(
 		eprintln!("  Cookies: {}", state.cookies.len());
 		eprintln!("  Origins with localStorage: {}", state.origins.len());
 		eprintln!();
-		eprintln!("Use with other commands: pw --auth {} <command>", args.output.display());
+		eprintln!("Use with other commands: pw exec <op> --auth {}", args.output.display());
 	}
 
 	session.close().await?;
@@ -382,8 +437,9 @@ async fn cookies_resolved(args: &CookiesResolved, ctx: &CommandContext, session:
 		Target::CurrentPage => session.page().url(),
 	};
 
-	let cookies = session.context().cookies(Some(vec![&cookie_url])).await?;
+	let mut cookies = session.context().cookies(Some(vec![&cookie_url])).await?;
 	session.close().await?;
+	cookies.retain(|cookie| args.filter.matches(cookie));
 
 	Ok(serde_json::json!({
 		"url": cookie_url,
@@ -463,3 +519,37 @@ fn format_expiry(expires: Option<f64>) -> String {
 		d => format!("{}d", d / 86400),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cookie_filter_narrows_by_name_domain_and_secure() {
+		let session = Cookie::new("session_token", "v", ".Example.com").secure(true);
+		let tracking = Cookie::new("_ga", "v", "ads.tracker.net");
+
+		assert!(CookieFilter::default().matches(&tracking));
+
+		let by_name = CookieFilter {
+			name: Some("token".into()),
+			..Default::default()
+		};
+		assert!(by_name.matches(&session));
+		assert!(!by_name.matches(&tracking));
+
+		let by_domain = CookieFilter {
+			domain: Some("example.COM".into()),
+			..Default::default()
+		};
+		assert!(by_domain.matches(&session));
+		assert!(!by_domain.matches(&tracking));
+
+		let secure_only = CookieFilter {
+			secure_only: true,
+			..Default::default()
+		};
+		assert!(secure_only.matches(&session));
+		assert!(!secure_only.matches(&tracking));
+	}
+}
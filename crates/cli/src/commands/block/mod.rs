@@ -0,0 +1,158 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::context::BlockConfig;
+use crate::error::{PwError, Result};
+use crate::output::CommandInputs;
+use crate::target::ResolveEnv;
+
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSetRaw {
+	/// URL pattern to block, e.g. `*.png`, `*googletagmanager*` or `**/api/track/**`
+	#[arg(value_name = "PATTERN")]
+	pub pattern: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockSetResolved {
+	pub pattern: String,
+}
+
+impl Resolve for BlockSetRaw {
+	type Output = BlockSetResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let pattern = self.pattern.trim();
+		if pattern.is_empty() {
+			return Err(PwError::Context("Block pattern must not be empty".into()));
+		}
+		Ok(BlockSetResolved { pattern: pattern.to_string() })
+	}
+}
+
+pub struct BlockSetCommand;
+
+impl CommandDef for BlockSetCommand {
+	const NAME: &'static str = "block.set";
+
+	type Raw = BlockSetRaw;
+	type Resolved = BlockSetResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let added = exec.ctx_state.add_block_pattern(args.pattern.clone());
+			let patterns = exec.ctx_state.block_patterns().to_vec();
+			let data = json!({
+				"added": added,
+				"pattern": args.pattern,
+				"urlGlob": BlockConfig::is_url_glob(&args.pattern),
+				"patterns": patterns,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({ "pattern": args.pattern })),
+					..Default::default()
+				},
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockListRaw {}
+
+#[derive(Debug, Clone)]
+pub struct BlockListResolved;
+
+impl Resolve for BlockListRaw {
+	type Output = BlockListResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(BlockListResolved)
+	}
+}
+
+pub struct BlockListCommand;
+
+impl CommandDef for BlockListCommand {
+	const NAME: &'static str = "block.list";
+
+	type Raw = BlockListRaw;
+	type Resolved = BlockListResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let patterns = exec.ctx_state.block_patterns().to_vec();
+			let count = patterns.len();
+			let data = json!({
+				"patterns": patterns,
+				"count": count,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockClearRaw {}
+
+#[derive(Debug, Clone)]
+pub struct BlockClearResolved;
+
+impl Resolve for BlockClearRaw {
+	type Output = BlockClearResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(BlockClearResolved)
+	}
+}
+
+pub struct BlockClearCommand;
+
+impl CommandDef for BlockClearCommand {
+	const NAME: &'static str = "block.clear";
+
+	type Raw = BlockClearRaw;
+	type Resolved = BlockClearResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let removed = exec.ctx_state.clear_block_patterns();
+			let data = json!({
+				"cleared": removed > 0,
+				"removed": removed,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
@@ -3,15 +3,15 @@
 use std::time::Duration;
 
 use clap::Args;
-use pw_rs::{ClickOptions, WaitUntil};
+use pw_rs::{ClickOptions, GotoOptions, KeyboardModifier, Locator, MouseButton, Position, WaitUntil};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::commands::contract::{resolve_target_and_selector, standard_delta_with_url, standard_inputs};
-use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::contract::{describe_target, resolve_target_and_selector, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
-use crate::output::{ClickData, DownloadedFile};
+use crate::error::{PwError, Result};
+use crate::output::{ClickData, CommandInputs, DownloadedFile, PopupPage};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget};
 
@@ -37,10 +37,63 @@ pub struct ClickRaw {
 	#[serde(default, alias = "selector_flag")]
 	pub selector_flag: Option<String>,
 
-	/// Time to wait for navigation after click (milliseconds)
+	/// Maximum time to wait for the click to trigger a navigation (milliseconds)
 	#[arg(long, default_value = "500")]
 	#[serde(default, alias = "wait_ms")]
 	pub wait_ms: Option<u64>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+
+	/// Click every matching element in document order
+	#[arg(long)]
+	#[serde(default)]
+	pub all: Option<bool>,
+
+	/// Let Playwright wait for a navigation the click starts before returning
+	///
+	/// Defaults to false: clicks that never navigate then return at once
+	/// instead of risking a timeout. Set to true when the click must not
+	/// return before its navigation is underway.
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default, alias = "wait_after")]
+	pub wait_after: Option<bool>,
+
+	/// Skip actionability checks and click even if the element is covered or disabled
+	///
+	/// The click is still dispatched at the element's coordinates, so an
+	/// overlay on top receives the events and the element itself may not see
+	/// the same pointer events a user click would produce.
+	#[arg(long)]
+	#[serde(default)]
+	pub force: Option<bool>,
+
+	/// Click at "x,y" relative to the element's top-left corner instead of its center
+	#[arg(long, value_name = "X,Y")]
+	#[serde(default)]
+	pub position: Option<String>,
+
+	/// Modifier keys held during the click: comma-separated ctrl, meta, shift, alt, or mod (ctrl, or meta on macOS)
+	#[arg(long, value_name = "KEYS")]
+	#[serde(default)]
+	pub modifiers: Option<String>,
+
+	/// Mouse button to click with: left (default), right, or middle
+	#[arg(long, value_name = "BUTTON")]
+	#[serde(default)]
+	pub button: Option<String>,
+
+	/// Number of clicks in the sequence, e.g. 3 to triple-click and select a paragraph
+	#[arg(long, value_name = "N")]
+	#[serde(default, alias = "click_count")]
+	pub click_count: Option<u32>,
+
+	/// Wait for the click to open a popup and report its URL and title
+	#[arg(long)]
+	#[serde(default, alias = "capture_popup")]
+	pub capture_popup: Option<bool>,
 }
 
 /// Resolved inputs ready for execution.
@@ -49,6 +102,24 @@ pub struct ClickResolved {
 	pub target: ResolvedTarget,
 	pub selector: String,
 	pub wait_ms: u64,
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+	/// Click every match in order instead of a single element.
+	pub all: bool,
+	/// Wait up to `wait_ms` (or the command timeout) for a popup instead of a navigation.
+	pub capture_popup: bool,
+	/// Let Playwright's click auto-wait for navigations it starts.
+	pub wait_after: bool,
+	/// Skip Playwright's actionability checks.
+	pub force: bool,
+	/// Click offset from the element's top-left corner.
+	pub position: Option<Position>,
+	/// Modifier keys held during the click, in the order given.
+	pub modifiers: Vec<KeyboardModifier>,
+	/// Mouse button to click with.
+	pub button: MouseButton,
+	/// Clicks in the sequence; always at least 1.
+	pub click_count: u32,
 }
 
 impl Resolve for ClickRaw {
@@ -57,8 +128,28 @@ impl Resolve for ClickRaw {
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, Some("css=button"))?;
 		let wait_ms = self.wait_ms.unwrap_or(0);
+		let position = self.position.as_deref().map(parse_position).transpose()?;
+		let modifiers = self.modifiers.as_deref().map(parse_modifiers).transpose()?.unwrap_or_default();
+		let button = self.button.as_deref().map(parse_button).transpose()?.unwrap_or(MouseButton::Left);
+		let click_count = self.click_count.unwrap_or(1);
+		if click_count == 0 {
+			return Err(PwError::Context("clickCount must be at least 1".into()));
+		}
 
-		Ok(ClickResolved { target, selector, wait_ms })
+		Ok(ClickResolved {
+			target,
+			selector,
+			wait_ms,
+			strict: self.strict.unwrap_or(true),
+			all: self.all.unwrap_or(false),
+			capture_popup: self.capture_popup.unwrap_or(false),
+			wait_after: self.wait_after.unwrap_or(false),
+			force: self.force.unwrap_or(false),
+			position,
+			modifiers,
+			button,
+			click_count,
+		})
 	}
 }
 
@@ -71,6 +162,35 @@ impl CommandDef for ClickCommand {
 	type Resolved = ClickResolved;
 	type Data = ClickData;
 
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let action = match (args.force, args.button) {
+			(true, _) => format!("force-{}", click_verb(args.button)),
+			(false, button) => click_verb(button).to_string(),
+		};
+		let matching = if args.all {
+			"every element"
+		} else if args.strict {
+			"the single element"
+		} else {
+			"the first element"
+		};
+		let mut summary = format!("Will {}, {action} {matching} matching '{}'", describe_target(&args.target), args.selector);
+		if args.click_count > 1 {
+			summary.push_str(&format!(" {} times", args.click_count));
+		}
+		if args.capture_popup {
+			summary.push_str(&format!(", capture the popup it opens (up to {} ms)", args.wait_ms));
+		} else if args.wait_ms > 0 {
+			summary.push_str(&format!(", wait {} ms", args.wait_ms));
+		}
+		summary.push('.');
+
+		Some(Explanation {
+			summary,
+			inputs: click_inputs(args),
+		})
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -82,6 +202,10 @@ impl CommandDef for ClickCommand {
 			let selector = args.selector.clone();
 			let selector_for_outcome = selector.clone();
 			let wait_ms = args.wait_ms;
+			let strict = args.strict;
+			let all = args.all;
+			let capture_popup = args.capture_popup;
+			let base_opts = click_options(args);
 
 			let (after_url, data) = run_page_flow(
 				&mut exec,
@@ -90,69 +214,74 @@ impl CommandDef for ClickCommand {
 				ArtifactsPolicy::OnError { command: "click" },
 				move |session, flow| {
 					let selector = selector.clone();
+					let base_opts = base_opts.clone();
 					Box::pin(async move {
 						session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-						let before_url = session
-							.page()
-							.evaluate_value("window.location.href")
-							.await
-							.unwrap_or_else(|_| session.page().url());
-
-						let locator = session.page().locator(&selector).await;
-						let click_opts = ClickOptions::builder()
-							// We compute navigation ourselves via before/after URL checks.
-							// Disabling auto-wait avoids false 30s timeouts on non-navigating clicks.
-							.no_wait_after(true)
-							.timeout(flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64) as f64)
-							.build();
-						match locator.click(Some(click_opts)).await {
-							Ok(()) => {}
-							Err(err) => {
-								let msg = err.to_string();
-								if msg.to_lowercase().contains("timeout") {
-									// Playwright 1.57+ can intermittently hang on locator click
-									// for simple static elements. Fallback to a DOM click.
-									let selector_json = serde_json::to_string(&selector)?;
-									let expr = format!(
-										r#"(() => {{
-                                                const el = document.querySelector({selector});
-                                                if (!el) {{
-                                                    throw new Error("selector not found for click fallback");
-                                                }}
-                                                el.click();
-                                                return true;
-                                            }})()"#,
-										selector = selector_json
-									);
-									session.page().evaluate_value(&expr).await?;
-								} else {
-									return Err(err.into());
+						let before_url = session.evaluate_cached("window.location.href").await.unwrap_or_else(|_| session.page().url());
+
+						let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+						let click_opts = ClickOptions {
+							timeout: Some(timeout_ms as f64),
+							..base_opts
+						};
+						let locators = if all {
+							let locator = session.locator(&selector).await;
+							let count = locator.count().await?;
+							if count == 0 && strict {
+								return Err(PwError::ElementNotFound { selector: selector.clone() });
+							}
+							(0..count).map(|index| locator.nth(index as i32)).collect()
+						} else if strict {
+							vec![session.single_locator(&selector).await?]
+						} else {
+							vec![session.locator(&selector).await]
+						};
+						let clicked = all.then_some(locators.len());
+
+						let mut warnings = Vec::new();
+						if let Some(position) = click_opts.position {
+							for locator in &locators {
+								if let Some(warning) = position_warning(locator, &selector, position).await {
+									warn!(target = "pw", %warning, "click position outside element");
+									warnings.push(warning);
 								}
 							}
 						}
 
-						if wait_ms > 0 {
-							tokio::time::sleep(Duration::from_millis(wait_ms)).await;
-						}
+						let click_all = async {
+							for locator in &locators {
+								click_locator(locator, &click_opts).await?;
+							}
+							Ok(())
+						};
+						let mut popup = None;
+						let committed = if capture_popup {
+							let timeout = Duration::from_millis(if wait_ms > 0 { wait_ms } else { timeout_ms });
+							popup = match session.page().expect_popup(click_all, Some(timeout)).await {
+								Ok(page) => Some(Some(describe_popup(&page, timeout_ms).await?)),
+								Err(pw_rs::Error::Timeout(_)) => Some(None),
+								Err(err) => return Err(err.into()),
+							};
+							false
+						} else if wait_ms > 0 {
+							let options = GotoOptions::new().timeout(Duration::from_millis(wait_ms)).wait_until(WaitUntil::Commit);
+							match session.page().expect_navigation(click_all, Some(options)).await {
+								Ok(navigation) => navigation.error.is_none(),
+								Err(pw_rs::Error::Timeout(_)) => false,
+								Err(err) => return Err(err.into()),
+							}
+						} else {
+							click_all.await?;
+							false
+						};
+						session.invalidate_evaluations();
 
-						let after_url = session
-							.page()
-							.evaluate_value("window.location.href")
-							.await
-							.unwrap_or_else(|_| session.page().url());
+						let after_url = session.evaluate_cached("window.location.href").await.unwrap_or_else(|_| session.page().url());
 
-						let navigated = before_url != after_url;
+						let navigated = committed || before_url != after_url;
 
-						let downloads: Vec<DownloadedFile> = session
-							.downloads()
-							.into_iter()
-							.map(|d| DownloadedFile {
-								url: d.url,
-								suggested_filename: d.suggested_filename,
-								path: d.path,
-							})
-							.collect();
+						let downloads: Vec<DownloadedFile> = session.downloads().into_iter().map(DownloadedFile::from).collect();
 
 						let data = ClickData {
 							before_url,
@@ -160,6 +289,9 @@ impl CommandDef for ClickCommand {
 							navigated,
 							selector: selector.clone(),
 							downloads,
+							clicked,
+							popup,
+							warnings,
 						};
 
 						Ok((after_url, data))
@@ -168,7 +300,7 @@ impl CommandDef for ClickCommand {
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&selector_for_outcome), None, None, None);
+			let inputs = click_inputs(args);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -179,9 +311,196 @@ impl CommandDef for ClickCommand {
 	}
 }
 
+/// Inputs echoed for a click, listing only options that differ from their defaults.
+fn click_inputs(args: &ClickResolved) -> CommandInputs {
+	let mut extra = serde_json::Map::new();
+	if args.wait_ms > 0 {
+		extra.insert("waitMs".into(), args.wait_ms.into());
+	}
+	if !args.strict {
+		extra.insert("strict".into(), false.into());
+	}
+	if args.all {
+		extra.insert("all".into(), true.into());
+	}
+	if args.capture_popup {
+		extra.insert("capturePopup".into(), true.into());
+	}
+	if args.wait_after {
+		extra.insert("waitAfter".into(), true.into());
+	}
+	if args.force {
+		extra.insert("force".into(), true.into());
+	}
+	if let Some(position) = args.position {
+		extra.insert("position".into(), format!("{},{}", position.x, position.y).into());
+	}
+	if args.button != MouseButton::Left {
+		extra.insert("button".into(), button_name(args.button).into());
+	}
+	if args.click_count > 1 {
+		extra.insert("clickCount".into(), args.click_count.into());
+	}
+	if !args.modifiers.is_empty() {
+		extra.insert(
+			"modifiers".into(),
+			args.modifiers.iter().map(|m| modifier_name(*m)).collect::<Vec<_>>().join(",").into(),
+		);
+	}
+	let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+	standard_inputs(&args.target, Some(&args.selector), None, None, extra)
+}
+
+/// Waits for a popup to load and reads its URL and title.
+async fn describe_popup(page: &pw_rs::Page, timeout_ms: u64) -> Result<PopupPage> {
+	page.wait_for_load_state(WaitUntil::Load, Duration::from_millis(timeout_ms)).await?;
+	let url = page.evaluate_value("window.location.href").await.unwrap_or_else(|_| page.url());
+	let title = page.title().await.unwrap_or_default();
+	Ok(PopupPage { url, title })
+}
+
+/// Builds the options shared by every click, leaving the timeout to the flow.
+///
+/// Playwright's post-click auto-wait is off unless `wait_after` is set:
+/// navigation is detected by [`pw_rs::Page::expect_navigation`] around the
+/// click, and the auto-wait would otherwise stall non-navigating clicks.
+/// Other options are only sent when set.
+fn click_options(args: &ClickResolved) -> ClickOptions {
+	ClickOptions {
+		no_wait_after: Some(!args.wait_after),
+		force: args.force.then_some(true),
+		position: args.position,
+		modifiers: (!args.modifiers.is_empty()).then(|| args.modifiers.clone()),
+		button: (args.button != MouseButton::Left).then_some(args.button),
+		click_count: (args.click_count > 1).then_some(args.click_count),
+		..ClickOptions::default()
+	}
+}
+
+/// Parses an `x,y` click offset.
+pub(crate) fn parse_position(raw: &str) -> Result<Position> {
+	let invalid = || PwError::Context(format!("invalid position '{raw}': expected 'x,y' with non-negative numbers"));
+	let (x, y) = raw.split_once(',').ok_or_else(invalid)?;
+	let x: f64 = x.trim().parse().map_err(|_| invalid())?;
+	let y: f64 = y.trim().parse().map_err(|_| invalid())?;
+	if !(x >= 0.0 && y >= 0.0) {
+		return Err(invalid());
+	}
+	Ok(Position { x, y })
+}
+
+/// Parses a mouse button name.
+fn parse_button(raw: &str) -> Result<MouseButton> {
+	match raw.trim().to_ascii_lowercase().as_str() {
+		"left" => Ok(MouseButton::Left),
+		"right" => Ok(MouseButton::Right),
+		"middle" => Ok(MouseButton::Middle),
+		_ => Err(PwError::Context(format!("unknown button '{raw}': expected left, right, or middle"))),
+	}
+}
+
+/// Canonical input name for a mouse button.
+fn button_name(button: MouseButton) -> &'static str {
+	match button {
+		MouseButton::Left => "left",
+		MouseButton::Right => "right",
+		MouseButton::Middle => "middle",
+	}
+}
+
+/// Verb used in explanations for a click with `button`.
+fn click_verb(button: MouseButton) -> &'static str {
+	match button {
+		MouseButton::Left => "click",
+		MouseButton::Right => "right-click",
+		MouseButton::Middle => "middle-click",
+	}
+}
+
+/// Parses comma-separated modifier key names, rejecting unknown names and duplicates.
+pub(crate) fn parse_modifiers(raw: &str) -> Result<Vec<KeyboardModifier>> {
+	let mut modifiers = Vec::new();
+	for name in raw.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+		let modifier = match name.to_ascii_lowercase().as_str() {
+			"ctrl" | "control" => KeyboardModifier::Control,
+			"meta" | "cmd" | "command" => KeyboardModifier::Meta,
+			"shift" => KeyboardModifier::Shift,
+			"alt" | "option" => KeyboardModifier::Alt,
+			"mod" | "controlormeta" => KeyboardModifier::ControlOrMeta,
+			_ => {
+				return Err(PwError::Context(format!("unknown modifier '{name}': expected ctrl, meta, shift, alt, or mod")));
+			}
+		};
+		if !modifiers.contains(&modifier) {
+			modifiers.push(modifier);
+		}
+	}
+	Ok(modifiers)
+}
+
+/// Canonical input name for a modifier key.
+pub(crate) fn modifier_name(modifier: KeyboardModifier) -> &'static str {
+	match modifier {
+		KeyboardModifier::Alt => "alt",
+		KeyboardModifier::Control => "ctrl",
+		KeyboardModifier::Meta => "meta",
+		KeyboardModifier::Shift => "shift",
+		KeyboardModifier::ControlOrMeta => "mod",
+	}
+}
+
+/// Describes a click offset that falls outside the element's bounding box, if it does.
+async fn position_warning(locator: &Locator, selector: &str, position: Position) -> Option<String> {
+	let size = locator
+		.evaluate("el => { const r = el.getBoundingClientRect(); return [r.width, r.height]; }", None)
+		.await
+		.ok()?;
+	let width = size.get(0)?.as_f64()?;
+	let height = size.get(1)?.as_f64()?;
+	(position.x > width || position.y > height)
+		.then(|| format!("position {},{} is outside the {width}x{height} bounds of '{selector}'", position.x, position.y))
+}
+
+/// Clicks a single element, falling back to a DOM click when Playwright times out.
+///
+/// The fallback only runs for a plain click: see [`dom_click_fallback_allowed`].
+async fn click_locator(locator: &Locator, click_opts: &ClickOptions) -> pw_rs::Result<()> {
+	match locator.click(Some(click_opts.clone())).await {
+		Ok(()) => Ok(()),
+		Err(err) => {
+			let msg = err.to_string();
+			if msg.to_lowercase().contains("timeout") && dom_click_fallback_allowed(click_opts) {
+				// Playwright 1.57+ can intermittently hang on locator click
+				// for simple static elements. Fallback to a DOM click.
+				locator.evaluate("el => el.click()", None).await?;
+				Ok(())
+			} else {
+				Err(err)
+			}
+		}
+	}
+}
+
+/// Whether `el.click()` performs the same click as `click_opts`.
+///
+/// A DOM click is a single, unmodified left click with no pointer position,
+/// so any other button, click count, modifier, position, delay, `force` or
+/// `trial` rules it out and the timeout error is returned instead. `timeout`
+/// and `no_wait_after` only govern waiting and are ignored.
+fn dom_click_fallback_allowed(click_opts: &ClickOptions) -> bool {
+	matches!(click_opts.button, None | Some(MouseButton::Left))
+		&& matches!(click_opts.click_count, None | Some(1))
+		&& click_opts.modifiers.as_ref().is_none_or(Vec::is_empty)
+		&& click_opts.position.is_none()
+		&& click_opts.delay.is_none()
+		&& click_opts.force != Some(true)
+		&& click_opts.trial != Some(true)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::target::{Target, TargetSource};
 
 	#[test]
 	fn click_raw_deserialize() {
@@ -198,4 +517,173 @@ mod tests {
 		let raw: ClickRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.wait_ms, None);
 	}
+
+	#[test]
+	fn click_raw_strict_opt_out() {
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "button", "strict": false}"#).unwrap();
+		assert_eq!(raw.strict, Some(false));
+
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "button"}"#).unwrap();
+		assert_eq!(raw.strict, None);
+	}
+
+	#[test]
+	fn click_raw_all() {
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "li", "all": true, "strict": false}"#).unwrap();
+		assert_eq!(raw.all, Some(true));
+		assert_eq!(raw.strict, Some(false));
+	}
+
+	#[test]
+	fn click_raw_capture_popup() {
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "a", "capturePopup": true}"#).unwrap();
+		assert_eq!(raw.capture_popup, Some(true));
+	}
+
+	fn resolved() -> ClickResolved {
+		ClickResolved {
+			target: ResolvedTarget {
+				target: Target::Navigate(url::Url::parse("https://example.com").unwrap()),
+				source: TargetSource::Explicit,
+			},
+			selector: "button.submit".into(),
+			wait_ms: 500,
+			strict: true,
+			all: false,
+			capture_popup: false,
+			wait_after: false,
+			force: false,
+			position: None,
+			modifiers: Vec::new(),
+			button: MouseButton::Left,
+			click_count: 1,
+		}
+	}
+
+	#[test]
+	fn wait_after_flips_no_wait_after() {
+		assert_eq!(click_options(&resolved()).no_wait_after, Some(true));
+		let args = ClickResolved {
+			wait_after: true,
+			..resolved()
+		};
+		assert_eq!(click_options(&args).no_wait_after, Some(false));
+	}
+
+	#[test]
+	fn force_is_sent_only_when_set() {
+		assert_eq!(click_options(&resolved()).force, None);
+		let args = ClickResolved { force: true, ..resolved() };
+		assert_eq!(click_options(&args).force, Some(true));
+	}
+
+	#[test]
+	fn position_parses_and_reaches_click_options() {
+		assert_eq!(parse_position("30,40").unwrap(), Position { x: 30.0, y: 40.0 });
+		assert_eq!(parse_position(" 0.5 , 2 ").unwrap(), Position { x: 0.5, y: 2.0 });
+		assert!(parse_position("30").is_err());
+		assert!(parse_position("a,b").is_err());
+		assert!(parse_position("-1,5").is_err());
+
+		let args = ClickResolved {
+			position: Some(Position { x: 30.0, y: 40.0 }),
+			..resolved()
+		};
+		assert_eq!(click_options(&args).position, Some(Position { x: 30.0, y: 40.0 }));
+		assert_eq!(click_inputs(&args).extra, Some(serde_json::json!({ "waitMs": 500, "position": "30,40" })));
+	}
+
+	#[test]
+	fn modifiers_parse_and_reach_click_options() {
+		assert_eq!(
+			parse_modifiers("ctrl, Shift,ctrl").unwrap(),
+			vec![KeyboardModifier::Control, KeyboardModifier::Shift]
+		);
+		assert_eq!(parse_modifiers("meta,alt,mod").unwrap().len(), 3);
+		assert!(parse_modifiers("hyper").is_err());
+
+		assert_eq!(click_options(&resolved()).modifiers, None);
+		let args = ClickResolved {
+			modifiers: vec![KeyboardModifier::Control, KeyboardModifier::Shift],
+			..resolved()
+		};
+		assert_eq!(click_options(&args).modifiers, Some(vec![KeyboardModifier::Control, KeyboardModifier::Shift]));
+		assert_eq!(click_inputs(&args).extra, Some(serde_json::json!({ "waitMs": 500, "modifiers": "ctrl,shift" })));
+	}
+
+	#[test]
+	fn button_parses_and_reaches_click_options() {
+		assert_eq!(parse_button("Right").unwrap(), MouseButton::Right);
+		assert_eq!(parse_button("middle").unwrap(), MouseButton::Middle);
+		assert!(parse_button("back").is_err());
+
+		assert_eq!(click_options(&resolved()).button, None);
+		let args = ClickResolved {
+			button: MouseButton::Right,
+			..resolved()
+		};
+		assert_eq!(click_options(&args).button, Some(MouseButton::Right));
+		assert_eq!(click_inputs(&args).extra, Some(serde_json::json!({ "waitMs": 500, "button": "right" })));
+		assert!(ClickCommand::explain(&args).unwrap().summary.contains("right-click the single element"));
+	}
+
+	#[test]
+	fn click_count_reaches_click_options() {
+		assert_eq!(click_options(&resolved()).click_count, None);
+		let args = ClickResolved { click_count: 3, ..resolved() };
+		assert_eq!(click_options(&args).click_count, Some(3));
+		assert_eq!(click_inputs(&args).extra, Some(serde_json::json!({ "waitMs": 500, "clickCount": 3 })));
+
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "p", "clickCount": 3}"#).unwrap();
+		assert_eq!(raw.click_count, Some(3));
+	}
+
+	#[test]
+	fn dom_fallback_only_for_plain_clicks() {
+		let plain = ClickOptions {
+			timeout: Some(5000.0),
+			..click_options(&resolved())
+		};
+		assert!(dom_click_fallback_allowed(&plain));
+
+		let customized = [
+			ClickResolved {
+				button: MouseButton::Right,
+				..resolved()
+			},
+			ClickResolved {
+				button: MouseButton::Middle,
+				..resolved()
+			},
+			ClickResolved {
+				modifiers: vec![KeyboardModifier::Shift],
+				..resolved()
+			},
+			ClickResolved {
+				position: Some(Position { x: 1.0, y: 2.0 }),
+				..resolved()
+			},
+			ClickResolved { click_count: 2, ..resolved() },
+			ClickResolved { force: true, ..resolved() },
+		];
+		for args in &customized {
+			assert!(
+				!dom_click_fallback_allowed(&click_options(args)),
+				"fallback allowed for {:?}",
+				click_options(args)
+			);
+		}
+	}
+
+	#[test]
+	fn explain_describes_click() {
+		let args = resolved();
+		let explanation = ClickCommand::explain(&args).unwrap();
+		assert_eq!(
+			explanation.summary,
+			"Will navigate to https://example.com/, click the single element matching 'button.submit', wait 500 ms."
+		);
+		assert_eq!(explanation.inputs.selector.as_deref(), Some("button.submit"));
+		assert_eq!(explanation.inputs.extra, Some(serde_json::json!({ "waitMs": 500 })));
+	}
 }
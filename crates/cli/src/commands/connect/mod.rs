@@ -12,7 +12,7 @@ use serde_json::json;
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
 use crate::error::Result;
 use crate::output::CommandInputs;
-use crate::session::connect::resolve_connect_port;
+use crate::session::connect::{LIST_PORTS, resolve_connect_port};
 use crate::session::connect_service::ConnectService;
 use crate::target::ResolveEnv;
 
@@ -35,6 +35,10 @@ pub struct ConnectRaw {
 	#[arg(long)]
 	#[serde(default)]
 	pub discover: bool,
+	/// Lists every remote-debugging browser on ports 9222-9230 and the profile's port.
+	#[arg(long)]
+	#[serde(default)]
+	pub list: bool,
 	/// Kills browser process bound to the resolved debug port.
 	#[arg(long)]
 	#[serde(default)]
@@ -60,6 +64,8 @@ pub struct ConnectResolved {
 	pub launch: bool,
 	/// Discovers an already-running remote-debugging browser.
 	pub discover: bool,
+	/// Lists every remote-debugging browser on ports 9222-9230 and the profile's port.
+	pub list: bool,
 	/// Kills browser process bound to the resolved debug port.
 	pub kill: bool,
 	/// Explicit remote-debugging port.
@@ -77,6 +83,7 @@ impl Resolve for ConnectRaw {
 			clear: self.clear,
 			launch: self.launch,
 			discover: self.discover,
+			list: self.list,
 			kill: self.kill,
 			port: self.port,
 			user_data_dir: self.user_data_dir,
@@ -100,7 +107,7 @@ impl CommandDef for ConnectCommand {
 	{
 		Box::pin(async move {
 			let port = resolve_connect_port(exec.ctx_state, args.port);
-			let mut service = ConnectService::new(exec.ctx_state, exec.ctx.auth_file());
+			let mut service = ConnectService::new(exec.ctx_state, exec.ctx.auth_files());
 
 			let data = if args.kill {
 				service.kill(port).await?
@@ -108,6 +115,9 @@ impl CommandDef for ConnectCommand {
 				service.clear()
 			} else if args.launch {
 				service.launch(port, args.user_data_dir.as_deref()).await?
+			} else if args.list {
+				let extra_port = (!LIST_PORTS.contains(&port)).then_some(port);
+				service.list(LIST_PORTS.chain(extra_port)).await
 			} else if args.discover {
 				service.discover(port).await?
 			} else if let Some(ep) = &args.endpoint {
@@ -123,6 +133,7 @@ impl CommandDef for ConnectCommand {
 						"clear": args.clear,
 						"launch": args.launch,
 						"discover": args.discover,
+						"list": args.list,
 						"kill": args.kill,
 						"port": args.port,
 						"userDataDir": args.user_data_dir,
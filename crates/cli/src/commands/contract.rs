@@ -7,6 +7,8 @@
 
 use std::path::{Path, PathBuf};
 
+use serde::Deserialize;
+
 use crate::args;
 use crate::commands::def::ContextDelta;
 use crate::error::Result;
@@ -50,6 +52,25 @@ pub fn resolve_target_and_explicit_selector(
 	Ok((target, selector))
 }
 
+/// Deserialize a list that batch JSON may also give as a single value.
+pub fn one_or_many<'de, D, T>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+	T: Deserialize<'de>,
+{
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum OneOrMany<T> {
+		One(T),
+		Many(Vec<T>),
+	}
+
+	Ok(match OneOrMany::deserialize(deserializer)? {
+		OneOrMany::One(value) => vec![value],
+		OneOrMany::Many(values) => values,
+	})
+}
+
 /// Build standard command input metadata.
 pub fn standard_inputs(
 	target: &ResolvedTarget,
@@ -67,6 +88,14 @@ pub fn standard_inputs(
 	}
 }
 
+/// Describe where a command runs, as the opening clause of an explanation.
+pub fn describe_target(target: &ResolvedTarget) -> String {
+	match target.url_str() {
+		Some(url) => format!("navigate to {url}"),
+		None => "use the current page".to_string(),
+	}
+}
+
 /// Build a standard context delta using the resolved target URL.
 pub fn standard_delta(target: &ResolvedTarget, selector: Option<&str>, output: Option<&Path>) -> ContextDelta {
 	standard_delta_with_url(target.url_str().map(String::from), selector, output)
@@ -111,6 +140,16 @@ mod tests {
 		assert_eq!(inputs.extra, Some(serde_json::json!({ "flag": true })));
 	}
 
+	#[test]
+	fn describe_target_names_url_or_current_page() {
+		assert_eq!(describe_target(&resolved_target("https://example.com")), "navigate to https://example.com/");
+		let current = ResolvedTarget {
+			target: Target::CurrentPage,
+			source: TargetSource::Explicit,
+		};
+		assert_eq!(describe_target(&current), "use the current page");
+	}
+
 	#[test]
 	fn standard_delta_uses_target_url() {
 		let target = resolved_target("https://example.com/page");
@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use clap::Args;
@@ -30,18 +31,29 @@ pub struct DaemonStartRaw {
 	#[arg(long)]
 	#[serde(default)]
 	pub foreground: bool,
+	/// Close browsers nobody holds after this many seconds unused.
+	#[arg(long = "idle-timeout", value_name = "SECS")]
+	#[serde(default, alias = "idle_timeout_secs")]
+	pub idle_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DaemonStartResolved {
 	pub foreground: bool,
+	pub idle_timeout: Option<Duration>,
 }
 
 impl Resolve for DaemonStartRaw {
 	type Output = DaemonStartResolved;
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
-		Ok(DaemonStartResolved { foreground: self.foreground })
+		if self.idle_timeout_secs == Some(0) {
+			return Err(PwError::Context("--idle-timeout must be at least 1 second".to_string()));
+		}
+		Ok(DaemonStartResolved {
+			foreground: self.foreground,
+			idle_timeout: self.idle_timeout_secs.map(Duration::from_secs),
+		})
 	}
 }
 
@@ -75,7 +87,7 @@ impl CommandDef for DaemonStartCommand {
 					));
 				}
 
-				let daemon = Daemon::start().await?;
+				let daemon = Daemon::start().await?.with_idle_timeout(args.idle_timeout);
 				let (ready_tx, ready_rx) = tokio::sync::oneshot::channel();
 				let run_task = tokio::spawn(async move { daemon.run_with_ready(Some(ready_tx)).await });
 
@@ -95,7 +107,8 @@ impl CommandDef for DaemonStartCommand {
 					},
 					data: json!({
 						"started": true,
-						"foreground": true
+						"foreground": true,
+						"idle_timeout_secs": args.idle_timeout.map(|timeout| timeout.as_secs())
 					}),
 					delta: ContextDelta::default(),
 				});
@@ -132,10 +145,12 @@ impl CommandDef for DaemonStartCommand {
 
 				let exe = std::env::current_exe().map_err(|e| PwError::Anyhow(anyhow!("Failed to get executable path: {e}")))?;
 
-				let mut child = std::process::Command::new(&exe)
-					.arg("daemon")
-					.arg("start")
-					.arg("--foreground")
+				let mut command = std::process::Command::new(&exe);
+				command.arg("daemon").arg("start").arg("--foreground");
+				if let Some(timeout) = args.idle_timeout {
+					command.arg("--idle-timeout").arg(timeout.as_secs().to_string());
+				}
+				let mut child = command
 					.stdin(std::process::Stdio::null())
 					.stdout(std::process::Stdio::null())
 					.stderr(std::process::Stdio::null())
@@ -266,9 +281,11 @@ impl CommandDef for DaemonStatusCommand {
 		Box::pin(async move {
 			let data = if let Some(true) = daemon::ping().await? {
 				let list = daemon::list_browsers().await?.unwrap_or_default();
+				let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs();
 				json!({
 					"running": true,
-					"browsers": list
+					"browsers": list,
+					"leases": daemon::lease_statuses(&list, now)
 				})
 			} else {
 				json!({
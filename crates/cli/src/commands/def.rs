@@ -35,6 +35,49 @@ pub enum ExecMode {
 	Batch,
 }
 
+/// Whether a command is described before, or instead of, running (`--explain`, `--explain-only`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplainMode {
+	#[default]
+	Off,
+	/// Attach the explanation to the response and run the command.
+	Describe,
+	/// Report the explanation and stop before executing.
+	Only,
+}
+
+impl ExplainMode {
+	pub fn from_flags(explain: bool, explain_only: bool) -> Self {
+		match (explain, explain_only) {
+			(_, true) => Self::Only,
+			(true, false) => Self::Describe,
+			(false, false) => Self::Off,
+		}
+	}
+}
+
+/// Plain description of what a resolved command will do.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+	/// Prose summary, e.g. "Will navigate to X, click the element matching Y."
+	pub summary: String,
+	/// The inputs the command will run with.
+	pub inputs: CommandInputs,
+}
+
+impl Explanation {
+	/// Fallback for commands without a dedicated description: names the op and echoes its raw input.
+	pub fn generic(command: &str, input: serde_json::Value) -> Self {
+		Self {
+			summary: format!("Will run {command}."),
+			inputs: CommandInputs {
+				extra: Some(input),
+				..CommandInputs::default()
+			},
+		}
+	}
+}
+
 /// Unified execution context; replaces per-command parameter drift.
 pub struct ExecCtx<'exec, 'ctx> {
 	pub mode: ExecMode,
@@ -56,6 +99,12 @@ pub struct ExecCtx<'exec, 'ctx> {
 
 	/// Last URL from context store (for `Target::CurrentPage` preference).
 	pub last_url: Option<&'exec str>,
+
+	/// Hold headed sessions open after the command until Enter (`--pause`).
+	pub pause: bool,
+
+	/// Describe the command before running it, or instead of running it.
+	pub explain: ExplainMode,
 }
 
 /// State mutations to apply after successful command execution.
@@ -87,6 +136,21 @@ pub struct ErasedOutcome {
 	pub inputs: CommandInputs,
 	pub data: serde_json::Value,
 	pub delta: ContextDelta,
+	/// Prose summary when running under `--explain`.
+	pub explanation: Option<String>,
+}
+
+impl ErasedOutcome {
+	/// Outcome for `--explain-only`: the command was resolved but not executed.
+	pub fn unexecuted(command: &'static str, explanation: Explanation) -> Self {
+		Self {
+			command,
+			inputs: explanation.inputs,
+			data: serde_json::json!({ "executed": false }),
+			delta: ContextDelta::default(),
+			explanation: Some(explanation.summary),
+		}
+	}
 }
 
 impl<T: Serialize> CommandOutcome<T> {
@@ -97,6 +161,7 @@ impl<T: Serialize> CommandOutcome<T> {
 			inputs: self.inputs,
 			data: serde_json::to_value(self.data)?,
 			delta: self.delta,
+			explanation: None,
 		})
 	}
 }
@@ -123,6 +188,13 @@ pub trait CommandDef: 'static {
 		Ok(())
 	}
 
+	/// Describe what the command will do with `args`, for `--explain`.
+	///
+	/// Commands returning `None` are described by [`Explanation::generic`].
+	fn explain(_args: &Self::Resolved) -> Option<Explanation> {
+		None
+	}
+
 	/// Execute the command. **Must not print**. Wrapper prints.
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
@@ -0,0 +1,473 @@
+//! Text diff between two pages.
+//!
+//! Loads both URLs in one session, extracts text the way `page.text` does,
+//! and reports a line diff as structured changes plus a unified diff.
+
+use std::sync::LazyLock;
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::commands::page::text::filter_garbage;
+use crate::error::{PwError, Result};
+use crate::output::{DiffTextData, LineChangeKind, TextLineChange};
+use crate::session::SessionHandle;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Lines of unchanged context around each hunk in the unified diff.
+const CONTEXT_LINES: usize = 3;
+
+static NUMBER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+(?:[.,]\d+)*").expect("NUMBER_RE should compile"));
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffTextRaw {
+	/// First URL (positional)
+	#[serde(default, alias = "url_a")]
+	pub url_a: Option<String>,
+
+	/// Second URL (positional)
+	#[serde(default, alias = "url_b")]
+	pub url_b: Option<String>,
+
+	/// CSS selector to extract text from (default: body)
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Compare lines with whitespace collapsed, ignoring blank lines
+	#[arg(long)]
+	#[serde(default, alias = "ignore_whitespace")]
+	pub ignore_whitespace: Option<bool>,
+
+	/// Compare lines with every number replaced by a placeholder
+	#[arg(long)]
+	#[serde(default, alias = "ignore_numbers")]
+	pub ignore_numbers: Option<bool>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct DiffTextResolved {
+	pub target_a: ResolvedTarget,
+	pub target_b: ResolvedTarget,
+	pub selector: String,
+	pub normalize: LineNormalizer,
+}
+
+impl Resolve for DiffTextRaw {
+	type Output = DiffTextResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let (Some(url_a), Some(url_b)) = (self.url_a, self.url_b) else {
+			return Err(PwError::Context("diff-text requires both urlA and urlB".into()));
+		};
+
+		Ok(DiffTextResolved {
+			target_a: env.resolve_target(Some(url_a), TargetPolicy::RequireUrl)?,
+			target_b: env.resolve_target(Some(url_b), TargetPolicy::RequireUrl)?,
+			selector: self.selector.unwrap_or_else(|| "body".to_string()),
+			normalize: LineNormalizer {
+				whitespace: self.ignore_whitespace.unwrap_or(false),
+				numbers: self.ignore_numbers.unwrap_or(false),
+			},
+		})
+	}
+}
+
+/// Line normalization applied before comparison.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineNormalizer {
+	/// Trim and collapse whitespace runs; blank lines are dropped.
+	pub whitespace: bool,
+	/// Replace each number (`1,234.5`, `42`) with `#`.
+	pub numbers: bool,
+}
+
+impl LineNormalizer {
+	/// Returns the comparison key for `line`, or `None` when the line should be ignored.
+	fn key(&self, line: &str) -> Option<String> {
+		let mut key = if self.whitespace {
+			line.split_whitespace().collect::<Vec<_>>().join(" ")
+		} else {
+			line.to_string()
+		};
+		if self.whitespace && key.is_empty() {
+			return None;
+		}
+		if self.numbers {
+			key = NUMBER_RE.replace_all(&key, "#").into_owned();
+		}
+		Some(key)
+	}
+}
+
+pub struct DiffTextCommand;
+
+impl CommandDef for DiffTextCommand {
+	const NAME: &'static str = "diff-text";
+
+	type Raw = DiffTextRaw;
+	type Resolved = DiffTextResolved;
+	type Data = DiffTextData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_a = args.target_a.url_str().unwrap_or_default().to_string();
+			let url_b = args.target_b.url_str().unwrap_or_default().to_string();
+			info!(target = "pw", %url_a, %url_b, selector = %args.selector, browser = %exec.ctx.browser, "diff text");
+
+			let selector = args.selector.clone();
+			let normalize = args.normalize;
+			let (flow_url_a, flow_url_b) = (url_a.clone(), url_b.clone());
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target_a,
+				WaitUntil::NetworkIdle,
+				ArtifactsPolicy::Never,
+				move |session, flow| {
+					let selector = selector.clone();
+					let (url_a, url_b) = (flow_url_a.clone(), flow_url_b.clone());
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+						let text_a = extract_text(session, &selector).await?;
+
+						session.goto(&url_b, flow.timeout_ms).await?;
+						let text_b = extract_text(session, &selector).await?;
+
+						let lines_a: Vec<&str> = text_a.lines().collect();
+						let lines_b: Vec<&str> = text_b.lines().collect();
+						let ops = diff_lines(&lines_a, &lines_b, normalize);
+
+						let changes = collect_changes(&ops, &lines_a, &lines_b);
+						let added = changes.iter().filter(|change| change.kind == LineChangeKind::Added).count();
+						let removed = changes.len() - added;
+						let unified = unified_diff(&ops, &lines_a, &lines_b, &url_a, &url_b);
+
+						Ok(DiffTextData {
+							url_a,
+							url_b,
+							selector,
+							identical: changes.is_empty(),
+							added,
+							removed,
+							changes,
+							unified,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let mut extra = serde_json::Map::new();
+			extra.insert("urlB".into(), url_b.clone().into());
+			if args.normalize.whitespace {
+				extra.insert("ignoreWhitespace".into(), true.into());
+			}
+			if args.normalize.numbers {
+				extra.insert("ignoreNumbers".into(), true.into());
+			}
+			let inputs = standard_inputs(&args.target_a, Some(&args.selector), None, None, Some(serde_json::Value::Object(extra)));
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(url_b), Some(&args.selector), None),
+			})
+		})
+	}
+}
+
+/// Extracts the rendered text of the first `selector` match, as `page.text` does.
+async fn extract_text(session: &SessionHandle, selector: &str) -> Result<String> {
+	let locator = session.page().locator(selector).await;
+	if locator.count().await? == 0 {
+		return Err(PwError::ElementNotFound {
+			selector: selector.to_string(),
+		});
+	}
+	Ok(filter_garbage(&locator.inner_text().await?).trim().to_string())
+}
+
+/// One step of a line diff, holding indices into the compared line slices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+	Equal(usize, usize),
+	Removed(usize),
+	Added(usize),
+}
+
+/// Computes a longest-common-subsequence line diff under `normalize`.
+///
+/// Lines the normalizer ignores never appear in the result.
+fn diff_lines(a: &[&str], b: &[&str], normalize: LineNormalizer) -> Vec<DiffOp> {
+	let keyed = |lines: &[&str]| -> Vec<KeyedLine> { lines.iter().enumerate().filter_map(|(i, line)| Some((i, normalize.key(line)?))).collect() };
+	let (a, b) = (keyed(a), keyed(b));
+
+	let prefix = a.iter().zip(&b).take_while(|(x, y)| x.1 == y.1).count();
+	let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|(x, y)| x.1 == y.1).count();
+	let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+	let mut ops: Vec<DiffOp> = a[..prefix].iter().zip(&b[..prefix]).map(|(x, y)| DiffOp::Equal(x.0, y.0)).collect();
+	lcs_ops(mid_a, mid_b, &mut ops);
+	ops.extend(a[a.len() - suffix..].iter().zip(&b[b.len() - suffix..]).map(|(x, y)| DiffOp::Equal(x.0, y.0)));
+	ops
+}
+
+/// A line's index paired with its normalized key.
+type KeyedLine = (usize, String);
+
+/// Appends a longest-common-subsequence diff of `a` and `b` to `ops`.
+///
+/// Uses Hirschberg's divide and conquer, so memory stays linear in the line
+/// counts rather than growing with their product. Within a changed block,
+/// removals come before additions.
+fn lcs_ops(a: &[KeyedLine], b: &[KeyedLine], ops: &mut Vec<DiffOp>) {
+	match a {
+		[] => ops.extend(b.iter().map(|y| DiffOp::Added(y.0))),
+		_ if b.is_empty() => ops.extend(a.iter().map(|x| DiffOp::Removed(x.0))),
+		[x] => match b.iter().position(|y| y.1 == x.1) {
+			Some(j) => {
+				ops.extend(b[..j].iter().map(|y| DiffOp::Added(y.0)));
+				ops.push(DiffOp::Equal(x.0, b[j].0));
+				ops.extend(b[j + 1..].iter().map(|y| DiffOp::Added(y.0)));
+			}
+			None => {
+				ops.push(DiffOp::Removed(x.0));
+				ops.extend(b.iter().map(|y| DiffOp::Added(y.0)));
+			}
+		},
+		_ => {
+			let mid = a.len() / 2;
+			let front = lcs_lengths(a[..mid].iter(), b.iter());
+			let back = lcs_lengths(a[mid..].iter().rev(), b.iter().rev());
+			// `rev` makes `max_by_key` settle on the smallest split among ties.
+			let split = (0..=b.len()).rev().max_by_key(|&k| front[k] + back[b.len() - k]).unwrap_or(0);
+			lcs_ops(&a[..mid], &b[..split], ops);
+			lcs_ops(&a[mid..], &b[split..], ops);
+		}
+	}
+}
+
+/// Returns `row` where `row[k]` is the LCS length of `a` and the first `k` lines of `b`.
+fn lcs_lengths<'a>(a: impl Iterator<Item = &'a KeyedLine>, b: impl Iterator<Item = &'a KeyedLine>) -> Vec<u32> {
+	let b: Vec<&KeyedLine> = b.collect();
+	let mut row = vec![0u32; b.len() + 1];
+	for x in a {
+		// Value of `row[k]` before this pass, i.e. the diagonal neighbour.
+		let mut diagonal = 0;
+		for (k, y) in b.iter().enumerate() {
+			let above = row[k + 1];
+			row[k + 1] = if x.1 == y.1 { diagonal + 1 } else { above.max(row[k]) };
+			diagonal = above;
+		}
+	}
+	row
+}
+
+fn collect_changes(ops: &[DiffOp], a: &[&str], b: &[&str]) -> Vec<TextLineChange> {
+	ops.iter()
+		.filter_map(|op| match *op {
+			DiffOp::Equal(..) => None,
+			DiffOp::Removed(i) => Some(TextLineChange {
+				kind: LineChangeKind::Removed,
+				line: i + 1,
+				text: a[i].to_string(),
+			}),
+			DiffOp::Added(j) => Some(TextLineChange {
+				kind: LineChangeKind::Added,
+				line: j + 1,
+				text: b[j].to_string(),
+			}),
+		})
+		.collect()
+}
+
+/// Renders `ops` as a unified diff with [`CONTEXT_LINES`] of context.
+///
+/// Hunk line numbers refer to the original (unnormalized) text.
+fn unified_diff(ops: &[DiffOp], a: &[&str], b: &[&str], label_a: &str, label_b: &str) -> String {
+	let changed: Vec<usize> = ops
+		.iter()
+		.enumerate()
+		.filter(|(_, op)| !matches!(op, DiffOp::Equal(..)))
+		.map(|(idx, _)| idx)
+		.collect();
+	if changed.is_empty() {
+		return String::new();
+	}
+
+	// Merge changes whose context windows overlap into [start, end) op ranges.
+	let mut hunks: Vec<(usize, usize)> = Vec::new();
+	for &idx in &changed {
+		let start = idx.saturating_sub(CONTEXT_LINES);
+		let end = (idx + CONTEXT_LINES + 1).min(ops.len());
+		match hunks.last_mut() {
+			Some(last) if start <= last.1 => last.1 = end,
+			_ => hunks.push((start, end)),
+		}
+	}
+
+	let mut out = format!("--- {label_a}\n+++ {label_b}\n");
+	for (start, end) in hunks {
+		let hunk = &ops[start..end];
+		let first_a = hunk.iter().find_map(|op| match op {
+			DiffOp::Equal(i, _) | DiffOp::Removed(i) => Some(*i),
+			DiffOp::Added(_) => None,
+		});
+		let first_b = hunk.iter().find_map(|op| match op {
+			DiffOp::Equal(_, j) | DiffOp::Added(j) => Some(*j),
+			DiffOp::Removed(_) => None,
+		});
+		let len_a = hunk.iter().filter(|op| !matches!(op, DiffOp::Added(_))).count();
+		let len_b = hunk.iter().filter(|op| !matches!(op, DiffOp::Removed(_))).count();
+		// An empty side is reported at the line before the hunk, per unified diff convention.
+		let start_a = first_a.map_or_else(|| preceding_line(&ops[..start], true), |i| i + 1);
+		let start_b = first_b.map_or_else(|| preceding_line(&ops[..start], false), |j| j + 1);
+
+		out.push_str(&format!("@@ -{start_a},{len_a} +{start_b},{len_b} @@\n"));
+		for op in hunk {
+			match *op {
+				DiffOp::Equal(i, _) => out.push_str(&format!(" {}\n", a[i])),
+				DiffOp::Removed(i) => out.push_str(&format!("-{}\n", a[i])),
+				DiffOp::Added(j) => out.push_str(&format!("+{}\n", b[j])),
+			}
+		}
+	}
+	out
+}
+
+/// Returns the 1-based number of the last line on one side before `ops` ends, or 0.
+fn preceding_line(ops: &[DiffOp], side_a: bool) -> usize {
+	ops.iter()
+		.rev()
+		.find_map(|op| match (*op, side_a) {
+			(DiffOp::Equal(i, _) | DiffOp::Removed(i), true) => Some(i + 1),
+			(DiffOp::Equal(_, j) | DiffOp::Added(j), false) => Some(j + 1),
+			_ => None,
+		})
+		.unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PLAIN: LineNormalizer = LineNormalizer {
+		whitespace: false,
+		numbers: false,
+	};
+
+	fn lines(text: &str) -> Vec<&str> {
+		text.lines().collect()
+	}
+
+	#[test]
+	fn identical_text_has_no_changes() {
+		let (a, b) = (lines("one\ntwo"), lines("one\ntwo"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		assert!(collect_changes(&ops, &a, &b).is_empty());
+		assert_eq!(unified_diff(&ops, &a, &b, "a", "b"), "");
+	}
+
+	#[test]
+	fn single_changed_line_is_removed_then_added() {
+		let (a, b) = (lines("title\nprice 10\nfooter"), lines("title\nprice 12\nfooter"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		assert_eq!(
+			collect_changes(&ops, &a, &b),
+			vec![
+				TextLineChange {
+					kind: LineChangeKind::Removed,
+					line: 2,
+					text: "price 10".into()
+				},
+				TextLineChange {
+					kind: LineChangeKind::Added,
+					line: 2,
+					text: "price 12".into()
+				},
+			]
+		);
+		assert_eq!(
+			unified_diff(&ops, &a, &b, "a", "b"),
+			"--- a\n+++ b\n@@ -1,3 +1,3 @@\n title\n-price 10\n+price 12\n footer\n"
+		);
+	}
+
+	#[test]
+	fn unified_diff_splits_distant_hunks() {
+		let (a, b) = (lines("x\n1\n2\n3\n4\n5\n6\n7\n8\ny"), lines("X\n1\n2\n3\n4\n5\n6\n7\n8\nY"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		let unified = unified_diff(&ops, &a, &b, "a", "b");
+		assert_eq!(unified.matches("@@ -").count(), 2);
+		assert!(unified.contains("@@ -1,4 +1,4 @@\n-x\n+X\n 1\n 2\n 3\n"));
+		assert!(unified.contains("@@ -7,4 +7,4 @@\n 6\n 7\n 8\n-y\n+Y\n"));
+	}
+
+	#[test]
+	fn pure_insertion_keeps_surrounding_context() {
+		let (a, b) = (lines("a\nb"), lines("a\nnew\nb"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		assert_eq!(unified_diff(&ops, &a, &b, "a", "b"), "--- a\n+++ b\n@@ -1,2 +1,3 @@\n a\n+new\n b\n");
+	}
+
+	#[test]
+	fn insertion_into_empty_text_starts_at_zero() {
+		let (a, b) = (lines(""), lines("new"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		assert_eq!(unified_diff(&ops, &a, &b, "a", "b"), "--- a\n+++ b\n@@ -0,0 +1,1 @@\n+new\n");
+	}
+
+	#[test]
+	fn interleaved_edits_keep_the_longest_common_lines() {
+		let (a, b) = (lines("a\nb\nc\nd\ne"), lines("b\nx\nd\ne\nf"));
+		let ops = diff_lines(&a, &b, PLAIN);
+		let changes: Vec<String> = collect_changes(&ops, &a, &b)
+			.into_iter()
+			.map(|change| format!("{:?} {}", change.kind, change.text))
+			.collect();
+		assert_eq!(changes, ["Removed a", "Removed c", "Added x", "Added f"]);
+	}
+
+	#[test]
+	fn large_unrelated_texts_diff_without_quadratic_memory() {
+		let a: Vec<String> = (0..3000).map(|i| format!("old {i}")).collect();
+		let b: Vec<String> = (0..3000).map(|i| format!("new {i}")).collect();
+		let (a, b): (Vec<&str>, Vec<&str>) = (a.iter().map(String::as_str).collect(), b.iter().map(String::as_str).collect());
+		let ops = diff_lines(&a, &b, PLAIN);
+		assert_eq!(collect_changes(&ops, &a, &b).len(), 6000);
+	}
+
+	#[test]
+	fn ignore_whitespace_collapses_runs_and_blank_lines() {
+		let normalize = LineNormalizer { whitespace: true, ..PLAIN };
+		let (a, b) = (lines("Hello   world\n\nbye"), lines("  Hello world\nbye  "));
+		let ops = diff_lines(&a, &b, normalize);
+		assert!(collect_changes(&ops, &a, &b).is_empty());
+	}
+
+	#[test]
+	fn ignore_numbers_masks_digits() {
+		let normalize = LineNormalizer { numbers: true, ..PLAIN };
+		let (a, b) = (lines("Total: 1,234.50 items\nBuild 42"), lines("Total: 9.99 items\nBuild 43"));
+		let ops = diff_lines(&a, &b, normalize);
+		assert!(collect_changes(&ops, &a, &b).is_empty());
+
+		let (a, b) = (lines("Total: 3"), lines("Sum: 3"));
+		let ops = diff_lines(&a, &b, normalize);
+		assert_eq!(collect_changes(&ops, &a, &b).len(), 2);
+	}
+}
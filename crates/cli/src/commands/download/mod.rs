@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::error::{PwError, Result};
+use crate::output::CommandInputs;
+use crate::target::ResolveEnv;
+
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadSetDirRaw {
+	/// Directory downloads are saved to; created when a session starts
+	#[arg(value_name = "PATH")]
+	pub path: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadSetDirResolved {
+	pub path: PathBuf,
+}
+
+impl Resolve for DownloadSetDirRaw {
+	type Output = DownloadSetDirResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		if self.path.as_os_str().is_empty() {
+			return Err(PwError::Context("Download directory must not be empty".into()));
+		}
+		Ok(DownloadSetDirResolved { path: self.path })
+	}
+}
+
+pub struct DownloadSetDirCommand;
+
+impl CommandDef for DownloadSetDirCommand {
+	const NAME: &'static str = "download.set-dir";
+
+	type Raw = DownloadSetDirRaw;
+	type Resolved = DownloadSetDirResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let changed = exec.ctx_state.set_download_dir(args.path.clone());
+			let data = json!({
+				"changed": changed,
+				"dir": args.path,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({ "path": args.path })),
+					..Default::default()
+				},
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadListRaw {}
+
+#[derive(Debug, Clone)]
+pub struct DownloadListResolved;
+
+impl Resolve for DownloadListRaw {
+	type Output = DownloadListResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(DownloadListResolved)
+	}
+}
+
+/// A file found in the download directory.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedDownload {
+	pub name: String,
+	pub path: PathBuf,
+	pub size_bytes: u64,
+}
+
+pub struct DownloadListCommand;
+
+impl CommandDef for DownloadListCommand {
+	const NAME: &'static str = "download.list";
+
+	type Raw = DownloadListRaw;
+	type Resolved = DownloadListResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let dir = exec.ctx.download_config().dir.clone();
+			let files = match &dir {
+				Some(dir) => saved_downloads(dir)?,
+				None => Vec::new(),
+			};
+			let total_bytes: u64 = files.iter().map(|f| f.size_bytes).sum();
+			let data = json!({
+				"dir": dir,
+				"count": files.len(),
+				"totalBytes": total_bytes,
+				"files": files,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+/// Lists regular files directly inside `dir`, sorted by name.
+///
+/// A directory that does not exist yet holds no downloads.
+fn saved_downloads(dir: &Path) -> Result<Vec<SavedDownload>> {
+	let entries = match std::fs::read_dir(dir) {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut files = Vec::new();
+	for entry in entries {
+		let entry = entry?;
+		let metadata = entry.metadata()?;
+		if !metadata.is_file() {
+			continue;
+		}
+		files.push(SavedDownload {
+			name: entry.file_name().to_string_lossy().into_owned(),
+			path: entry.path(),
+			size_bytes: metadata.len(),
+		});
+	}
+	files.sort_by(|a, b| a.name.cmp(&b.name));
+	Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::TempDir;
+
+	use super::*;
+
+	#[test]
+	fn saved_downloads_lists_files_with_sizes() {
+		let dir = TempDir::new().unwrap();
+		std::fs::write(dir.path().join("b.csv"), "a,b\n1,2\n").unwrap();
+		std::fs::write(dir.path().join("a.txt"), "hello").unwrap();
+		std::fs::create_dir(dir.path().join("nested")).unwrap();
+
+		let files = saved_downloads(dir.path()).unwrap();
+		let names: Vec<_> = files.iter().map(|f| (f.name.as_str(), f.size_bytes)).collect();
+		assert_eq!(names, [("a.txt", 5), ("b.csv", 8)]);
+	}
+
+	#[test]
+	fn saved_downloads_of_missing_dir_is_empty() {
+		let dir = TempDir::new().unwrap();
+		assert!(saved_downloads(&dir.path().join("missing")).unwrap().is_empty());
+	}
+}
@@ -0,0 +1,265 @@
+use clap::Args;
+use pw_rs::Viewport;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::context::EmulationConfig;
+use crate::context_store::types::EmulationDefaults;
+use crate::error::{PwError, Result};
+use crate::output::CommandInputs;
+use crate::target::ResolveEnv;
+use crate::types::{ColorScheme, ReducedMotion};
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulationSetRaw {
+	#[arg(long, value_name = "LOCALE")]
+	#[serde(default)]
+	pub locale: Option<String>,
+	#[arg(long = "timezone", value_name = "TZ")]
+	#[serde(default, alias = "timezone")]
+	pub timezone_id: Option<String>,
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub color_scheme: Option<ColorScheme>,
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub reduced_motion: Option<ReducedMotion>,
+	/// Viewport size as WIDTHxHEIGHT (for example 1280x720).
+	#[arg(long, value_name = "WIDTHxHEIGHT")]
+	#[serde(default)]
+	pub viewport: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmulationSetResolved {
+	pub locale: Option<String>,
+	pub timezone_id: Option<String>,
+	pub color_scheme: Option<ColorScheme>,
+	pub reduced_motion: Option<ReducedMotion>,
+	pub viewport: Option<Viewport>,
+}
+
+impl Resolve for EmulationSetRaw {
+	type Output = EmulationSetResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let viewport = self.viewport.as_deref().map(parse_viewport).transpose()?;
+		let resolved = EmulationSetResolved {
+			locale: self.locale,
+			timezone_id: self.timezone_id,
+			color_scheme: self.color_scheme,
+			reduced_motion: self.reduced_motion,
+			viewport,
+		};
+		if resolved.locale.is_none()
+			&& resolved.timezone_id.is_none()
+			&& resolved.color_scheme.is_none()
+			&& resolved.reduced_motion.is_none()
+			&& resolved.viewport.is_none()
+		{
+			return Err(PwError::Context(
+				"emulation.set requires at least one of locale, timezoneId, colorScheme, reducedMotion, viewport".to_string(),
+			));
+		}
+
+		EmulationConfig {
+			locale: resolved.locale.clone(),
+			timezone_id: resolved.timezone_id.clone(),
+			..Default::default()
+		}
+		.validate()?;
+
+		Ok(resolved)
+	}
+}
+
+pub struct EmulationSetCommand;
+
+impl CommandDef for EmulationSetCommand {
+	const NAME: &'static str = "emulation.set";
+
+	type Raw = EmulationSetRaw;
+	type Resolved = EmulationSetResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let mut emulation = exec.ctx_state.emulation_defaults().cloned().unwrap_or_default();
+			merge_emulation(&mut emulation, args);
+			let changed = exec.ctx_state.set_emulation_defaults(emulation.clone());
+			let data = json!({
+				"changed": changed,
+				"emulation": emulation,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({
+						"locale": args.locale,
+						"timezoneId": args.timezone_id,
+						"colorScheme": args.color_scheme,
+						"reducedMotion": args.reduced_motion,
+						"viewport": args.viewport,
+					})),
+					..Default::default()
+				},
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulationShowRaw {}
+
+#[derive(Debug, Clone)]
+pub struct EmulationShowResolved;
+
+impl Resolve for EmulationShowRaw {
+	type Output = EmulationShowResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(EmulationShowResolved)
+	}
+}
+
+pub struct EmulationShowCommand;
+
+impl CommandDef for EmulationShowCommand {
+	const NAME: &'static str = "emulation.show";
+
+	type Raw = EmulationShowRaw;
+	type Resolved = EmulationShowResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let emulation = exec.ctx_state.emulation_defaults().cloned().unwrap_or_default();
+			let data = json!({
+				"enabled": !emulation.is_empty(),
+				"emulation": emulation,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulationClearRaw {}
+
+#[derive(Debug, Clone)]
+pub struct EmulationClearResolved;
+
+impl Resolve for EmulationClearRaw {
+	type Output = EmulationClearResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(EmulationClearResolved)
+	}
+}
+
+pub struct EmulationClearCommand;
+
+impl CommandDef for EmulationClearCommand {
+	const NAME: &'static str = "emulation.clear";
+
+	type Raw = EmulationClearRaw;
+	type Resolved = EmulationClearResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let cleared = exec.ctx_state.clear_emulation_defaults();
+			let data = json!({ "cleared": cleared });
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+/// Overlays the provided fields onto persisted defaults, keeping the rest.
+fn merge_emulation(emulation: &mut EmulationDefaults, args: &EmulationSetResolved) {
+	if let Some(locale) = &args.locale {
+		emulation.locale = Some(locale.clone());
+	}
+	if let Some(tz) = &args.timezone_id {
+		emulation.timezone_id = Some(tz.clone());
+	}
+	if let Some(scheme) = args.color_scheme {
+		emulation.color_scheme = Some(scheme);
+	}
+	if let Some(motion) = args.reduced_motion {
+		emulation.reduced_motion = Some(motion);
+	}
+	if let Some(viewport) = &args.viewport {
+		emulation.viewport = Some(viewport.clone());
+	}
+}
+
+fn parse_viewport(value: &str) -> Result<Viewport> {
+	let invalid = || PwError::Context(format!("invalid viewport '{value}' (expected WIDTHxHEIGHT, for example 1280x720)"));
+	let (width, height) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+	let width = width.trim().parse::<u32>().map_err(|_| invalid())?;
+	let height = height.trim().parse::<u32>().map_err(|_| invalid())?;
+	if width == 0 || height == 0 {
+		return Err(invalid());
+	}
+	Ok(Viewport { width, height })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_viewport_accepts_width_by_height() {
+		assert_eq!(parse_viewport("1280x720").unwrap(), Viewport { width: 1280, height: 720 });
+		assert!(parse_viewport("1280").is_err());
+		assert!(parse_viewport("0x720").is_err());
+	}
+
+	#[test]
+	fn merge_keeps_unset_fields() {
+		let mut emulation = EmulationDefaults {
+			locale: Some("de-DE".to_string()),
+			color_scheme: Some(ColorScheme::Light),
+			..Default::default()
+		};
+		merge_emulation(
+			&mut emulation,
+			&EmulationSetResolved {
+				locale: None,
+				timezone_id: None,
+				color_scheme: Some(ColorScheme::Dark),
+				reduced_motion: Some(ReducedMotion::Reduce),
+				viewport: None,
+			},
+		);
+		assert_eq!(emulation.locale.as_deref(), Some("de-DE"));
+		assert_eq!(emulation.color_scheme, Some(ColorScheme::Dark));
+		assert_eq!(emulation.reduced_motion, Some(ReducedMotion::Reduce));
+	}
+}
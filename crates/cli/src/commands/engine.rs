@@ -1,50 +1,233 @@
 use std::io::Write;
 use std::path::Path;
+use std::time::Duration;
 
+use futures::stream::{FuturesUnordered, StreamExt};
+use serde::Serialize;
 use serde_json::{Value, json};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 
-use crate::cli::{BatchArgs, DaemonAction, ExecArgs, ProfileAction};
-use crate::commands::def::{ExecCtx, ExecMode};
-use crate::commands::registry::{command_name, lookup_command_exact, run_command};
+use crate::cli::{BatchArgs, DaemonAction, EmulationArgs, ExecArgs, InstallArgs, LaunchArgs, ProfileAction, ReplayArgs};
+use crate::commands::def::{ExecCtx, ExecMode, ExplainMode};
+use crate::commands::registry::{command_name, lookup_command, lookup_command_exact, run_command, validate_input};
 use crate::error::{PwError, Result};
 use crate::output::{CommandError, ErrorCode, OutputFormat};
 use crate::protocol::{CommandRequest, CommandResponse, EffectiveRuntime, RuntimeSpec, SCHEMA_VERSION, print_response};
 use crate::runtime::{RuntimeConfig, build_runtime};
-use crate::session::SessionManager;
-use crate::workspace::normalize_profile;
+use crate::session::{InterruptToken, SessionManager};
+use crate::workspace::checked_profile;
+
+/// Pause before re-running a batch request that failed transiently.
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+pub async fn run_exec(
+	args: ExecArgs,
+	format: OutputFormat,
+	pause: bool,
+	explain: ExplainMode,
+	timeout_ms: Option<u64>,
+	device: Option<&str>,
+	interrupt: &InterruptToken,
+) -> Result<()> {
+	let mut request = parse_exec_request(&args)?;
+	apply_runtime_flags(&mut request, &args.launch, &args.emulation, timeout_ms, device);
+	let streamed = streamed_output(&request, format, explain)?;
+	let response = execute_request(
+		request,
+		Some(args.profile),
+		ExecMode::Cli,
+		args.artifacts_dir.as_deref(),
+		pause,
+		explain,
+		interrupt,
+	)
+	.await;
+	if !(streamed && response.ok) {
+		print_response(&response, format);
+	}
+	Ok(())
+}
 
-pub async fn run_exec(args: ExecArgs, format: OutputFormat) -> Result<()> {
-	let request = parse_exec_request(&args)?;
-	let response = execute_request(request, Some(args.profile), ExecMode::Cli, args.artifacts_dir.as_deref()).await;
-	print_response(&response, format);
+pub async fn run_replay(
+	args: ReplayArgs,
+	format: OutputFormat,
+	pause: bool,
+	explain: ExplainMode,
+	timeout_ms: Option<u64>,
+	device: Option<&str>,
+	interrupt: &InterruptToken,
+) -> Result<()> {
+	let envelope = std::fs::read_to_string(&args.file)?;
+	let mut request = crate::commands::replay::request_from_envelope(&envelope, args.profile.as_deref())?;
+	apply_runtime_flags(&mut request, &args.launch, &args.emulation, timeout_ms, device);
+	let streamed = streamed_output(&request, format, explain)?;
+	let response = execute_request(request, None, ExecMode::Cli, args.artifacts_dir.as_deref(), pause, explain, interrupt).await;
+	if !(streamed && response.ok) {
+		print_response(&response, format);
+	}
 	Ok(())
 }
 
-pub async fn run_batch(args: BatchArgs, format: OutputFormat) -> Result<()> {
-	let stdin = tokio::io::stdin();
-	let mut reader = BufReader::new(stdin);
-	let mut line = String::new();
+/// Whether the request writes its own output to stdout in place of the response envelope.
+///
+/// Only `page.elements` with `stream` and `page.eval` with `raw` do; a
+/// successful streamed run prints no envelope, and `--explain-only` still gets
+/// one since nothing runs.
+fn streamed_output(request: &CommandRequest, format: OutputFormat, explain: ExplainMode) -> Result<bool> {
+	let flag = match request.op.as_str() {
+		"page.elements" => "stream",
+		"page.eval" => "raw",
+		_ => return Ok(false),
+	};
+	let streamed = request.input.get(flag).and_then(Value::as_bool) == Some(true);
+	if !streamed || explain == ExplainMode::Only {
+		return Ok(false);
+	}
+	if format == OutputFormat::Csv {
+		return Err(PwError::Context(format!(
+			"{} {flag} writes directly to stdout and cannot be combined with --format csv",
+			request.op
+		)));
+	}
+	Ok(true)
+}
+
+/// Page operations that only read state and may overlap in batch mode.
+const CONCURRENT_READ_OPS: &[&str] = &[
+	"page.text",
+	"page.html",
+	"page.meta",
+	"page.schema",
+	"page.read",
+	"page.elements",
+	"page.snapshot",
+	"page.coords",
+	"page.coords-all",
+];
+
+/// Returns the page a request reads from when it may run alongside others.
+///
+/// Only [`CONCURRENT_READ_OPS`] with an explicit `url`/`urlFlag` input
+/// qualify; requests sharing a URL still run one at a time. Everything else
+/// returns `None` and runs exclusively, after in-flight requests finish.
+/// Requests that end up on the same shared browser (daemon, descriptor or
+/// CDP session) are further serialized by the session manager's endpoint lock.
+fn concurrency_key(request: &CommandRequest) -> Option<String> {
+	if !CONCURRENT_READ_OPS.contains(&request.op.as_str()) {
+		return None;
+	}
+	["url", "urlFlag", "url_flag"]
+		.iter()
+		.find_map(|key| request.input.get(*key).and_then(Value::as_str))
+		.map(str::to_string)
+}
+
+/// Tracks which batch requests are running so conflicting ones wait.
+#[derive(Debug, Default)]
+struct BatchSlots {
+	max: usize,
+	running: Vec<Option<String>>,
+}
+
+impl BatchSlots {
+	fn new(max: usize) -> Self {
+		Self { max, running: Vec::new() }
+	}
+
+	/// Returns whether a request with `key` may start now.
+	fn can_start(&self, key: &Option<String>) -> bool {
+		match key {
+			None => self.running.is_empty(),
+			Some(key) => self.running.len() < self.max && self.running.iter().all(|running| running.as_ref().is_some_and(|running| running != key)),
+		}
+	}
+
+	fn start(&mut self, key: Option<String>) {
+		self.running.push(key);
+	}
+
+	fn finish(&mut self, key: &Option<String>) {
+		if let Some(index) = self.running.iter().position(|running| running == key) {
+			self.running.remove(index);
+		}
+	}
+}
+
+pub async fn run_batch(
+	args: BatchArgs,
+	format: OutputFormat,
+	pause: bool,
+	explain: ExplainMode,
+	timeout_ms: Option<u64>,
+	device: Option<&str>,
+	interrupt: &InterruptToken,
+) -> Result<()> {
+	if args.validate {
+		return validate_batch(&args, format).await;
+	}
+
+	let mut lines = match &args.input {
+		Some(path) => spawn_line_reader(tokio::fs::File::open(path).await?),
+		None => spawn_line_reader(tokio::io::stdin()),
+	};
+	// A replayed script is sequential, so later requests usually depend on earlier ones.
+	let stop_on_error = args.input.is_some() && !args.continue_on_error;
+	let mut halted = false;
 	let mut stdout = std::io::stdout();
 	let default_profile = args.profile;
+	let mut slots = BatchSlots::new(usize::from(args.max_concurrency));
+	let mut in_flight = FuturesUnordered::new();
+	let mut queued: Option<(CommandRequest, Option<String>)> = None;
+	let mut stdin_open = true;
 
 	loop {
-		line.clear();
-		match reader.read_line(&mut line).await {
-			Ok(0) => break,
-			Ok(_) => {}
-			Err(err) => {
-				tracing::error!(target = "pw.batch", error = %err, "stdin read failed");
-				break;
+		// Requests already running are drained below, so their sessions still close.
+		if halted {
+			break;
+		}
+
+		if let Some((request, key)) = queued.take() {
+			if slots.can_start(&key) {
+				slots.start(key.clone());
+				let profile = default_profile.clone();
+				let retries = request.retry.unwrap_or(args.retries);
+				let interrupt = interrupt.clone();
+				in_flight.push(async move { (key, execute_with_retries(request, retries, profile, pause, explain, &interrupt).await) });
+				continue;
 			}
+			queued = Some((request, key));
+			if let Some((key, response)) = in_flight.next().await {
+				slots.finish(&key);
+				write_batch_response(&mut stdout, &response, format);
+				halted |= stop_on_error && !response.ok;
+			}
+			continue;
 		}
 
+		if !stdin_open {
+			break;
+		}
+
+		let line = tokio::select! {
+			line = lines.recv() => line,
+			Some((key, response)) = in_flight.next(), if !in_flight.is_empty() => {
+				slots.finish(&key);
+				write_batch_response(&mut stdout, &response, format);
+				halted |= stop_on_error && !response.ok;
+				continue;
+			}
+		};
+		let Some(line) = line else {
+			stdin_open = false;
+			continue;
+		};
+
 		let line = line.trim();
 		if line.is_empty() {
 			continue;
 		}
 
-		let request: CommandRequest = match serde_json::from_str(line) {
+		let mut request: CommandRequest = match serde_json::from_str(line) {
 			Ok(value) => value,
 			Err(err) => {
 				let response = error_response(
@@ -58,27 +241,35 @@ pub async fn run_batch(args: BatchArgs, format: OutputFormat) -> Result<()> {
 					None,
 				);
 				write_batch_response(&mut stdout, &response, format);
+				halted |= stop_on_error;
 				continue;
 			}
 		};
 
 		if request.op == "quit" || request.op == "exit" {
+			while let Some((key, response)) = in_flight.next().await {
+				slots.finish(&key);
+				write_batch_response(&mut stdout, &response, format);
+			}
 			let response = CommandResponse {
 				schema_version: SCHEMA_VERSION,
 				request_id: request.request_id,
 				op: "quit".to_string(),
 				ok: true,
 				inputs: None,
+				explanation: None,
 				data: Some(json!({ "quit": true })),
 				error: None,
 				duration_ms: None,
 				artifacts: Vec::new(),
+				downloads: Vec::new(),
 				diagnostics: Vec::new(),
 				context_delta: None,
 				effective_runtime: None,
+				attempts: None,
 			};
 			write_batch_response(&mut stdout, &response, format);
-			break;
+			return Ok(());
 		}
 
 		if request.op == "ping" {
@@ -88,35 +279,225 @@ pub async fn run_batch(args: BatchArgs, format: OutputFormat) -> Result<()> {
 				op: "ping".to_string(),
 				ok: true,
 				inputs: None,
+				explanation: None,
 				data: Some(json!({ "alive": true })),
 				error: None,
 				duration_ms: None,
 				artifacts: Vec::new(),
+				downloads: Vec::new(),
 				diagnostics: Vec::new(),
 				context_delta: None,
 				effective_runtime: None,
+				attempts: None,
 			};
 			write_batch_response(&mut stdout, &response, format);
 			continue;
 		}
 
-		let response = execute_request(request, Some(default_profile.clone()), ExecMode::Batch, None).await;
+		apply_runtime_flags(&mut request, &args.launch, &args.emulation, timeout_ms, device);
+		let key = concurrency_key(&request);
+		queued = Some((request, key));
+	}
+
+	while let Some((key, response)) = in_flight.next().await {
+		slots.finish(&key);
 		write_batch_response(&mut stdout, &response, format);
 	}
 
+	if halted {
+		return Err(PwError::Context(
+			"batch stopped at the first failed request; pass --continue-on-error to run every line".to_string(),
+		));
+	}
 	Ok(())
 }
 
+/// An invalid request found by `batch --validate`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchLineError {
+	/// 1-based line number in the input.
+	line: usize,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	op: Option<String>,
+	code: ErrorCode,
+	message: String,
+}
+
+/// Checks every request in the batch input without launching a browser.
+///
+/// Prints one `batch.validate` response listing all invalid lines and fails
+/// when there is at least one, so scripts can gate a replay on it.
+async fn validate_batch(args: &BatchArgs, format: OutputFormat) -> Result<()> {
+	let text = match &args.input {
+		Some(path) => tokio::fs::read_to_string(path).await?,
+		None => {
+			let mut text = String::new();
+			tokio::io::AsyncReadExt::read_to_string(&mut tokio::io::stdin(), &mut text).await?;
+			text
+		}
+	};
+
+	let (requests, errors) = validate_lines(&text);
+	let ok = errors.is_empty();
+	let response = CommandResponse {
+		schema_version: SCHEMA_VERSION,
+		request_id: None,
+		op: "batch.validate".to_string(),
+		ok,
+		inputs: None,
+		explanation: None,
+		data: Some(json!({ "requests": requests, "invalid": errors.len(), "errors": errors })),
+		error: (!ok).then(|| CommandError {
+			code: ErrorCode::InvalidInput,
+			message: format!("{} of {requests} batch request(s) are invalid", errors.len()),
+			details: None,
+		}),
+		duration_ms: None,
+		artifacts: Vec::new(),
+		downloads: Vec::new(),
+		diagnostics: Vec::new(),
+		context_delta: None,
+		effective_runtime: None,
+		attempts: None,
+	};
+	print_response(&response, format);
+
+	if ok {
+		Ok(())
+	} else {
+		Err(PwError::Context(format!("{} invalid batch request(s)", errors.len())))
+	}
+}
+
+/// Validates each non-blank NDJSON line, returning the request count and every error found.
+fn validate_lines(text: &str) -> (usize, Vec<BatchLineError>) {
+	let mut requests = 0;
+	let mut errors = Vec::new();
+
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		requests += 1;
+		let mut error = |op: Option<String>, code: ErrorCode, message: String| {
+			errors.push(BatchLineError {
+				line: index + 1,
+				op,
+				code,
+				message,
+			});
+		};
+
+		let request: CommandRequest = match serde_json::from_str(line) {
+			Ok(request) => request,
+			Err(err) => {
+				error(None, ErrorCode::InvalidInput, format!("Invalid request JSON: {err}"));
+				continue;
+			}
+		};
+		if request.schema_version != SCHEMA_VERSION {
+			let message = format!("unsupported schemaVersion {} (expected {})", request.schema_version, SCHEMA_VERSION);
+			error(Some(request.op), ErrorCode::InvalidInput, message);
+			continue;
+		}
+		if matches!(request.op.as_str(), "quit" | "exit" | "ping") {
+			continue;
+		}
+
+		let Some(id) = lookup_command(&request.op) else {
+			error(Some(request.op.clone()), ErrorCode::InvalidInput, format!("unknown operation: {}", request.op));
+			continue;
+		};
+		if lookup_command_exact(&request.op).is_none() {
+			let message = format!("operation '{}' is an alias; batch requests must use '{}'", request.op, command_name(id));
+			error(Some(request.op), ErrorCode::InvalidInput, message);
+			continue;
+		}
+		if let Err(err) = validate_input(id, request.input, ExecMode::Batch) {
+			let err = err.to_command_error();
+			error(Some(request.op), err.code, err.message);
+		}
+	}
+
+	(requests, errors)
+}
+
+/// Forwards input lines over a channel so reads can be awaited alongside in-flight requests.
+///
+/// Stops after a `quit`/`exit` request: a blocking stdin read left pending
+/// would otherwise keep the runtime from shutting down until the next line.
+fn spawn_line_reader<R: AsyncRead + Unpin + Send + 'static>(input: R) -> tokio::sync::mpsc::UnboundedReceiver<String> {
+	let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+	tokio::spawn(async move {
+		let mut reader = BufReader::new(input);
+		loop {
+			let mut line = String::new();
+			match reader.read_line(&mut line).await {
+				Ok(0) => break,
+				Ok(_) => {
+					let is_quit = serde_json::from_str::<Value>(&line)
+						.ok()
+						.is_some_and(|value| matches!(value.get("op").and_then(Value::as_str), Some("quit" | "exit")));
+					if tx.send(line).is_err() || is_quit {
+						break;
+					}
+				}
+				Err(err) => {
+					tracing::error!(target = "pw.batch", error = %err, "batch input read failed");
+					break;
+				}
+			}
+		}
+	});
+	rx
+}
+
 pub async fn run_profile(action: ProfileAction, format: OutputFormat) -> Result<()> {
 	let request = request_from_profile_action(action);
-	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None).await;
+	let response = execute_request(
+		request,
+		Some("default".to_string()),
+		ExecMode::Cli,
+		None,
+		false,
+		ExplainMode::Off,
+		&InterruptToken::new(),
+	)
+	.await;
 	print_response(&response, format);
 	Ok(())
 }
 
 pub async fn run_daemon(action: DaemonAction, format: OutputFormat) -> Result<()> {
 	let request = request_from_daemon_action(action);
-	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None).await;
+	let response = execute_request(
+		request,
+		Some("default".to_string()),
+		ExecMode::Cli,
+		None,
+		false,
+		ExplainMode::Off,
+		&InterruptToken::new(),
+	)
+	.await;
+	print_response(&response, format);
+	Ok(())
+}
+
+pub async fn run_install(args: InstallArgs, format: OutputFormat) -> Result<()> {
+	let request = request_from_install_args(args);
+	let response = execute_request(
+		request,
+		Some("default".to_string()),
+		ExecMode::Cli,
+		None,
+		false,
+		ExplainMode::Off,
+		&InterruptToken::new(),
+	)
+	.await;
 	print_response(&response, format);
 	Ok(())
 }
@@ -146,9 +527,24 @@ fn parse_exec_request(args: &ExecArgs) -> Result<CommandRequest> {
 			profile: Some(args.profile.clone()),
 			overrides: None,
 		}),
+		retry: None,
 	})
 }
 
+/// Layers CLI launch flags and the global `--timeout` over the request's runtime overrides.
+fn apply_runtime_flags(request: &mut CommandRequest, launch: &LaunchArgs, emulation: &EmulationArgs, timeout_ms: Option<u64>, device: Option<&str>) {
+	let runtime = request.runtime.get_or_insert_with(RuntimeSpec::default);
+	let overrides = runtime.overrides.get_or_insert_with(Default::default);
+	launch.apply_to(overrides);
+	emulation.apply_to(overrides);
+	if timeout_ms.is_some() {
+		overrides.timeout_ms = timeout_ms;
+	}
+	if let Some(device) = device {
+		overrides.device = Some(device.to_string());
+	}
+}
+
 fn write_batch_response(stdout: &mut std::io::Stdout, response: &CommandResponse, format: OutputFormat) {
 	match format {
 		OutputFormat::Ndjson => {
@@ -162,22 +558,90 @@ fn write_batch_response(stdout: &mut std::io::Stdout, response: &CommandResponse
 	}
 }
 
-async fn execute_request(request: CommandRequest, fallback_profile: Option<String>, mode: ExecMode, artifacts_dir: Option<&Path>) -> CommandResponse {
+/// Runs a batch request, re-running it up to `retries` more times while it
+/// fails with a retryable error.
+async fn execute_with_retries(
+	request: CommandRequest,
+	retries: u32,
+	profile: String,
+	pause: bool,
+	explain: ExplainMode,
+	interrupt: &InterruptToken,
+) -> CommandResponse {
+	retry_transient(&request.op, retries, || {
+		execute_attempt(request.clone(), Some(profile.clone()), ExecMode::Batch, None, pause, explain, interrupt)
+	})
+	.await
+}
+
+/// Calls `attempt` until it succeeds, fails for good, or `retries` re-runs are used up.
+///
+/// Sets `attempts` on the final response whenever retries were allowed.
+async fn retry_transient<F, Fut>(op: &str, retries: u32, mut attempt: F) -> CommandResponse
+where
+	F: FnMut() -> Fut,
+	Fut: std::future::Future<Output = (CommandResponse, bool)>,
+{
+	let mut attempts = 1;
+	loop {
+		let (mut response, retryable) = attempt().await;
+		if response.ok || !retryable || attempts > retries {
+			if retries > 0 {
+				response.attempts = Some(attempts);
+			}
+			return response;
+		}
+		tracing::warn!(target = "pw.batch", op, attempt = attempts, "retrying after transient failure");
+		attempts += 1;
+		tokio::time::sleep(RETRY_DELAY).await;
+	}
+}
+
+async fn execute_request(
+	request: CommandRequest,
+	fallback_profile: Option<String>,
+	mode: ExecMode,
+	artifacts_dir: Option<&Path>,
+	pause: bool,
+	explain: ExplainMode,
+	interrupt: &InterruptToken,
+) -> CommandResponse {
+	execute_attempt(request, fallback_profile, mode, artifacts_dir, pause, explain, interrupt)
+		.await
+		.0
+}
+
+/// Runs one request, also reporting whether a failure is worth retrying.
+async fn execute_attempt(
+	request: CommandRequest,
+	fallback_profile: Option<String>,
+	mode: ExecMode,
+	artifacts_dir: Option<&Path>,
+	pause: bool,
+	explain: ExplainMode,
+	interrupt: &InterruptToken,
+) -> (CommandResponse, bool) {
 	if request.schema_version != SCHEMA_VERSION {
-		return error_response(
-			request.request_id,
-			request.op,
-			CommandError {
-				code: ErrorCode::InvalidInput,
-				message: format!("unsupported schemaVersion {} (expected {})", request.schema_version, SCHEMA_VERSION),
-				details: None,
-			},
-			None,
+		return (
+			error_response(
+				request.request_id,
+				request.op,
+				CommandError {
+					code: ErrorCode::InvalidInput,
+					message: format!("unsupported schemaVersion {} (expected {})", request.schema_version, SCHEMA_VERSION),
+					details: None,
+				},
+				None,
+			),
+			false,
 		);
 	}
 
 	let runtime = request.runtime.clone().unwrap_or_default();
-	let profile = normalize_profile(runtime.profile.as_deref().or(fallback_profile.as_deref()).unwrap_or("default"));
+	let profile = match checked_profile(runtime.profile.as_deref().or(fallback_profile.as_deref()).unwrap_or("default")) {
+		Ok(profile) => profile,
+		Err(err) => return (error_response(request.request_id, request.op, err.to_command_error(), None), false),
+	};
 	let overrides = runtime.overrides.unwrap_or_default();
 
 	let runtime_config = RuntimeConfig {
@@ -188,7 +652,7 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 	let crate::runtime::RuntimeContext { ctx, mut ctx_state, info } = match build_runtime(&runtime_config) {
 		Ok(runtime) => runtime,
 		Err(err) => {
-			return error_response(request.request_id, request.op, err.to_command_error(), None);
+			return (error_response(request.request_id, request.op, err.to_command_error(), None), false);
 		}
 	};
 
@@ -204,19 +668,23 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 		ctx_state.session_descriptor_path(),
 		Some(ctx_state.profile_id()),
 		ctx_state.refresh_requested(),
-	);
+	)
+	.with_interrupt(interrupt.clone());
 
 	let Some(cmd_id) = lookup_command_exact(request.op.as_str()) else {
 		let unknown_op = request.op.clone();
-		return error_response(
-			request.request_id,
-			unknown_op.clone(),
-			CommandError {
-				code: ErrorCode::InvalidInput,
-				message: format!("unknown operation: {unknown_op}"),
-				details: None,
-			},
-			Some(effective_runtime.clone()),
+		return (
+			error_response(
+				request.request_id,
+				unknown_op.clone(),
+				CommandError {
+					code: ErrorCode::InvalidInput,
+					message: format!("unknown operation: {unknown_op}"),
+					details: None,
+				},
+				Some(effective_runtime.clone()),
+			),
+			false,
 		);
 	};
 
@@ -230,6 +698,8 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 		format: OutputFormat::Json,
 		artifacts_dir,
 		last_url: last_url.as_deref(),
+		pause,
+		explain,
 	};
 
 	match run_command(cmd_id, request.input, has_cdp, exec).await {
@@ -239,23 +709,30 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 			let delta = outcome.delta.clone();
 			delta.clone().apply(&mut ctx_state);
 			if let Err(err) = ctx_state.persist_if_dirty() {
-				return error_response(request_id, op, err.to_command_error(), Some(effective_runtime.clone()));
+				return (error_response(request_id, op, err.to_command_error(), Some(effective_runtime.clone())), false);
 			}
 
-			CommandResponse::success(request_id, op, outcome.inputs, outcome.data, delta, effective_runtime)
+			let mut response = CommandResponse::success(request_id, op, outcome.inputs, outcome.data, delta, effective_runtime);
+			response.explanation = outcome.explanation;
+			response.downloads = session.take_downloads();
+			(response, false)
+		}
+		Err(err) => {
+			let retryable = err.is_retryable();
+			let response = error_response(
+				request.request_id,
+				command_name(cmd_id).to_string(),
+				err.to_command_error(),
+				Some(effective_runtime),
+			);
+			(response, retryable)
 		}
-		Err(err) => error_response(
-			request.request_id,
-			command_name(cmd_id).to_string(),
-			err.to_command_error(),
-			Some(effective_runtime),
-		),
 	}
 }
 
 fn request_from_daemon_action(action: DaemonAction) -> CommandRequest {
 	let (op, input) = match action {
-		DaemonAction::Start { foreground } => ("daemon.start".to_string(), json!({ "foreground": foreground })),
+		DaemonAction::Start { foreground, idle_timeout } => ("daemon.start".to_string(), json!({ "foreground": foreground, "idleTimeoutSecs": idle_timeout })),
 		DaemonAction::Stop => ("daemon.stop".to_string(), json!({})),
 		DaemonAction::Status => ("daemon.status".to_string(), json!({})),
 	};
@@ -268,10 +745,20 @@ fn request_from_profile_action(action: ProfileAction) -> CommandRequest {
 		ProfileAction::Show { name } => ("profile.show".to_string(), json!({ "name": name })),
 		ProfileAction::Set { name, file } => ("profile.set".to_string(), json!({ "name": name, "file": file })),
 		ProfileAction::Delete { name } => ("profile.delete".to_string(), json!({ "name": name })),
+		ProfileAction::Export { name, output } => ("profile.export".to_string(), json!({ "name": name, "output": output })),
+		ProfileAction::Import { file, name, force } => ("profile.import".to_string(), json!({ "file": file, "name": name, "force": force })),
 	};
 	command_request(op, input)
 }
 
+fn request_from_install_args(args: InstallArgs) -> CommandRequest {
+	let mut input = json!({ "browsers": args.browsers, "check": args.check });
+	if let Some(dir) = args.cache_dir {
+		input["cacheDir"] = json!(dir);
+	}
+	command_request("install".to_string(), input)
+}
+
 fn command_request(op: String, input: Value) -> CommandRequest {
 	CommandRequest {
 		schema_version: SCHEMA_VERSION,
@@ -282,9 +769,174 @@ fn command_request(op: String, input: Value) -> CommandRequest {
 			profile: Some("default".to_string()),
 			overrides: None,
 		}),
+		retry: None,
 	}
 }
 
 fn error_response(request_id: Option<String>, op: String, error: CommandError, effective_runtime: Option<EffectiveRuntime>) -> CommandResponse {
 	CommandResponse::error(request_id, op, error, effective_runtime)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn request(op: &str, input: Value) -> CommandRequest {
+		CommandRequest {
+			schema_version: SCHEMA_VERSION,
+			request_id: None,
+			op: op.to_string(),
+			input,
+			runtime: None,
+			retry: None,
+		}
+	}
+
+	fn attempt_outcome(ok: bool, retryable: bool) -> (CommandResponse, bool) {
+		let error = CommandError {
+			code: ErrorCode::Timeout,
+			message: "transient".to_string(),
+			details: None,
+		};
+		let mut response = error_response(None, "page.text".to_string(), error, None);
+		if ok {
+			response.ok = true;
+			response.error = None;
+		}
+		(response, retryable)
+	}
+
+	#[tokio::test]
+	async fn retry_transient_reruns_until_success() {
+		let mut calls = 0;
+		let response = retry_transient("page.text", 2, || {
+			calls += 1;
+			let outcome = attempt_outcome(calls > 1, true);
+			async move { outcome }
+		})
+		.await;
+		assert!(response.ok);
+		assert_eq!(calls, 2);
+		assert_eq!(response.attempts, Some(2));
+	}
+
+	#[tokio::test]
+	async fn retry_transient_stops_on_permanent_failure_or_exhaustion() {
+		let mut calls = 0;
+		let response = retry_transient("page.text", 3, || {
+			calls += 1;
+			async { attempt_outcome(false, false) }
+		})
+		.await;
+		assert!(!response.ok);
+		assert_eq!((calls, response.attempts), (1, Some(1)));
+
+		let mut calls = 0;
+		let response = retry_transient("page.text", 1, || {
+			calls += 1;
+			async { attempt_outcome(false, true) }
+		})
+		.await;
+		assert_eq!((calls, response.attempts), (2, Some(2)));
+
+		let response = retry_transient("page.text", 0, || async { attempt_outcome(false, true) }).await;
+		assert_eq!(response.attempts, None);
+	}
+
+	#[test]
+	fn concurrency_key_only_for_reads_with_explicit_url() {
+		assert_eq!(
+			concurrency_key(&request("page.text", json!({ "url": "https://a.test" }))).as_deref(),
+			Some("https://a.test")
+		);
+		assert_eq!(
+			concurrency_key(&request("page.html", json!({ "urlFlag": "https://b.test" }))).as_deref(),
+			Some("https://b.test")
+		);
+		assert_eq!(concurrency_key(&request("page.text", json!({ "selector": "h1" }))), None);
+		assert_eq!(concurrency_key(&request("click", json!({ "url": "https://a.test" }))), None);
+	}
+
+	#[test]
+	fn streamed_output_only_for_streaming_elements_and_raw_eval() {
+		let streaming = request("page.elements", json!({ "stream": true }));
+		assert!(streamed_output(&streaming, OutputFormat::Toon, ExplainMode::Off).unwrap());
+		assert!(!streamed_output(&streaming, OutputFormat::Toon, ExplainMode::Only).unwrap());
+		assert!(streamed_output(&streaming, OutputFormat::Csv, ExplainMode::Off).is_err());
+		assert!(!streamed_output(&request("page.elements", json!({})), OutputFormat::Csv, ExplainMode::Off).unwrap());
+		assert!(!streamed_output(&request("page.text", json!({ "stream": true })), OutputFormat::Json, ExplainMode::Off).unwrap());
+		assert!(streamed_output(&request("page.eval", json!({ "raw": true })), OutputFormat::Json, ExplainMode::Off).unwrap());
+		assert!(!streamed_output(&request("page.eval", json!({ "stream": true })), OutputFormat::Json, ExplainMode::Off).unwrap());
+	}
+
+	#[test]
+	fn timeout_flag_overrides_envelope_timeout() {
+		let mut req = request("click", json!({}));
+		req.runtime = Some(serde_json::from_value(json!({ "overrides": { "timeoutMs": 30000 } })).unwrap());
+		apply_runtime_flags(&mut req, &LaunchArgs::default(), &EmulationArgs::default(), Some(500), None);
+		assert_eq!(req.runtime.unwrap().overrides.unwrap().timeout_ms, Some(500));
+
+		let mut req = request("click", json!({}));
+		req.runtime = Some(serde_json::from_value(json!({ "overrides": { "timeoutMs": 30000 } })).unwrap());
+		apply_runtime_flags(&mut req, &LaunchArgs::default(), &EmulationArgs::default(), None, None);
+		assert_eq!(req.runtime.unwrap().overrides.unwrap().timeout_ms, Some(30000));
+	}
+
+	#[test]
+	fn only_timeouts_and_lost_connections_are_retried() {
+		let timeout = PwError::Timeout {
+			ms: 100,
+			condition: "selector".into(),
+		};
+		assert!(timeout.is_retryable());
+		assert!(PwError::Playwright(pw_rs::Error::ChannelClosed).is_retryable());
+		assert!(PwError::Playwright(pw_rs::Error::Timeout("goto".into())).is_retryable());
+		assert!(!PwError::Context("bad input".into()).is_retryable());
+		assert!(!PwError::UnsupportedMode("cdp".into()).is_retryable());
+
+		let req: CommandRequest = serde_json::from_str(r#"{"op":"click","input":{},"retry":3}"#).unwrap();
+		assert_eq!(req.retry, Some(3));
+	}
+
+	#[test]
+	fn validate_lines_reports_every_invalid_line() {
+		let text = [
+			r#"{"op":"click","input":{"selector":"button"}}"#,
+			"",
+			"not json",
+			r#"{"op":"frobnicate","input":{}}"#,
+			r#"{"op":"click","input":{"selector":5}}"#,
+			r#"{"op":"auth.login","input":{}}"#,
+			r#"{"op":"ping","input":{}}"#,
+		]
+		.join("\n");
+
+		let (requests, errors) = validate_lines(&text);
+		assert_eq!(requests, 6);
+		let lines: Vec<_> = errors.iter().map(|e| (e.line, e.op.as_deref())).collect();
+		assert_eq!(lines, vec![(3, None), (4, Some("frobnicate")), (5, Some("click")), (6, Some("auth.login"))]);
+		assert!(errors[0].message.starts_with("Invalid request JSON"), "{}", errors[0].message);
+		assert!(errors[2].message.contains("INVALID_INPUT"), "{}", errors[2].message);
+		assert_eq!(errors[3].code, ErrorCode::UnsupportedMode);
+	}
+
+	#[test]
+	fn batch_slots_serialize_conflicts() {
+		let a = Some("https://a.test".to_string());
+		let b = Some("https://b.test".to_string());
+		let mut slots = BatchSlots::new(2);
+
+		slots.start(a.clone());
+		assert!(slots.can_start(&b), "reads of different pages overlap");
+		assert!(!slots.can_start(&a), "reads of the same page are serialized");
+		assert!(!slots.can_start(&None), "exclusive requests wait for in-flight reads");
+
+		slots.start(b.clone());
+		assert!(!slots.can_start(&Some("https://c.test".to_string())), "the limit bounds concurrency");
+
+		slots.finish(&a);
+		slots.finish(&b);
+		slots.start(None);
+		assert!(!slots.can_start(&a), "reads wait for exclusive requests");
+	}
+}
@@ -1,24 +1,32 @@
 //! Fill form element command.
 //!
 //! Fills a form input element with text. Supports text inputs, textareas,
-//! and contenteditable elements.
+//! and contenteditable elements. With `checked`, sets a checkbox or radio
+//! button to that state instead, doing nothing when it already matches.
+//! With `submit`, presses Enter in the field afterwards (or clicks
+//! `submitSelector`) and reports the URL the form led to.
 //!
 //! # Examples
 //!
 //! ```bash
 //! pw fill --selector "input[name=email]" --text "user@example.com"
+//! pw fill --selector "input[name=terms]" --checked true
+//! pw fill --selector "input[name=q]" --text "rust" --submit
 //! ```
 
+pub(crate) mod submit;
+
 use clap::Args;
-use pw_rs::WaitUntil;
+use pw_rs::{CheckOptions, FillOptions, WaitUntil};
 use serde::{Deserialize, Serialize};
+use submit::{Submit, SubmitPlan, insert_submit_inputs, resolve_submit, submit_form};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
-use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::contract::{describe_target, resolve_target_from_url_pair, standard_delta, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
-use crate::output::FillData;
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, FillData};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
 
@@ -38,6 +46,48 @@ pub struct FillRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default)]
 	pub url: Option<String>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+
+	/// Set to control whether Playwright waits for a navigation the fill starts
+	///
+	/// Unset leaves Playwright's default. True can hang on inputs that never
+	/// navigate; false can return before a submit-on-input navigation begins.
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default, alias = "wait_after")]
+	pub wait_after: Option<bool>,
+
+	/// Check (true) or uncheck (false) a checkbox or radio button instead of filling text
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub checked: Option<bool>,
+
+	/// Submit the form after filling by pressing Enter in the field
+	#[arg(long)]
+	#[serde(default)]
+	pub submit: Option<bool>,
+
+	/// Submit by clicking this element instead, e.g. "[type=submit]" (implies --submit)
+	#[arg(long, value_name = "SELECTOR")]
+	#[serde(default, alias = "submit_selector")]
+	pub submit_selector: Option<String>,
+
+	/// Maximum time to wait for the submit to navigate (milliseconds, default: 5000; 0 skips the wait)
+	#[arg(long, value_name = "MS")]
+	#[serde(default, alias = "submit_wait_ms")]
+	pub submit_wait_ms: Option<u64>,
+}
+
+/// What `fill` puts into the element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FillValue {
+	/// Text for an input, textarea, or contenteditable element.
+	Text(String),
+	/// Desired state of a checkbox or radio button.
+	Checked(bool),
 }
 
 /// Resolved inputs ready for execution.
@@ -49,8 +99,17 @@ pub struct FillResolved {
 	/// CSS selector for the target element.
 	pub selector: String,
 
-	/// Text to fill into the element.
-	pub text: String,
+	/// Text or checked state to apply to the element.
+	pub value: FillValue,
+
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+
+	/// Playwright's post-fill navigation wait; `None` keeps its default.
+	pub wait_after: Option<bool>,
+
+	/// How to submit the form afterwards, if at all.
+	pub submit: Option<SubmitPlan>,
 }
 
 impl Resolve for FillRaw {
@@ -59,9 +118,25 @@ impl Resolve for FillRaw {
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
 		let selector = env.resolve_selector(self.selector, None)?;
-		let text = self.text.unwrap_or_default();
+		let value = match (self.text, self.checked) {
+			(Some(_), Some(_)) => return Err(PwError::Context("text cannot be combined with checked".into())),
+			(None, Some(_)) if self.wait_after.is_some() => return Err(PwError::Context("waitAfter does not apply to checked".into())),
+			(None, Some(checked)) => FillValue::Checked(checked),
+			(text, None) => FillValue::Text(text.unwrap_or_default()),
+		};
+		let submit = resolve_submit(self.submit, self.submit_selector, self.submit_wait_ms)?;
+		if matches!(value, FillValue::Checked(_)) && submit.as_ref().is_some_and(|plan| plan.how == Submit::Enter) {
+			return Err(PwError::Context("Enter does not submit from a checkbox; set submitSelector".into()));
+		}
 
-		Ok(FillResolved { target, selector, text })
+		Ok(FillResolved {
+			target,
+			selector,
+			value,
+			strict: self.strict.unwrap_or(true),
+			wait_after: self.wait_after,
+			submit,
+		})
 	}
 }
 
@@ -74,6 +149,24 @@ impl CommandDef for FillCommand {
 	type Resolved = FillResolved;
 	type Data = FillData;
 
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let matching = if args.strict { "the single element" } else { "the first element" };
+		let action = match &args.value {
+			FillValue::Text(text) => format!("fill {matching} matching '{}' with {} characters of text", args.selector, text.chars().count()),
+			FillValue::Checked(true) => format!("check {matching} matching '{}'", args.selector),
+			FillValue::Checked(false) => format!("uncheck {matching} matching '{}'", args.selector),
+		};
+		let submit = match args.submit.as_ref().map(|plan| &plan.how) {
+			Some(Submit::Enter) => ", then press Enter to submit",
+			Some(Submit::Click(_)) => ", then click the submit element",
+			None => "",
+		};
+		Some(Explanation {
+			summary: format!("Will {}, {action}{submit}.", describe_target(&args.target)),
+			inputs: fill_inputs(args),
+		})
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -83,7 +176,10 @@ impl CommandDef for FillCommand {
 			info!(target = "pw", url = %url_display, selector = %args.selector, "fill");
 
 			let selector = args.selector.clone();
-			let text = args.text.clone();
+			let value = args.value.clone();
+			let strict = args.strict;
+			let wait_after = args.wait_after;
+			let submit = args.submit.clone();
 
 			let data = run_page_flow(
 				&mut exec,
@@ -92,30 +188,83 @@ impl CommandDef for FillCommand {
 				ArtifactsPolicy::OnError { command: "fill" },
 				move |session, flow| {
 					let selector = selector.clone();
-					let text = text.clone();
+					let value = value.clone();
+					let submit = submit.clone();
 					Box::pin(async move {
 						session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-						let locator = session.page().locator(&selector).await;
-						locator.fill(&text, None).await?;
+						let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+						let locator = if strict {
+							session.single_locator(&selector).await?
+						} else {
+							session.locator(&selector).await
+						};
+						let (text_length, checked) = match value {
+							FillValue::Text(text) => {
+								locator.fill(&text, Some(fill_options(wait_after, timeout_ms))).await?;
+								(Some(text.chars().count()), None)
+							}
+							FillValue::Checked(checked) => {
+								locator
+									.set_checked(checked, Some(CheckOptions::builder().timeout(timeout_ms as f64).build()))
+									.await?;
+								(None, Some(checked))
+							}
+						};
+						let submit = match &submit {
+							Some(plan) => Some(submit_form(session, Some(&locator), plan, timeout_ms).await?),
+							None => None,
+						};
 
-						Ok(FillData { selector, text })
+						Ok(FillData {
+							selector,
+							text_length,
+							checked,
+							submit,
+						})
 					})
 				},
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+			let inputs = fill_inputs(args);
+			let delta = match &data.submit {
+				Some(submit) => standard_delta_with_url(Some(submit.url.clone()), Some(&args.selector), None),
+				None => standard_delta(&args.target, Some(&args.selector), None),
+			};
 
-			Ok(CommandOutcome {
-				inputs,
-				data,
-				delta: standard_delta(&args.target, Some(&args.selector), None),
-			})
+			Ok(CommandOutcome { inputs, data, delta })
 		})
 	}
 }
 
+/// Inputs echoed for a fill. The text is reported by length only, since it is often a password.
+fn fill_inputs(args: &FillResolved) -> CommandInputs {
+	let mut extra = match &args.value {
+		FillValue::Text(text) => serde_json::json!({ "textLength": text.chars().count() }),
+		FillValue::Checked(checked) => serde_json::json!({ "checked": checked }),
+	};
+	if !args.strict {
+		extra["strict"] = false.into();
+	}
+	if let Some(wait_after) = args.wait_after {
+		extra["waitAfter"] = wait_after.into();
+	}
+	if let Some(plan) = &args.submit {
+		insert_submit_inputs(&mut extra, plan);
+	}
+	standard_inputs(&args.target, Some(&args.selector), None, None, Some(extra))
+}
+
+/// Builds fill options, or `None` to keep Playwright's defaults.
+fn fill_options(wait_after: Option<bool>, timeout_ms: u64) -> FillOptions {
+	let mut builder = FillOptions::builder().timeout(timeout_ms as f64);
+	if let Some(wait_after) = wait_after {
+		builder = builder.no_wait_after(!wait_after);
+	}
+	builder.build()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -128,4 +277,37 @@ mod tests {
 		assert_eq!(raw.selector, Some("input".into()));
 		assert_eq!(raw.text, Some("hello".into()));
 	}
+
+	#[test]
+	fn fill_raw_deserialize_checked() {
+		let raw: FillRaw = serde_json::from_str(r#"{"selector": "input[name=terms]", "checked": false}"#).unwrap();
+		assert_eq!(raw.checked, Some(false));
+		assert_eq!(raw.text, None);
+	}
+
+	#[test]
+	fn fill_inputs_report_text_length_only() {
+		let args = FillResolved {
+			target: ResolvedTarget {
+				target: crate::target::Target::CurrentPage,
+				source: crate::target::TargetSource::Explicit,
+			},
+			selector: "#password".into(),
+			value: FillValue::Text("hunter2".into()),
+			strict: true,
+			wait_after: None,
+			submit: None,
+		};
+		let inputs = serde_json::to_value(fill_inputs(&args)).unwrap();
+		assert_eq!(inputs["textLength"], 7);
+		assert!(!inputs.to_string().contains("hunter2"), "inputs leaked the text: {inputs}");
+	}
+
+	#[test]
+	fn wait_after_flips_no_wait_after() {
+		assert_eq!(fill_options(None, 500).no_wait_after, None);
+		assert_eq!(fill_options(None, 500).timeout, Some(500.0));
+		assert_eq!(fill_options(Some(false), 500).no_wait_after, Some(true));
+		assert_eq!(fill_options(Some(true), 500).no_wait_after, Some(false));
+	}
 }
@@ -0,0 +1,130 @@
+//! Form submission shared by `fill` and `form-fill`.
+
+use std::time::Duration;
+
+use pw_rs::{ClickOptions, GotoOptions, Locator, PressOptions, WaitUntil};
+
+use crate::error::{PwError, Result};
+use crate::output::SubmitData;
+use crate::session::SessionHandle;
+
+/// How long a submit waits for navigation unless `submitWaitMs` says otherwise.
+const DEFAULT_SUBMIT_WAIT_MS: u64 = 5000;
+
+/// How to submit a form once its fields are filled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Submit {
+	/// Press Enter in the last filled text field.
+	Enter,
+	/// Click the element matching this selector, e.g. `[type=submit]`.
+	Click(String),
+}
+
+/// A submission and how long to wait for the navigation it starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SubmitPlan {
+	pub how: Submit,
+	/// Maximum wait for a navigation; 0 returns right after submitting.
+	pub wait_ms: u64,
+}
+
+/// Resolves the `submit`, `submitSelector` and `submitWaitMs` inputs.
+///
+/// A submit selector implies `submit`. Returns `None` when not submitting.
+pub(crate) fn resolve_submit(submit: Option<bool>, submit_selector: Option<String>, wait_ms: Option<u64>) -> Result<Option<SubmitPlan>> {
+	let how = match (submit, submit_selector) {
+		(Some(false), Some(_)) => return Err(PwError::Context("submitSelector cannot be combined with submit=false".into())),
+		(_, Some(selector)) => Submit::Click(selector),
+		(Some(true), None) => Submit::Enter,
+		(_, None) if wait_ms.is_some() => return Err(PwError::Context("submitWaitMs requires submit".into())),
+		(_, None) => return Ok(None),
+	};
+	Ok(Some(SubmitPlan {
+		how,
+		wait_ms: wait_ms.unwrap_or(DEFAULT_SUBMIT_WAIT_MS),
+	}))
+}
+
+/// Echoes a submission into a command's `extra` inputs object.
+pub(crate) fn insert_submit_inputs(extra: &mut serde_json::Value, plan: &SubmitPlan) {
+	match &plan.how {
+		Submit::Enter => extra["submit"] = true.into(),
+		Submit::Click(selector) => extra["submitSelector"] = selector.as_str().into(),
+	}
+	if plan.wait_ms != DEFAULT_SUBMIT_WAIT_MS {
+		extra["submitWaitMs"] = plan.wait_ms.into();
+	}
+}
+
+/// Submits the form and waits up to `plan.wait_ms` for the navigation it starts.
+///
+/// `field` is where Enter is pressed; [`Submit::Click`] ignores it. A submit
+/// that does not navigate in time is not an error: forms that post with
+/// `fetch` stay on the page and report `navigated: false`.
+pub(crate) async fn submit_form(session: &SessionHandle, field: Option<&Locator>, plan: &SubmitPlan, timeout_ms: u64) -> Result<SubmitData> {
+	let before_url = session.evaluate_cached("window.location.href").await.unwrap_or_else(|_| session.page().url());
+
+	let (locator, click) = match &plan.how {
+		Submit::Enter => {
+			let field = field.ok_or_else(|| PwError::Context("no text field to press Enter in; set submitSelector".into()))?;
+			(field.clone(), false)
+		}
+		Submit::Click(selector) => (session.single_locator(selector).await?, true),
+	};
+	let action = async {
+		if click {
+			let click_opts = ClickOptions {
+				no_wait_after: Some(true),
+				timeout: Some(timeout_ms as f64),
+				..ClickOptions::default()
+			};
+			locator.click(Some(click_opts)).await
+		} else {
+			locator.press("Enter", Some(PressOptions::builder().timeout(timeout_ms as f64).build())).await
+		}
+	};
+
+	let committed = if plan.wait_ms > 0 {
+		let options = GotoOptions::new().timeout(Duration::from_millis(plan.wait_ms)).wait_until(WaitUntil::Load);
+		match session.page().expect_navigation(action, Some(options)).await {
+			Ok(navigation) => navigation.error.is_none(),
+			Err(pw_rs::Error::Timeout(_)) => false,
+			Err(err) => return Err(err.into()),
+		}
+	} else {
+		action.await?;
+		false
+	};
+	session.invalidate_evaluations();
+
+	let url = session.evaluate_cached("window.location.href").await.unwrap_or_else(|_| session.page().url());
+	let navigated = committed || url != before_url;
+	Ok(SubmitData { url, navigated })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn resolve_submit_picks_enter_or_click() {
+		assert_eq!(resolve_submit(None, None, None).unwrap(), None);
+		assert_eq!(resolve_submit(Some(false), None, None).unwrap(), None);
+		assert_eq!(
+			resolve_submit(Some(true), None, None).unwrap(),
+			Some(SubmitPlan {
+				how: Submit::Enter,
+				wait_ms: DEFAULT_SUBMIT_WAIT_MS
+			})
+		);
+		assert_eq!(
+			resolve_submit(None, Some("[type=submit]".into()), Some(0)).unwrap(),
+			Some(SubmitPlan {
+				how: Submit::Click("[type=submit]".into()),
+				wait_ms: 0
+			})
+		);
+		assert!(resolve_submit(Some(false), Some("button".into()), None).is_err());
+		assert!(resolve_submit(None, None, Some(1000)).is_err());
+	}
+}
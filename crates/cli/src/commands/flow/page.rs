@@ -29,7 +29,7 @@ pub async fn run_page_flow<'exec, 'ctx, T>(
 	resolved_target: &ResolvedTarget,
 	wait_until: WaitUntil,
 	artifacts: ArtifactsPolicy,
-	run: impl for<'s> FnOnce(&'s SessionHandle, PageFlowCtx) -> Pin<Box<dyn Future<Output = Result<T>> + 's>>,
+	run: impl for<'s> Fn(&'s SessionHandle, PageFlowCtx) -> Pin<Box<dyn Future<Output = Result<T>> + 's>>,
 ) -> Result<T>
 where
 	'ctx: 'exec,
@@ -40,5 +40,5 @@ where
 		target: plan.target,
 	};
 
-	with_session(exec, plan.request, artifacts, move |session| run(session, flow_ctx)).await
+	with_session(exec, plan.request, artifacts, move |session| run(session, flow_ctx.clone())).await
 }
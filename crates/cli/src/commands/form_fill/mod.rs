@@ -0,0 +1,417 @@
+//! Whole-form fill command.
+//!
+//! Fills several form fields in one page session from a map of field keys to
+//! values. A key made only of letters, digits, `_` and `-` is a field name and
+//! matches `[name="key"]`; any other key is used as a CSS selector. Each field
+//! is handled according to the element it resolves to:
+//!
+//! * `<select>`: a string or list of strings selects options by value or label.
+//! * Checkbox: `true`/`false` checks or unchecks it.
+//! * Radio group: a string checks the radio with that value; `true`/`false` sets the first match.
+//! * Anything else: the value is filled as text (numbers are stringified).
+//!
+//! A failing field is reported and the remaining fields are still filled.
+//! With `submit`, Enter is pressed in the last text field once every field
+//! succeeded (or `submitSelector` is clicked), and the resulting URL reported.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw form-fill https://example.com/signup --data form.json
+//! pw exec form-fill --input '{"fields":{"email":"me@example.com","terms":true,"plan":"Pro"}}'
+//! pw form-fill --data login.json --submit-selector "[type=submit]"
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::{SelectOption, WaitUntil};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::commands::contract::{describe_target, resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::fill::submit::{SubmitPlan, insert_submit_inputs, resolve_submit, submit_form};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, FormFieldAction, FormFieldResult, FormFillData};
+use crate::session::SessionHandle;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Classifies an element as `select`, `checkbox`, `radio`, or `text`.
+const FIELD_KIND_JS: &str = "el => el.tagName === 'SELECT' ? 'select' : el.type === 'checkbox' ? 'checkbox' : el.type === 'radio' ? 'radio' : 'text'";
+
+/// Maps each wanted string to the value of the option whose value, or else label, matches it (`null` when none does).
+const OPTION_VALUES_JS: &str = r#"(el, wanted) => wanted.map((w) => {
+    const options = Array.from(el.options);
+    const option = options.find((o) => o.value === w) || options.find((o) => o.label.trim() === w || o.text.trim() === w);
+    return option ? option.value : null;
+})"#;
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormFillRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// JSON file mapping field names or selectors to values
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub data: Option<PathBuf>,
+
+	/// Field map given inline instead of a file
+	#[arg(skip)]
+	#[serde(default)]
+	pub fields: Option<FieldMap>,
+
+	/// Submit the form after filling by pressing Enter in the last text field
+	#[arg(long)]
+	#[serde(default)]
+	pub submit: Option<bool>,
+
+	/// Submit by clicking this element instead, e.g. "[type=submit]" (implies --submit)
+	#[arg(long, value_name = "SELECTOR")]
+	#[serde(default, alias = "submit_selector")]
+	pub submit_selector: Option<String>,
+
+	/// Maximum time to wait for the submit to navigate (milliseconds, default: 5000; 0 skips the wait)
+	#[arg(long, value_name = "MS")]
+	#[serde(default, alias = "submit_wait_ms")]
+	pub submit_wait_ms: Option<u64>,
+}
+
+/// Field keys and values in the order the deserializer yields them.
+///
+/// A `data` file keeps the order it was written in; inline `fields` arrive
+/// through the request JSON, which sorts object keys.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FieldMap(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for FieldMap {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+		struct FieldMapVisitor;
+
+		impl<'de> Visitor<'de> for FieldMapVisitor {
+			type Value = FieldMap;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("an object mapping field names or selectors to values")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<FieldMap, A::Error> {
+				let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+				while let Some(entry) = map.next_entry()? {
+					entries.push(entry);
+				}
+				Ok(FieldMap(entries))
+			}
+		}
+
+		deserializer.deserialize_map(FieldMapVisitor)
+	}
+}
+
+/// Value to put into one field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+	/// Text to fill, option to select, or radio value to check.
+	Text(String),
+	/// Desired state of a checkbox or radio button.
+	Checked(bool),
+	/// Options to select in a multi-select.
+	Options(Vec<String>),
+}
+
+/// One resolved entry of the field map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+	/// Key as written in the map.
+	pub key: String,
+	/// Selector the key resolves to.
+	pub selector: String,
+	pub value: FieldValue,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct FormFillResolved {
+	pub target: ResolvedTarget,
+	/// Field map file, when the map came from one.
+	pub data: Option<PathBuf>,
+	/// Fields in map order.
+	pub fields: Vec<FormField>,
+	/// How to submit the form once every field is filled, if at all.
+	pub submit: Option<SubmitPlan>,
+}
+
+impl Resolve for FormFillRaw {
+	type Output = FormFillResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		let map = match (&self.data, self.fields) {
+			(Some(_), Some(_)) => return Err(PwError::Context("data cannot be combined with fields".into())),
+			(Some(path), None) => serde_json::from_str(&std::fs::read_to_string(path)?)
+				.map_err(|err| PwError::Context(format!("invalid form data in {}: {err}", path.display())))?,
+			(None, Some(map)) => map,
+			(None, None) => return Err(PwError::Context("form-fill needs data or fields".into())),
+		};
+
+		Ok(FormFillResolved {
+			target,
+			data: self.data,
+			fields: form_fields(map)?,
+			submit: resolve_submit(self.submit, self.submit_selector, self.submit_wait_ms)?,
+		})
+	}
+}
+
+/// Resolves every map entry to a selector and a typed value.
+fn form_fields(map: FieldMap) -> Result<Vec<FormField>> {
+	if map.0.is_empty() {
+		return Err(PwError::Context("form data has no fields".into()));
+	}
+	map.0
+		.into_iter()
+		.map(|(key, value)| {
+			let value = field_value(&key, value)?;
+			Ok(FormField {
+				selector: field_selector(&key),
+				key,
+				value,
+			})
+		})
+		.collect()
+}
+
+/// Treats identifier-like keys as field names and everything else as a CSS selector.
+fn field_selector(key: &str) -> String {
+	let is_name = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+	if is_name { format!("[name=\"{key}\"]") } else { key.to_string() }
+}
+
+fn field_value(key: &str, value: Value) -> Result<FieldValue> {
+	let scalar = |value: &Value| match value {
+		Value::String(s) => Some(s.clone()),
+		Value::Number(n) => Some(n.to_string()),
+		_ => None,
+	};
+	match &value {
+		Value::Bool(checked) => Ok(FieldValue::Checked(*checked)),
+		Value::Array(items) => items
+			.iter()
+			.map(scalar)
+			.collect::<Option<Vec<_>>>()
+			.map(FieldValue::Options)
+			.ok_or_else(|| PwError::Context(format!("field '{key}': lists may only hold strings and numbers"))),
+		_ => scalar(&value)
+			.map(FieldValue::Text)
+			.ok_or_else(|| PwError::Context(format!("field '{key}': expected a string, number, boolean, or list"))),
+	}
+}
+
+pub struct FormFillCommand;
+
+impl CommandDef for FormFillCommand {
+	const NAME: &'static str = "form-fill";
+
+	type Raw = FormFillRaw;
+	type Resolved = FormFillResolved;
+	type Data = FormFillData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		Some(Explanation {
+			summary: format!(
+				"Will {}, fill {} form fields in order{}.",
+				describe_target(&args.target),
+				args.fields.len(),
+				if args.submit.is_some() { ", then submit the form" } else { "" }
+			),
+			inputs: form_fill_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, fields = args.fields.len(), browser = %exec.ctx.browser, "form fill");
+
+			let fields = args.fields.clone();
+			let submit = args.submit.clone();
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "form-fill" },
+				move |session, flow| {
+					let fields = fields.clone();
+					let submit = submit.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let mut results = Vec::with_capacity(fields.len());
+						let mut last_text = None;
+						for field in fields {
+							let (action, error) = match fill_field(session, &field).await {
+								Ok(action) => {
+									if action == FormFieldAction::Fill {
+										last_text = Some(session.locator(&field.selector).await.first());
+									}
+									(Some(action), None)
+								}
+								Err(err) => {
+									warn!(target = "pw", field = %field.key, error = %err, "form field failed");
+									(None, Some(err.to_string()))
+								}
+							};
+							results.push(FormFieldResult {
+								field: field.key,
+								selector: field.selector,
+								ok: error.is_none(),
+								action,
+								error,
+							});
+						}
+
+						let failed = results.iter().filter(|result| !result.ok).count();
+						let url = session.page().url();
+						let submit = match &submit {
+							Some(_) if failed > 0 => {
+								warn!(target = "pw", failed, "form not submitted after failed fields");
+								None
+							}
+							Some(plan) => {
+								let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+								Some(submit_form(session, last_text.as_ref(), plan, timeout_ms).await?)
+							}
+							None => None,
+						};
+
+						Ok(FormFillData {
+							url,
+							filled: results.len() - failed,
+							failed,
+							fields: results,
+							submit,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = form_fill_inputs(args);
+			let final_url = data.submit.as_ref().map_or(&data.url, |submit| &submit.url).clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), None, None),
+			})
+		})
+	}
+}
+
+fn form_fill_inputs(args: &FormFillResolved) -> CommandInputs {
+	let mut extra = match &args.data {
+		Some(path) => serde_json::json!({ "data": path }),
+		None => serde_json::json!({ "fields": args.fields.iter().map(|field| field.key.as_str()).collect::<Vec<_>>() }),
+	};
+	if let Some(plan) = &args.submit {
+		insert_submit_inputs(&mut extra, plan);
+	}
+	standard_inputs(&args.target, None, None, None, Some(extra))
+}
+
+/// Applies one field's value using the strategy its element calls for.
+async fn fill_field(session: &SessionHandle, field: &FormField) -> Result<FormFieldAction> {
+	let locator = session.locator(&field.selector).await;
+	if locator.count().await? == 0 {
+		return Err(PwError::ElementNotFound {
+			selector: field.selector.clone(),
+		});
+	}
+	let first = locator.first();
+	let kind = first.evaluate(FIELD_KIND_JS, None).await?;
+	let mismatch = |expected: &str| PwError::Context(format!("field '{}' is a {} and needs {expected}", field.key, kind.as_str().unwrap_or("field")));
+
+	match (kind.as_str(), &field.value) {
+		(Some("select"), FieldValue::Text(wanted)) => select_options(&first, field, std::slice::from_ref(wanted)).await,
+		(Some("select"), FieldValue::Options(wanted)) => select_options(&first, field, wanted).await,
+		(Some("select"), FieldValue::Checked(_)) => Err(mismatch("an option value or label")),
+		(Some("checkbox" | "radio"), FieldValue::Checked(checked)) => {
+			first.set_checked(*checked, None).await?;
+			Ok(if *checked { FormFieldAction::Check } else { FormFieldAction::Uncheck })
+		}
+		(Some("radio"), FieldValue::Text(value)) => {
+			let find = format!("els => els.findIndex((el) => el.value === {})", serde_json::to_string(value)?);
+			let index = locator.evaluate_all(&find).await?.as_i64().unwrap_or(-1);
+			if index < 0 {
+				return Err(PwError::Context(format!("field '{}' has no radio with value '{value}'", field.key)));
+			}
+			locator.nth(index as i32).check(None).await?;
+			Ok(FormFieldAction::Check)
+		}
+		(Some("checkbox"), _) | (Some("radio"), FieldValue::Options(_)) => Err(mismatch("true or false")),
+		(_, FieldValue::Text(text)) => {
+			first.fill(text, None).await?;
+			Ok(FormFieldAction::Fill)
+		}
+		(_, _) => Err(mismatch("a string")),
+	}
+}
+
+/// Selects the options matching `wanted` by value or label, failing if any is missing.
+async fn select_options(select: &pw_rs::Locator, field: &FormField, wanted: &[String]) -> Result<FormFieldAction> {
+	let values: Vec<Option<String>> = serde_json::from_value(select.evaluate(OPTION_VALUES_JS, Some(serde_json::json!(wanted))).await?)?;
+	let mut options = Vec::with_capacity(values.len());
+	for (value, wanted) in values.into_iter().zip(wanted) {
+		let value = value.ok_or_else(|| PwError::Context(format!("field '{}' has no option '{wanted}'", field.key)))?;
+		options.push(SelectOption::Value(value));
+	}
+	select.select_option_multiple(&options, None).await?;
+	Ok(FormFieldAction::Select)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn field_map_keeps_written_order() {
+		let raw: FormFillRaw = serde_json::from_str(r##"{"fields": {"zip": "1012", "country": "NL", "#terms": true}}"##).unwrap();
+		let keys: Vec<String> = raw.fields.unwrap().0.into_iter().map(|(key, _)| key).collect();
+		assert_eq!(keys, vec!["zip", "country", "#terms"]);
+	}
+
+	#[test]
+	fn form_fields_resolve_names_and_values() {
+		let map: FieldMap = serde_json::from_str(r##"{"email": "me@example.com", "#age": 42, "terms": true, "select[multiple]": ["a", 2]}"##).unwrap();
+		let fields = form_fields(map).unwrap();
+		assert_eq!(fields[0].selector, r#"[name="email"]"#);
+		assert_eq!(fields[0].value, FieldValue::Text("me@example.com".into()));
+		assert_eq!(fields[1].selector, "#age");
+		assert_eq!(fields[1].value, FieldValue::Text("42".into()));
+		assert_eq!(fields[2].value, FieldValue::Checked(true));
+		assert_eq!(fields[3].selector, "select[multiple]");
+		assert_eq!(fields[3].value, FieldValue::Options(vec!["a".into(), "2".into()]));
+
+		assert!(form_fields(FieldMap::default()).is_err());
+		assert!(form_fields(serde_json::from_str(r#"{"email": null}"#).unwrap()).is_err());
+		assert!(form_fields(serde_json::from_str(r#"{"tags": [true]}"#).unwrap()).is_err());
+	}
+}
@@ -15,6 +15,12 @@ command_graph! {
 		Fill => crate::commands::fill::FillCommand {
 			names: ["fill"],
 		},
+		FormFill => crate::commands::form_fill::FormFillCommand {
+			names: ["form-fill"],
+		},
+		Type => crate::commands::type_text::TypeCommand {
+			names: ["type"],
+		},
 		Wait => crate::commands::wait::WaitCommand {
 			names: ["wait"],
 		},
@@ -24,9 +30,27 @@ command_graph! {
 		PageText => crate::commands::page::text::TextCommand {
 			names: ["page.text"],
 		},
+		DiffText => crate::commands::diff_text::DiffTextCommand {
+			names: ["diff-text"],
+		},
+		PageMeta => crate::commands::page::meta::MetaCommand {
+			names: ["page.meta"],
+		},
 		PageHtml => crate::commands::page::html::HtmlCommand {
 			names: ["page.html"],
 		},
+		PagePdf => crate::commands::page::pdf::PdfCommand {
+			names: ["page.pdf"],
+		},
+		PageHover => crate::commands::page::hover::HoverCommand {
+			names: ["page.hover"],
+		},
+		PageSelect => crate::commands::page::select::SelectCommand {
+			names: ["page.select"],
+		},
+		PageUpload => crate::commands::page::upload::UploadCommand {
+			names: ["page.upload"],
+		},
 		PageEval => crate::commands::page::eval::EvalCommand {
 			names: ["page.eval"],
 		},
@@ -36,12 +60,24 @@ command_graph! {
 		PageRead => crate::commands::page::read::ReadCommand {
 			names: ["page.read"],
 		},
+		PageSchema => crate::commands::page::schema::SchemaCommand {
+			names: ["page.schema"],
+		},
 		PageElements => crate::commands::page::elements::ElementsCommand {
 			names: ["page.elements"],
 		},
+		PageAccessibility => crate::commands::page::accessibility::AccessibilityCommand {
+			names: ["page.accessibility"],
+		},
 		PageSnapshot => crate::commands::page::snapshot::SnapshotCommand {
 			names: ["page.snapshot"],
 		},
+		PageScroll => crate::commands::page::scroll::ScrollCommand {
+			names: ["page.scroll"],
+		},
+		PageScrollToLoad => crate::commands::page::scroll_to_load::ScrollToLoadCommand {
+			names: ["page.scroll-to-load"],
+		},
 		PageCoords => crate::commands::page::coords::CoordsCommand {
 			names: ["page.coords"],
 		},
@@ -72,6 +108,9 @@ command_graph! {
 		SessionStop => crate::commands::session::SessionStopCommand {
 			names: ["session.stop"],
 		},
+		SessionList => crate::commands::session::SessionListCommand {
+			names: ["session.list"],
+		},
 		DaemonStart => crate::commands::daemon::DaemonStartCommand {
 			names: ["daemon.start"],
 		},
@@ -93,6 +132,12 @@ command_graph! {
 		ProfileDelete => crate::commands::profile::ProfileDeleteCommand {
 			names: ["profile.delete"],
 		},
+		ProfileExport => crate::commands::profile::ProfileExportCommand {
+			names: ["profile.export"],
+		},
+		ProfileImport => crate::commands::profile::ProfileImportCommand {
+			names: ["profile.import"],
+		},
 		Connect => crate::commands::connect::ConnectCommand {
 			names: ["connect"],
 		},
@@ -126,8 +171,35 @@ command_graph! {
 		HarClear => crate::commands::har::HarClearCommand {
 			names: ["har.clear"],
 		},
+		BlockSet => crate::commands::block::BlockSetCommand {
+			names: ["block.set"],
+		},
+		BlockList => crate::commands::block::BlockListCommand {
+			names: ["block.list"],
+		},
+		BlockClear => crate::commands::block::BlockClearCommand {
+			names: ["block.clear"],
+		},
+		DownloadSetDir => crate::commands::download::DownloadSetDirCommand {
+			names: ["download.set-dir"],
+		},
+		DownloadList => crate::commands::download::DownloadListCommand {
+			names: ["download.list"],
+		},
+		EmulationSet => crate::commands::emulation::EmulationSetCommand {
+			names: ["emulation.set"],
+		},
+		EmulationShow => crate::commands::emulation::EmulationShowCommand {
+			names: ["emulation.show"],
+		},
+		EmulationClear => crate::commands::emulation::EmulationClearCommand {
+			names: ["emulation.clear"],
+		},
 		Init => crate::commands::init::InitCommand {
 			names: ["init"],
 		},
+		Install => crate::commands::install::InstallCommand {
+			names: ["install"],
+		},
 	],
 }
@@ -14,6 +14,8 @@ use crate::target::ResolveEnv;
 #[derive(Debug, Clone, Args, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HarSetRaw {
+	/// HAR output path. `{timestamp}` (UTC `YYYYMMDDTHHMMSSZ`), `{date}` (`YYYY-MM-DD`)
+	/// and `{host}` (host of the target URL) expand each time a session starts.
 	#[arg(value_name = "FILE")]
 	pub file: PathBuf,
 	#[arg(long, value_enum, default_value = "attach")]
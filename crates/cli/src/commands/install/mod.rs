@@ -0,0 +1,304 @@
+//! Driver and browser installation command.
+//!
+//! Locates the Playwright driver bundled at build time, reports its version
+//! against the pinned one, and runs the driver's own `install` for the
+//! requested browsers. Browser versions and install locations come from
+//! `install --dry-run`, so `check` reports the same details without
+//! downloading anything.
+//!
+//! `cacheDir` is passed to the driver as `PLAYWRIGHT_BROWSERS_PATH`; without
+//! it the driver's default (or an inherited `PLAYWRIGHT_BROWSERS_PATH`) applies.
+
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::process::Command;
+use tracing::info;
+
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::error::{PwError, Result};
+use crate::output::{BrowserInstall, CommandInputs, DriverInfo, InstallData};
+use crate::target::ResolveEnv;
+use crate::types::BrowserKind;
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallRaw {
+	/// Browsers to install (default: chromium)
+	#[arg(long, value_delimiter = ',', value_name = "BROWSERS")]
+	#[serde(default)]
+	pub browsers: Option<Vec<BrowserKind>>,
+
+	/// Directory browsers are installed into (PLAYWRIGHT_BROWSERS_PATH)
+	#[arg(long, value_name = "DIR")]
+	#[serde(default, alias = "cache_dir")]
+	pub cache_dir: Option<PathBuf>,
+
+	/// Only verify and report what is installed
+	#[arg(long)]
+	#[serde(default)]
+	pub check: Option<bool>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct InstallResolved {
+	pub browsers: Vec<BrowserKind>,
+	pub cache_dir: Option<PathBuf>,
+	/// Report without running the installer.
+	pub check: bool,
+}
+
+impl Resolve for InstallRaw {
+	type Output = InstallResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let mut browsers = unique_browsers(self.browsers.unwrap_or_default());
+		if browsers.is_empty() {
+			browsers.push(BrowserKind::Chromium);
+		}
+
+		Ok(InstallResolved {
+			browsers,
+			cache_dir: self.cache_dir.or_else(|| std::env::var_os("PLAYWRIGHT_BROWSERS_PATH").map(PathBuf::from)),
+			check: self.check.unwrap_or(false),
+		})
+	}
+}
+
+/// Drops repeated browsers, keeping the first mention of each in order.
+fn unique_browsers(browsers: Vec<BrowserKind>) -> Vec<BrowserKind> {
+	let mut unique = Vec::with_capacity(browsers.len());
+	for browser in browsers {
+		if !unique.contains(&browser) {
+			unique.push(browser);
+		}
+	}
+	unique
+}
+
+pub struct InstallCommand;
+
+impl CommandDef for InstallCommand {
+	const NAME: &'static str = "install";
+
+	type Raw = InstallRaw;
+	type Resolved = InstallResolved;
+	type Data = InstallData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, _exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let (node, cli_js) = pw_runtime::get_driver_executable().map_err(|err| {
+				PwError::Init(format!(
+					"{err}. The driver is downloaded when pw is built; rebuild with network access or set PLAYWRIGHT_DRIVER_PATH"
+				))
+			})?;
+			let driver = driver_info(&cli_js);
+			info!(target = "pw", driver = ?driver.version, pinned = %driver.pinned_version, check = args.check, "install");
+
+			if !args.check {
+				run_driver(&node, &cli_js, &install_args(&args.browsers, false), args.cache_dir.as_deref()).await?;
+			}
+			let browsers = check_browsers(&node, &cli_js, &args.browsers, args.cache_dir.as_deref()).await?;
+			let ready = browsers.iter().all(|browser| browser.installed);
+
+			let names: Vec<String> = args.browsers.iter().map(ToString::to_string).collect();
+			let mut extra = json!({ "browsers": names });
+			if let Some(dir) = &args.cache_dir {
+				extra["cacheDir"] = json!(dir);
+			}
+			if args.check {
+				extra["check"] = true.into();
+			}
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(extra),
+					..Default::default()
+				},
+				data: InstallData {
+					driver,
+					browsers,
+					cache_dir: args.cache_dir.clone(),
+					installed: !args.check,
+					ready,
+				},
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+/// Reads the driver version from the `package.json` next to its `cli.js`.
+fn driver_info(cli_js: &Path) -> DriverInfo {
+	let version = cli_js
+		.parent()
+		.and_then(|dir| std::fs::read_to_string(dir.join("package.json")).ok())
+		.and_then(|manifest| serde_json::from_str::<serde_json::Value>(&manifest).ok())
+		.and_then(|manifest| manifest.get("version")?.as_str().map(str::to_string));
+
+	DriverInfo {
+		version,
+		pinned_version: pw_runtime::DRIVER_VERSION.to_string(),
+		cli_path: cli_js.to_path_buf(),
+	}
+}
+
+fn install_args(browsers: &[BrowserKind], dry_run: bool) -> Vec<String> {
+	let mut args = vec!["install".to_string()];
+	if dry_run {
+		args.push("--dry-run".to_string());
+	}
+	args.extend(browsers.iter().map(ToString::to_string));
+	args
+}
+
+/// Runs the driver CLI and returns its stdout, failing on a non-zero exit.
+async fn run_driver(node: &Path, cli_js: &Path, args: &[String], cache_dir: Option<&Path>) -> Result<String> {
+	let mut cmd = Command::new(node);
+	cmd.arg(cli_js).args(args);
+	if let Some(dir) = cache_dir {
+		cmd.env("PLAYWRIGHT_BROWSERS_PATH", dir);
+	}
+
+	let output = cmd.output().await?;
+	if !output.status.success() {
+		let stderr = String::from_utf8_lossy(&output.stderr);
+		return Err(PwError::Init(format!("playwright {} failed: {}", args.join(" "), stderr.trim())));
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Reports each browser build the installer would fetch and whether it is already on disk.
+async fn check_browsers(node: &Path, cli_js: &Path, browsers: &[BrowserKind], cache_dir: Option<&Path>) -> Result<Vec<BrowserInstall>> {
+	let plan = run_driver(node, cli_js, &install_args(browsers, true), cache_dir).await?;
+	Ok(parse_dry_run(&plan)
+		.into_iter()
+		.map(|(name, version, location)| BrowserInstall {
+			installed: location.is_dir(),
+			name,
+			version,
+			location,
+		})
+		.collect())
+}
+
+/// Parses `playwright install --dry-run` output into `(name, version, location)` entries.
+///
+/// Each entry starts with `browser: <name>[ version <version>]` followed by an
+/// indented `Install location:` line; download URLs are ignored.
+fn parse_dry_run(output: &str) -> Vec<(String, Option<String>, PathBuf)> {
+	let mut entries = Vec::new();
+	let mut current: Option<(String, Option<String>)> = None;
+
+	for line in output.lines() {
+		let line = line.trim();
+		if let Some(header) = line.strip_prefix("browser:") {
+			current = Some(match header.trim().split_once(" version ") {
+				Some((name, version)) => (name.trim().to_string(), Some(version.trim().to_string())),
+				None => (header.trim().to_string(), None),
+			});
+		} else if let Some(location) = line.strip_prefix("Install location:") {
+			if let Some((name, version)) = current.take() {
+				entries.push((name, version, PathBuf::from(location.trim())));
+			}
+		}
+	}
+
+	entries
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DRY_RUN: &str = "browser: chromium version 143.0.7499.4
+  Install location:    /cache/chromium-1200
+  Download url:        https://cdn.playwright.dev/chromium-linux.zip
+
+browser: chromium-headless-shell version 143.0.7499.4
+  Install location:    /cache/chromium_headless_shell-1200
+  Download url:        https://cdn.playwright.dev/chromium-headless-shell-linux.zip
+
+browser: ffmpeg
+  Install location:    /cache/ffmpeg-1011
+  Download url:        https://cdn.playwright.dev/ffmpeg-linux.zip
+";
+
+	#[test]
+	fn parse_dry_run_reads_names_versions_and_locations() {
+		let entries = parse_dry_run(DRY_RUN);
+		assert_eq!(entries.len(), 3);
+		assert_eq!(
+			entries[0],
+			("chromium".to_string(), Some("143.0.7499.4".to_string()), PathBuf::from("/cache/chromium-1200"))
+		);
+		assert_eq!(entries[1].0, "chromium-headless-shell");
+		assert_eq!(entries[2], ("ffmpeg".to_string(), None, PathBuf::from("/cache/ffmpeg-1011")));
+	}
+
+	#[test]
+	fn install_raw_parses_browsers_and_cache_dir() {
+		let raw: InstallRaw = serde_json::from_str(r#"{"browsers": ["chromium", "firefox"], "cacheDir": "/tmp/pw", "check": true}"#).unwrap();
+		assert_eq!(raw.browsers, Some(vec![BrowserKind::Chromium, BrowserKind::Firefox]));
+		assert_eq!(raw.cache_dir, Some(PathBuf::from("/tmp/pw")));
+		assert_eq!(raw.check, Some(true));
+	}
+
+	#[test]
+	fn unique_browsers_drops_non_adjacent_duplicates() {
+		let browsers = unique_browsers(vec![BrowserKind::Chromium, BrowserKind::Firefox, BrowserKind::Chromium, BrowserKind::Firefox]);
+		assert_eq!(browsers, vec![BrowserKind::Chromium, BrowserKind::Firefox]);
+	}
+
+	#[test]
+	fn driver_info_reads_package_version() {
+		let dir = tempfile::TempDir::new().unwrap();
+		std::fs::write(dir.path().join("package.json"), r#"{"name": "playwright-core", "version": "1.57.0"}"#).unwrap();
+
+		let info = driver_info(&dir.path().join("cli.js"));
+		assert_eq!(info.version.as_deref(), Some("1.57.0"));
+		assert_eq!(info.pinned_version, pw_runtime::DRIVER_VERSION);
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn check_reports_installed_browsers_from_mock_driver() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let dir = tempfile::TempDir::new().unwrap();
+		let cache = dir.path().join("cache");
+		std::fs::create_dir_all(cache.join("chromium-1200")).unwrap();
+
+		// Stands in for node: prints a dry-run plan rooted at PLAYWRIGHT_BROWSERS_PATH.
+		let node = dir.path().join("node");
+		std::fs::write(
+			&node,
+			"#!/bin/sh\n[ \"$2 $3\" = \"install --dry-run\" ] || exit 1\n\
+			 echo \"browser: chromium version 143.0.7499.4\"\n\
+			 echo \"  Install location:    $PLAYWRIGHT_BROWSERS_PATH/chromium-1200\"\n\
+			 echo \"browser: firefox version 144.0\"\n\
+			 echo \"  Install location:    $PLAYWRIGHT_BROWSERS_PATH/firefox-1497\"\n",
+		)
+		.unwrap();
+		std::fs::set_permissions(&node, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+		let browsers = check_browsers(&node, &dir.path().join("cli.js"), &[BrowserKind::Chromium, BrowserKind::Firefox], Some(&cache))
+			.await
+			.unwrap();
+
+		assert_eq!(browsers.len(), 2);
+		assert_eq!(browsers[0].name, "chromium");
+		assert_eq!(browsers[0].version.as_deref(), Some("143.0.7499.4"));
+		assert!(browsers[0].installed);
+		assert_eq!(browsers[1].name, "firefox");
+		assert_eq!(browsers[1].location, cache.join("firefox-1497"));
+		assert!(!browsers[1].installed);
+	}
+}
@@ -1,36 +1,60 @@
 mod auth;
+mod block;
 pub(crate) mod click;
 mod connect;
 pub(crate) mod contract;
 mod daemon;
 pub(crate) mod def;
+pub(crate) mod diff_text;
+mod download;
+mod emulation;
 mod engine;
 pub(crate) mod exec_flow;
 pub(crate) mod fill;
 pub(crate) mod flow;
+pub(crate) mod form_fill;
 pub(crate) mod graph;
 mod har;
 pub mod init;
+mod install;
 pub(crate) mod navigate;
 pub(crate) mod page;
 mod profile;
 mod protect;
 pub(crate) mod registry;
+mod replay;
 pub(crate) mod screenshot;
 mod session;
 mod tabs;
 pub mod test;
+pub(crate) mod type_text;
 pub(crate) mod wait;
 
 use crate::cli::{Cli, Commands};
+use crate::commands::def::ExplainMode;
 use crate::error::Result;
+use crate::session::InterruptToken;
 
 pub async fn dispatch(cli: Cli) -> Result<()> {
+	let explain = ExplainMode::from_flags(cli.explain, cli.explain_only);
+	if cli.debug_stats {
+		crate::debug_stats::enable();
+	}
+	let interrupt = InterruptToken::new();
+	let result = interrupt.guard(run(cli, explain, &interrupt)).await;
+	crate::debug_stats::print_report();
+	result
+}
+
+async fn run(cli: Cli, explain: ExplainMode, interrupt: &InterruptToken) -> Result<()> {
+	let device = cli.device.as_deref();
 	match cli.command {
-		Commands::Exec(args) => engine::run_exec(args, cli.format).await?,
-		Commands::Batch(args) => engine::run_batch(args, cli.format).await?,
+		Commands::Exec(args) => engine::run_exec(args, cli.format, cli.pause, explain, cli.timeout_ms, device, interrupt).await?,
+		Commands::Batch(args) => engine::run_batch(args, cli.format, cli.pause, explain, cli.timeout_ms, device, interrupt).await?,
+		Commands::Replay(args) => engine::run_replay(args, cli.format, cli.pause, explain, cli.timeout_ms, device, interrupt).await?,
 		Commands::Profile(args) => engine::run_profile(args.action, cli.format).await?,
 		Commands::Daemon(args) => engine::run_daemon(args.action, cli.format).await?,
+		Commands::Install(args) => engine::run_install(args, cli.format).await?,
 	}
 
 	Ok(())
@@ -1,4 +1,29 @@
 //! Navigation command.
+//!
+//! With `followRedirects: false` the command navigates unconditionally and
+//! reports every HTTP redirect hop in `redirects`, walking the request chain
+//! behind the response `goto` returns. Playwright navigations always follow
+//! redirects, so the page still ends up on the final URL; the flag only
+//! controls whether the chain is surfaced instead of being silently followed.
+//!
+//! With `security: true` the command reports the TLS details (issuer, subject,
+//! protocol, validity) of the final main-frame response. Only `https://`
+//! targets are accepted.
+//!
+//! Whenever the command navigates, it reports the final main-frame response's
+//! `status`, `contentType`, and `Content-Length` header as `contentLength`, all
+//! read from the response `goto` returns without downloading the body.
+//! Targets without a network response (`data:` URLs, an already-open page)
+//! omit them.
+//!
+//! Uncaught JavaScript exceptions thrown by the page are reported in
+//! `pageErrors` with their message and stack. On the current page this
+//! includes errors thrown before the command ran.
+//!
+//! With `failOnStatus` (e.g. `"4xx,5xx,302"`) the command fails with
+//! `NAVIGATION_FAILED` when that status matches a listed code or class,
+//! which makes `navigate` usable as a basic uptime check. A navigation with no
+//! HTTP response to check (e.g. a `data:` URL) fails too.
 
 use clap::Args;
 use pw_rs::WaitUntil;
@@ -6,15 +31,17 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
-use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::commands::page::snapshot::{EXTRACT_ELEMENTS_JS, EXTRACT_META_JS, EXTRACT_TEXT_JS, PageMeta, RawElement};
-use crate::error::Result;
-use crate::output::{InteractiveElement, SnapshotData};
+use crate::commands::page::snapshot::{
+	DEFAULT_MAX_TEXT_LENGTH, EXTRACT_ELEMENTS_JS, EXTRACT_META_JS, PageMeta, RawElement, configured_viewport, extract_text_js, resolve_max_text_length,
+	stabilize_elements,
+};
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, InteractiveElement, RedirectHop, SnapshotData};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, Target, TargetPolicy};
-
-const DEFAULT_MAX_TEXT_LENGTH: usize = 5000;
+use crate::types::PageError;
 
 /// Raw inputs from CLI or batch JSON.
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
@@ -28,12 +55,40 @@ pub struct NavigateRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Set to false to record and report the redirect chain
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default, alias = "follow_redirects")]
+	pub follow_redirects: Option<bool>,
+
+	/// Report TLS certificate details of the main response (https only)
+	#[arg(long)]
+	#[serde(default)]
+	pub security: Option<bool>,
+
+	/// Maximum text length to extract (default: 5000 or the profile default, 0 = unbounded)
+	#[arg(long = "max-text", value_name = "N")]
+	#[serde(default, alias = "max_text")]
+	pub max_text: Option<usize>,
+
+	/// Fail when the main response status matches (e.g. "4xx,5xx,302")
+	#[arg(long, value_name = "CODES")]
+	#[serde(default, alias = "fail_on_status")]
+	pub fail_on_status: Option<String>,
 }
 
 /// Resolved inputs ready for execution.
 #[derive(Debug, Clone)]
 pub struct NavigateResolved {
 	pub target: ResolvedTarget,
+	/// Record main-frame redirect hops instead of following them silently.
+	pub record_redirects: bool,
+	/// Capture TLS details of the final main-frame response.
+	pub security: bool,
+	/// Maximum text length to extract in characters (0 = unbounded).
+	pub max_text_length: usize,
+	/// Statuses that fail the navigation; empty disables gating.
+	pub fail_on_status: Vec<StatusPattern>,
 }
 
 impl Resolve for NavigateRaw {
@@ -41,7 +96,29 @@ impl Resolve for NavigateRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
-		Ok(NavigateResolved { target })
+		let security = self.security.unwrap_or(false);
+
+		if security {
+			match &target.target {
+				Target::Navigate(url) if url.scheme() == "https" => {}
+				Target::Navigate(url) => {
+					return Err(PwError::Context(format!(
+						"TLS security details require an https:// URL; {url} is not served over TLS"
+					)));
+				}
+				Target::CurrentPage => {
+					return Err(PwError::Context("TLS security details require an explicit https:// URL to navigate to".into()));
+				}
+			}
+		}
+
+		Ok(NavigateResolved {
+			target,
+			record_redirects: self.follow_redirects == Some(false),
+			security,
+			max_text_length: resolve_max_text_length(self.max_text, env),
+			fail_on_status: self.fail_on_status.as_deref().map(parse_status_patterns).transpose()?.unwrap_or_default(),
+		})
 	}
 }
 
@@ -54,6 +131,29 @@ impl CommandDef for NavigateCommand {
 	type Resolved = NavigateResolved;
 	type Data = SnapshotData;
 
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let mut summary = match args.target.url_str() {
+			Some(url) => format!("Will navigate to {url} and snapshot the page"),
+			None => "Will snapshot the current page".to_string(),
+		};
+		if args.record_redirects {
+			summary.push_str(", reporting redirects");
+		}
+		if args.security {
+			summary.push_str(", reporting TLS details");
+		}
+		if !args.fail_on_status.is_empty() {
+			let spec: Vec<String> = args.fail_on_status.iter().map(ToString::to_string).collect();
+			summary.push_str(&format!(", failing on status {}", spec.join(",")));
+		}
+		summary.push('.');
+
+		Some(Explanation {
+			summary,
+			inputs: navigate_inputs(args),
+		})
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -62,11 +162,57 @@ impl CommandDef for NavigateCommand {
 			let url_display = args.target.url_str().unwrap_or("<current page>");
 			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "navigate");
 
+			let record_redirects = args.record_redirects;
+			let capture_security = args.security;
+			let max_text_length = args.max_text_length;
+			let fail_on_status = args.fail_on_status.clone();
+
 			let (final_url, data) = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+				let fail_on_status = fail_on_status.clone();
 				Box::pin(async move {
+					let mut redirects = Vec::new();
+					let mut security = None;
+					let mut response_meta = ResponseMeta::default();
+					let mut page_errors = Vec::new();
 					match &flow.target {
 						Target::Navigate(url) => {
-							session.goto_if_needed(url.as_str(), flow.timeout_ms).await?;
+							let earlier_errors = session.page().take_page_errors();
+							let force = record_redirects || capture_security || !fail_on_status.is_empty();
+							let response = if force || !session.is_current_url(url.as_str()).await {
+								session.goto(url.as_str(), flow.timeout_ms).await?
+							} else {
+								// Still on the same document, so its earlier errors still apply.
+								page_errors = earlier_errors;
+								None
+							};
+							if let Some(response) = &response {
+								if record_redirects {
+									redirects = redirect_chain(response).await?;
+								}
+								response_meta = ResponseMeta::from_response(response);
+							}
+							if !fail_on_status.is_empty() {
+								let failure = match response_meta.status {
+									Some(status) if fail_on_status.iter().any(|p| p.matches(status)) => {
+										Some(format!("response status {status} matched failOnStatus"))
+									}
+									Some(_) => None,
+									None => Some("no HTTP response to check against failOnStatus".to_string()),
+								};
+								if let Some(failure) = failure {
+									return Err(PwError::Navigation {
+										url: url.to_string(),
+										source: anyhow::anyhow!(failure),
+									});
+								}
+							}
+							if capture_security {
+								let (final_url, details) = match &response {
+									Some(response) => (Some(response.url()), response.object().security_details().await?),
+									None => (None, None),
+								};
+								security = Some(tls_details(url.as_str(), final_url, details)?);
+							}
 						}
 						Target::CurrentPage => {}
 					}
@@ -76,20 +222,30 @@ impl CommandDef for NavigateCommand {
 					let meta_js = format!("JSON.stringify({})", EXTRACT_META_JS);
 					let meta: PageMeta = serde_json::from_str(&session.page().evaluate_value(&meta_js).await?)?;
 
-					let text_js = format!("JSON.stringify({}({}, {}))", EXTRACT_TEXT_JS, DEFAULT_MAX_TEXT_LENGTH, false);
-					let text: String = serde_json::from_str(&session.page().evaluate_value(&text_js).await?)?;
+					let text: String = serde_json::from_str(&session.page().evaluate_value(&extract_text_js(max_text_length, false)).await?)?;
 
 					let elements_js = format!("JSON.stringify({})", EXTRACT_ELEMENTS_JS);
 					let raw_elements: Vec<RawElement> = serde_json::from_str(&session.page().evaluate_value(&elements_js).await?)?;
 
-					let elements: Vec<InteractiveElement> = raw_elements.into_iter().map(Into::into).collect();
+					let elements: Vec<InteractiveElement> = stabilize_elements(raw_elements.into_iter().map(Into::into).collect());
 					let element_count = elements.len();
 
+					page_errors.extend(session.page().take_page_errors());
+
 					let data = SnapshotData {
 						url: meta.url.clone(),
 						title: meta.title,
 						viewport_width: meta.viewport_width,
 						viewport_height: meta.viewport_height,
+						client_width: meta.client_width,
+						client_height: meta.client_height,
+						configured_viewport: configured_viewport(session.page()),
+						redirects,
+						security,
+						status: response_meta.status,
+						content_type: response_meta.content_type,
+						content_length: response_meta.content_length,
+						page_errors: page_errors.into_iter().map(PageError::from).collect(),
 						text,
 						elements,
 						element_count,
@@ -100,7 +256,7 @@ impl CommandDef for NavigateCommand {
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let inputs = navigate_inputs(args);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -111,6 +267,137 @@ impl CommandDef for NavigateCommand {
 	}
 }
 
+fn navigate_inputs(args: &NavigateResolved) -> CommandInputs {
+	let mut extra = serde_json::Map::new();
+	if args.record_redirects {
+		extra.insert("followRedirects".into(), false.into());
+	}
+	if args.security {
+		extra.insert("security".into(), true.into());
+	}
+	if !args.fail_on_status.is_empty() {
+		let spec: Vec<String> = args.fail_on_status.iter().map(ToString::to_string).collect();
+		extra.insert("failOnStatus".into(), spec.join(",").into());
+	}
+	if args.max_text_length != DEFAULT_MAX_TEXT_LENGTH {
+		extra.insert("maxText".into(), args.max_text_length.into());
+	}
+	let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+	standard_inputs(&args.target, None, None, None, extra)
+}
+
+/// Walks the redirect chain behind `response` back to the original request.
+///
+/// Returns one hop per redirect, oldest first.
+async fn redirect_chain(response: &pw_rs::Response) -> Result<Vec<RedirectHop>> {
+	let mut hops = Vec::new();
+	let mut request = response.object().request().await?;
+	while let Some(previous) = request.redirected_from().await? {
+		let status = previous.response().await?.map_or(0, |response| response.status());
+		hops.push(RedirectHop {
+			from: previous.url().to_string(),
+			to: request.url().to_string(),
+			status,
+		});
+		request = previous;
+	}
+	hops.reverse();
+	Ok(hops)
+}
+
+/// One entry of a `failOnStatus` list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusPattern {
+	/// An exact status code such as `404`.
+	Code(u16),
+	/// A status class such as `4xx`, stored as its leading digit.
+	Class(u16),
+}
+
+impl std::fmt::Display for StatusPattern {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Code(code) => write!(f, "{code}"),
+			Self::Class(class) => write!(f, "{class}xx"),
+		}
+	}
+}
+
+impl StatusPattern {
+	fn matches(self, status: u16) -> bool {
+		match self {
+			Self::Code(code) => status == code,
+			Self::Class(class) => status / 100 == class,
+		}
+	}
+}
+
+/// Parses a comma-separated list of status codes and `Nxx` classes.
+fn parse_status_patterns(spec: &str) -> Result<Vec<StatusPattern>> {
+	let invalid = |entry: &str| {
+		PwError::Context(format!(
+			"invalid failOnStatus entry '{entry}': expected a status code like 404 or a class like 4xx"
+		))
+	};
+
+	spec.split(',')
+		.map(str::trim)
+		.filter(|entry| !entry.is_empty())
+		.map(|entry| {
+			let lower = entry.to_ascii_lowercase();
+			if let Some(class) = lower.strip_suffix("xx") {
+				return match class.as_bytes() {
+					[digit @ b'1'..=b'5'] => Ok(StatusPattern::Class(u16::from(digit - b'0'))),
+					_ => Err(invalid(entry)),
+				};
+			}
+			match lower.parse::<u16>() {
+				Ok(code @ 100..=599) => Ok(StatusPattern::Code(code)),
+				_ => Err(invalid(entry)),
+			}
+		})
+		.collect()
+}
+
+/// Status, content type, and content length of a main-frame response.
+#[derive(Debug, Default)]
+struct ResponseMeta {
+	status: Option<u16>,
+	content_type: Option<String>,
+	content_length: Option<u64>,
+}
+
+impl ResponseMeta {
+	/// Reads metadata from the headers of `response`.
+	fn from_response(response: &pw_rs::Response) -> Self {
+		let header = |name: &str| {
+			response
+				.headers()
+				.iter()
+				.find(|(key, _)| key.eq_ignore_ascii_case(name))
+				.map(|(_, value)| value.as_str())
+		};
+		Self {
+			status: Some(response.status()),
+			content_type: header("content-type").map(str::to_string),
+			content_length: header("content-length").and_then(|v| v.trim().parse().ok()),
+		}
+	}
+}
+
+/// Returns the TLS details of the final response for `url`, or explains why there are none.
+fn tls_details(url: &str, final_url: Option<&str>, details: Option<pw_rs::SecurityDetails>) -> Result<pw_rs::SecurityDetails> {
+	if let Some(details) = details {
+		return Ok(details);
+	}
+	let reason = match final_url {
+		None => "the navigation produced no HTTP response".to_string(),
+		Some(final_url) if !final_url.starts_with("https://") => format!("it ended on {final_url}, which is not served over HTTPS"),
+		Some(final_url) => format!("the browser reported none for {final_url}"),
+	};
+	Err(PwError::Context(format!("no TLS security details available for {url}; {reason}")))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -121,4 +408,62 @@ mod tests {
 		let raw: NavigateRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.url, Some("https://example.com".into()));
 	}
+
+	#[test]
+	fn parse_status_patterns_accepts_codes_and_classes() {
+		let patterns = parse_status_patterns("4xx, 5XX,302").unwrap();
+		assert_eq!(patterns, vec![StatusPattern::Class(4), StatusPattern::Class(5), StatusPattern::Code(302)]);
+		assert!(patterns.iter().any(|p| p.matches(404)));
+		assert!(patterns.iter().any(|p| p.matches(302)));
+		assert!(!patterns.iter().any(|p| p.matches(200)));
+		assert!(!patterns.iter().any(|p| p.matches(301)));
+	}
+
+	#[test]
+	fn parse_status_patterns_rejects_malformed_entries() {
+		for spec in ["4x", "9xx", "44xx", "abc", "42", "600"] {
+			assert!(matches!(parse_status_patterns(spec), Err(PwError::Context(_))), "{spec} should be rejected");
+		}
+	}
+
+	#[test]
+	fn navigate_raw_follow_redirects_off() {
+		let raw: NavigateRaw = serde_json::from_str(r#"{"url": "https://example.com", "followRedirects": false}"#).unwrap();
+		assert_eq!(raw.follow_redirects, Some(false));
+	}
+
+	#[test]
+	fn navigate_raw_deserialize_security_flag() {
+		let raw: NavigateRaw = serde_json::from_str(r#"{"url": "https://example.com", "security": true}"#).unwrap();
+		assert_eq!(raw.security, Some(true));
+	}
+
+	fn sample_details() -> pw_rs::SecurityDetails {
+		pw_rs::SecurityDetails {
+			issuer: Some("R3".into()),
+			protocol: Some("TLS 1.3".into()),
+			subject_name: Some("example.com".into()),
+			valid_from: Some(1700000000.0),
+			valid_to: Some(1710000000.0),
+		}
+	}
+
+	#[test]
+	fn tls_details_pass_through_when_reported() {
+		let details = tls_details("https://example.com/", Some("https://example.com/"), Some(sample_details())).unwrap();
+		assert_eq!(details, sample_details());
+	}
+
+	#[test]
+	fn tls_details_explain_why_they_are_missing() {
+		let downgraded = tls_details("https://example.com/", Some("http://example.com/"), None).unwrap_err();
+		assert!(downgraded.to_string().contains("ended on http://example.com/"), "{downgraded}");
+
+		let no_response = tls_details("https://example.com/#top", None, None).unwrap_err();
+		assert!(no_response.to_string().contains("no HTTP response"), "{no_response}");
+
+		let unreported = tls_details("https://example.com/", Some("https://example.com/"), None).unwrap_err();
+		assert!(unreported.to_string().contains("reported none"), "{unreported}");
+		assert!(!unreported.to_string().contains("not served over HTTPS"));
+	}
 }
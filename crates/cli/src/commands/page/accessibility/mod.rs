@@ -0,0 +1,172 @@
+//! Accessibility tree snapshot command.
+//!
+//! Returns the page's accessibility tree (role, name, value and state per
+//! node) as nested JSON. Compared with `page.elements`, the tree reflects what
+//! assistive technologies see, so roles and names stay stable across markup
+//! changes that keep the same semantics.
+//!
+//! ```bash
+//! pw exec page.accessibility --input '{"url":"https://example.com"}'
+//! pw exec page.accessibility --input '{"selector":"form#signup","interestingOnly":false}'
+//! ```
+
+use clap::Args;
+use pw_rs::{AccessibilityNode, AccessibilitySnapshotOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// Root the snapshot at the first element matching this selector
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Prune nodes without semantic meaning (default: true)
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default, alias = "interesting_only")]
+	pub interesting_only: Option<bool>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct AccessibilityResolved {
+	pub target: ResolvedTarget,
+	/// Root element selector; `None` snapshots the whole page.
+	pub selector: Option<String>,
+	pub interesting_only: bool,
+}
+
+impl Resolve for AccessibilityRaw {
+	type Output = AccessibilityResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(AccessibilityResolved {
+			target,
+			selector: self.selector,
+			interesting_only: self.interesting_only.unwrap_or(true),
+		})
+	}
+}
+
+/// Output data for the accessibility command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityData {
+	/// Root node of the tree, absent when the page exposes none.
+	pub tree: Option<AccessibilityNode>,
+	/// Total nodes in `tree`, including the root.
+	pub node_count: usize,
+	pub interesting_only: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub selector: Option<String>,
+}
+
+pub struct AccessibilityCommand;
+
+impl CommandDef for AccessibilityCommand {
+	const NAME: &'static str = "page.accessibility";
+
+	type Raw = AccessibilityRaw;
+	type Resolved = AccessibilityResolved;
+	type Data = AccessibilityData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "accessibility snapshot");
+
+			let selector = args.selector.clone();
+			let interesting_only = args.interesting_only;
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+				let selector = selector.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let mut options = AccessibilitySnapshotOptions::builder().interesting_only(interesting_only);
+					if let Some(selector) = &selector {
+						options = options.root_locator(session.locator(selector).await);
+					}
+
+					let tree = session
+						.page()
+						.accessibility_snapshot(Some(options.build()))
+						.await
+						.map_err(|e| match (e, &selector) {
+							(pw_rs::pw_runtime::Error::ElementNotFound(_), Some(selector)) => PwError::ElementNotFound { selector: selector.clone() },
+							(e, _) => e.into(),
+						})?;
+
+					Ok(AccessibilityData {
+						node_count: tree.as_ref().map_or(0, count_nodes),
+						tree,
+						interesting_only,
+						selector,
+					})
+				})
+			})
+			.await?;
+
+			let extra = (!args.interesting_only).then(|| serde_json::json!({ "interestingOnly": false }));
+			let inputs = standard_inputs(&args.target, args.selector.as_deref(), None, None, extra);
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, None, None),
+			})
+		})
+	}
+}
+
+fn count_nodes(node: &AccessibilityNode) -> usize {
+	1 + node.children.iter().flatten().map(count_nodes).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn accessibility_raw_deserializes_options() {
+		let raw: AccessibilityRaw = serde_json::from_str(r#"{"selector": "form", "interestingOnly": false}"#).unwrap();
+		assert_eq!(raw.selector.as_deref(), Some("form"));
+		assert_eq!(raw.interesting_only, Some(false));
+	}
+
+	#[test]
+	fn count_nodes_includes_nested_children() {
+		let tree: AccessibilityNode = serde_json::from_value(serde_json::json!({
+			"role": "WebArea",
+			"children": [
+				{ "role": "textbox", "name": "Email" },
+				{ "role": "group", "children": [{ "role": "button", "name": "Submit" }] }
+			]
+		}))
+		.unwrap();
+		assert_eq!(count_nodes(&tree), 4);
+	}
+}
@@ -1,8 +1,13 @@
 //! Console message capture command.
 //!
 //! Captures JavaScript console output (log, warn, error, etc.) from a page.
-//! Injects a capture script before navigation, then collects messages after
-//! a configurable timeout.
+//! Collects the page's console events during navigation and a configurable
+//! window afterwards. Console events are only subscribed to once this command
+//! runs, so without a URL the current page's earlier messages are included
+//! only if a previous `page.console` on the same session enabled capture.
+//!
+//! Uncaught exceptions are collected the same way and reported separately
+//! under `pageErrors` with their message and stack.
 //!
 //! # Examples
 //!
@@ -15,16 +20,15 @@ use std::time::Duration;
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
+use tracing::info;
 
-use crate::browser::js::console_capture_injection_js;
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::Result;
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
-use crate::types::ConsoleMessage;
+use crate::types::{ConsoleMessage, PageError};
 
 /// Raw inputs from CLI or batch JSON before resolution.
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
@@ -78,6 +82,9 @@ pub struct ConsoleData {
 	pub count: usize,
 	pub error_count: usize,
 	pub warning_count: usize,
+	/// Uncaught exceptions, kept apart from console output.
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub page_errors: Vec<PageError>,
 }
 
 pub struct ConsoleCommand;
@@ -101,28 +108,26 @@ impl CommandDef for ConsoleCommand {
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				Box::pin(async move {
-					if let Err(err) = session.page().evaluate(console_capture_injection_js()).await {
-						warn!(target = "pw.browser.console", error = %err, "failed to inject console capture");
+					session.context().capture_console().await?;
+					if flow.target.url_str().is_some() {
+						// Drop what the previous document logged.
+						session.page().take_console_messages();
+						session.page().take_page_errors();
 					}
 
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					tokio::time::sleep(Duration::from_millis(capture_timeout_ms)).await;
 
-					let messages_json = session
-						.page()
-						.evaluate_value("JSON.stringify(window.__consoleMessages || [])")
-						.await
-						.unwrap_or_else(|_| "[]".to_string());
-
-					let messages: Vec<ConsoleMessage> = serde_json::from_str(&messages_json).unwrap_or_default();
+					let messages: Vec<ConsoleMessage> = session.page().take_console_messages().into_iter().map(ConsoleMessage::from).collect();
+					let page_errors: Vec<PageError> = session.page().take_page_errors().into_iter().map(PageError::from).collect();
 
 					for msg in &messages {
 						info!(
 							target = "pw.browser.console",
 							kind = %msg.msg_type,
 							text = %msg.text,
-							stack = ?msg.stack,
+							location = ?msg.location,
 							"browser console"
 						);
 					}
@@ -136,12 +141,13 @@ impl CommandDef for ConsoleCommand {
 						count,
 						error_count,
 						warning_count,
+						page_errors,
 					})
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, Some(serde_json::json!({ "timeout_ms": args.timeout_ms })));
+			let inputs = standard_inputs(&args.target, None, None, None, Some(serde_json::json!({ "timeoutMs": args.timeout_ms })));
 
 			Ok(CommandOutcome {
 				inputs,
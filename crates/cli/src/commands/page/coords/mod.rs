@@ -4,6 +4,9 @@
 //! of elements matching a CSS selector. Useful for visual automation and
 //! click coordinate calculation.
 //!
+//! `coords` asks the browser for the element's box, so positions inside
+//! iframes are reported in top-level page coordinates.
+//!
 //! # Commands
 //!
 //! * `coords`: Get coordinates of the first matching element
@@ -17,7 +20,7 @@
 //! ```
 
 use clap::Args;
-use pw_rs::WaitUntil;
+use pw_rs::{BoundingBox, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
@@ -121,13 +124,16 @@ impl CommandDef for CoordsCommand {
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-					let result_json = session.page().evaluate_value(&js::get_element_coords_js(&selector)).await?;
-
-					if result_json == "null" {
-						return Err(PwError::ElementNotFound { selector: selector.clone() });
-					}
+					let locator = session.locator(&selector).await;
+					let not_found = |e: pw_rs::pw_runtime::Error| match e {
+						pw_rs::pw_runtime::Error::ElementNotFound(_) => PwError::ElementNotFound { selector: selector.clone() },
+						e => e.into(),
+					};
 
-					let coords: ElementCoords = serde_json::from_str(&result_json)?;
+					// Unrendered elements have no box; report them as zero-sized like `coords-all` does.
+					let bbox = locator.bounding_box().await.map_err(not_found)?.unwrap_or_default();
+					let label: ElementLabel = serde_json::from_value(locator.evaluate(js::ELEMENT_LABEL_JS, None).await.map_err(not_found)?)?;
+					let coords = element_coords(&bbox, label);
 
 					Ok(CoordsData { coords, selector })
 				})
@@ -145,6 +151,26 @@ impl CommandDef for CoordsCommand {
 	}
 }
 
+/// Text preview and link target captured alongside the box.
+#[derive(Debug, Default, Deserialize)]
+struct ElementLabel {
+	text: Option<String>,
+	href: Option<String>,
+}
+
+/// Converts a bounding box to rounded center coordinates.
+fn element_coords(bbox: &BoundingBox, label: ElementLabel) -> ElementCoords {
+	let (x, y) = bbox.center();
+	ElementCoords {
+		x: x.round() as i32,
+		y: y.round() as i32,
+		width: bbox.width.round() as i32,
+		height: bbox.height.round() as i32,
+		text: label.text,
+		href: label.href,
+	}
+}
+
 pub struct CoordsAllCommand;
 
 impl CommandDef for CoordsAllCommand {
@@ -201,4 +227,21 @@ mod tests {
 		assert_eq!(raw.url, Some("https://example.com".into()));
 		assert_eq!(raw.selector, Some("button".into()));
 	}
+
+	#[test]
+	fn element_coords_reports_rounded_center() {
+		let bbox = BoundingBox {
+			x: 40.0,
+			y: 60.4,
+			width: 100.0,
+			height: 49.6,
+		};
+		let label = ElementLabel {
+			text: Some("Go".into()),
+			href: None,
+		};
+		let coords = element_coords(&bbox, label);
+		assert_eq!((coords.x, coords.y, coords.width, coords.height), (90, 85, 100, 50));
+		assert_eq!(coords.text.as_deref(), Some("Go"));
+	}
 }
@@ -15,17 +15,38 @@
 //!
 //! ```bash
 //! pw elements https://example.com --wait --timeout-ms 5000
+//! pw elements https://example.com --include-iframes
+//! pw elements https://example.com --wait-for ".toolbar" --settle 300
 //! ```
+//!
+//! # Streaming
+//!
+//! With `stream`, elements are read from the page in chunks and written to
+//! stdout as one JSON object per line while extraction proceeds, instead of
+//! buffering the full list. Streamed output bypasses the normal result
+//! envelope, so it is only available to `pw exec` and cannot be combined with
+//! `--format csv`. Elements arrive in document order rather than the sorted
+//! order of the buffered result.
+//!
+//! ```bash
+//! pw exec page.elements --input '{"url": "https://example.com", "stream": true}' > elements.jsonl
+//! ```
+
+use std::collections::HashMap;
+use std::io::Write;
 
 use clap::Args;
 use pw_rs::WaitUntil;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
-use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, ExecMode, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
+use crate::commands::page::snapshot::{stabilize_elements, stable_element_id};
+use crate::commands::wait::ReadyGates;
+use crate::error::{PwError, Result};
 use crate::output::{ElementsData, InteractiveElement};
 use crate::session::SessionHandle;
 use crate::session_helpers::ArtifactsPolicy;
@@ -53,6 +74,26 @@ pub struct ElementsRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Also extract elements from same-origin iframes
+	#[arg(long)]
+	#[serde(default, alias = "include_iframes")]
+	pub include_iframes: Option<bool>,
+
+	/// Wait until this CSS selector is present before extracting
+	#[arg(long = "wait-for", value_name = "SELECTOR")]
+	#[serde(default, alias = "wait_for")]
+	pub wait_for: Option<String>,
+
+	/// Wait until the DOM has gone this many milliseconds without mutations before extracting
+	#[arg(long, value_name = "MS")]
+	#[serde(default)]
+	pub settle: Option<u64>,
+
+	/// Write one JSON object per element to stdout as they are extracted, without the result envelope
+	#[arg(long)]
+	#[serde(default)]
+	pub stream: Option<bool>,
 }
 
 /// Resolved inputs ready for execution.
@@ -68,6 +109,15 @@ pub struct ElementsResolved {
 
 	/// Timeout in milliseconds when waiting.
 	pub timeout_ms: u64,
+
+	/// Whether to recurse into same-origin iframes.
+	pub include_iframes: bool,
+
+	/// Selector and DOM-settle gates applied before extraction.
+	pub ready: ReadyGates,
+
+	/// Whether to stream elements to stdout as JSON lines.
+	pub stream: bool,
 }
 
 impl Resolve for ElementsRaw {
@@ -80,6 +130,12 @@ impl Resolve for ElementsRaw {
 			target,
 			wait: self.wait.unwrap_or(false),
 			timeout_ms: self.timeout_ms.unwrap_or(10000),
+			include_iframes: self.include_iframes.unwrap_or(false),
+			ready: ReadyGates {
+				wait_for: self.wait_for,
+				settle_ms: self.settle,
+			},
+			stream: self.stream.unwrap_or(false),
 		})
 	}
 }
@@ -93,6 +149,15 @@ impl CommandDef for ElementsCommand {
 	type Resolved = ElementsResolved;
 	type Data = ElementsData;
 
+	fn validate_mode(raw: &Self::Raw, mode: ExecMode) -> Result<()> {
+		if mode == ExecMode::Batch && raw.stream == Some(true) {
+			return Err(PwError::UnsupportedMode(
+				"command 'page.elements' with stream is not available in batch/ndjson mode".to_string(),
+			));
+		}
+		Ok(())
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -103,6 +168,9 @@ impl CommandDef for ElementsCommand {
 
 			let wait = args.wait;
 			let poll_timeout_ms = args.timeout_ms;
+			let include_iframes = args.include_iframes;
+			let ready = args.ready.clone();
+			let stream = args.stream;
 
 			let data = run_page_flow(
 				&mut exec,
@@ -110,47 +178,53 @@ impl CommandDef for ElementsCommand {
 				WaitUntil::NetworkIdle,
 				ArtifactsPolicy::OnError { command: "elements" },
 				move |session, flow| {
+					let ready = ready.clone();
 					Box::pin(async move {
 						session.goto_target(&flow.target, flow.timeout_ms).await?;
+						ready.apply(session, flow.timeout_ms).await?;
+
+						if stream {
+							return stream_elements(session, include_iframes, wait.then_some(poll_timeout_ms)).await;
+						}
 
-						let js = format!("JSON.stringify({})", EXTRACT_ELEMENTS_JS);
+						let js = format!("JSON.stringify({}({}))", EXTRACT_ELEMENTS_JS, include_iframes);
 
-						let raw_elements: Vec<RawElement> = if wait {
-							poll_for_elements(session, &js, poll_timeout_ms).await?
+						let extraction: RawExtraction = if wait {
+							poll_extraction(session, &js, poll_timeout_ms, |e: &RawExtraction| !e.elements.is_empty()).await?
 						} else {
 							let raw_result = session.page().evaluate_value(&js).await?;
 							serde_json::from_str(&raw_result)?
 						};
 
-						let elements: Vec<InteractiveElement> = raw_elements
-							.into_iter()
-							.map(|e| InteractiveElement {
-								tag: e.kind,
-								selector: e.selector,
-								text: if e.label.is_empty() || e.label == "(unlabeled)" {
-									None
-								} else {
-									Some(e.label)
-								},
-								href: None,
-								name: e.extra,
-								id: None,
-								x: e.x,
-								y: e.y,
-								width: e.width,
-								height: e.height,
-							})
-							.collect();
+						let elements: Vec<InteractiveElement> = extraction.elements.into_iter().map(RawElement::into_interactive).collect();
+						let elements = stabilize_elements(elements);
 
 						let count = elements.len();
 
-						Ok(ElementsData { elements, count })
+						Ok(ElementsData {
+							elements,
+							count,
+							skipped_frames: extraction.skipped_frames,
+						})
 					})
 				},
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let mut extra = serde_json::Map::new();
+			if args.wait {
+				extra.insert("wait".into(), true.into());
+				extra.insert("timeoutMs".into(), args.timeout_ms.into());
+			}
+			if args.include_iframes {
+				extra.insert("includeIframes".into(), true.into());
+			}
+			args.ready.record(&mut extra);
+			if args.stream {
+				extra.insert("stream".into(), true.into());
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, None, None, None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -161,6 +235,15 @@ impl CommandDef for ElementsCommand {
 	}
 }
 
+/// Result of the extraction JavaScript.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawExtraction {
+	elements: Vec<RawElement>,
+	#[serde(default)]
+	skipped_frames: Vec<String>,
+}
+
 /// Element data as returned by the extraction JavaScript.
 #[derive(Debug, Deserialize)]
 struct RawElement {
@@ -178,163 +261,265 @@ struct RawElement {
 	height: i32,
 }
 
+impl RawElement {
+	fn into_interactive(self) -> InteractiveElement {
+		InteractiveElement {
+			stable_id: String::new(),
+			tag: self.kind,
+			selector: self.selector,
+			text: if self.label.is_empty() || self.label == "(unlabeled)" {
+				None
+			} else {
+				Some(self.label)
+			},
+			href: None,
+			name: self.extra,
+			id: None,
+			x: self.x,
+			y: self.y,
+			width: self.width,
+			height: self.height,
+		}
+	}
+}
+
+/// Elements read back from the page per evaluation when streaming.
+const STREAM_CHUNK_SIZE: usize = 200;
+
+/// Page global holding the elements still to be streamed.
+const STREAM_BUFFER: &str = "__pwStreamElements";
+
+/// Summary of a streamed extraction; the elements stay in the page.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamHeader {
+	count: usize,
+	#[serde(default)]
+	skipped_frames: Vec<String>,
+}
+
+/// Extracts elements into a page-side buffer, then drains it in chunks,
+/// writing each element to stdout as a JSON line.
+///
+/// Stable IDs are assigned as elements arrive, so duplicate suffixes follow
+/// document order. The returned data carries the count but no elements.
+async fn stream_elements(session: &SessionHandle, include_iframes: bool, poll_timeout_ms: Option<u64>) -> Result<ElementsData> {
+	let js = format!(
+		"JSON.stringify((() => {{ const r = ({EXTRACT_ELEMENTS_JS})({include_iframes}); window.{STREAM_BUFFER} = r.elements; return {{ count: r.elements.length, skippedFrames: r.skippedFrames }}; }})())"
+	);
+	let header: StreamHeader = match poll_timeout_ms {
+		Some(timeout_ms) => poll_extraction(session, &js, timeout_ms, |h: &StreamHeader| h.count > 0).await?,
+		None => serde_json::from_str(&session.page().evaluate_value(&js).await?)?,
+	};
+
+	let chunk_js = format!("JSON.stringify(window.{STREAM_BUFFER}.splice(0, {STREAM_CHUNK_SIZE}))");
+	let mut seen: HashMap<String, usize> = HashMap::new();
+	let mut count = 0;
+	loop {
+		let chunk: Vec<RawElement> = serde_json::from_str(&session.page().evaluate_value(&chunk_js).await?)?;
+		if chunk.is_empty() {
+			break;
+		}
+
+		let mut stdout = std::io::stdout().lock();
+		for raw in chunk {
+			let mut element = raw.into_interactive();
+			element.stable_id = stable_element_id(&element);
+			let seen_count = seen.entry(element.stable_id.clone()).or_insert(0);
+			*seen_count += 1;
+			if *seen_count > 1 {
+				element.stable_id = format!("{}-{}", element.stable_id, seen_count);
+			}
+			serde_json::to_writer(&mut stdout, &element)?;
+			writeln!(stdout)?;
+			count += 1;
+		}
+		stdout.flush()?;
+	}
+	let _ = session.page().evaluate_value(&format!("delete window.{STREAM_BUFFER}")).await;
+
+	Ok(ElementsData {
+		elements: Vec::new(),
+		count,
+		skipped_frames: header.skipped_frames,
+	})
+}
+
 /// JavaScript that extracts interactive elements from the page.
 ///
 /// Generates stable selectors preferring: ID > name attribute > text content >
 /// aria-label > class combination > nth-of-type fallback.
+///
+/// Takes `includeIframes`; when set, recurses into same-origin frames,
+/// prefixing selectors with the frame path (`<frame> >> internal:control=enter-frame >> `)
+/// and offsetting coordinates into the top-level viewport. Frames whose
+/// document is not accessible are reported in `skippedFrames` by URL.
 const EXTRACT_ELEMENTS_JS: &str = r#"
-(() => {
+((includeIframes) => {
     const elements = [];
+    const skippedFrames = [];
     const seen = new Set();
     
-    function getStableSelector(el) {
-        if (el.id) return '#' + CSS.escape(el.id);
+    function collect(doc, framePath, offsetX, offsetY) {
+        function getStableSelector(el) {
+            if (el.id) return '#' + CSS.escape(el.id);
+            
+            if (el.name && (el.tagName === 'INPUT' || el.tagName === 'SELECT' || el.tagName === 'TEXTAREA')) {
+                const sel = el.tagName.toLowerCase() + '[name="' + el.name + '"]';
+                if (doc.querySelectorAll(sel).length === 1) return sel;
+            }
+            
+            const text = (el.textContent || '').trim().substring(0, 50);
+            if (text && (el.tagName === 'BUTTON' || el.tagName === 'A' || el.role === 'button')) {
+                const shortText = text.split('\n')[0].trim();
+                if (shortText.length > 0 && shortText.length < 40) {
+                    const sel = el.tagName.toLowerCase() + ':has-text("' + shortText.replace(/"/g, '\\"') + '")';
+                    return sel;
+                }
+            }
+            
+            if (el.getAttribute('aria-label')) {
+                const sel = '[aria-label="' + el.getAttribute('aria-label').replace(/"/g, '\\"') + '"]';
+                if (doc.querySelectorAll(sel).length === 1) return sel;
+            }
+            
+            if (el.className && typeof el.className === 'string') {
+                const classes = el.className.split(/\s+/).filter(c => c && !c.match(/^(hover|active|focus|disabled)/));
+                if (classes.length > 0 && classes.length <= 3) {
+                    const sel = el.tagName.toLowerCase() + '.' + classes.slice(0, 2).join('.');
+                    if (doc.querySelectorAll(sel).length === 1) return sel;
+                }
+            }
+            
+            const parent = el.parentElement;
+            if (parent) {
+                const siblings = Array.from(parent.children).filter(c => c.tagName === el.tagName);
+                const idx = siblings.indexOf(el) + 1;
+                if (siblings.length > 1) {
+                    return el.tagName.toLowerCase() + ':nth-of-type(' + idx + ')';
+                }
+            }
+            
+            return el.tagName.toLowerCase();
+        }
         
-        if (el.name && (el.tagName === 'INPUT' || el.tagName === 'SELECT' || el.tagName === 'TEXTAREA')) {
-            const sel = el.tagName.toLowerCase() + '[name="' + el.name + '"]';
-            if (document.querySelectorAll(sel).length === 1) return sel;
+        function getLabel(el) {
+             if (el.id) {
+                 const label = doc.querySelector('label[for="' + el.id + '"]');
+                 if (label) return cleanText(label.textContent);
+             }
+             
+             const ariaLabel = el.getAttribute('aria-label');
+             if (ariaLabel) return ariaLabel.trim().substring(0, 40);
+             
+             if (el.placeholder) return el.placeholder.trim().substring(0, 40);
+             
+             if (el.title) return el.title.trim().substring(0, 40);
+             
+             if (el.value && (el.type === 'submit' || el.type === 'button')) {
+                 return el.value.trim().substring(0, 40);
+             }
+             
+             return cleanText(el.textContent);
         }
         
-        const text = (el.textContent || '').trim().substring(0, 50);
-        if (text && (el.tagName === 'BUTTON' || el.tagName === 'A' || el.role === 'button')) {
-            const shortText = text.split('\n')[0].trim();
-            if (shortText.length > 0 && shortText.length < 40) {
-                const sel = el.tagName.toLowerCase() + ':has-text("' + shortText.replace(/"/g, '\\"') + '")';
-                return sel;
-            }
+        function cleanText(str) {
+            if (!str) return '';
+            return str.replace(/\s+/g, ' ').trim().substring(0, 40);
         }
         
-        if (el.getAttribute('aria-label')) {
-            const sel = '[aria-label="' + el.getAttribute('aria-label').replace(/"/g, '\\"') + '"]';
-            if (document.querySelectorAll(sel).length === 1) return sel;
+        function isVisible(el) {
+            const rect = el.getBoundingClientRect();
+            if (rect.width === 0 || rect.height === 0) return false;
+            const style = doc.defaultView.getComputedStyle(el);
+            if (style.display === 'none' || style.visibility === 'hidden' || style.opacity === '0') return false;
+            return true;
         }
         
-        if (el.className && typeof el.className === 'string') {
-            const classes = el.className.split(/\s+/).filter(c => c && !c.match(/^(hover|active|focus|disabled)/));
-            if (classes.length > 0 && classes.length <= 3) {
-                const sel = el.tagName.toLowerCase() + '.' + classes.slice(0, 2).join('.');
-                if (document.querySelectorAll(sel).length === 1) return sel;
-            }
+        function addElement(el, kind, extra) {
+            if (!isVisible(el)) return;
+            const selector = framePath + getStableSelector(el);
+            const key = kind + ':' + selector;
+            if (seen.has(key)) return;
+            seen.add(key);
+            
+            const label = getLabel(el) || '(unlabeled)';
+            const rect = el.getBoundingClientRect();
+            
+            elements.push({
+                kind: kind,
+                label: label.substring(0, 60),
+                selector: selector,
+                extra: extra || null,
+                x: Math.round(rect.x + offsetX),
+                y: Math.round(rect.y + offsetY),
+                width: Math.round(rect.width),
+                height: Math.round(rect.height)
+            });
         }
         
-        const parent = el.parentElement;
-        if (parent) {
-            const siblings = Array.from(parent.children).filter(c => c.tagName === el.tagName);
-            const idx = siblings.indexOf(el) + 1;
-            if (siblings.length > 1) {
-                return el.tagName.toLowerCase() + ':nth-of-type(' + idx + ')';
+        doc.querySelectorAll('button, [role="button"], input[type="submit"], input[type="button"]').forEach(el => {
+            addElement(el, 'button', null);
+        });
+        
+        doc.querySelectorAll('a[href]').forEach(el => {
+            const href = el.getAttribute('href');
+            if (href && !href.startsWith('javascript:') && !href.startsWith('#')) {
+                addElement(el, 'link', null);
             }
-        }
+        });
         
-        return el.tagName.toLowerCase();
-    }
-    
-    function getLabel(el) {
-         if (el.id) {
-             const label = document.querySelector('label[for="' + el.id + '"]');
-             if (label) return cleanText(label.textContent);
-         }
-         
-         const ariaLabel = el.getAttribute('aria-label');
-         if (ariaLabel) return ariaLabel.trim().substring(0, 40);
-         
-         if (el.placeholder) return el.placeholder.trim().substring(0, 40);
-         
-         if (el.title) return el.title.trim().substring(0, 40);
-         
-         if (el.value && (el.type === 'submit' || el.type === 'button')) {
-             return el.value.trim().substring(0, 40);
-         }
-         
-         return cleanText(el.textContent);
-    }
-    
-    function cleanText(str) {
-        if (!str) return '';
-        return str.replace(/\s+/g, ' ').trim().substring(0, 40);
-    }
-    
-    function isVisible(el) {
-        const rect = el.getBoundingClientRect();
-        if (rect.width === 0 || rect.height === 0) return false;
-        const style = window.getComputedStyle(el);
-        if (style.display === 'none' || style.visibility === 'hidden' || style.opacity === '0') return false;
-        return true;
-    }
-    
-    function addElement(el, kind, extra) {
-        if (!isVisible(el)) return;
-        const selector = getStableSelector(el);
-        const key = kind + ':' + selector;
-        if (seen.has(key)) return;
-        seen.add(key);
+        doc.querySelectorAll('input:not([type="hidden"]):not([type="submit"]):not([type="button"]):not([type="checkbox"]):not([type="radio"])').forEach(el => {
+            addElement(el, 'input', el.type || 'text');
+        });
+        
+        doc.querySelectorAll('textarea').forEach(el => {
+            addElement(el, 'textarea', null);
+        });
         
-        const label = getLabel(el) || '(unlabeled)';
-        const rect = el.getBoundingClientRect();
+        doc.querySelectorAll('select').forEach(el => {
+            addElement(el, 'select', null);
+        });
         
-        elements.push({
-            kind: kind,
-            label: label.substring(0, 60),
-            selector: selector,
-            extra: extra || null,
-            x: Math.round(rect.x),
-            y: Math.round(rect.y),
-            width: Math.round(rect.width),
-            height: Math.round(rect.height)
+        doc.querySelectorAll('input[type="checkbox"]').forEach(el => {
+            addElement(el, 'checkbox', el.checked ? 'checked' : 'unchecked');
+        });
+        
+        doc.querySelectorAll('input[type="radio"]').forEach(el => {
+            addElement(el, 'radio', el.checked ? 'checked' : 'unchecked');
+        });
+        
+        if (!includeIframes) return;
+        doc.querySelectorAll('iframe, frame').forEach(frame => {
+            let child = null;
+            try { child = frame.contentDocument; } catch (e) {}
+            if (!child || !child.documentElement) {
+                skippedFrames.push(frame.src || 'about:blank');
+                return;
+            }
+            const rect = frame.getBoundingClientRect();
+            const path = framePath + getStableSelector(frame) + ' >> internal:control=enter-frame >> ';
+            collect(child, path, offsetX + rect.x + frame.clientLeft, offsetY + rect.y + frame.clientTop);
         });
     }
     
-    document.querySelectorAll('button, [role="button"], input[type="submit"], input[type="button"]').forEach(el => {
-        addElement(el, 'button', null);
-    });
-    
-    document.querySelectorAll('a[href]').forEach(el => {
-        const href = el.getAttribute('href');
-        if (href && !href.startsWith('javascript:') && !href.startsWith('#')) {
-            addElement(el, 'link', null);
-        }
-    });
-    
-    document.querySelectorAll('input:not([type="hidden"]):not([type="submit"]):not([type="button"]):not([type="checkbox"]):not([type="radio"])').forEach(el => {
-        addElement(el, 'input', el.type || 'text');
-    });
-    
-    document.querySelectorAll('textarea').forEach(el => {
-        addElement(el, 'textarea', null);
-    });
-    
-    document.querySelectorAll('select').forEach(el => {
-        addElement(el, 'select', null);
-    });
-    
-    document.querySelectorAll('input[type="checkbox"]').forEach(el => {
-        addElement(el, 'checkbox', el.checked ? 'checked' : 'unchecked');
-    });
-    
-    document.querySelectorAll('input[type="radio"]').forEach(el => {
-        addElement(el, 'radio', el.checked ? 'checked' : 'unchecked');
-    });
-    
-    return elements;
-})()
+    collect(document, '', 0, 0);
+    return { elements, skippedFrames };
+})
 "#;
 
-/// Polls for elements until some appear or timeout is reached.
-async fn poll_for_elements(session: &SessionHandle, js: &str, timeout_ms: u64) -> Result<Vec<RawElement>> {
+/// Polls the extraction until `found` accepts it or timeout is reached.
+async fn poll_extraction<T: DeserializeOwned>(session: &SessionHandle, js: &str, timeout_ms: u64, found: impl Fn(&T) -> bool) -> Result<T> {
 	let start = std::time::Instant::now();
 	let poll_interval = std::time::Duration::from_millis(500);
 	let timeout = std::time::Duration::from_millis(timeout_ms);
 
 	loop {
 		let raw_result = session.page().evaluate_value(js).await?;
-		let elements: Vec<RawElement> = serde_json::from_str(&raw_result)?;
+		let extraction: T = serde_json::from_str(&raw_result)?;
 
-		if !elements.is_empty() {
-			return Ok(elements);
-		}
-
-		if start.elapsed() >= timeout {
-			return Ok(vec![]);
+		if found(&extraction) || start.elapsed() >= timeout {
+			return Ok(extraction);
 		}
 
 		tokio::time::sleep(poll_interval).await;
@@ -360,5 +545,33 @@ mod tests {
 		let raw: ElementsRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.wait, None);
 		assert_eq!(raw.timeout_ms, None);
+		assert_eq!(raw.include_iframes, None);
+		assert_eq!(raw.wait_for, None);
+		assert_eq!(raw.settle, None);
+		assert_eq!(raw.stream, None);
+	}
+
+	#[test]
+	fn elements_stream_is_rejected_in_batch_mode() {
+		let raw: ElementsRaw = serde_json::from_str(r#"{"stream": true}"#).unwrap();
+		assert!(ElementsCommand::validate_mode(&raw, ExecMode::Cli).is_ok());
+		assert!(matches!(
+			ElementsCommand::validate_mode(&raw, ExecMode::Batch),
+			Err(PwError::UnsupportedMode(_))
+		));
+	}
+
+	#[test]
+	fn elements_raw_deserializes_ready_gates() {
+		let raw: ElementsRaw = serde_json::from_str(r#"{"waitFor": ".row", "settle": 300}"#).unwrap();
+		assert_eq!(raw.wait_for.as_deref(), Some(".row"));
+		assert_eq!(raw.settle, Some(300));
+	}
+
+	#[test]
+	fn raw_extraction_defaults_skipped_frames() {
+		let extraction: RawExtraction = serde_json::from_str(r#"{"elements": []}"#).unwrap();
+		assert!(extraction.elements.is_empty());
+		assert!(extraction.skipped_frames.is_empty());
 	}
 }
@@ -1,14 +1,27 @@
 //! JavaScript evaluation command.
+//!
+//! By default the result is returned as JSON inside the response envelope.
+//! `raw` prints a string result verbatim to stdout instead, with no envelope,
+//! so it is only available to `pw exec`. `base64` decodes a base64 string
+//! result (a `data:` URL prefix is stripped) and writes the bytes to `output`.
+//!
+//! ```bash
+//! pw exec page.eval --input '{"expression":"document.body.innerHTML","raw":true}' > body.html
+//! pw exec page.eval --input '{"expression":"document.querySelector(\"canvas\").toDataURL()","base64":true,"output":"chart.png"}'
+//! ```
 
+use std::io::Write;
 use std::path::PathBuf;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
-use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, ExecMode, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::{PwError, Result};
 use crate::output::EvalData;
@@ -41,6 +54,31 @@ pub struct EvalRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Run the expression as a function of the first element matching this selector
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// With --selector, pass the array of all matches instead of the first
+	#[arg(long)]
+	#[serde(default)]
+	pub all: Option<bool>,
+
+	/// Print a string result verbatim to stdout instead of the JSON envelope
+	#[arg(long)]
+	#[serde(default)]
+	pub raw: Option<bool>,
+
+	/// Decode a base64 string result and write the bytes to --output
+	#[arg(long)]
+	#[serde(default)]
+	pub base64: Option<bool>,
+
+	/// Output file for --base64
+	#[arg(short, long, value_name = "FILE")]
+	#[serde(default)]
+	pub output: Option<PathBuf>,
 }
 
 /// Resolved inputs ready for execution.
@@ -48,6 +86,42 @@ pub struct EvalRaw {
 pub struct EvalResolved {
 	pub target: ResolvedTarget,
 	pub expression: String,
+	/// Elements the expression runs against; `None` evaluates in page scope.
+	pub scope: Option<EvalScope>,
+	pub output: EvalOutput,
+}
+
+/// How the evaluation result is delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalOutput {
+	/// JSON `result` in the response envelope.
+	Json,
+	/// String result printed verbatim to stdout.
+	Raw,
+	/// Base64 string result decoded and written to a file.
+	Base64(PathBuf),
+}
+
+impl EvalOutput {
+	fn from_flags(raw: Option<bool>, base64: Option<bool>, output: Option<PathBuf>) -> Result<Self> {
+		match (raw.unwrap_or(false), base64.unwrap_or(false), output) {
+			(true, true, _) => Err(PwError::Context("--raw and --base64 cannot be combined".into())),
+			(true, false, Some(_)) => Err(PwError::Context("--raw writes to stdout; --output is only used with --base64".into())),
+			(true, false, None) => Ok(Self::Raw),
+			(false, true, Some(path)) => Ok(Self::Base64(path)),
+			(false, true, None) => Err(PwError::Context("--base64 requires --output".into())),
+			(false, false, Some(_)) => Err(PwError::Context("--output requires --base64".into())),
+			(false, false, None) => Ok(Self::Json),
+		}
+	}
+}
+
+/// Element scope for a selector-bound evaluation.
+#[derive(Debug, Clone)]
+pub struct EvalScope {
+	pub selector: String,
+	/// Pass every match as an array rather than the first match.
+	pub all: bool,
 }
 
 impl Resolve for EvalRaw {
@@ -64,7 +138,21 @@ impl Resolve for EvalRaw {
 			.or(self.expression)
 			.ok_or_else(|| PwError::Context("expression is required (provide positionally, via --expr, or via --file)".into()))?;
 
-		Ok(EvalResolved { target, expression })
+		let all = self.all.unwrap_or(false);
+		let scope = match self.selector {
+			Some(selector) => Some(EvalScope { selector, all }),
+			None if all => return Err(PwError::Context("--all requires --selector".into())),
+			None => None,
+		};
+
+		let output = EvalOutput::from_flags(self.raw, self.base64, self.output)?;
+
+		Ok(EvalResolved {
+			target,
+			expression,
+			scope,
+			output,
+		})
 	}
 }
 
@@ -77,6 +165,15 @@ impl CommandDef for EvalCommand {
 	type Resolved = EvalResolved;
 	type Data = EvalData;
 
+	fn validate_mode(raw: &Self::Raw, mode: ExecMode) -> Result<()> {
+		if mode == ExecMode::Batch && raw.raw == Some(true) {
+			return Err(PwError::UnsupportedMode(
+				"command 'page.eval' with raw is not available in batch/ndjson mode".to_string(),
+			));
+		}
+		Ok(())
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -89,23 +186,86 @@ impl CommandDef for EvalCommand {
 			let expression = args.expression.clone();
 			let expression_for_inputs = truncate_expression(&expression);
 
-			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+			let scope = args.scope.clone();
+
+			let mut data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let expression = expression.clone();
+				let scope = scope.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-					let wrapped_expr = format!("JSON.stringify({})", expression);
-					let raw_result = session.page().evaluate_value(&wrapped_expr).await;
+					let value = match scope {
+						Some(EvalScope { selector, all }) => {
+							let locator = session.locator(&selector).await;
+							let result = if all {
+								locator.evaluate_all(&expression).await
+							} else {
+								locator.evaluate(&expression, None).await
+							};
+							result.map_err(|e| match e {
+								pw_rs::pw_runtime::Error::ElementNotFound(_) => PwError::ElementNotFound { selector: selector.clone() },
+								e => PwError::JsEval(e.to_string()),
+							})?
+						}
+						None => {
+							let wrapped_expr = format!("JSON.stringify({})", expression);
+							let raw_result = session.page().evaluate_value(&wrapped_expr).await;
 
-					let json_str = raw_result.map_err(|e| PwError::JsEval(e.to_string()))?;
-					let value: serde_json::Value = serde_json::from_str(&json_str).unwrap_or(serde_json::Value::Null);
+							let json_str = raw_result.map_err(|e| PwError::JsEval(e.to_string()))?;
+							serde_json::from_str(&json_str).unwrap_or(serde_json::Value::Null)
+						}
+					};
 
-					Ok(EvalData { result: value, expression })
+					Ok(EvalData {
+						result: value,
+						expression,
+						output: None,
+						size_bytes: None,
+					})
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, Some(expression_for_inputs), None, None);
+			match &args.output {
+				EvalOutput::Json => {}
+				EvalOutput::Raw => {
+					let text = string_result(&data.result, "--raw")?;
+					let mut stdout = std::io::stdout().lock();
+					stdout.write_all(text.as_bytes())?;
+					stdout.flush()?;
+				}
+				EvalOutput::Base64(path) => {
+					let bytes = decode_base64(string_result(&data.result, "--base64")?)?;
+					let path = exec.ctx_state.resolve_output(exec.ctx, Some(path.clone()));
+					if let Some(parent) = path.parent() {
+						if !parent.as_os_str().is_empty() && !parent.exists() {
+							std::fs::create_dir_all(parent)?;
+						}
+					}
+					std::fs::write(&path, &bytes)?;
+					data.result = serde_json::Value::Null;
+					data.output = Some(path);
+					data.size_bytes = Some(bytes.len());
+				}
+			}
+
+			let selector = args.scope.as_ref().map(|scope| scope.selector.as_str());
+			let mut extra = serde_json::Map::new();
+			if args.scope.as_ref().is_some_and(|scope| scope.all) {
+				extra.insert("all".into(), true.into());
+			}
+			match &args.output {
+				EvalOutput::Json => {}
+				EvalOutput::Raw => {
+					extra.insert("raw".into(), true.into());
+				}
+				EvalOutput::Base64(path) => {
+					extra.insert("base64".into(), true.into());
+					extra.insert("output".into(), serde_json::to_value(path)?);
+				}
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, selector, Some(expression_for_inputs), None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -116,6 +276,35 @@ impl CommandDef for EvalCommand {
 	}
 }
 
+/// Returns the result as a string, or fails naming the `flag` that needs one.
+fn string_result<'a>(value: &'a serde_json::Value, flag: &str) -> Result<&'a str> {
+	value
+		.as_str()
+		.ok_or_else(|| PwError::Context(format!("{flag} requires the expression to return a string, got {}", json_type(value))))
+}
+
+fn json_type(value: &serde_json::Value) -> &'static str {
+	match value {
+		serde_json::Value::Null => "null",
+		serde_json::Value::Bool(_) => "boolean",
+		serde_json::Value::Number(_) => "number",
+		serde_json::Value::String(_) => "string",
+		serde_json::Value::Array(_) => "array",
+		serde_json::Value::Object(_) => "object",
+	}
+}
+
+/// Decodes standard base64, dropping a leading `data:...;base64,` prefix.
+fn decode_base64(text: &str) -> Result<Vec<u8>> {
+	let payload = match text.strip_prefix("data:") {
+		Some(rest) => rest.split_once(";base64,").map_or(text, |(_, data)| data),
+		None => text,
+	};
+	STANDARD
+		.decode(payload.trim())
+		.map_err(|e| PwError::Context(format!("--base64 result is not valid base64: {e}")))
+}
+
 /// Truncate expression for output (avoid huge expressions in output)
 fn truncate_expression(expr: &str) -> String {
 	const MAX_LEN: usize = 500;
@@ -157,4 +346,38 @@ mod tests {
 		assert_eq!(raw.url, Some("https://example.com".into()));
 		assert_eq!(raw.expression, Some("document.title".into()));
 	}
+
+	#[test]
+	fn eval_raw_deserializes_selector_scope() {
+		let json = r#"{"expression": "els => els.length", "selector": "li", "all": true}"#;
+		let raw: EvalRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.selector.as_deref(), Some("li"));
+		assert_eq!(raw.all, Some(true));
+	}
+
+	#[test]
+	fn eval_output_flags_resolve() {
+		assert_eq!(EvalOutput::from_flags(None, None, None).unwrap(), EvalOutput::Json);
+		assert_eq!(EvalOutput::from_flags(Some(true), None, None).unwrap(), EvalOutput::Raw);
+		assert_eq!(
+			EvalOutput::from_flags(None, Some(true), Some(PathBuf::from("a.bin"))).unwrap(),
+			EvalOutput::Base64(PathBuf::from("a.bin"))
+		);
+		assert!(EvalOutput::from_flags(None, Some(true), None).is_err());
+		assert!(EvalOutput::from_flags(Some(true), Some(true), Some(PathBuf::from("a.bin"))).is_err());
+		assert!(EvalOutput::from_flags(None, None, Some(PathBuf::from("a.bin"))).is_err());
+	}
+
+	#[test]
+	fn decode_base64_strips_data_url_prefix() {
+		assert_eq!(decode_base64("aGk=").unwrap(), b"hi");
+		assert_eq!(decode_base64("data:text/plain;base64,aGk=").unwrap(), b"hi");
+		assert!(decode_base64("not base64!").is_err());
+	}
+
+	#[test]
+	fn string_result_rejects_non_strings() {
+		let err = string_result(&serde_json::json!(42), "--raw").unwrap_err();
+		assert!(err.to_string().contains("got number"), "unexpected error: {err}");
+	}
 }
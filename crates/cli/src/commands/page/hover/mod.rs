@@ -0,0 +1,221 @@
+//! Hover element command.
+//!
+//! Moves the mouse over an element, e.g. to open a dropdown menu before
+//! extracting its entries with `page.elements` or `page.text` in a batch.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.hover --selector "nav .account"
+//! pw page.hover --selector "#chart" --position 40,10
+//! ```
+
+use clap::Args;
+use pw_rs::{HoverOptions, KeyboardModifier, Position, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::click::{modifier_name, parse_modifiers, parse_position};
+use crate::commands::contract::{describe_target, resolve_target_and_selector, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::output::{CommandInputs, HoverData};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoverRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// CSS selector (positional)
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// CSS selector (named alternative)
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default, alias = "selector_flag")]
+	pub selector_flag: Option<String>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+
+	/// Skip actionability checks and hover even if the element is covered
+	#[arg(long)]
+	#[serde(default)]
+	pub force: Option<bool>,
+
+	/// Hover at "x,y" relative to the element's top-left corner instead of its center
+	#[arg(long, value_name = "X,Y")]
+	#[serde(default)]
+	pub position: Option<String>,
+
+	/// Modifier keys held during the hover: comma-separated ctrl, meta, shift, alt, or mod
+	#[arg(long, value_name = "KEYS")]
+	#[serde(default)]
+	pub modifiers: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct HoverResolved {
+	pub target: ResolvedTarget,
+	pub selector: String,
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+	/// Skip Playwright's actionability checks.
+	pub force: bool,
+	/// Hover offset from the element's top-left corner.
+	pub position: Option<Position>,
+	/// Modifier keys held during the hover, in the order given.
+	pub modifiers: Vec<KeyboardModifier>,
+}
+
+impl Resolve for HoverRaw {
+	type Output = HoverResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, None)?;
+		let position = self.position.as_deref().map(parse_position).transpose()?;
+		let modifiers = self.modifiers.as_deref().map(parse_modifiers).transpose()?.unwrap_or_default();
+
+		Ok(HoverResolved {
+			target,
+			selector,
+			strict: self.strict.unwrap_or(true),
+			force: self.force.unwrap_or(false),
+			position,
+			modifiers,
+		})
+	}
+}
+
+pub struct HoverCommand;
+
+impl CommandDef for HoverCommand {
+	const NAME: &'static str = "page.hover";
+
+	type Raw = HoverRaw;
+	type Resolved = HoverResolved;
+	type Data = HoverData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let action = if args.force { "force-hover" } else { "hover" };
+		let matching = if args.strict { "the single element" } else { "the first element" };
+		Some(Explanation {
+			summary: format!("Will {}, {action} {matching} matching '{}'.", describe_target(&args.target), args.selector),
+			inputs: hover_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, browser = %exec.ctx.browser, "hover element");
+
+			let selector = args.selector.clone();
+			let strict = args.strict;
+			let base_opts = hover_options(args);
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "page.hover" },
+				move |session, flow| {
+					let selector = selector.clone();
+					let base_opts = base_opts.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let locator = if strict {
+							session.single_locator(&selector).await?
+						} else {
+							session.locator(&selector).await
+						};
+						let hover_opts = HoverOptions {
+							timeout: Some(flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64) as f64),
+							..base_opts
+						};
+						locator.hover(Some(hover_opts)).await?;
+
+						Ok(HoverData {
+							url: session.page().url(),
+							selector,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = hover_inputs(args);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), Some(&args.selector), None),
+			})
+		})
+	}
+}
+
+/// Inputs echoed for a hover, listing only options that differ from their defaults.
+fn hover_inputs(args: &HoverResolved) -> CommandInputs {
+	let mut extra = serde_json::Map::new();
+	if !args.strict {
+		extra.insert("strict".into(), false.into());
+	}
+	if args.force {
+		extra.insert("force".into(), true.into());
+	}
+	if let Some(position) = args.position {
+		extra.insert("position".into(), format!("{},{}", position.x, position.y).into());
+	}
+	if !args.modifiers.is_empty() {
+		extra.insert(
+			"modifiers".into(),
+			args.modifiers.iter().map(|m| modifier_name(*m)).collect::<Vec<_>>().join(",").into(),
+		);
+	}
+	let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+	standard_inputs(&args.target, Some(&args.selector), None, None, extra)
+}
+
+/// Builds the hover options, leaving the timeout to the flow. Options are only sent when set.
+fn hover_options(args: &HoverResolved) -> HoverOptions {
+	HoverOptions {
+		force: args.force.then_some(true),
+		position: args.position,
+		modifiers: (!args.modifiers.is_empty()).then(|| args.modifiers.clone()),
+		..HoverOptions::default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn hover_raw_deserialize_from_json() {
+		let raw: HoverRaw = serde_json::from_str(r#"{"selector": "nav .menu", "position": "5,5", "modifiers": "shift"}"#).unwrap();
+		assert_eq!(raw.selector, Some("nav .menu".into()));
+		assert_eq!(raw.position, Some("5,5".into()));
+		assert_eq!(raw.modifiers, Some("shift".into()));
+		assert_eq!(raw.force, None);
+	}
+}
@@ -92,7 +92,7 @@ impl CommandDef for HtmlCommand {
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-					let locator = session.page().locator(&selector).await;
+					let locator = session.locator(&selector).await;
 					let html = locator.inner_html().await?;
 
 					Ok(HtmlData {
@@ -0,0 +1,185 @@
+//! Page meta tag extraction command.
+//!
+//! Returns the SEO-relevant head metadata of a page: title, description,
+//! canonical URL, robots directives, OpenGraph and Twitter card tags,
+//! JSON-LD structured data parsed into JSON, and the page language.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.meta https://example.com
+//! pw exec page.meta --input '{"url":"https://example.com"}'
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::commands::page::snapshot::{EXTRACT_META_JS, PageMeta};
+use crate::error::Result;
+use crate::output::PageMetaData;
+use crate::readable::page_language;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct MetaResolved {
+	/// Navigation target (URL to navigate to, or current page in CDP mode).
+	pub target: ResolvedTarget,
+}
+
+impl Resolve for MetaRaw {
+	type Output = MetaResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(MetaResolved { target })
+	}
+}
+
+pub struct MetaCommand;
+
+impl CommandDef for MetaCommand {
+	const NAME: &'static str = "page.meta";
+
+	type Raw = MetaRaw;
+	type Resolved = MetaResolved;
+	type Data = PageMetaData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "page meta");
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let meta_js = format!("JSON.stringify({})", EXTRACT_META_JS);
+					let meta: PageMeta = serde_json::from_str(&session.page().evaluate_value(&meta_js).await?)?;
+					let text = match meta.lang {
+						Some(_) => String::new(),
+						None => session.page().evaluate_value(TEXT_SAMPLE_JS).await?,
+					};
+					Ok(meta_data(meta, &text))
+				})
+			})
+			.await?;
+
+			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), None, None),
+			})
+		})
+	}
+}
+
+/// Body text sampled for language detection when `<html lang>` is missing.
+const TEXT_SAMPLE_JS: &str = "(document.body ? document.body.innerText : '').slice(0, 5000)";
+
+/// Builds the command output from extracted metadata, parsing JSON-LD blocks.
+///
+/// The language comes from `<html lang>`, falling back to detection on `text`.
+fn meta_data(meta: PageMeta, text: &str) -> PageMetaData {
+	let mut json_ld = Vec::with_capacity(meta.json_ld.len());
+	let mut invalid_json_ld = 0;
+	for block in &meta.json_ld {
+		match serde_json::from_str(block) {
+			Ok(value) => json_ld.push(value),
+			Err(_) => invalid_json_ld += 1,
+		}
+	}
+
+	PageMetaData {
+		url: meta.url,
+		title: meta.title,
+		description: meta.description,
+		canonical: meta.canonical,
+		robots: meta.robots,
+		og: meta.og,
+		twitter: meta.twitter,
+		json_ld,
+		invalid_json_ld,
+		language: page_language(meta.lang.as_deref(), text),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn meta_raw_deserialize_from_json() {
+		let raw: MetaRaw = serde_json::from_str(r#"{"urlFlag": "https://example.com"}"#).unwrap();
+		assert_eq!(raw.url, None);
+		assert_eq!(raw.url_flag, Some("https://example.com".into()));
+	}
+
+	#[test]
+	fn meta_data_parses_json_ld_and_counts_invalid_blocks() {
+		let meta: PageMeta = serde_json::from_value(serde_json::json!({
+			"url": "https://example.com/",
+			"title": "Example",
+			"viewport_width": 1280,
+			"viewport_height": 720,
+			"og": {"title": "OG Example"},
+			"json_ld": [r#"{"@type": "Article", "headline": "Hi"}"#, "{not json"]
+		}))
+		.unwrap();
+
+		let data = meta_data(meta, "");
+		assert_eq!(data.og.get("title").map(String::as_str), Some("OG Example"));
+		assert_eq!(data.json_ld.len(), 1);
+		assert_eq!(data.json_ld[0]["@type"], "Article");
+		assert_eq!(data.invalid_json_ld, 1);
+		assert_eq!(data.language, None);
+	}
+
+	#[test]
+	fn meta_data_prefers_html_lang_over_detection() {
+		let meta = |lang: Option<&str>| -> PageMeta {
+			serde_json::from_value(serde_json::json!({
+				"url": "https://example.com/",
+				"title": "Example",
+				"viewport_width": 1280,
+				"viewport_height": 720,
+				"lang": lang,
+			}))
+			.unwrap()
+		};
+		let text = "Die Stadt liegt an dem Fluss und ist für ihre Märkte bekannt, die auch im Winter nicht geschlossen sind.";
+
+		let language = meta_data(meta(Some("pt-BR")), text).language.unwrap();
+		assert_eq!((language.code.as_str(), language.confidence), ("pt", 1.0));
+
+		let language = meta_data(meta(None), text).language.unwrap();
+		assert_eq!(language.code, "de");
+		assert!(language.confidence < 1.0);
+	}
+}
@@ -1,10 +1,19 @@
 //! Page content extraction commands.
 
+pub mod accessibility;
 pub mod console;
 pub mod coords;
 pub mod elements;
 pub mod eval;
+pub mod hover;
 pub mod html;
+pub mod meta;
+pub mod pdf;
 pub mod read;
+pub mod schema;
+pub mod scroll;
+pub mod scroll_to_load;
+pub mod select;
 pub mod snapshot;
 pub mod text;
+pub mod upload;
@@ -0,0 +1,212 @@
+//! PDF export command (Chromium only).
+
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::{PdfMargin, PdfOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::cli::CliPdfFormat;
+use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+use crate::types::BrowserKind;
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Output file path (defaults to page.pdf)
+	#[arg(short, long, value_name = "FILE")]
+	#[serde(default)]
+	pub output: Option<PathBuf>,
+
+	/// Paper format
+	#[arg(long, value_enum, value_name = "FORMAT")]
+	#[serde(default)]
+	pub format: Option<CliPdfFormat>,
+
+	/// Landscape orientation
+	#[arg(long)]
+	#[serde(default)]
+	pub landscape: Option<bool>,
+
+	/// Print background graphics
+	#[arg(long)]
+	#[serde(default, alias = "print_background")]
+	pub print_background: Option<bool>,
+
+	/// Rendering scale, between 0.1 and 2
+	#[arg(long)]
+	#[serde(default)]
+	pub scale: Option<f64>,
+
+	/// Margin on every side as a CSS length (e.g. 1cm, 0.5in)
+	#[arg(long, value_name = "LENGTH")]
+	#[serde(default)]
+	pub margin: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct PdfResolved {
+	pub target: ResolvedTarget,
+	/// Output path as given; project placement happens at execution.
+	pub output: PathBuf,
+	pub format: Option<CliPdfFormat>,
+	pub landscape: bool,
+	pub print_background: bool,
+	pub scale: Option<f64>,
+	pub margin: Option<String>,
+}
+
+impl Resolve for PdfRaw {
+	type Output = PdfResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		if let Some(scale) = self.scale {
+			if !(0.1..=2.0).contains(&scale) {
+				return Err(PwError::Context(format!("scale must be between 0.1 and 2, got {scale}")));
+			}
+		}
+
+		Ok(PdfResolved {
+			target,
+			output: self.output.unwrap_or_else(|| PathBuf::from("page.pdf")),
+			format: self.format,
+			landscape: self.landscape.unwrap_or(false),
+			print_background: self.print_background.unwrap_or(false),
+			scale: self.scale,
+			margin: self.margin,
+		})
+	}
+}
+
+impl PdfResolved {
+	fn options(&self) -> PdfOptions {
+		PdfOptions {
+			format: self.format.map(Into::into),
+			landscape: Some(self.landscape),
+			print_background: Some(self.print_background),
+			scale: self.scale,
+			margin: self.margin.clone().map(PdfMargin::uniform),
+		}
+	}
+}
+
+/// Output data for pdf command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfData {
+	pub path: PathBuf,
+	pub size_bytes: usize,
+}
+
+pub struct PdfCommand;
+
+impl CommandDef for PdfCommand {
+	const NAME: &'static str = "page.pdf";
+
+	type Raw = PdfRaw;
+	type Resolved = PdfResolved;
+	type Data = PdfData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			if exec.ctx.browser != BrowserKind::Chromium {
+				return Err(PwError::Context(format!(
+					"page.pdf requires chromium; PDF generation is not supported in {}",
+					exec.ctx.browser
+				)));
+			}
+
+			let output = exec.ctx_state.resolve_output(exec.ctx, Some(args.output.clone()));
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, path = %output.display(), "pdf");
+
+			if let Some(parent) = output.parent() {
+				if !parent.as_os_str().is_empty() && !parent.exists() {
+					std::fs::create_dir_all(parent)?;
+				}
+			}
+
+			let options = args.options();
+			let path = output.clone();
+
+			let size_bytes = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+				let options = options.clone();
+				let path = path.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+					let bytes = session.page().pdf(Some(options)).await?;
+					tokio::fs::write(&path, &bytes).await?;
+					Ok(bytes.len())
+				})
+			})
+			.await?;
+
+			let mut extra = serde_json::Map::new();
+			if let Some(format) = args.format {
+				extra.insert("format".into(), serde_json::to_value(format)?);
+			}
+			if args.landscape {
+				extra.insert("landscape".into(), true.into());
+			}
+			if args.print_background {
+				extra.insert("printBackground".into(), true.into());
+			}
+			if let Some(scale) = args.scale {
+				extra.insert("scale".into(), scale.into());
+			}
+			if let Some(margin) = &args.margin {
+				extra.insert("margin".into(), margin.as_str().into());
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, None, None, Some(&output), extra);
+
+			Ok(CommandOutcome {
+				inputs,
+				data: PdfData {
+					path: output.clone(),
+					size_bytes,
+				},
+				delta: standard_delta(&args.target, None, Some(&output)),
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn pdf_raw_deserialize() {
+		let json =
+			r#"{"url": "https://example.com", "output": "out.pdf", "format": "a4", "landscape": true, "printBackground": true, "scale": 0.5, "margin": "1cm"}"#;
+		let raw: PdfRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.output, Some(PathBuf::from("out.pdf")));
+		assert_eq!(raw.format, Some(CliPdfFormat::A4));
+		assert_eq!(raw.landscape, Some(true));
+		assert_eq!(raw.print_background, Some(true));
+		assert_eq!(raw.scale, Some(0.5));
+		assert_eq!(raw.margin.as_deref(), Some("1cm"));
+	}
+}
@@ -0,0 +1,244 @@
+//! Bulk readable extraction across a URL list.
+//!
+//! Opens up to `concurrency` extra pages in the session's browser context,
+//! each pulling the next URL off a shared queue, and writes one markdown file
+//! per extracted page. A failing URL is recorded and the rest carry on.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use pw_rs::{GotoOptions, WaitUntil};
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::error::{PwError, Result};
+use crate::readable::{ReadableContent, ReadableOptions, extract_readable_with};
+use crate::session::SessionHandle;
+
+/// Pages extracted at once when the request does not set `concurrency`.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Directory written to when the request does not set `outDir`.
+pub const DEFAULT_OUT_DIR: &str = "articles";
+
+/// Longest file stem derived from a title or URL, in characters.
+const MAX_FILE_STEM_CHARS: usize = 80;
+
+/// Resolved bulk-mode inputs.
+#[derive(Debug, Clone)]
+pub struct BulkRead {
+	/// Path of the URL list, as given.
+	pub list: PathBuf,
+	/// URLs read from the list, in order.
+	pub urls: Vec<String>,
+	/// Maximum pages extracted at once; always at least 1.
+	pub concurrency: usize,
+	/// Directory receiving one markdown file per extracted page.
+	pub out_dir: PathBuf,
+}
+
+impl BulkRead {
+	/// Reads the URL list at `list`: one URL per line, skipping blank lines and `#` comments.
+	pub(crate) fn load(list: PathBuf, concurrency: Option<usize>, out_dir: Option<PathBuf>) -> Result<Self> {
+		let content = std::fs::read_to_string(&list)?;
+		let urls: Vec<String> = content
+			.lines()
+			.map(str::trim)
+			.filter(|line| !line.is_empty() && !line.starts_with('#'))
+			.map(str::to_string)
+			.collect();
+		if urls.is_empty() {
+			return Err(PwError::Context(format!("no URLs in {}", list.display())));
+		}
+
+		let concurrency = concurrency.unwrap_or(DEFAULT_CONCURRENCY);
+		if concurrency == 0 {
+			return Err(PwError::Context("concurrency must be at least 1".into()));
+		}
+
+		Ok(Self {
+			list,
+			urls,
+			concurrency,
+			out_dir: out_dir.unwrap_or_else(|| PathBuf::from(DEFAULT_OUT_DIR)),
+		})
+	}
+}
+
+/// Result data for `page.read` with a URL list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadBulkData {
+	pub out_dir: PathBuf,
+	/// Pages written, in URL-list order.
+	pub articles: Vec<ReadArticle>,
+	/// URLs that failed to load, extract, or write, in URL-list order.
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub failures: Vec<ReadFailure>,
+}
+
+/// One page written by bulk extraction.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadArticle {
+	pub url: String,
+	pub path: PathBuf,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	pub word_count: usize,
+}
+
+/// One URL that bulk extraction gave up on.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadFailure {
+	pub url: String,
+	pub error: String,
+}
+
+/// Extracts every URL in `bulk` and writes the markdown files.
+///
+/// Each worker page navigates with `timeout_ms` and waits for network idle,
+/// like single-page `page.read`. A worker that cannot open its page records
+/// the error against the URL it claimed and tries again for the next one.
+/// Worker pages are closed afterwards; the session's own page is left
+/// untouched.
+pub(crate) async fn read_all(session: &SessionHandle, bulk: &BulkRead, options: ReadableOptions, timeout_ms: Option<u64>) -> Result<ReadBulkData> {
+	std::fs::create_dir_all(&bulk.out_dir)?;
+
+	let next = AtomicUsize::new(0);
+	let workers = (0..bulk.concurrency.min(bulk.urls.len())).map(|worker| {
+		let next = &next;
+		async move {
+			let mut page = None;
+			let mut results = Vec::new();
+			loop {
+				let index = next.fetch_add(1, Ordering::Relaxed);
+				let Some(url) = bulk.urls.get(index) else {
+					break;
+				};
+				debug!(target = "pw", worker, url, "bulk read");
+				let result = match &page {
+					Some(page) => read_one(page, url, options, timeout_ms).await,
+					None => match session.context().new_page().await {
+						Ok(opened) => read_one(page.insert(opened), url, options, timeout_ms).await,
+						Err(err) => Err(err.into()),
+					},
+				};
+				results.push((index, result));
+			}
+			if let Some(page) = page
+				&& let Err(err) = page.close().await
+			{
+				warn!(target = "pw", worker, error = %err, "failed to close bulk read page");
+			}
+			results
+		}
+	});
+
+	let mut results: Vec<_> = futures::future::join_all(workers).await.into_iter().flatten().collect();
+	results.sort_by_key(|(index, _)| *index);
+
+	let mut data = ReadBulkData {
+		out_dir: bulk.out_dir.clone(),
+		articles: Vec::new(),
+		failures: Vec::new(),
+	};
+	let mut used = HashSet::new();
+	for (index, result) in results {
+		let url = &bulk.urls[index];
+		match result.and_then(|readable| write_article(&bulk.out_dir, url, readable, &mut used)) {
+			Ok(article) => data.articles.push(article),
+			Err(err) => data.failures.push(ReadFailure {
+				url: url.clone(),
+				error: err.to_string(),
+			}),
+		}
+	}
+	Ok(data)
+}
+
+async fn read_one(page: &pw_rs::Page, url: &str, options: ReadableOptions, timeout_ms: Option<u64>) -> Result<ReadableContent> {
+	let goto_opts = GotoOptions {
+		wait_until: Some(WaitUntil::NetworkIdle),
+		timeout: timeout_ms.map(Duration::from_millis),
+	};
+	page.goto(url, Some(goto_opts)).await?;
+	let html = page.evaluate_value("document.documentElement.outerHTML").await?;
+	Ok(extract_readable_with(&html, Some(url), options))
+}
+
+fn write_article(out_dir: &Path, url: &str, readable: ReadableContent, used: &mut HashSet<String>) -> Result<ReadArticle> {
+	let stem = file_stem(readable.metadata.title.as_deref(), url);
+	let mut name = format!("{stem}.md");
+	let mut suffix = 2;
+	while !used.insert(name.clone()) {
+		name = format!("{stem}-{suffix}.md");
+		suffix += 1;
+	}
+
+	let path = out_dir.join(name);
+	std::fs::write(&path, readable.markdown.as_deref().unwrap_or(&readable.text))?;
+	Ok(ReadArticle {
+		url: url.to_string(),
+		path,
+		title: readable.metadata.title,
+		word_count: readable.word_count,
+	})
+}
+
+/// Builds a lowercase, dash-separated file stem from `title`, falling back to `url`.
+fn file_stem(title: Option<&str>, url: &str) -> String {
+	let slug = |source: &str| -> String {
+		let mut slug = String::new();
+		for c in source.chars().flat_map(char::to_lowercase) {
+			if c.is_alphanumeric() {
+				slug.push(c);
+			} else if !slug.is_empty() && !slug.ends_with('-') {
+				slug.push('-');
+			}
+		}
+		slug.chars().take(MAX_FILE_STEM_CHARS).collect::<String>().trim_end_matches('-').to_string()
+	};
+
+	let from_title = title.map(slug).unwrap_or_default();
+	if !from_title.is_empty() {
+		return from_title;
+	}
+	let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+	let from_url = slug(without_scheme);
+	if from_url.is_empty() { "article".to_string() } else { from_url }
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::TempDir;
+
+	use super::*;
+
+	#[test]
+	fn file_stem_prefers_title_then_url() {
+		assert_eq!(file_stem(Some("Hello, World! A Story"), "https://example.com/x"), "hello-world-a-story");
+		assert_eq!(file_stem(Some("  "), "https://example.com/posts/42?ref=rss"), "example-com-posts-42-ref-rss");
+		assert_eq!(file_stem(None, "://"), "article");
+		assert_eq!(file_stem(Some("a".repeat(200).as_str()), "").len(), MAX_FILE_STEM_CHARS);
+	}
+
+	#[test]
+	fn load_skips_blank_lines_and_comments() {
+		let dir = TempDir::new().unwrap();
+		let list = dir.path().join("urls.txt");
+		std::fs::write(&list, "# corpus\nhttps://a.example\n\n  https://b.example  \n").unwrap();
+
+		let bulk = BulkRead::load(list.clone(), None, None).unwrap();
+		assert_eq!(bulk.urls, vec!["https://a.example", "https://b.example"]);
+		assert_eq!(bulk.concurrency, DEFAULT_CONCURRENCY);
+		assert_eq!(bulk.out_dir, PathBuf::from(DEFAULT_OUT_DIR));
+		assert!(BulkRead::load(list.clone(), Some(0), None).is_err());
+
+		std::fs::write(&list, "# nothing\n").unwrap();
+		assert!(BulkRead::load(list, None, None).is_err());
+	}
+}
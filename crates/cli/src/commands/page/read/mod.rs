@@ -14,8 +14,24 @@
 //!
 //! ```bash
 //! pw read https://example.com/article --metadata
+//! pw read https://example.com/article --output article.md
+//! pw read --urls urls.txt --concurrency 4 --out-dir articles/
 //! ```
+//!
+//! With `--selector`, only the first matching element's `outerHTML` is fed to
+//! the extraction pipeline, skipping its main-content detection. Metadata then
+//! comes from that element alone, so fields like the title are often missing.
+//!
+//! With `--output`, the markdown is also written to a file headed by YAML
+//! frontmatter (title, byline, site name, url, extraction time).
+//!
+//! With `--urls`, every listed page is extracted in bulk; see [`bulk`].
+
+mod bulk;
+
+use std::path::{Path, PathBuf};
 
+pub use bulk::{BulkRead, ReadBulkData};
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
@@ -25,8 +41,8 @@ use crate::cli::ReadOutputFormat;
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
-use crate::readable::{ReadableContent, extract_readable};
+use crate::error::{PwError, Result};
+use crate::readable::{DEFAULT_MAX_DEPTH, DEFAULT_WORDS_PER_MINUTE, Language, ReadableContent, ReadableOptions, extract_readable_with, markdown_document};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
 
@@ -43,6 +59,11 @@ pub struct ReadRaw {
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
 
+	/// Extract only within the element matching this CSS selector
+	#[arg(long, short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
 	/// Output format: markdown (default), text, or html
 	#[arg(long, short = 'o', default_value = "markdown", value_enum)]
 	#[serde(default, alias = "output_format")]
@@ -52,6 +73,36 @@ pub struct ReadRaw {
 	#[arg(long, short = 'm')]
 	#[serde(default)]
 	pub metadata: Option<bool>,
+
+	/// Drop elements nested deeper than this many levels (default: 256)
+	#[arg(long, value_name = "N")]
+	#[serde(default, alias = "max_depth")]
+	pub max_depth: Option<usize>,
+
+	/// Reading speed for the reading-time estimate (default: 200)
+	#[arg(long = "wpm", value_name = "N")]
+	#[serde(default, alias = "wpm", alias = "words_per_minute")]
+	pub words_per_minute: Option<usize>,
+
+	/// Also write the markdown, with YAML frontmatter, to this file
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub output: Option<PathBuf>,
+
+	/// File listing URLs to extract in bulk, one per line
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub urls: Option<PathBuf>,
+
+	/// Pages extracted at once with --urls (default: 4)
+	#[arg(long, value_name = "N")]
+	#[serde(default)]
+	pub concurrency: Option<usize>,
+
+	/// Directory for the markdown files written with --urls (default: articles)
+	#[arg(long, value_name = "DIR")]
+	#[serde(default, alias = "out_dir")]
+	pub out_dir: Option<PathBuf>,
 }
 
 /// Resolved inputs ready for execution.
@@ -67,19 +118,62 @@ pub struct ReadResolved {
 
 	/// Whether to include article metadata.
 	pub include_metadata: bool,
+
+	/// Depth guard and reading speed passed to the extraction pipeline.
+	pub options: ReadableOptions,
+
+	/// Content root to extract from instead of the whole document.
+	pub selector: Option<String>,
+
+	/// Markdown file to write alongside the response.
+	pub output: Option<PathBuf>,
+
+	/// URL list to extract into files instead of reading [`target`](Self::target).
+	pub bulk: Option<BulkRead>,
 }
 
 impl Resolve for ReadRaw {
 	type Output = ReadResolved;
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let bulk = match self.urls {
+			Some(_) if self.url.is_some() || self.url_flag.is_some() => {
+				return Err(PwError::Context("urls cannot be combined with url".into()));
+			}
+			Some(list) => Some(BulkRead::load(list, self.concurrency, self.out_dir)?),
+			None if self.concurrency.is_some() || self.out_dir.is_some() => {
+				return Err(PwError::Context("concurrency and outDir require urls".into()));
+			}
+			None => None,
+		};
+		if self.selector.is_some() && bulk.is_some() {
+			return Err(PwError::Context("selector cannot be combined with urls".into()));
+		}
+		if self.output.is_some() && bulk.is_some() {
+			return Err(PwError::Context("output cannot be combined with urls; use outDir".into()));
+		}
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
 		let output_format = self.output_format.unwrap_or(ReadOutputFormat::Markdown);
+		if self.output.is_some() && output_format != ReadOutputFormat::Markdown {
+			return Err(PwError::Context("output writes markdown; outputFormat must be markdown".into()));
+		}
+		let words_per_minute = self.words_per_minute.unwrap_or(DEFAULT_WORDS_PER_MINUTE);
+		if words_per_minute == 0 {
+			return Err(PwError::Context("wordsPerMinute must be at least 1".into()));
+		}
 
 		Ok(ReadResolved {
 			target,
 			output_format,
 			include_metadata: self.metadata.unwrap_or(false),
+			options: ReadableOptions {
+				max_depth: self.max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+				words_per_minute,
+				scoped: self.selector.is_some(),
+			},
+			selector: self.selector,
+			output: self.output,
+			bulk,
 		})
 	}
 }
@@ -91,44 +185,135 @@ impl CommandDef for ReadCommand {
 
 	type Raw = ReadRaw;
 	type Resolved = ReadResolved;
-	type Data = ReadData;
+	type Data = ReadOutput;
 
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
 	{
 		Box::pin(async move {
+			if let Some(bulk) = &args.bulk {
+				return execute_bulk(args, bulk, exec).await;
+			}
+
 			let url_display = args.target.url_str().unwrap_or("<current page>");
 			info!(target = "pw", url = %url_display, output_format = ?args.output_format, browser = %exec.ctx.browser, "extract readable content");
 
 			let output_format = args.output_format;
 			let include_metadata = args.include_metadata;
+			let options = args.options;
 			let url_str = args.target.url_str().map(String::from);
+			let output = args.output.clone();
+			let selector = args.selector.clone();
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let url_str = url_str.clone();
+				let output = output.clone();
+				let selector = selector.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-					let locator = session.page().locator("html").await;
-					let html = locator.inner_html().await?;
-					let readable = extract_readable(&html, url_str.as_deref());
-					Ok(ReadData::from_readable(readable, output_format, include_metadata))
+					let html = match &selector {
+						Some(selector) => {
+							let outer_html = session.locator(selector).await.first().evaluate("el => el.outerHTML", None).await?;
+							outer_html.as_str().unwrap_or_default().to_string()
+						}
+						None => session.page().evaluate_value("document.documentElement.outerHTML").await?,
+					};
+					let readable = extract_readable_with(&html, url_str.as_deref(), options);
+					if let Some(path) = &output {
+						let page_url = url_str.clone().unwrap_or_else(|| session.page().url());
+						write_markdown_file(path, &readable, &page_url)?;
+					}
+					let mut data = ReadData::from_readable(readable, output_format, include_metadata);
+					data.path = output;
+					Ok(data)
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let mut extra = serde_json::json!({
+				"outputFormat": args.output_format,
+				"metadata": args.include_metadata,
+			});
+			if args.options.max_depth != DEFAULT_MAX_DEPTH {
+				extra["maxDepth"] = args.options.max_depth.into();
+			}
+			if args.options.words_per_minute != DEFAULT_WORDS_PER_MINUTE {
+				extra["wordsPerMinute"] = args.options.words_per_minute.into();
+			}
+			let inputs = standard_inputs(&args.target, args.selector.as_deref(), None, args.output.as_deref(), Some(extra));
 
 			Ok(CommandOutcome {
 				inputs,
-				data,
-				delta: standard_delta(&args.target, None, None),
+				data: ReadOutput::Article(Box::new(data)),
+				delta: standard_delta(&args.target, args.selector.as_deref(), args.output.as_deref()),
 			})
 		})
 	}
 }
 
+async fn execute_bulk<'exec, 'ctx>(args: &'exec ReadResolved, bulk: &'exec BulkRead, mut exec: ExecCtx<'exec, 'ctx>) -> Result<CommandOutcome<ReadOutput>>
+where
+	'ctx: 'exec,
+{
+	info!(
+		target = "pw",
+		list = %bulk.list.display(),
+		urls = bulk.urls.len(),
+		concurrency = bulk.concurrency,
+		out_dir = %bulk.out_dir.display(),
+		browser = %exec.ctx.browser,
+		"extract readable content in bulk"
+	);
+
+	let options = args.options;
+	let list = bulk.clone();
+	let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+		let list = list.clone();
+		Box::pin(async move { bulk::read_all(session, &list, options, flow.timeout_ms).await })
+	})
+	.await?;
+
+	let mut extra = serde_json::json!({
+		"urls": bulk.list,
+		"concurrency": bulk.concurrency,
+		"outDir": bulk.out_dir,
+	});
+	if args.options.max_depth != DEFAULT_MAX_DEPTH {
+		extra["maxDepth"] = args.options.max_depth.into();
+	}
+	let inputs = standard_inputs(&args.target, None, None, None, Some(extra));
+
+	Ok(CommandOutcome {
+		inputs,
+		data: ReadOutput::Bulk(data),
+		delta: standard_delta(&args.target, None, None),
+	})
+}
+
+/// Writes `readable` as a markdown document with frontmatter to `path`.
+fn write_markdown_file(path: &Path, readable: &ReadableContent, url: &str) -> Result<()> {
+	let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+	let extracted_at = chrono::DateTime::from_timestamp(now.as_secs() as i64, 0)
+		.unwrap_or_default()
+		.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+	if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+		std::fs::create_dir_all(parent)?;
+	}
+	std::fs::write(path, markdown_document(readable, Some(url), &extracted_at))?;
+	Ok(())
+}
+
+/// Result data for `page.read`: one article, or a bulk run over a URL list.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ReadOutput {
+	Article(Box<ReadData>),
+	Bulk(ReadBulkData),
+}
+
 /// Extracted readable content with optional metadata.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -139,8 +324,8 @@ pub struct ReadData {
 	/// Format of the content field (`"text"`, `"html"`, or `"markdown"`).
 	pub format: String,
 
-	/// Word count of the extracted content.
-	pub word_count: usize,
+	/// Word count and reading time of the extracted text.
+	pub stats: ReadStats,
 
 	/// Page title from metadata.
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -165,6 +350,28 @@ pub struct ReadData {
 	/// Site name.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub site: Option<String>,
+
+	/// Declared `<html lang>` or language detected from the content.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub language: Option<Language>,
+
+	/// Whether content nested past `maxDepth` was dropped.
+	#[serde(skip_serializing_if = "std::ops::Not::not")]
+	pub truncated: bool,
+
+	/// Markdown file written with `output`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub path: Option<PathBuf>,
+}
+
+/// Size of the extracted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadStats {
+	/// Words in the cleaned text, ignoring stray punctuation.
+	pub word_count: usize,
+	/// `wordCount` at `wordsPerMinute` (default 200), rounded up to whole minutes.
+	pub estimated_reading_minutes: usize,
 }
 
 impl ReadData {
@@ -175,31 +382,41 @@ impl ReadData {
 			ReadOutputFormat::Markdown => (readable.markdown.unwrap_or_else(|| readable.text.clone()), "markdown".to_string()),
 		};
 
-		let word_count = content.split_whitespace().count();
+		let truncated = readable.truncated;
+		let stats = ReadStats {
+			word_count: readable.word_count,
+			estimated_reading_minutes: readable.reading_time_minutes,
+		};
 
 		if include_metadata {
 			Self {
 				content,
 				format,
-				word_count,
+				stats,
 				title: readable.metadata.title,
 				author: readable.metadata.author,
 				published: readable.metadata.published,
 				description: readable.metadata.description,
 				image: readable.metadata.image,
 				site: readable.metadata.site,
+				language: readable.metadata.language,
+				truncated,
+				path: None,
 			}
 		} else {
 			Self {
 				content,
 				format,
-				word_count,
+				stats,
 				title: None,
 				author: None,
 				published: None,
 				description: None,
 				image: None,
 				site: None,
+				language: None,
+				truncated,
+				path: None,
 			}
 		}
 	}
@@ -224,5 +441,42 @@ mod tests {
 		let raw: ReadRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.output_format, None);
 		assert_eq!(raw.metadata, None);
+		assert_eq!(raw.max_depth, None);
+	}
+
+	#[test]
+	fn read_raw_deserialize_max_depth() {
+		let raw: ReadRaw = serde_json::from_str(r#"{"maxDepth": 64}"#).unwrap();
+		assert_eq!(raw.max_depth, Some(64));
+		let raw: ReadRaw = serde_json::from_str(r#"{"max_depth": 64}"#).unwrap();
+		assert_eq!(raw.max_depth, Some(64));
+	}
+
+	#[test]
+	fn read_raw_deserialize_words_per_minute() {
+		let raw: ReadRaw = serde_json::from_str(r#"{"wordsPerMinute": 250}"#).unwrap();
+		assert_eq!(raw.words_per_minute, Some(250));
+		let raw: ReadRaw = serde_json::from_str(r#"{"wpm": 250}"#).unwrap();
+		assert_eq!(raw.words_per_minute, Some(250));
+	}
+
+	#[test]
+	fn read_raw_deserialize_bulk() {
+		let raw: ReadRaw = serde_json::from_str(r#"{"urls": "urls.txt", "concurrency": 8, "outDir": "out"}"#).unwrap();
+		assert_eq!(raw.urls, Some(PathBuf::from("urls.txt")));
+		assert_eq!(raw.concurrency, Some(8));
+		assert_eq!(raw.out_dir, Some(PathBuf::from("out")));
+	}
+
+	#[test]
+	fn read_raw_deserialize_output() {
+		let raw: ReadRaw = serde_json::from_str(r#"{"url": "https://example.com", "output": "article.md"}"#).unwrap();
+		assert_eq!(raw.output, Some(PathBuf::from("article.md")));
+	}
+
+	#[test]
+	fn read_raw_deserialize_selector() {
+		let raw: ReadRaw = serde_json::from_str(r##"{"url": "https://example.com", "selector": "#comments"}"##).unwrap();
+		assert_eq!(raw.selector.as_deref(), Some("#comments"));
 	}
 }
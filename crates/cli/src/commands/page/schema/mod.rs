@@ -0,0 +1,319 @@
+//! Structured data extraction command.
+//!
+//! Collects schema.org data embedded in a page: every
+//! `<script type="application/ld+json">` block, microdata items
+//! (`itemscope`/`itemprop`) and RDFa items (`typeof`/`property`). Microdata
+//! and RDFa items use the WHATWG microdata JSON shape:
+//! `{"type": [...], "id": ..., "properties": {"name": [values]}}`.
+//!
+//! JSON-LD blocks are parsed leniently: HTML comment and CDATA wrappers,
+//! trailing semicolons and raw line breaks inside strings are tolerated. A
+//! block that still fails to parse is reported under `errors` and the rest of
+//! the page is still returned. Identical items are only reported once.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.schema https://example.com/product
+//! pw exec page.schema --input '{"url":"https://example.com/product"}'
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::output::{SchemaData, SchemaError, SchemaItem, SchemaSource};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Collects raw JSON-LD blocks plus microdata and RDFa items from the document.
+const EXTRACT_SCHEMA_JS: &str = r#"
+(() => {
+    const tokens = (value) => (value || '').trim().split(/\s+/).filter(Boolean);
+    const text = (el) => (el.textContent || '').replace(/\s+/g, ' ').trim();
+    const scalar = (el, attrs) => {
+        for (const attr of attrs) {
+            if (el.hasAttribute(attr)) return el.getAttribute(attr).trim();
+        }
+        switch (el.tagName) {
+            case 'A': case 'AREA': case 'LINK': return el.href;
+            case 'IMG': case 'AUDIO': case 'VIDEO': case 'SOURCE': case 'EMBED': case 'IFRAME': case 'TRACK': return el.src;
+            case 'OBJECT': return el.data;
+            case 'DATA': case 'METER': return el.getAttribute('value') || text(el);
+            case 'TIME': return el.getAttribute('datetime') || text(el);
+            default: return text(el);
+        }
+    };
+    const item = (scope, scopeAttr, propAttr, types, idAttr, attrs) => {
+        const out = { type: types(scope), properties: {} };
+        const id = scope.getAttribute(idAttr);
+        if (id) out.id = id.trim();
+        const walk = (el) => {
+            for (const child of el.children) {
+                const names = tokens(child.getAttribute(propAttr));
+                const nested = child.hasAttribute(scopeAttr);
+                if (names.length) {
+                    const value = nested ? item(child, scopeAttr, propAttr, types, idAttr, attrs) : scalar(child, attrs);
+                    for (const name of names) (out.properties[name] ||= []).push(value);
+                }
+                if (!nested) walk(child);
+            }
+        };
+        walk(scope);
+        return out;
+    };
+    const topLevel = (scopeAttr, propAttr) => Array.from(document.querySelectorAll(`[${scopeAttr}]`))
+        .filter((el) => !el.hasAttribute(propAttr) && !(el.parentElement && el.parentElement.closest(`[${scopeAttr}]`)));
+
+    const microdataTypes = (el) => tokens(el.getAttribute('itemtype'));
+    const rdfaTypes = (el) => {
+        const vocabEl = el.closest('[vocab]');
+        const vocab = vocabEl ? vocabEl.getAttribute('vocab').trim() : '';
+        return tokens(el.getAttribute('typeof')).map((t) => (vocab && !t.includes(':') ? vocab + t : t));
+    };
+
+    return {
+        url: window.location.href,
+        jsonLd: Array.from(document.querySelectorAll('script[type="application/ld+json"]')).map((s) => s.textContent || ''),
+        microdata: topLevel('itemscope', 'itemprop').map((el) => item(el, 'itemscope', 'itemprop', microdataTypes, 'itemid', ['content'])),
+        rdfa: topLevel('typeof', 'property').map((el) => item(el, 'typeof', 'property', rdfaTypes, 'resource', ['content', 'resource', 'href', 'src'])),
+    };
+})()
+"#;
+
+/// Raw structured data as returned by [`EXTRACT_SCHEMA_JS`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageSchema {
+	url: String,
+	#[serde(default)]
+	json_ld: Vec<String>,
+	#[serde(default)]
+	microdata: Vec<Value>,
+	#[serde(default)]
+	rdfa: Vec<Value>,
+}
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct SchemaResolved {
+	/// Navigation target (URL to navigate to, or current page in CDP mode).
+	pub target: ResolvedTarget,
+}
+
+impl Resolve for SchemaRaw {
+	type Output = SchemaResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(SchemaResolved { target })
+	}
+}
+
+pub struct SchemaCommand;
+
+impl CommandDef for SchemaCommand {
+	const NAME: &'static str = "page.schema";
+
+	type Raw = SchemaRaw;
+	type Resolved = SchemaResolved;
+	type Data = SchemaData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "page schema");
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let schema_js = format!("JSON.stringify({})", EXTRACT_SCHEMA_JS);
+					let schema: PageSchema = serde_json::from_str(&session.page().evaluate_value(&schema_js).await?)?;
+					Ok(schema_data(schema))
+				})
+			})
+			.await?;
+
+			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), None, None),
+			})
+		})
+	}
+}
+
+/// Builds the command output: parses JSON-LD blocks and merges all sources, dropping duplicates.
+///
+/// Top-level JSON-LD arrays and `@graph` lists contribute one item per entry.
+fn schema_data(schema: PageSchema) -> SchemaData {
+	let mut items: Vec<SchemaItem> = Vec::new();
+	let mut errors = Vec::new();
+	let mut push = |source: SchemaSource, value: Value| {
+		if !items.iter().any(|item| item.source == source && item.value == value) {
+			items.push(SchemaItem {
+				source,
+				types: item_types(source, &value),
+				value,
+			});
+		}
+	};
+
+	for (block, raw) in schema.json_ld.iter().enumerate() {
+		match parse_json_ld(raw) {
+			Ok(value) => {
+				let mut objects = Vec::new();
+				flatten_json_ld(value, &mut objects);
+				objects.into_iter().for_each(|value| push(SchemaSource::JsonLd, value));
+			}
+			Err(err) => errors.push(SchemaError { block, error: err.to_string() }),
+		}
+	}
+	for value in schema.microdata {
+		push(SchemaSource::Microdata, value);
+	}
+	for value in schema.rdfa {
+		push(SchemaSource::Rdfa, value);
+	}
+
+	SchemaData {
+		url: schema.url,
+		item_count: items.len(),
+		items,
+		errors,
+	}
+}
+
+/// Parses a JSON-LD block, retrying once with common authoring mistakes cleaned up.
+fn parse_json_ld(raw: &str) -> serde_json::Result<Value> {
+	serde_json::from_str(raw).or_else(|err| serde_json::from_str(&clean_json_ld(raw)).map_err(|_| err))
+}
+
+/// Strips comment and CDATA wrappers and a trailing `;`, and turns raw line breaks and tabs into spaces.
+///
+/// Outside strings the replaced characters are insignificant whitespace; inside
+/// strings they are not valid JSON, so a space is the closest legal reading.
+fn clean_json_ld(raw: &str) -> String {
+	let mut s = raw.trim();
+	for prefix in ["<!--", "//<![CDATA[", "/*<![CDATA[*/", "<![CDATA["] {
+		s = s.strip_prefix(prefix).unwrap_or(s).trim_start();
+	}
+	for suffix in ["-->", "//]]>", "/*]]>*/", "]]>"] {
+		s = s.strip_suffix(suffix).unwrap_or(s).trim_end();
+	}
+	s = s.strip_suffix(';').unwrap_or(s);
+	s.replace(['\r', '\n', '\t'], " ")
+}
+
+/// Collects the objects of a JSON-LD document, flattening top-level arrays and `@graph` lists.
+///
+/// A `@graph` container's remaining keys (usually just `@context`) are kept as
+/// their own item only when they say more than the context.
+fn flatten_json_ld(value: Value, out: &mut Vec<Value>) {
+	match value {
+		Value::Array(entries) => entries.into_iter().for_each(|entry| flatten_json_ld(entry, out)),
+		Value::Object(mut map) => match map.remove("@graph") {
+			Some(graph) => {
+				flatten_json_ld(graph, out);
+				if map.keys().any(|key| key != "@context") {
+					out.push(Value::Object(map));
+				}
+			}
+			None => out.push(Value::Object(map)),
+		},
+		_ => {}
+	}
+}
+
+/// Reads an item's types: JSON-LD `@type`, or the microdata/RDFa `type` list.
+fn item_types(source: SchemaSource, value: &Value) -> Vec<String> {
+	let key = match source {
+		SchemaSource::JsonLd => "@type",
+		SchemaSource::Microdata | SchemaSource::Rdfa => "type",
+	};
+	match value.get(key) {
+		Some(Value::String(ty)) => vec![ty.clone()],
+		Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+		_ => Vec::new(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn schema_raw_deserialize_from_json() {
+		let raw: SchemaRaw = serde_json::from_str(r#"{"urlFlag": "https://example.com"}"#).unwrap();
+		assert_eq!(raw.url, None);
+		assert_eq!(raw.url_flag, Some("https://example.com".into()));
+	}
+
+	#[test]
+	fn parses_json_ld_leniently_and_reports_bad_blocks() {
+		let schema = PageSchema {
+			url: "https://example.com/".into(),
+			json_ld: vec![
+				"<!--\n{\"@type\": \"Product\", \"name\": \"Kettle\n2L\"};\n-->".into(),
+				"{\"@type\": \"Product\",".into(),
+				r#"{"@context": "https://schema.org", "@graph": [{"@type": "Organization", "name": "Acme"}, {"@type": ["Product", "Thing"], "name": "Mug"}]}"#
+					.into(),
+			],
+			..Default::default()
+		};
+
+		let data = schema_data(schema);
+		assert_eq!(data.item_count, 3);
+		assert_eq!(data.items[0].value["name"], "Kettle 2L");
+		assert_eq!(data.items[1].types, vec!["Organization"]);
+		assert_eq!(data.items[2].types, vec!["Product", "Thing"]);
+		assert_eq!(data.errors.len(), 1);
+		assert_eq!(data.errors[0].block, 1);
+	}
+
+	#[test]
+	fn drops_duplicate_items_per_source() {
+		let product = json!({"type": ["https://schema.org/Product"], "properties": {"name": ["Mug"]}});
+		let schema = PageSchema {
+			url: "https://example.com/".into(),
+			json_ld: vec![r#"{"@type": "Product", "name": "Mug"}"#.into(), r#"{"name": "Mug", "@type": "Product"}"#.into()],
+			microdata: vec![product.clone(), product.clone()],
+			rdfa: vec![product],
+		};
+
+		let data = schema_data(schema);
+		let sources: Vec<SchemaSource> = data.items.iter().map(|item| item.source).collect();
+		assert_eq!(sources, vec![SchemaSource::JsonLd, SchemaSource::Microdata, SchemaSource::Rdfa]);
+		assert_eq!(data.items[1].types, vec!["https://schema.org/Product"]);
+		assert!(data.errors.is_empty());
+	}
+}
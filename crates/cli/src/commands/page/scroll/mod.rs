@@ -0,0 +1,221 @@
+//! Page scroll command.
+//!
+//! Scrolls an element into view, or scrolls the page by `x`/`y` pixels with
+//! mouse wheel events, then reports the resulting scroll offset. Useful before
+//! a screenshot or `page.elements` on pages that load content lazily.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.scroll --selector "#comments"
+//! pw page.scroll --y 2000
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{describe_target, resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, ScrollData};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Resolves once `scrollX`/`scrollY` hold still for two animation frames, or after about two seconds.
+///
+/// Wheel events return before the page finishes scrolling, and smooth
+/// scrolling animates over several frames.
+const SCROLL_SETTLE_JS: &str = r#"
+new Promise((resolve) => {
+    let last = null, still = 0, frames = 0;
+    const tick = () => {
+        const pos = window.scrollX + ',' + window.scrollY;
+        still = pos === last ? still + 1 : 0;
+        last = pos;
+        if (still >= 2 || ++frames > 120) resolve(null);
+        else requestAnimationFrame(tick);
+    };
+    requestAnimationFrame(tick);
+})
+"#;
+
+/// Evaluates to the window's scroll offset as `[scrollX, scrollY]`.
+const SCROLL_POSITION_JS: &str = "[window.scrollX, window.scrollY]";
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// CSS selector of an element to scroll into view
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Horizontal wheel delta in pixels (positive scrolls right)
+	#[arg(long, value_name = "PX")]
+	#[serde(default)]
+	pub x: Option<i32>,
+
+	/// Vertical wheel delta in pixels (positive scrolls down)
+	#[arg(long, value_name = "PX")]
+	#[serde(default)]
+	pub y: Option<i32>,
+}
+
+/// What to scroll.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrollTarget {
+	/// Scroll the first element matching the selector into view.
+	Element(String),
+	/// Scroll by wheel deltas in pixels.
+	By { x: i32, y: i32 },
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct ScrollResolved {
+	pub target: ResolvedTarget,
+	pub scroll: ScrollTarget,
+}
+
+impl Resolve for ScrollRaw {
+	type Output = ScrollResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		let scroll = scroll_target(self.selector, self.x, self.y)?;
+		Ok(ScrollResolved { target, scroll })
+	}
+}
+
+/// Picks the scroll mode: a selector, or `x`/`y` deltas with the missing axis at 0.
+fn scroll_target(selector: Option<String>, x: Option<i32>, y: Option<i32>) -> Result<ScrollTarget> {
+	match (selector, x, y) {
+		(Some(_), Some(_), _) | (Some(_), _, Some(_)) => Err(PwError::Context("selector cannot be combined with x or y".into())),
+		(Some(selector), None, None) => Ok(ScrollTarget::Element(selector)),
+		(None, None, None) => Err(PwError::Context("page.scroll needs a selector or x/y".into())),
+		(None, x, y) => Ok(ScrollTarget::By {
+			x: x.unwrap_or(0),
+			y: y.unwrap_or(0),
+		}),
+	}
+}
+
+pub struct ScrollCommand;
+
+impl CommandDef for ScrollCommand {
+	const NAME: &'static str = "page.scroll";
+
+	type Raw = ScrollRaw;
+	type Resolved = ScrollResolved;
+	type Data = ScrollData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let action = match &args.scroll {
+			ScrollTarget::Element(selector) => format!("scroll the first element matching '{selector}' into view"),
+			ScrollTarget::By { x, y } => format!("scroll by {x},{y} pixels with the mouse wheel"),
+		};
+		Some(Explanation {
+			summary: format!("Will {}, {action}.", describe_target(&args.target)),
+			inputs: scroll_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, scroll = ?args.scroll, browser = %exec.ctx.browser, "page scroll");
+
+			let scroll = args.scroll.clone();
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "page.scroll" },
+				move |session, flow| {
+					let scroll = scroll.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let page = session.page();
+						let selector = match scroll {
+							ScrollTarget::Element(selector) => {
+								session.locator(&selector).await.first().scroll_into_view_if_needed().await?;
+								Some(selector)
+							}
+							ScrollTarget::By { x, y } => {
+								page.mouse().wheel(x, y).await?;
+								None
+							}
+						};
+						page.evaluate(SCROLL_SETTLE_JS).await?;
+						let (scroll_x, scroll_y): (f64, f64) = page.evaluate_typed(SCROLL_POSITION_JS).await?;
+
+						Ok(ScrollData {
+							url: page.url(),
+							selector,
+							scroll_x,
+							scroll_y,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = scroll_inputs(args);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), None, None),
+			})
+		})
+	}
+}
+
+fn scroll_inputs(args: &ScrollResolved) -> CommandInputs {
+	match &args.scroll {
+		ScrollTarget::Element(selector) => standard_inputs(&args.target, Some(selector), None, None, None),
+		ScrollTarget::By { x, y } => standard_inputs(&args.target, None, None, None, Some(serde_json::json!({ "x": x, "y": y }))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scroll_raw_deserialize_from_json() {
+		let raw: ScrollRaw = serde_json::from_str(r##"{"selector": "#footer"}"##).unwrap();
+		assert_eq!(raw.selector, Some("#footer".into()));
+
+		let raw: ScrollRaw = serde_json::from_str(r#"{"y": -400}"#).unwrap();
+		assert_eq!(raw.x, None);
+		assert_eq!(raw.y, Some(-400));
+	}
+
+	#[test]
+	fn scroll_target_requires_exactly_one_mode() {
+		assert_eq!(scroll_target(Some("#a".into()), None, None).unwrap(), ScrollTarget::Element("#a".into()));
+		assert_eq!(scroll_target(None, None, Some(300)).unwrap(), ScrollTarget::By { x: 0, y: 300 });
+		assert!(scroll_target(Some("#a".into()), None, Some(300)).is_err());
+		assert!(scroll_target(None, None, None).is_err());
+	}
+}
@@ -0,0 +1,257 @@
+//! Infinite-scroll loading command.
+//!
+//! Scrolls to the bottom of the page repeatedly, letting the DOM settle after
+//! each scroll, until `scrollHeight` stops growing or `maxScrolls` is reached.
+//! Then extracts the same text and interactive elements as
+//! [`page.snapshot`](super::snapshot).
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.scroll-to-load https://example.com/feed
+//! pw page.scroll-to-load https://example.com/feed --max-scrolls 50 --settle-ms 1000
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::commands::page::snapshot::{DEFAULT_MAX_TEXT_LENGTH, extract_elements_if_needed, extract_text_js, resolve_max_text_length};
+use crate::commands::wait::settle_dom;
+use crate::error::{PwError, Result};
+use crate::output::ScrollToLoadData;
+use crate::session::SessionHandle;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Scroll cap used when the request does not set one.
+const DEFAULT_MAX_SCROLLS: u32 = 20;
+
+/// Quiet window after each scroll used when the request does not set one.
+const DEFAULT_SETTLE_MS: u64 = 500;
+
+/// Scrolls the document to its bottom edge.
+const SCROLL_TO_BOTTOM_JS: &str = "window.scrollTo(0, (document.scrollingElement || document.documentElement).scrollHeight)";
+
+/// Evaluates to the document's current `scrollHeight`.
+const SCROLL_HEIGHT_JS: &str = "(document.scrollingElement || document.documentElement).scrollHeight";
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollToLoadRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// Maximum number of scrolls before giving up on further content (default: 20)
+	#[arg(long, value_name = "N")]
+	#[serde(default, alias = "max_scrolls")]
+	pub max_scrolls: Option<u32>,
+
+	/// Milliseconds the DOM must go without mutations after each scroll (default: 500)
+	#[arg(long, value_name = "MS")]
+	#[serde(default, alias = "settle_ms")]
+	pub settle_ms: Option<u64>,
+
+	/// Skip interactive element extraction (faster, text-focused)
+	#[arg(long)]
+	#[serde(default, alias = "text_only")]
+	pub text_only: Option<bool>,
+
+	/// Maximum text length to extract (default: 5000 or the profile default, 0 = unbounded)
+	#[arg(long, visible_alias = "max-text", value_name = "N")]
+	#[serde(default, alias = "max_text_length", alias = "maxText")]
+	pub max_text_length: Option<usize>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct ScrollToLoadResolved {
+	pub target: ResolvedTarget,
+	/// Scroll cap; always at least 1.
+	pub max_scrolls: u32,
+	/// Quiet window awaited after each scroll.
+	pub settle_ms: u64,
+	/// Skip interactive element extraction.
+	pub text_only: bool,
+	/// Maximum text length to extract in characters (0 = unbounded).
+	pub max_text_length: usize,
+}
+
+impl Resolve for ScrollToLoadRaw {
+	type Output = ScrollToLoadResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		let max_scrolls = self.max_scrolls.unwrap_or(DEFAULT_MAX_SCROLLS);
+		if max_scrolls == 0 {
+			return Err(PwError::Context("maxScrolls must be at least 1".into()));
+		}
+
+		Ok(ScrollToLoadResolved {
+			target,
+			max_scrolls,
+			settle_ms: self.settle_ms.unwrap_or(DEFAULT_SETTLE_MS),
+			text_only: self.text_only.unwrap_or(false),
+			max_text_length: resolve_max_text_length(self.max_text_length, env),
+		})
+	}
+}
+
+pub struct ScrollToLoadCommand;
+
+impl CommandDef for ScrollToLoadCommand {
+	const NAME: &'static str = "page.scroll-to-load";
+
+	type Raw = ScrollToLoadRaw;
+	type Resolved = ScrollToLoadResolved;
+	type Data = ScrollToLoadData;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, max_scrolls = args.max_scrolls, settle_ms = args.settle_ms, browser = %exec.ctx.browser, "scroll to load");
+
+			let max_scrolls = args.max_scrolls;
+			let settle_ms = args.settle_ms;
+			let text_only = args.text_only;
+			let max_text_length = args.max_text_length;
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::NetworkIdle,
+				ArtifactsPolicy::OnError {
+					command: "page.scroll-to-load",
+				},
+				move |session, flow| {
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let budget = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+						let progress = scroll_until_stable(session, max_scrolls, settle_ms, budget).await?;
+
+						let page = session.page();
+						let url = page.url();
+						let title = page.title().await?;
+						let text: String = serde_json::from_str(&page.evaluate_value(&extract_text_js(max_text_length, true)).await?)?;
+						let elements = extract_elements_if_needed(session, text_only).await?;
+						let element_count = elements.len();
+
+						Ok(ScrollToLoadData {
+							url,
+							title,
+							scrolls: progress.scrolls,
+							scroll_height: progress.height,
+							capped: progress.capped,
+							text,
+							elements,
+							element_count,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let mut extra = serde_json::Map::new();
+			if args.max_scrolls != DEFAULT_MAX_SCROLLS {
+				extra.insert("maxScrolls".into(), args.max_scrolls.into());
+			}
+			if args.settle_ms != DEFAULT_SETTLE_MS {
+				extra.insert("settleMs".into(), args.settle_ms.into());
+			}
+			if args.text_only {
+				extra.insert("textOnly".into(), true.into());
+			}
+			if args.max_text_length != DEFAULT_MAX_TEXT_LENGTH {
+				extra.insert("maxText".into(), args.max_text_length.into());
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, None, None, None, extra);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), None, None),
+			})
+		})
+	}
+}
+
+/// Where [`scroll_until_stable`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScrollProgress {
+	/// Scrolls performed, including the final one that loaded nothing.
+	scrolls: u32,
+	/// `scrollHeight` after the last scroll.
+	height: u64,
+	/// True when the cap was hit while the page was still growing.
+	capped: bool,
+}
+
+/// Scrolls to the bottom until `scrollHeight` stops growing or `max_scrolls` is reached.
+///
+/// Each scroll waits for the DOM to go `settle_ms` without mutations, bounded
+/// by `budget_ms`. A DOM that never settles is measured as-is.
+async fn scroll_until_stable(session: &SessionHandle, max_scrolls: u32, settle_ms: u64, budget_ms: u64) -> Result<ScrollProgress> {
+	let page = session.page();
+	let mut height: u64 = page.evaluate_typed(SCROLL_HEIGHT_JS).await?;
+
+	for scroll in 1..=max_scrolls {
+		page.evaluate(SCROLL_TO_BOTTOM_JS).await?;
+		let outcome = settle_dom(session, settle_ms, budget_ms.max(settle_ms)).await?;
+		if !outcome.settled {
+			debug!(target = "pw", scroll, waited_ms = outcome.waited_ms, "dom did not settle after scroll");
+		}
+
+		let next: u64 = page.evaluate_typed(SCROLL_HEIGHT_JS).await?;
+		let grew = next > height;
+		debug!(target = "pw", scroll, height = next, grew, "scrolled to bottom");
+		height = next;
+		if !grew {
+			return Ok(ScrollProgress {
+				scrolls: scroll,
+				height,
+				capped: false,
+			});
+		}
+	}
+
+	Ok(ScrollProgress {
+		scrolls: max_scrolls,
+		height,
+		capped: true,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scroll_to_load_raw_deserialize() {
+		let raw: ScrollToLoadRaw = serde_json::from_str(r#"{"url": "https://example.com/feed", "maxScrolls": 5, "settleMs": 250}"#).unwrap();
+		assert_eq!(raw.url, Some("https://example.com/feed".into()));
+		assert_eq!(raw.max_scrolls, Some(5));
+		assert_eq!(raw.settle_ms, Some(250));
+		assert_eq!(raw.text_only, None);
+
+		let raw: ScrollToLoadRaw = serde_json::from_str(r#"{"max_scrolls": 3, "settle_ms": 100}"#).unwrap();
+		assert_eq!(raw.max_scrolls, Some(3));
+		assert_eq!(raw.settle_ms, Some(100));
+	}
+}
@@ -0,0 +1,230 @@
+//! Select dropdown options command.
+//!
+//! Selects options in a `<select>` element by value, visible label, or
+//! 0-based index, and reports the values that ended up selected. Repeat a
+//! flag, or mix them, to select several options in a `<select multiple>`.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.select --selector "select[name=country]" --value NL
+//! pw page.select --selector "#size" --label Large
+//! pw page.select --selector "#tags" --index 0 --index 2
+//! ```
+
+use clap::Args;
+use pw_rs::{SelectOption, SelectOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{describe_target, one_or_many, resolve_target_and_selector, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, SelectData};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// CSS selector (positional)
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// CSS selector (named alternative)
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default, alias = "selector_flag")]
+	pub selector_flag: Option<String>,
+
+	/// Option value attribute to select (repeatable)
+	#[arg(long, value_name = "VALUE")]
+	#[serde(default, deserialize_with = "one_or_many")]
+	pub value: Vec<String>,
+
+	/// Option label (visible text) to select (repeatable)
+	#[arg(long, value_name = "LABEL")]
+	#[serde(default, deserialize_with = "one_or_many")]
+	pub label: Vec<String>,
+
+	/// 0-based option index to select (repeatable)
+	#[arg(long, value_name = "N")]
+	#[serde(default, deserialize_with = "one_or_many")]
+	pub index: Vec<usize>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct SelectResolved {
+	pub target: ResolvedTarget,
+	pub selector: String,
+	/// Options to select: values first, then labels, then indexes.
+	pub options: Vec<SelectOption>,
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+}
+
+impl Resolve for SelectRaw {
+	type Output = SelectResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, None)?;
+		let options = select_options(self.value, self.label, self.index)?;
+
+		Ok(SelectResolved {
+			target,
+			selector,
+			options,
+			strict: self.strict.unwrap_or(true),
+		})
+	}
+}
+
+/// Collects the requested options, requiring at least one.
+fn select_options(values: Vec<String>, labels: Vec<String>, indexes: Vec<usize>) -> Result<Vec<SelectOption>> {
+	let options: Vec<SelectOption> = values
+		.into_iter()
+		.map(SelectOption::Value)
+		.chain(labels.into_iter().map(SelectOption::Label))
+		.chain(indexes.into_iter().map(SelectOption::Index))
+		.collect();
+	if options.is_empty() {
+		return Err(PwError::Context("page.select needs a value, label, or index".into()));
+	}
+	Ok(options)
+}
+
+pub struct SelectCommand;
+
+impl CommandDef for SelectCommand {
+	const NAME: &'static str = "page.select";
+
+	type Raw = SelectRaw;
+	type Resolved = SelectResolved;
+	type Data = SelectData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let matching = if args.strict { "the single element" } else { "the first element" };
+		Some(Explanation {
+			summary: format!(
+				"Will {}, select {} option(s) in {matching} matching '{}'.",
+				describe_target(&args.target),
+				args.options.len(),
+				args.selector
+			),
+			inputs: select_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, options = ?args.options, browser = %exec.ctx.browser, "select options");
+
+			let selector = args.selector.clone();
+			let options = args.options.clone();
+			let strict = args.strict;
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "page.select" },
+				move |session, flow| {
+					let selector = selector.clone();
+					let options = options.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let locator = if strict {
+							session.single_locator(&selector).await?
+						} else {
+							session.locator(&selector).await
+						};
+						let select_opts = SelectOptions {
+							timeout: Some(flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64) as f64),
+							..SelectOptions::default()
+						};
+						let values = locator.select_option_multiple(&options, Some(select_opts)).await?;
+
+						Ok(SelectData {
+							url: session.page().url(),
+							selector,
+							values,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = select_inputs(args);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), Some(&args.selector), None),
+			})
+		})
+	}
+}
+
+/// Inputs echoed for a select, grouping the options by kind.
+fn select_inputs(args: &SelectResolved) -> CommandInputs {
+	let mut extra = serde_json::Map::new();
+	for option in &args.options {
+		let (key, value) = match option {
+			SelectOption::Value(value) => ("value", serde_json::Value::from(value.as_str())),
+			SelectOption::Label(label) => ("label", label.as_str().into()),
+			SelectOption::Index(index) => ("index", (*index).into()),
+		};
+		if let serde_json::Value::Array(list) = extra.entry(key).or_insert_with(|| serde_json::Value::Array(Vec::new())) {
+			list.push(value);
+		}
+	}
+	if !args.strict {
+		extra.insert("strict".into(), false.into());
+	}
+	standard_inputs(&args.target, Some(&args.selector), None, None, Some(serde_json::Value::Object(extra)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn select_raw_accepts_single_values_and_lists() {
+		let raw: SelectRaw = serde_json::from_str(r#"{"selector": "select", "value": "nl", "index": [0, 2]}"#).unwrap();
+		assert_eq!(raw.value, vec!["nl".to_string()]);
+		assert_eq!(raw.index, vec![0, 2]);
+		assert!(raw.label.is_empty());
+	}
+
+	#[test]
+	fn select_options_require_at_least_one() {
+		let options = select_options(vec!["nl".into()], vec!["Large".into()], vec![3]).unwrap();
+		assert_eq!(
+			options,
+			vec![SelectOption::Value("nl".into()), SelectOption::Label("Large".into()), SelectOption::Index(3)]
+		);
+		assert!(select_options(Vec::new(), Vec::new(), Vec::new()).is_err());
+	}
+}
@@ -12,7 +12,7 @@
 //!
 //! # Output Contents
 //!
-//! * Page metadata (URL, title, viewport dimensions)
+//! * Page metadata (URL, title, measured and configured viewport dimensions)
 //! * Interactive elements (buttons, links, inputs) with stable CSS selectors
 //! * Visible text content (configurable length limit)
 //!
@@ -23,8 +23,12 @@
 //! pw snapshot --text-only   # Skip interactive elements (faster)
 //! pw snapshot --full        # Include all text, not just visible
 //! pw snapshot --max-text-length 10000
+//! pw snapshot --max-text 0   # No text length limit
+//! pw snapshot --wait-for ".results" --settle 500   # Dynamic pages
 //! ```
 
+use std::collections::BTreeMap;
+
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
@@ -33,8 +37,9 @@ use tracing::info;
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
+use crate::commands::wait::ReadyGates;
 use crate::error::Result;
-use crate::output::{InteractiveElement, SnapshotData};
+use crate::output::{InteractiveElement, SnapshotData, ViewportSize};
 use crate::session::SessionHandle;
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
@@ -62,10 +67,20 @@ pub struct SnapshotRaw {
 	#[serde(default)]
 	pub full: Option<bool>,
 
-	/// Maximum text length to extract (default: 5000)
-	#[arg(long, default_value = "5000")]
-	#[serde(default, alias = "max_text_length")]
+	/// Maximum text length to extract (default: 5000 or the profile default, 0 = unbounded)
+	#[arg(long, visible_alias = "max-text", value_name = "N")]
+	#[serde(default, alias = "max_text_length", alias = "maxText")]
 	pub max_text_length: Option<usize>,
+
+	/// Wait until this CSS selector is present before extracting
+	#[arg(long = "wait-for", value_name = "SELECTOR")]
+	#[serde(default, alias = "wait_for")]
+	pub wait_for: Option<String>,
+
+	/// Wait until the DOM has gone this many milliseconds without mutations before extracting
+	#[arg(long, value_name = "MS")]
+	#[serde(default)]
+	pub settle: Option<u64>,
 }
 
 /// Resolved inputs ready for execution.
@@ -84,8 +99,11 @@ pub struct SnapshotResolved {
 	/// Include full page text instead of viewport-visible only.
 	pub full: bool,
 
-	/// Maximum text length to extract in characters.
+	/// Maximum text length to extract in characters (0 = unbounded).
 	pub max_text_length: usize,
+
+	/// Selector and DOM-settle gates applied before extraction.
+	pub ready: ReadyGates,
 }
 
 impl Resolve for SnapshotRaw {
@@ -98,7 +116,11 @@ impl Resolve for SnapshotRaw {
 			target,
 			text_only: self.text_only.unwrap_or(false),
 			full: self.full.unwrap_or(false),
-			max_text_length: self.max_text_length.unwrap_or(5000),
+			max_text_length: resolve_max_text_length(self.max_text_length, env),
+			ready: ReadyGates {
+				wait_for: self.wait_for,
+				settle_ms: self.settle,
+			},
 		})
 	}
 }
@@ -123,6 +145,7 @@ impl CommandDef for SnapshotCommand {
 			let text_only = args.text_only;
 			let full = args.full;
 			let max_text_length = args.max_text_length;
+			let ready = args.ready.clone();
 
 			let (final_url, data) = run_page_flow(
 				&mut exec,
@@ -130,14 +153,15 @@ impl CommandDef for SnapshotCommand {
 				WaitUntil::NetworkIdle,
 				ArtifactsPolicy::OnError { command: "snapshot" },
 				move |session, flow| {
+					let ready = ready.clone();
 					Box::pin(async move {
 						session.goto_target(&flow.target, flow.timeout_ms).await?;
+						ready.apply(session, flow.timeout_ms).await?;
 
 						let meta_js = format!("JSON.stringify({})", EXTRACT_META_JS);
 						let meta: PageMeta = serde_json::from_str(&session.page().evaluate_value(&meta_js).await?)?;
 
-						let text_js = format!("JSON.stringify({}({}, {}))", EXTRACT_TEXT_JS, max_text_length, full);
-						let text: String = serde_json::from_str(&session.page().evaluate_value(&text_js).await?)?;
+						let text: String = serde_json::from_str(&session.page().evaluate_value(&extract_text_js(max_text_length, full)).await?)?;
 
 						let elements = extract_elements_if_needed(session, text_only).await?;
 						let element_count = elements.len();
@@ -149,6 +173,15 @@ impl CommandDef for SnapshotCommand {
 							title: meta.title,
 							viewport_width: meta.viewport_width,
 							viewport_height: meta.viewport_height,
+							client_width: meta.client_width,
+							client_height: meta.client_height,
+							configured_viewport: configured_viewport(session.page()),
+							redirects: Vec::new(),
+							security: None,
+							status: None,
+							content_type: None,
+							content_length: None,
+							page_errors: Vec::new(),
 							text,
 							elements,
 							element_count,
@@ -160,7 +193,19 @@ impl CommandDef for SnapshotCommand {
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let mut extra = serde_json::Map::new();
+			if args.text_only {
+				extra.insert("textOnly".into(), true.into());
+			}
+			if args.full {
+				extra.insert("full".into(), true.into());
+			}
+			if args.max_text_length != DEFAULT_MAX_TEXT_LENGTH {
+				extra.insert("maxText".into(), args.max_text_length.into());
+			}
+			args.ready.record(&mut extra);
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, None, None, None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -195,20 +240,98 @@ pub(crate) struct PageMeta {
 	pub title: String,
 	pub viewport_width: i32,
 	pub viewport_height: i32,
+	#[serde(default)]
+	pub client_width: i32,
+	#[serde(default)]
+	pub client_height: i32,
+	#[serde(default)]
+	pub description: Option<String>,
+	#[serde(default)]
+	pub canonical: Option<String>,
+	#[serde(default)]
+	pub robots: Option<String>,
+	/// `<html lang>` attribute, when set.
+	#[serde(default)]
+	pub lang: Option<String>,
+	/// `og:*` properties keyed without the `og:` prefix.
+	#[serde(default)]
+	pub og: BTreeMap<String, String>,
+	/// `twitter:*` names keyed without the `twitter:` prefix.
+	#[serde(default)]
+	pub twitter: BTreeMap<String, String>,
+	/// Raw `application/ld+json` script contents, in document order.
+	#[serde(default)]
+	pub json_ld: Vec<String>,
 }
 
-/// JavaScript that extracts page metadata (URL, title, viewport size).
+/// Returns the context-configured viewport of `page`.
+pub(crate) fn configured_viewport(page: &pw_rs::Page) -> Option<ViewportSize> {
+	page.viewport_size().map(|(width, height)| ViewportSize { width, height })
+}
+
+/// JavaScript that extracts page metadata (URL, title, viewport size, meta tags).
+///
+/// Reports both the window inner size (scrollbars included) and the root
+/// element client size (scrollbars excluded). For repeated `og:`/`twitter:`
+/// keys the first tag wins; JSON-LD blocks are returned unparsed.
 pub(crate) const EXTRACT_META_JS: &str = r#"
 (() => {
+    const metaContent = (selector) => {
+        const el = document.querySelector(selector);
+        const value = el && el.getAttribute('content');
+        return value ? value.trim() : null;
+    };
+    const prefixed = (prefix) => {
+        const out = {};
+        for (const el of document.querySelectorAll('meta[property], meta[name]')) {
+            const key = (el.getAttribute('property') || el.getAttribute('name') || '').trim();
+            const value = el.getAttribute('content');
+            if (!key.toLowerCase().startsWith(prefix) || value === null) continue;
+            const name = key.substring(prefix.length);
+            if (name && !(name in out)) out[name] = value.trim();
+        }
+        return out;
+    };
+    const canonical = document.querySelector('link[rel="canonical"]');
     return {
         url: window.location.href,
         title: document.title || '',
         viewport_width: window.innerWidth,
-        viewport_height: window.innerHeight
+        viewport_height: window.innerHeight,
+        client_width: document.documentElement.clientWidth,
+        client_height: document.documentElement.clientHeight,
+        description: metaContent('meta[name="description"]'),
+        canonical: canonical ? canonical.href : null,
+        lang: document.documentElement.lang || null,
+        robots: metaContent('meta[name="robots"]'),
+        og: prefixed('og:'),
+        twitter: prefixed('twitter:'),
+        json_ld: Array.from(document.querySelectorAll('script[type="application/ld+json"]')).map(s => s.textContent || '')
     };
 })()
 "#;
 
+/// Text extraction cap used when neither the request nor the profile sets one.
+pub(crate) const DEFAULT_MAX_TEXT_LENGTH: usize = 5000;
+
+/// Resolves the text extraction cap: explicit input, then the profile's
+/// `defaults.maxTextLength`, then [`DEFAULT_MAX_TEXT_LENGTH`]. 0 means unbounded.
+pub(crate) fn resolve_max_text_length(provided: Option<usize>, env: &ResolveEnv<'_>) -> usize {
+	provided.or_else(|| env.ctx_state.max_text_length()).unwrap_or(DEFAULT_MAX_TEXT_LENGTH)
+}
+
+/// Builds the [`EXTRACT_TEXT_JS`] invocation returning the text as a JSON string.
+///
+/// A `max_text_length` of 0 is passed as `Infinity`.
+pub(crate) fn extract_text_js(max_text_length: usize, full: bool) -> String {
+	let max = if max_text_length == 0 {
+		"Infinity".to_string()
+	} else {
+		max_text_length.to_string()
+	};
+	format!("JSON.stringify({EXTRACT_TEXT_JS}({max}, {full}))")
+}
+
 /// JavaScript that extracts visible text content using TreeWalker.
 ///
 /// Accepts `maxLength` (character limit) and `full` (include non-visible text) parameters.
@@ -404,7 +527,7 @@ pub(crate) const EXTRACT_ELEMENTS_JS: &str = r#"
 "#;
 
 /// Extracts interactive elements unless `text_only` mode is enabled.
-async fn extract_elements_if_needed(session: &SessionHandle, text_only: bool) -> Result<Vec<InteractiveElement>> {
+pub(crate) async fn extract_elements_if_needed(session: &SessionHandle, text_only: bool) -> Result<Vec<InteractiveElement>> {
 	if text_only {
 		return Ok(Vec::new());
 	}
@@ -412,12 +535,92 @@ async fn extract_elements_if_needed(session: &SessionHandle, text_only: bool) ->
 	let elements_js = format!("JSON.stringify({})", EXTRACT_ELEMENTS_JS);
 	let raw_elements: Vec<RawElement> = serde_json::from_str(&session.page().evaluate_value(&elements_js).await?)?;
 
-	Ok(raw_elements.into_iter().map(Into::into).collect())
+	Ok(stabilize_elements(raw_elements.into_iter().map(Into::into).collect()))
+}
+
+/// Grid size in CSS pixels used to bucket element positions for stable IDs.
+const POSITION_BUCKET_PX: i32 = 100;
+
+/// Assigns stable IDs and sorts elements into a deterministic order.
+///
+/// Each ID hashes the element's tag, text, selector, name attribute, and
+/// position bucket (`x`/`y` divided by [`POSITION_BUCKET_PX`]), so small
+/// layout shifts and DOM reordering keep IDs unchanged. Elements are ordered
+/// by bucket row, bucket column, then ID; identical elements sharing a bucket
+/// get `-2`, `-3`, ... suffixes in that order. IDs use 64-bit FNV-1a, so they
+/// stay the same across `pw` builds and Rust toolchains.
+pub(crate) fn stabilize_elements(mut elements: Vec<InteractiveElement>) -> Vec<InteractiveElement> {
+	for element in &mut elements {
+		element.stable_id = stable_element_id(element);
+	}
+	elements.sort_by(|a, b| {
+		let key = |e: &InteractiveElement| (e.y.div_euclid(POSITION_BUCKET_PX), e.x.div_euclid(POSITION_BUCKET_PX));
+		key(a)
+			.cmp(&key(b))
+			.then_with(|| a.stable_id.cmp(&b.stable_id))
+			.then_with(|| (a.y, a.x).cmp(&(b.y, b.x)))
+	});
+
+	let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+	for element in &mut elements {
+		let count = seen.entry(element.stable_id.clone()).or_insert(0);
+		*count += 1;
+		if *count > 1 {
+			element.stable_id = format!("{}-{}", element.stable_id, count);
+		}
+	}
+	elements
+}
+
+pub(crate) fn stable_element_id(element: &InteractiveElement) -> String {
+	let mut hash = Fnv1a::default();
+	hash.write_str(&element.tag);
+	hash.write_opt_str(element.text.as_deref());
+	hash.write_str(&element.selector);
+	hash.write_opt_str(element.name.as_deref());
+	hash.write(&element.x.div_euclid(POSITION_BUCKET_PX).to_le_bytes());
+	hash.write(&element.y.div_euclid(POSITION_BUCKET_PX).to_le_bytes());
+	format!("{:016x}", hash.0)
+}
+
+/// 64-bit FNV-1a, fixed by spec unlike [`std::hash::DefaultHasher`].
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+	fn default() -> Self {
+		Self(0xcbf2_9ce4_8422_2325)
+	}
+}
+
+impl Fnv1a {
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.0 ^= u64::from(*byte);
+			self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+		}
+	}
+
+	/// Writes `s` with a terminator so adjacent fields cannot run together.
+	fn write_str(&mut self, s: &str) {
+		self.write(s.as_bytes());
+		self.write(&[0xff]);
+	}
+
+	fn write_opt_str(&mut self, s: Option<&str>) {
+		match s {
+			Some(s) => {
+				self.write(&[1]);
+				self.write_str(s);
+			}
+			None => self.write(&[0]),
+		}
+	}
 }
 
 impl From<RawElement> for InteractiveElement {
 	fn from(e: RawElement) -> Self {
 		Self {
+			stable_id: String::new(),
 			tag: e.kind,
 			selector: e.selector,
 			text: if e.label.is_empty() || e.label == "(unlabeled)" {
@@ -458,6 +661,73 @@ mod tests {
 		assert_eq!(raw.max_text_length, Some(2000));
 	}
 
+	#[test]
+	fn deserialize_max_text_alias() {
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"maxText": 0}"#).unwrap();
+
+		assert_eq!(raw.max_text_length, Some(0));
+	}
+
+	#[test]
+	fn extract_text_js_treats_zero_as_unbounded() {
+		assert!(extract_text_js(0, false).ends_with("(Infinity, false))"));
+		assert!(extract_text_js(100, true).ends_with("(100, true))"));
+	}
+
+	fn element(tag: &str, selector: &str, x: i32, y: i32) -> InteractiveElement {
+		InteractiveElement {
+			stable_id: String::new(),
+			tag: tag.into(),
+			selector: selector.into(),
+			text: Some("Go".into()),
+			href: None,
+			name: None,
+			id: None,
+			x,
+			y,
+			width: 10,
+			height: 10,
+		}
+	}
+
+	#[test]
+	fn stabilize_elements_is_order_independent() {
+		let first = stabilize_elements(vec![element("button", "#a", 10, 10), element("link", "#b", 10, 300)]);
+		let second = stabilize_elements(vec![element("link", "#b", 12, 305), element("button", "#a", 14, 8)]);
+
+		let ids = |els: &[InteractiveElement]| els.iter().map(|e| e.stable_id.clone()).collect::<Vec<_>>();
+		assert_eq!(ids(&first), ids(&second));
+		assert_eq!(first[0].selector, "#a");
+	}
+
+	#[test]
+	fn fnv1a_matches_reference_vectors() {
+		let hash = |bytes: &[u8]| {
+			let mut hash = Fnv1a::default();
+			hash.write(bytes);
+			hash.0
+		};
+		assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+		assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+		assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+	}
+
+	#[test]
+	fn stable_element_id_separates_fields() {
+		// Without separators both would hash "ab" followed by "#c".
+		let mut a = element("button", "#c", 0, 0);
+		a.text = Some("ab".into());
+		let mut b = element("button", "b#c", 0, 0);
+		b.text = Some("a".into());
+		assert_ne!(stable_element_id(&a), stable_element_id(&b));
+	}
+
+	#[test]
+	fn stabilize_elements_suffixes_duplicates() {
+		let elements = stabilize_elements(vec![element("button", "#a", 10, 10), element("button", "#a", 20, 20)]);
+		assert_eq!(elements[1].stable_id, format!("{}-2", elements[0].stable_id));
+	}
+
 	#[test]
 	fn deserialize_empty_uses_defaults() {
 		let raw: SnapshotRaw = serde_json::from_str("{}").unwrap();
@@ -5,9 +5,11 @@ use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
+use crate::browser::js;
 use crate::commands::contract::{resolve_target_and_selector, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
+use crate::commands::page::snapshot::{DEFAULT_MAX_TEXT_LENGTH, resolve_max_text_length};
 use crate::error::{PwError, Result};
 use crate::output::TextData;
 use crate::session_helpers::ArtifactsPolicy;
@@ -34,6 +36,31 @@ pub struct TextRaw {
 	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
 	#[serde(default, alias = "selector_flag")]
 	pub selector_flag: Option<String>,
+
+	/// Only include rendered text, skipping hidden and zero-size elements
+	#[arg(long)]
+	#[serde(default, alias = "visible_only")]
+	pub visible_only: Option<bool>,
+
+	/// Append text from same-origin iframes inside the matched element
+	#[arg(long)]
+	#[serde(default, alias = "include_iframes")]
+	pub include_iframes: Option<bool>,
+
+	/// Character offset into the extracted text (default: 0)
+	#[arg(long, value_name = "N")]
+	#[serde(default)]
+	pub offset: Option<usize>,
+
+	/// Maximum characters to return from the offset (default: 5000 or the profile default, 0 = unbounded)
+	#[arg(long, visible_alias = "max-text", value_name = "M")]
+	#[serde(default, alias = "maxText", alias = "max_text")]
+	pub limit: Option<usize>,
+
+	/// Return each match's text separately in `texts` instead of one combined `text`
+	#[arg(long)]
+	#[serde(default)]
+	pub all: Option<bool>,
 }
 
 /// Resolved inputs ready for execution.
@@ -41,6 +68,16 @@ pub struct TextRaw {
 pub struct TextResolved {
 	pub target: ResolvedTarget,
 	pub selector: String,
+	/// Extract rendered text only (CSS selectors, first match).
+	pub visible_only: bool,
+	/// Append same-origin iframe text (CSS selectors, first match).
+	pub include_iframes: bool,
+	/// Character offset of the returned slice.
+	pub offset: usize,
+	/// Maximum characters in the returned slice (0 = unbounded); per entry with `all`.
+	pub limit: usize,
+	/// Return every match's text content separately.
+	pub all: bool,
 }
 
 impl Resolve for TextRaw {
@@ -48,7 +85,24 @@ impl Resolve for TextRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, None)?;
-		Ok(TextResolved { target, selector })
+		let all = self.all.unwrap_or(false);
+		let visible_only = self.visible_only.unwrap_or(false);
+		let include_iframes = self.include_iframes.unwrap_or(false);
+		let offset = self.offset.unwrap_or(0);
+		if all && (visible_only || include_iframes || offset > 0) {
+			return Err(PwError::Context(
+				"--all cannot be combined with --visible-only, --include-iframes or --offset".into(),
+			));
+		}
+		Ok(TextResolved {
+			target,
+			selector,
+			visible_only,
+			include_iframes,
+			offset,
+			limit: resolve_max_text_length(self.limit, env),
+			all,
+		})
 	}
 }
 
@@ -70,6 +124,11 @@ impl CommandDef for TextCommand {
 			info!(target = "pw", url = %url_display, selector = %args.selector, browser = %exec.ctx.browser, "get text");
 
 			let selector = args.selector.clone();
+			let visible_only = args.visible_only;
+			let include_iframes = args.include_iframes;
+			let all = args.all;
+			let offset = args.offset;
+			let limit = if args.limit == 0 { usize::MAX } else { args.limit };
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let selector = selector.clone();
@@ -83,20 +142,81 @@ impl CommandDef for TextCommand {
 						return Err(PwError::ElementNotFound { selector });
 					}
 
-					let text = locator.inner_text().await?;
+					if all {
+						let mut total_length = 0;
+						let texts = locator
+							.all_text_contents()
+							.await?
+							.iter()
+							.map(|text| {
+								let filtered = filter_garbage(text);
+								let (text, length) = slice_chars(filtered.trim(), 0, limit);
+								total_length += length;
+								text
+							})
+							.collect();
+						return Ok(TextData {
+							text: String::new(),
+							selector,
+							match_count: count,
+							total_length,
+							next_offset: None,
+							skipped_frames: Vec::new(),
+							texts: Some(texts),
+						});
+					}
+
+					let mut text = if visible_only {
+						let text = locator.evaluate(js::VISIBLE_TEXT_JS, None).await?;
+						text.as_str()
+							.map(str::to_string)
+							.ok_or_else(|| PwError::ElementNotFound { selector: selector.clone() })?
+					} else {
+						locator.inner_text().await?
+					};
+
+					let mut skipped_frames = Vec::new();
+					if include_iframes {
+						let frames: FrameTexts = serde_json::from_value(locator.evaluate(js::FRAME_TEXTS_JS, None).await?)?;
+						for frame_text in frames.texts {
+							text.push_str("\n\n");
+							text.push_str(&frame_text);
+						}
+						skipped_frames = frames.skipped_frames;
+					}
 					let filtered = filter_garbage(&text);
-					let trimmed = filtered.trim().to_string();
+					let (text, total_length) = slice_chars(filtered.trim(), offset, limit);
+					let end = offset.saturating_add(limit);
 
 					Ok(TextData {
-						text: trimmed,
+						text,
 						selector,
 						match_count: count,
+						total_length,
+						next_offset: (end < total_length).then_some(end),
+						skipped_frames,
+						texts: None,
 					})
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+			let mut extra = serde_json::Map::new();
+			if args.visible_only {
+				extra.insert("visibleOnly".into(), true.into());
+			}
+			if args.include_iframes {
+				extra.insert("includeIframes".into(), true.into());
+			}
+			if args.all {
+				extra.insert("all".into(), true.into());
+			}
+			if args.offset > 0 || args.limit != DEFAULT_MAX_TEXT_LENGTH {
+				extra.insert("offset".into(), args.offset.into());
+				extra.insert("limit".into(), args.limit.into());
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -107,6 +227,20 @@ impl CommandDef for TextCommand {
 	}
 }
 
+/// Frame text returned by [`js::FRAME_TEXTS_JS`].
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FrameTexts {
+	texts: Vec<String>,
+	skipped_frames: Vec<String>,
+}
+
+/// Returns the `[offset, offset + limit)` character slice of `text` and its total length in characters.
+fn slice_chars(text: &str, offset: usize, limit: usize) -> (String, usize) {
+	let total = text.chars().count();
+	(text.chars().skip(offset).take(limit).collect(), total)
+}
+
 /// Heuristically detect if a line looks like minified JavaScript or garbage
 fn is_garbage_line(line: &str) -> bool {
 	let trimmed = line.trim();
@@ -156,7 +290,7 @@ fn is_garbage_line(line: &str) -> bool {
 }
 
 /// Filter out garbage lines from extracted text, collapsing multiple blank lines
-fn filter_garbage(text: &str) -> String {
+pub(crate) fn filter_garbage(text: &str) -> String {
 	let filtered: Vec<&str> = text.lines().filter(|line| !is_garbage_line(line)).collect();
 
 	let mut result = Vec::new();
@@ -241,5 +375,22 @@ mod tests {
 		let raw: TextRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.url, Some("https://example.com".into()));
 		assert_eq!(raw.selector, Some("main".into()));
+		assert_eq!(raw.visible_only, None);
+	}
+
+	#[test]
+	fn slice_chars_reports_total_and_clamps() {
+		assert_eq!(slice_chars("abcdef", 0, 4), ("abcd".to_string(), 6));
+		assert_eq!(slice_chars("abcdef", 4, 4), ("ef".to_string(), 6));
+		assert_eq!(slice_chars("abcdef", 10, 4), (String::new(), 6));
+		assert_eq!(slice_chars("héllo", 1, 2), ("él".to_string(), 5));
+	}
+
+	#[test]
+	fn text_raw_deserialize_visible_only() {
+		let raw: TextRaw = serde_json::from_str(r#"{"visibleOnly": true}"#).unwrap();
+		assert_eq!(raw.visible_only, Some(true));
+		let raw: TextRaw = serde_json::from_str(r#"{"visible_only": true}"#).unwrap();
+		assert_eq!(raw.visible_only, Some(true));
 	}
 }
@@ -0,0 +1,197 @@
+//! File upload command.
+//!
+//! Sets the files of an `<input type=file>` element, as if the user picked
+//! them in the file chooser. Repeat `--file` to upload several files into an
+//! input with the `multiple` attribute.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw page.upload --selector "input[type=file]" --file report.pdf
+//! pw page.upload --selector "#photos" --file a.jpg --file b.jpg
+//! ```
+
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{describe_target, one_or_many, resolve_target_and_selector, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::output::{CommandInputs, UploadData};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// CSS selector (positional)
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// CSS selector (named alternative)
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default, alias = "selector_flag")]
+	pub selector_flag: Option<String>,
+
+	/// File to upload (repeatable)
+	#[arg(long = "file", value_name = "PATH")]
+	#[serde(default, alias = "files", deserialize_with = "one_or_many")]
+	pub file: Vec<PathBuf>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct UploadResolved {
+	pub target: ResolvedTarget,
+	pub selector: String,
+	/// Files to upload, each checked to exist.
+	pub files: Vec<PathBuf>,
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+}
+
+impl Resolve for UploadRaw {
+	type Output = UploadResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, None)?;
+		if self.file.is_empty() {
+			return Err(PwError::Context("page.upload needs at least one file".into()));
+		}
+		if let Some(missing) = self.file.iter().find(|path| !path.is_file()) {
+			return Err(PwError::Context(format!("file not found: {}", missing.display())));
+		}
+
+		Ok(UploadResolved {
+			target,
+			selector,
+			files: self.file,
+			strict: self.strict.unwrap_or(true),
+		})
+	}
+}
+
+pub struct UploadCommand;
+
+impl CommandDef for UploadCommand {
+	const NAME: &'static str = "page.upload";
+
+	type Raw = UploadRaw;
+	type Resolved = UploadResolved;
+	type Data = UploadData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let matching = if args.strict { "the single element" } else { "the first element" };
+		Some(Explanation {
+			summary: format!(
+				"Will {}, upload {} file(s) into {matching} matching '{}'.",
+				describe_target(&args.target),
+				args.files.len(),
+				args.selector
+			),
+			inputs: upload_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, files = args.files.len(), browser = %exec.ctx.browser, "upload files");
+
+			let selector = args.selector.clone();
+			let files = args.files.clone();
+			let strict = args.strict;
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "page.upload" },
+				move |session, flow| {
+					let selector = selector.clone();
+					let files = files.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let locator = if strict {
+							session.single_locator(&selector).await?
+						} else {
+							session.locator(&selector).await
+						};
+						let paths: Vec<&PathBuf> = files.iter().collect();
+						locator.set_input_files_multiple(&paths, None).await?;
+
+						Ok(UploadData {
+							url: session.page().url(),
+							selector,
+							files: files.iter().map(|path| file_name(path)).collect(),
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = upload_inputs(args);
+			let final_url = data.url.clone();
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta_with_url(Some(final_url), Some(&args.selector), None),
+			})
+		})
+	}
+}
+
+/// The name the page sees for an uploaded file.
+fn file_name(path: &std::path::Path) -> String {
+	path.file_name()
+		.map(|name| name.to_string_lossy().into_owned())
+		.unwrap_or_else(|| path.display().to_string())
+}
+
+fn upload_inputs(args: &UploadResolved) -> CommandInputs {
+	let mut extra = serde_json::json!({ "file": args.files.iter().map(|path| path.display().to_string()).collect::<Vec<_>>() });
+	if !args.strict {
+		extra["strict"] = false.into();
+	}
+	standard_inputs(&args.target, Some(&args.selector), None, None, Some(extra))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn upload_raw_accepts_one_file_or_many() {
+		let raw: UploadRaw = serde_json::from_str(r#"{"selector": "input", "file": "a.txt"}"#).unwrap();
+		assert_eq!(raw.file, vec![PathBuf::from("a.txt")]);
+
+		let raw: UploadRaw = serde_json::from_str(r#"{"files": ["a.txt", "dir/b.png"]}"#).unwrap();
+		assert_eq!(raw.file, vec![PathBuf::from("a.txt"), PathBuf::from("dir/b.png")]);
+		assert_eq!(file_name(&raw.file[1]), "b.png");
+	}
+}
@@ -0,0 +1,258 @@
+//! Tar archives for moving a profile between machines.
+//!
+//! An archive holds a `pw-profile.json` manifest followed by the profile
+//! directory's files (config, cache and captured auth). Session descriptors
+//! are left out since they point at browsers on the exporting machine.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::context_store::types::{CliConfig, SCHEMA_VERSION};
+use crate::error::{PwError, Result};
+use crate::workspace::checked_profile;
+
+/// Archive entry describing the exported profile.
+pub const MANIFEST: &str = "pw-profile.json";
+
+/// Profile subdirectories excluded from exports.
+const SKIPPED_DIRS: &[&str] = &["sessions"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+	/// Config [`SCHEMA_VERSION`] of the exporting CLI.
+	pub schema: u32,
+	/// Name of the exported profile.
+	pub profile: String,
+}
+
+/// Writes `profile_dir` to a tar file at `output`, returning the archived file paths.
+pub fn export(profile_dir: &Path, profile: &str, output: &Path) -> Result<Vec<String>> {
+	let mut files = Vec::new();
+	collect_files(profile_dir, Path::new(""), &mut files)?;
+	files.sort();
+
+	if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+		fs::create_dir_all(parent)?;
+	}
+	let mut builder = tar::Builder::new(fs::File::create(output)?);
+
+	let manifest = serde_json::to_vec_pretty(&Manifest {
+		schema: SCHEMA_VERSION,
+		profile: profile.to_string(),
+	})?;
+	append_bytes(&mut builder, Path::new(MANIFEST), &manifest)?;
+	for file in &files {
+		builder.append_path_with_name(profile_dir.join(file), file)?;
+	}
+	builder.into_inner()?.sync_all()?;
+
+	Ok(files.iter().map(|f| f.to_string_lossy().replace('\\', "/")).collect())
+}
+
+fn collect_files(root: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+	for entry in fs::read_dir(root.join(relative))? {
+		let entry = entry?;
+		let path = relative.join(entry.file_name());
+		let file_type = entry.file_type()?;
+		if file_type.is_dir() {
+			if relative.as_os_str().is_empty() && SKIPPED_DIRS.iter().any(|d| entry.file_name() == *d) {
+				continue;
+			}
+			collect_files(root, &path, files)?;
+		} else if file_type.is_file() {
+			files.push(path);
+		}
+	}
+	Ok(())
+}
+
+fn append_bytes<W: std::io::Write>(builder: &mut tar::Builder<W>, path: &Path, bytes: &[u8]) -> Result<()> {
+	let mut header = tar::Header::new_gnu();
+	header.set_size(bytes.len() as u64);
+	header.set_mode(0o644);
+	header.set_cksum();
+	builder.append_data(&mut header, path, bytes)?;
+	Ok(())
+}
+
+/// A profile archive read fully into memory and validated.
+#[derive(Debug)]
+pub struct ProfileArchive {
+	pub manifest: Manifest,
+	files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl ProfileArchive {
+	/// Reads and validates the archive at `path`.
+	///
+	/// # Errors
+	///
+	/// Returns [`PwError::Context`] when the manifest is missing, the archive
+	/// comes from a newer schema, the manifest names an invalid profile such
+	/// as `..`, an entry escapes the profile directory, or
+	/// `config.json` is not a valid config.
+	pub fn read(path: &Path) -> Result<Self> {
+		let mut archive = tar::Archive::new(fs::File::open(path)?);
+		let mut manifest = None;
+		let mut files = Vec::new();
+
+		for entry in archive.entries()? {
+			let mut entry = entry?;
+			if !entry.header().entry_type().is_file() {
+				continue;
+			}
+			let entry_path = entry.path()?.into_owned();
+			if !entry_path.components().all(|c| matches!(c, Component::Normal(_))) {
+				return Err(invalid(path, format!("entry '{}' escapes the profile directory", entry_path.display())));
+			}
+			let mut bytes = Vec::new();
+			std::io::Read::read_to_end(&mut entry, &mut bytes)?;
+
+			if entry_path == Path::new(MANIFEST) {
+				manifest = Some(serde_json::from_slice::<Manifest>(&bytes).map_err(|e| invalid(path, format!("bad manifest: {e}")))?);
+			} else {
+				files.push((entry_path, bytes));
+			}
+		}
+
+		let manifest = manifest.ok_or_else(|| invalid(path, format!("missing {MANIFEST}")))?;
+		if checked_profile(&manifest.profile).is_err() {
+			return Err(invalid(path, format!("manifest profile '{}' is not a valid profile name", manifest.profile)));
+		}
+		if manifest.schema > SCHEMA_VERSION {
+			return Err(invalid(
+				path,
+				format!("schema {} is newer than supported schema {SCHEMA_VERSION}", manifest.schema),
+			));
+		}
+		if let Some((_, bytes)) = files.iter().find(|(p, _)| p == Path::new("config.json")) {
+			let config = serde_json::from_slice::<CliConfig>(bytes).map_err(|e| invalid(path, format!("bad config.json: {e}")))?;
+			if config.schema > SCHEMA_VERSION {
+				return Err(invalid(
+					path,
+					format!("config schema {} is newer than supported schema {SCHEMA_VERSION}", config.schema),
+				));
+			}
+		}
+
+		Ok(Self { manifest, files })
+	}
+
+	/// Archived file paths, relative to the profile directory.
+	pub fn file_names(&self) -> Vec<String> {
+		self.files.iter().map(|(p, _)| p.to_string_lossy().replace('\\', "/")).collect()
+	}
+
+	/// Writes the archived files under `profile_dir`.
+	pub fn unpack(&self, profile_dir: &Path) -> Result<()> {
+		for (relative, bytes) in &self.files {
+			let target = profile_dir.join(relative);
+			if let Some(parent) = target.parent() {
+				fs::create_dir_all(parent)?;
+			}
+			fs::write(target, bytes)?;
+		}
+		Ok(())
+	}
+}
+
+fn invalid(path: &Path, reason: String) -> PwError {
+	PwError::Context(format!("invalid profile archive {}: {reason}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn write_profile(dir: &Path) {
+		fs::create_dir_all(dir.join("auth")).unwrap();
+		fs::create_dir_all(dir.join("sessions")).unwrap();
+		fs::write(dir.join("config.json"), serde_json::to_vec(&CliConfig::new()).unwrap()).unwrap();
+		fs::write(dir.join("auth/example.com.json"), br#"{"cookies":[]}"#).unwrap();
+		fs::write(dir.join("sessions/session.json"), b"{}").unwrap();
+	}
+
+	fn write_archive(path: &Path, manifest: &Manifest, files: &[(&str, &[u8])]) {
+		let mut builder = tar::Builder::new(fs::File::create(path).unwrap());
+		append_bytes(&mut builder, Path::new(MANIFEST), &serde_json::to_vec(manifest).unwrap()).unwrap();
+		for (name, bytes) in files {
+			append_bytes(&mut builder, Path::new(name), bytes).unwrap();
+		}
+		builder.finish().unwrap();
+	}
+
+	#[test]
+	fn export_round_trips_config_and_auth_without_sessions() {
+		let temp = tempfile::tempdir().unwrap();
+		let source = temp.path().join("source");
+		write_profile(&source);
+		let tarball = temp.path().join("out/profile.tar");
+
+		let files = export(&source, "work", &tarball).unwrap();
+		assert_eq!(files, vec!["auth/example.com.json", "config.json"]);
+
+		let archive = ProfileArchive::read(&tarball).unwrap();
+		assert_eq!(
+			archive.manifest,
+			Manifest {
+				schema: SCHEMA_VERSION,
+				profile: "work".into()
+			}
+		);
+
+		let target = temp.path().join("target");
+		archive.unpack(&target).unwrap();
+		assert_eq!(fs::read(target.join("auth/example.com.json")).unwrap(), br#"{"cookies":[]}"#);
+		assert!(target.join("config.json").exists());
+		assert!(!target.join("sessions").exists());
+	}
+
+	#[test]
+	fn read_rejects_newer_schema() {
+		let temp = tempfile::tempdir().unwrap();
+		let tarball = temp.path().join("future.tar");
+		let manifest = Manifest {
+			schema: SCHEMA_VERSION + 1,
+			profile: "work".into(),
+		};
+		write_archive(&tarball, &manifest, &[]);
+
+		let err = ProfileArchive::read(&tarball).unwrap_err();
+		assert!(err.to_string().contains("newer than supported"), "{err}");
+	}
+
+	#[test]
+	fn read_rejects_manifest_profile_outside_profiles_dir() {
+		let temp = tempfile::tempdir().unwrap();
+		let tarball = temp.path().join("escape.tar");
+		for profile in ["..", "."] {
+			let manifest = Manifest {
+				schema: SCHEMA_VERSION,
+				profile: profile.into(),
+			};
+			write_archive(&tarball, &manifest, &[]);
+			let err = ProfileArchive::read(&tarball).unwrap_err();
+			assert!(err.to_string().contains("not a valid profile name"), "{err}");
+		}
+	}
+
+	#[test]
+	fn read_rejects_missing_manifest_and_invalid_config() {
+		let temp = tempfile::tempdir().unwrap();
+		let tarball = temp.path().join("bare.tar");
+		let mut builder = tar::Builder::new(fs::File::create(&tarball).unwrap());
+		append_bytes(&mut builder, Path::new("config.json"), b"{}").unwrap();
+		builder.finish().unwrap();
+		assert!(ProfileArchive::read(&tarball).unwrap_err().to_string().contains(MANIFEST));
+
+		let manifest = Manifest {
+			schema: SCHEMA_VERSION,
+			profile: "work".into(),
+		};
+		write_archive(&tarball, &manifest, &[("config.json", b"not json")]);
+		assert!(ProfileArchive::read(&tarball).unwrap_err().to_string().contains("bad config.json"));
+	}
+}
@@ -1,3 +1,5 @@
+mod archive;
+
 use std::path::PathBuf;
 
 use clap::Args;
@@ -8,10 +10,10 @@ use serde_json::json;
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
 use crate::context_store::storage::StatePaths;
 use crate::context_store::types::{CliConfig, SCHEMA_VERSION};
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
 use crate::target::ResolveEnv;
-use crate::workspace::{STATE_VERSION_DIR, normalize_profile};
+use crate::workspace::{STATE_VERSION_DIR, checked_profile};
 
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -83,7 +85,7 @@ impl Resolve for ProfileShowRaw {
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		Ok(ProfileShowResolved {
-			name: normalize_profile(&self.name),
+			name: checked_profile(&self.name)?,
 		})
 	}
 }
@@ -143,7 +145,7 @@ impl Resolve for ProfileSetRaw {
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		Ok(ProfileSetResolved {
-			name: normalize_profile(&self.name),
+			name: checked_profile(&self.name)?,
 			file: self.file,
 		})
 	}
@@ -211,7 +213,7 @@ impl Resolve for ProfileDeleteRaw {
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		Ok(ProfileDeleteResolved {
-			name: normalize_profile(&self.name),
+			name: checked_profile(&self.name)?,
 		})
 	}
 }
@@ -252,3 +254,156 @@ impl CommandDef for ProfileDeleteCommand {
 		})
 	}
 }
+
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileExportRaw {
+	#[arg(value_name = "NAME")]
+	pub name: String,
+	/// Tar file to write.
+	#[arg(value_name = "OUTPUT")]
+	pub output: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileExportResolved {
+	pub name: String,
+	pub output: PathBuf,
+}
+
+impl Resolve for ProfileExportRaw {
+	type Output = ProfileExportResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(ProfileExportResolved {
+			name: checked_profile(&self.name)?,
+			output: self.output,
+		})
+	}
+}
+
+pub struct ProfileExportCommand;
+
+impl CommandDef for ProfileExportCommand {
+	const NAME: &'static str = "profile.export";
+
+	type Raw = ProfileExportRaw;
+	type Resolved = ProfileExportResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let paths = StatePaths::new(exec.ctx_state.workspace_root(), &args.name);
+			if !paths.profile_dir.is_dir() {
+				return Err(PwError::Context(format!("profile '{}' does not exist", args.name)));
+			}
+			let files = archive::export(&paths.profile_dir, &args.name, &args.output)?;
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({
+						"name": args.name,
+						"output": args.output,
+					})),
+					..Default::default()
+				},
+				data: json!({
+					"profile": args.name,
+					"output": args.output,
+					"schema": SCHEMA_VERSION,
+					"files": files,
+				}),
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileImportRaw {
+	/// Tar file written by `profile export`.
+	#[arg(value_name = "FILE")]
+	pub file: PathBuf,
+	/// Profile to import into (default: the exported profile's name).
+	#[arg(value_name = "NAME")]
+	#[serde(default)]
+	pub name: Option<String>,
+	/// Replace an existing profile with the same name.
+	#[arg(long)]
+	#[serde(default)]
+	pub force: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileImportResolved {
+	pub file: PathBuf,
+	pub name: Option<String>,
+	pub force: bool,
+}
+
+impl Resolve for ProfileImportRaw {
+	type Output = ProfileImportResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(ProfileImportResolved {
+			file: self.file,
+			name: self.name.as_deref().map(checked_profile).transpose()?,
+			force: self.force,
+		})
+	}
+}
+
+pub struct ProfileImportCommand;
+
+impl CommandDef for ProfileImportCommand {
+	const NAME: &'static str = "profile.import";
+
+	type Raw = ProfileImportRaw;
+	type Resolved = ProfileImportResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let archive = archive::ProfileArchive::read(&args.file)?;
+			let name = match &args.name {
+				Some(name) => name.clone(),
+				None => checked_profile(&archive.manifest.profile)?,
+			};
+			let paths = StatePaths::new(exec.ctx_state.workspace_root(), &name);
+
+			let replaced = paths.profile_dir.exists();
+			if replaced {
+				if !args.force {
+					return Err(PwError::Context(format!("profile '{name}' already exists; pass --force to replace it")));
+				}
+				std::fs::remove_dir_all(&paths.profile_dir)?;
+			}
+			archive.unpack(&paths.profile_dir)?;
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({
+						"file": args.file,
+						"name": args.name,
+						"force": args.force,
+					})),
+					..Default::default()
+				},
+				data: json!({
+					"profile": name,
+					"replaced": replaced,
+					"schema": archive.manifest.schema,
+					"files": archive.file_names(),
+				}),
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
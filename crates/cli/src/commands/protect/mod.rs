@@ -3,27 +3,44 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
 use crate::target::ResolveEnv;
+use crate::types::{PatternKind, ProtectedUrl};
 
 #[derive(Debug, Clone, Args, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtectAddRaw {
+	/// URL substring, glob with `*`, or regex prefixed with `re:`
 	#[arg(value_name = "PATTERN")]
 	pub pattern: String,
+
+	/// How the pattern matches (default: inferred from the pattern)
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub kind: Option<PatternKind>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProtectAddResolved {
-	pub pattern: String,
+	pub pattern: ProtectedUrl,
 }
 
 impl Resolve for ProtectAddRaw {
 	type Output = ProtectAddResolved;
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
-		Ok(ProtectAddResolved { pattern: self.pattern })
+		let pattern = protected_pattern(&self.pattern, self.kind);
+		pattern.validate().map_err(PwError::Context)?;
+		Ok(ProtectAddResolved { pattern })
+	}
+}
+
+/// Builds a pattern from user input, with `kind` overriding the inferred kind.
+fn protected_pattern(pattern: &str, kind: Option<PatternKind>) -> ProtectedUrl {
+	match kind {
+		Some(kind) => ProtectedUrl::new(pattern, kind),
+		None => ProtectedUrl::parse(pattern),
 	}
 }
 
@@ -51,7 +68,7 @@ impl CommandDef for ProtectAddCommand {
 
 			Ok(CommandOutcome {
 				inputs: CommandInputs {
-					extra: Some(json!({ "pattern": args.pattern })),
+					extra: Some(json!({ "pattern": args.pattern.pattern(), "kind": args.pattern.kind() })),
 					..Default::default()
 				},
 				data,
@@ -64,20 +81,28 @@ impl CommandDef for ProtectAddCommand {
 #[derive(Debug, Clone, Args, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProtectRemoveRaw {
+	/// Pattern to remove, written as it was added
 	#[arg(value_name = "PATTERN")]
 	pub pattern: String,
+
+	/// How the pattern matches (default: inferred from the pattern)
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub kind: Option<PatternKind>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProtectRemoveResolved {
-	pub pattern: String,
+	pub pattern: ProtectedUrl,
 }
 
 impl Resolve for ProtectRemoveRaw {
 	type Output = ProtectRemoveResolved;
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
-		Ok(ProtectRemoveResolved { pattern: self.pattern })
+		Ok(ProtectRemoveResolved {
+			pattern: protected_pattern(&self.pattern, self.kind),
+		})
 	}
 }
 
@@ -105,7 +130,7 @@ impl CommandDef for ProtectRemoveCommand {
 
 			Ok(CommandOutcome {
 				inputs: CommandInputs {
-					extra: Some(json!({ "pattern": args.pattern })),
+					extra: Some(json!({ "pattern": args.pattern.pattern(), "kind": args.pattern.kind() })),
 					..Default::default()
 				},
 				data,
@@ -1,7 +1,7 @@
 //! Command registry and generated dispatch glue.
 
 #[allow(unused_imports, reason = "registry module re-exports generated command graph symbols")]
-pub use crate::commands::graph::{CommandId, CommandMeta, all_commands, command_meta, command_name, lookup_command, run_command};
+pub use crate::commands::graph::{CommandId, CommandMeta, all_commands, command_meta, command_name, lookup_command, run_command, validate_input};
 
 /// Looks up only canonical command ids.
 pub fn lookup_command_exact(op: &str) -> Option<CommandId> {
@@ -18,6 +18,9 @@ mod tests {
 		assert_eq!(lookup_command("navigate"), Some(CommandId::Navigate));
 		assert_eq!(lookup_command("click"), Some(CommandId::Click));
 		assert_eq!(lookup_command("page.text"), Some(CommandId::PageText));
+		assert_eq!(lookup_command("page.scroll"), Some(CommandId::PageScroll));
+		assert_eq!(lookup_command("page.select"), Some(CommandId::PageSelect));
+		assert_eq!(lookup_command("page.upload"), Some(CommandId::PageUpload));
 		assert_eq!(lookup_command("connect"), Some(CommandId::Connect));
 		assert_eq!(lookup_command("session.status"), Some(CommandId::SessionStatus));
 		assert_eq!(lookup_command("har.show"), Some(CommandId::HarShow));
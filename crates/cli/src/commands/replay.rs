@@ -0,0 +1,196 @@
+//! Rebuilds a request from a previously captured result envelope.
+//!
+//! Successful responses echo the resolved `inputs` of the command that
+//! produced them. Replay maps those back onto the keys the command accepts
+//! and re-issues the same operation, which makes a saved `-f json` result
+//! enough to reproduce a run.
+
+use serde_json::{Map, Value};
+
+use crate::commands::registry::{command_name, lookup_command};
+use crate::error::{PwError, Result};
+use crate::protocol::{CommandRequest, CommandResponse, RuntimeSpec, SCHEMA_VERSION};
+
+/// Builds the request that reproduces the command recorded in `envelope`.
+///
+/// `profile` overrides the profile recorded in the envelope's effective runtime.
+pub fn request_from_envelope(envelope: &str, profile: Option<&str>) -> Result<CommandRequest> {
+	let response: CommandResponse = serde_json::from_str(envelope).map_err(|err| PwError::Context(format!("not a result envelope: {err}")))?;
+
+	if response.schema_version != SCHEMA_VERSION {
+		return Err(PwError::Context(format!(
+			"unsupported schemaVersion {} (expected {SCHEMA_VERSION})",
+			response.schema_version
+		)));
+	}
+
+	let id = lookup_command(&response.op).ok_or_else(|| PwError::Context(format!("unknown operation: {}", response.op)))?;
+	let op = command_name(id);
+
+	let inputs = response
+		.inputs
+		.ok_or_else(|| PwError::Context(format!("envelope for '{op}' has no inputs; only successful results can be replayed")))?;
+	let Value::Object(echoed) = serde_json::to_value(inputs)? else {
+		return Err(PwError::Context("envelope inputs must be an object".into()));
+	};
+
+	let mut input = Map::new();
+	for (key, value) in echoed {
+		if key == "expression" && value.as_str().is_some_and(is_truncated_expression) {
+			return Err(PwError::Context(
+				"envelope expression was truncated; re-run the original command instead".into(),
+			));
+		}
+		if key == "textLength" {
+			return Err(PwError::Context(format!(
+				"'{op}' envelopes echo only the length of the text; re-run the original command instead"
+			)));
+		}
+		input.insert(input_key(op, &key).to_string(), value);
+	}
+
+	let profile = profile
+		.map(str::to_string)
+		.or_else(|| response.effective_runtime.map(|runtime| runtime.profile))
+		.unwrap_or_else(|| "default".to_string());
+
+	Ok(CommandRequest {
+		schema_version: SCHEMA_VERSION,
+		request_id: response.request_id,
+		op: op.to_string(),
+		input: Value::Object(input),
+		runtime: Some(RuntimeSpec {
+			profile: Some(profile),
+			overrides: None,
+		}),
+		retry: None,
+	})
+}
+
+/// Maps an echoed input name to the name `op` accepts as input.
+fn input_key<'a>(op: &str, key: &'a str) -> &'a str {
+	match (op, key) {
+		(_, "outputPath") => "output",
+		("wait", "selector") => "condition",
+		("diff-text", "url") => "urlA",
+		_ => key,
+	}
+}
+
+/// `page.eval` echoes at most 500 bytes of its expression followed by `...`.
+fn is_truncated_expression(expression: &str) -> bool {
+	expression.len() > 500 && expression.ends_with("...")
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	fn replay(envelope: Value) -> Result<CommandRequest> {
+		request_from_envelope(&envelope.to_string(), None)
+	}
+
+	#[test]
+	fn navigate_envelope_replays_same_op_and_inputs() {
+		let request = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"requestId": "r1",
+			"op": "navigate",
+			"ok": true,
+			"inputs": { "url": "https://example.com/", "maxText": 0 },
+			"data": { "url": "https://example.com/" },
+			"effectiveRuntime": { "profile": "agent-a" }
+		}))
+		.unwrap();
+
+		assert_eq!(request.op, "navigate");
+		assert_eq!(request.request_id.as_deref(), Some("r1"));
+		assert_eq!(request.input, json!({ "url": "https://example.com/", "maxText": 0 }));
+		assert_eq!(request.runtime.unwrap().profile.as_deref(), Some("agent-a"));
+	}
+
+	#[test]
+	fn echoed_names_map_back_to_command_inputs() {
+		let request = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "screenshot",
+			"ok": true,
+			"inputs": { "outputPath": "shot.png", "fullPage": true }
+		}))
+		.unwrap();
+		assert_eq!(request.input, json!({ "output": "shot.png", "fullPage": true }));
+
+		let request = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "wait",
+			"ok": true,
+			"inputs": { "selector": ".ready" }
+		}))
+		.unwrap();
+		assert_eq!(request.input, json!({ "condition": ".ready" }));
+	}
+
+	#[test]
+	fn profile_flag_overrides_envelope_profile() {
+		let envelope = json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "page.text",
+			"ok": true,
+			"inputs": { "selector": "h1" },
+			"effectiveRuntime": { "profile": "agent-a" }
+		});
+		let request = request_from_envelope(&envelope.to_string(), Some("ci")).unwrap();
+		assert_eq!(request.runtime.unwrap().profile.as_deref(), Some("ci"));
+	}
+
+	#[test]
+	fn rejects_unknown_operation() {
+		let err = replay(json!({ "schemaVersion": SCHEMA_VERSION, "op": "teleport", "ok": true, "inputs": {} })).unwrap_err();
+		assert!(err.to_string().contains("unknown operation: teleport"));
+	}
+
+	#[test]
+	fn rejects_envelope_without_inputs() {
+		let err = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "click",
+			"ok": false,
+			"error": { "code": "TIMEOUT", "message": "Timeout" }
+		}))
+		.unwrap_err();
+		assert!(err.to_string().contains("no inputs"));
+	}
+
+	#[test]
+	fn rejects_non_envelope_json() {
+		assert!(request_from_envelope(r#"{"url":"https://example.com"}"#, None).is_err());
+		assert!(replay(json!({ "schemaVersion": 1, "op": "navigate", "ok": true, "inputs": {} })).is_err());
+	}
+
+	#[test]
+	fn rejects_truncated_expression() {
+		let expression = format!("{}...", "x".repeat(500));
+		let err = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "page.eval",
+			"ok": true,
+			"inputs": { "expression": expression }
+		}))
+		.unwrap_err();
+		assert!(err.to_string().contains("truncated"));
+	}
+
+	#[test]
+	fn rejects_redacted_text() {
+		let err = replay(json!({
+			"schemaVersion": SCHEMA_VERSION,
+			"op": "fill",
+			"ok": true,
+			"inputs": { "selector": "#password", "textLength": 7 }
+		}))
+		.unwrap_err();
+		assert!(err.to_string().contains("length of the text"));
+	}
+}
@@ -1,17 +1,31 @@
 //! Screenshot capture command.
+//!
+//! Two independent knobs stabilize captures of animated pages:
+//! * `animations: "disabled"` is passed to Playwright's screenshot call, which
+//!   fast-forwards finite CSS animations/transitions and cancels infinite ones
+//!   only for the duration of the capture
+//! * `freezeAnimations: true` registers an init script (and applies it to the
+//!   already loaded document) that zeroes animation and transition timing for
+//!   the whole session page, so the page settles into its final state before
+//!   the capture starts and stays there for later commands
+//!
+//! `clip: "x,y,w,h"` captures only that rectangle of the viewport, in CSS
+//! pixels. It cannot be combined with `fullPage`.
 
 use std::path::PathBuf;
 
 use clap::Args;
-use pw_rs::{ScreenshotOptions, WaitUntil};
+use pw_rs::{ScreenshotClip, ScreenshotOptions, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
+use crate::browser::js::freeze_animations_js;
+use crate::cli::CliScreenshotAnimations;
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
-use crate::output::ScreenshotData;
+use crate::error::{PwError, Result};
+use crate::output::{ClipRegion, ScreenshotData};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
 
@@ -33,6 +47,21 @@ pub struct ScreenshotRaw {
 	#[serde(default, alias = "full_page")]
 	pub full_page: Option<bool>,
 
+	/// Capture only this region, as "x,y,width,height" in CSS pixels
+	#[arg(long, value_name = "X,Y,W,H")]
+	#[serde(default)]
+	pub clip: Option<String>,
+
+	/// Animation handling during capture ('disabled' stops CSS animations and transitions)
+	#[arg(long, value_enum, value_name = "MODE")]
+	#[serde(default)]
+	pub animations: Option<CliScreenshotAnimations>,
+
+	/// Zero animation/transition durations via init script before the page loads
+	#[arg(long)]
+	#[serde(default, alias = "freeze_animations")]
+	pub freeze_animations: Option<bool>,
+
 	/// Target URL (named alternative)
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
@@ -45,6 +74,9 @@ pub struct ScreenshotResolved {
 	pub target: ResolvedTarget,
 	pub output: PathBuf,
 	pub full_page: bool,
+	pub clip: Option<ClipRegion>,
+	pub animations: Option<CliScreenshotAnimations>,
+	pub freeze_animations: bool,
 }
 
 impl Resolve for ScreenshotRaw {
@@ -54,8 +86,22 @@ impl Resolve for ScreenshotRaw {
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
 		let output = self.output.unwrap_or_else(|| PathBuf::from("screenshot.png"));
 		let full_page = self.full_page.unwrap_or(false);
+		let freeze_animations = self.freeze_animations.unwrap_or(false);
+		let clip = self.clip.as_deref().map(parse_clip).transpose()?;
+		if clip.is_some() && full_page {
+			return Err(PwError::Context(
+				"clip and fullPage cannot be combined; a clip region is taken from the viewport".into(),
+			));
+		}
 
-		Ok(ScreenshotResolved { target, output, full_page })
+		Ok(ScreenshotResolved {
+			target,
+			output,
+			full_page,
+			clip,
+			animations: self.animations,
+			freeze_animations,
+		})
 	}
 }
 
@@ -79,6 +125,7 @@ impl CommandDef for ScreenshotCommand {
 				url = %url_display,
 				path = %args.output.display(),
 				full_page = %args.full_page,
+				freeze_animations = %args.freeze_animations,
 				browser = %exec.ctx.browser,
 				"screenshot"
 			);
@@ -91,14 +138,31 @@ impl CommandDef for ScreenshotCommand {
 
 			let output = args.output.clone();
 			let full_page = args.full_page;
+			let clip = args.clip;
+			let animations = args.animations;
+			let freeze_animations = args.freeze_animations;
 
 			run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let output = output.clone();
 				Box::pin(async move {
-					session.goto_target(&flow.target, flow.timeout_ms).await?;
+					if freeze_animations {
+						let freeze_js = freeze_animations_js();
+						session.page().add_init_script(&freeze_js).await?;
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+						session.page().evaluate(&freeze_js).await?;
+					} else {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+					}
 
 					let screenshot_opts = ScreenshotOptions {
 						full_page: Some(full_page),
+						clip: clip.map(|c| ScreenshotClip {
+							x: f64::from(c.x),
+							y: f64::from(c.y),
+							width: f64::from(c.width),
+							height: f64::from(c.height),
+						}),
+						animations: animations.map(Into::into),
 						..Default::default()
 					};
 
@@ -112,11 +176,26 @@ impl CommandDef for ScreenshotCommand {
 			let data = ScreenshotData {
 				path: args.output.clone(),
 				full_page: args.full_page,
-				width: None,
-				height: None,
+				width: args.clip.map(|c| c.width),
+				height: args.clip.map(|c| c.height),
+				clip: args.clip,
 			};
 
-			let inputs = standard_inputs(&args.target, None, None, Some(&args.output), None);
+			let mut extra = serde_json::Map::new();
+			if args.full_page {
+				extra.insert("fullPage".into(), true.into());
+			}
+			if let Some(clip) = args.clip {
+				extra.insert("clip".into(), format!("{},{},{},{}", clip.x, clip.y, clip.width, clip.height).into());
+			}
+			if let Some(animations) = args.animations {
+				extra.insert("animations".into(), serde_json::to_value(animations)?);
+			}
+			if args.freeze_animations {
+				extra.insert("freezeAnimations".into(), true.into());
+			}
+			let extra = (!extra.is_empty()).then_some(serde_json::Value::Object(extra));
+			let inputs = standard_inputs(&args.target, None, None, Some(&args.output), extra);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -127,6 +206,24 @@ impl CommandDef for ScreenshotCommand {
 	}
 }
 
+/// Parses `x,y,width,height` into a clip region.
+fn parse_clip(spec: &str) -> Result<ClipRegion> {
+	let invalid = || PwError::Context(format!("invalid clip '{spec}': expected four non-negative integers as x,y,width,height"));
+
+	let values = spec
+		.split(',')
+		.map(|part| part.trim().parse::<u32>().map_err(|_| invalid()))
+		.collect::<Result<Vec<_>>>()?;
+	let [x, y, width, height] = values[..] else {
+		return Err(invalid());
+	};
+	if width == 0 || height == 0 {
+		return Err(PwError::Context(format!("invalid clip '{spec}': width and height must be greater than zero")));
+	}
+
+	Ok(ClipRegion { x, y, width, height })
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -139,4 +236,28 @@ mod tests {
 		assert_eq!(raw.output, Some(PathBuf::from("test.png")));
 		assert_eq!(raw.full_page, Some(true));
 	}
+
+	#[test]
+	fn screenshot_raw_deserialize_animation_options() {
+		let json = r#"{"animations": "disabled", "freezeAnimations": true}"#;
+		let raw: ScreenshotRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.animations, Some(CliScreenshotAnimations::Disabled));
+		assert_eq!(raw.freeze_animations, Some(true));
+	}
+
+	#[test]
+	fn parse_clip_accepts_four_integers() {
+		assert_eq!(
+			parse_clip("10, 20,300,200").unwrap(),
+			ClipRegion {
+				x: 10,
+				y: 20,
+				width: 300,
+				height: 200
+			}
+		);
+		for spec in ["10,20,300", "10,20,300,200,1", "-1,0,10,10", "a,b,c,d", "0,0,0,10", "1.5,0,10,10"] {
+			assert!(matches!(parse_clip(spec), Err(PwError::Context(_))), "{spec} should be rejected");
+		}
+	}
 }
@@ -7,6 +7,8 @@ use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, Exe
 use crate::error::{PwError, Result};
 use crate::output::{CommandInputs, SessionStartData};
 use crate::session::SessionRequest;
+use crate::session::descriptor::StoredDescriptor;
+use crate::session::repository::SessionRepository;
 use crate::target::ResolveEnv;
 use crate::types::BrowserKind;
 use crate::workspace::compute_cdp_port;
@@ -210,3 +212,179 @@ impl CommandDef for SessionStopCommand {
 		})
 	}
 }
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionListRaw {
+	/// Remove descriptors whose owning process is no longer running.
+	#[arg(long)]
+	#[serde(default)]
+	pub prune: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionListResolved {
+	pub prune: bool,
+}
+
+impl Resolve for SessionListRaw {
+	type Output = SessionListResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(SessionListResolved { prune: self.prune })
+	}
+}
+
+pub struct SessionListCommand;
+
+impl CommandDef for SessionListCommand {
+	const NAME: &'static str = "session.list";
+
+	type Raw = SessionListRaw;
+	type Resolved = SessionListResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let data = list_descriptors(exec.ctx_state.state_root(), args.prune)?;
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({ "prune": args.prune })),
+					..Default::default()
+				},
+				data,
+				delta: ContextDelta::default(),
+			})
+		})
+	}
+}
+
+/// Builds the `session.list` payload, removing dead descriptors when `prune` is set.
+///
+/// Descriptors that cannot be read are listed under `invalid` with the reason,
+/// and only removed when `prune` is set.
+fn list_descriptors(state_root: &std::path::Path, prune: bool) -> Result<serde_json::Value> {
+	let mut sessions = Vec::new();
+	let mut invalid = Vec::new();
+	let mut pruned = 0usize;
+
+	for repo in SessionRepository::discover(state_root)? {
+		let read = match repo.read() {
+			Ok(None) => continue,
+			Ok(Some(StoredDescriptor::Current(desc))) => Ok(desc),
+			Ok(Some(StoredDescriptor::Unversioned)) => Err("descriptor predates schema_version".to_string()),
+			Err(err) => Err(err.to_string()),
+		};
+		let desc = match read {
+			Ok(desc) => desc,
+			Err(error) => {
+				if !prune {
+					invalid.push(json!({ "path": repo.path(), "error": error }));
+				} else if repo.clear()? {
+					pruned += 1;
+				}
+				continue;
+			}
+		};
+		let alive = desc.is_alive();
+		if prune && !alive {
+			if repo.clear()? {
+				pruned += 1;
+			}
+			continue;
+		}
+
+		sessions.push(json!({
+			"namespace": desc.namespace,
+			"browser": desc.browser,
+			"headless": desc.headless,
+			"endpoint": desc.cdp_endpoint.as_deref().or(desc.ws_endpoint.as_deref()),
+			"pid": desc.pid,
+			"alive": alive,
+			"path": repo.path(),
+		}));
+	}
+
+	Ok(json!({
+		"count": sessions.len(),
+		"pruned": pruned,
+		"sessions": sessions,
+		"invalid": invalid,
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::session::SessionDescriptor;
+	use crate::session::descriptor::{DRIVER_HASH, SESSION_DESCRIPTOR_SCHEMA_VERSION};
+
+	fn write_descriptor(state_root: &std::path::Path, profile: &str, pid: u32) {
+		let path = state_root.join("profiles").join(profile).join("sessions").join("session.json");
+		SessionDescriptor {
+			schema_version: SESSION_DESCRIPTOR_SCHEMA_VERSION,
+			pid,
+			browser: BrowserKind::Chromium,
+			headless: true,
+			cdp_endpoint: Some("http://127.0.0.1:9222".into()),
+			ws_endpoint: None,
+			workspace_id: Some("ws".into()),
+			namespace: Some(profile.into()),
+			session_key: None,
+			driver_hash: Some(DRIVER_HASH.to_string()),
+			created_at: 0,
+		}
+		.save(&path)
+		.unwrap();
+	}
+
+	#[test]
+	fn list_reports_liveness_and_prunes_dead_descriptors() {
+		let dir = tempdir().unwrap();
+		let root = dir.path();
+		write_descriptor(root, "default", std::process::id());
+		write_descriptor(root, "stale", 0);
+
+		let listed = list_descriptors(root, false).unwrap();
+		assert_eq!(listed["count"], 2);
+		assert_eq!(listed["sessions"][0]["namespace"], "default");
+		assert_eq!(listed["sessions"][0]["alive"], true);
+		assert_eq!(listed["sessions"][0]["endpoint"], "http://127.0.0.1:9222");
+		assert_eq!(listed["sessions"][1]["alive"], false);
+
+		let pruned = list_descriptors(root, true).unwrap();
+		assert_eq!(pruned["count"], 1);
+		assert_eq!(pruned["pruned"], 1);
+		assert!(!root.join("profiles/stale/sessions/session.json").exists());
+	}
+
+	#[test]
+	fn list_reports_unreadable_descriptors_and_prune_removes_them() {
+		let dir = tempdir().unwrap();
+		let root = dir.path();
+		write_descriptor(root, "default", std::process::id());
+		let corrupt = root.join("profiles/corrupt/sessions/session.json");
+		let unversioned = root.join("profiles/old/sessions/session.json");
+		for (path, content) in [(&corrupt, "{not json"), (&unversioned, r#"{"pid": 1}"#)] {
+			std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+			std::fs::write(path, content).unwrap();
+		}
+
+		let listed = list_descriptors(root, false).unwrap();
+		assert_eq!(listed["count"], 1);
+		assert_eq!(listed["sessions"][0]["namespace"], "default");
+		assert_eq!(listed["invalid"].as_array().unwrap().len(), 2);
+		assert!(corrupt.exists() && unversioned.exists(), "listing must not delete descriptors");
+
+		let pruned = list_descriptors(root, true).unwrap();
+		assert_eq!(pruned["pruned"], 2);
+		assert_eq!(pruned["invalid"], json!([]));
+		assert!(!corrupt.exists() && !unversioned.exists());
+	}
+}
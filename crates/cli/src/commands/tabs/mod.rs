@@ -1,5 +1,6 @@
 use clap::Args;
 use pw_rs::WaitUntil;
+use pw_rs::pw_runtime::ChannelOwner;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -8,6 +9,7 @@ use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
 use crate::session::SessionRequest;
 use crate::target::ResolveEnv;
+use crate::types::{ProtectedUrl, is_protected_url};
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -57,7 +59,7 @@ impl CommandDef for TabsListCommand {
 
 			let mut tabs = Vec::new();
 			for (i, (url, title, _page)) in sorted_pages.iter().enumerate() {
-				let protected = is_protected(url, &protected_patterns);
+				let protected = is_protected_url(url, &protected_patterns);
 				tabs.push(TabInfo {
 					index: i,
 					title: title.clone(),
@@ -241,14 +243,19 @@ impl CommandDef for TabsNewCommand {
 			let context = session.context();
 			let page = context.new_page().await?;
 
+			// Without a URL the tab stays on about:blank; `navigated` reports which case applied.
 			if let Some(url) = &args.url {
 				page.goto(url, None).await?;
 			}
 
-			let final_url = page.evaluate_value("window.location.href").await.unwrap_or_else(|_| page.url());
-			let final_url = final_url.trim_matches('"').to_string();
+			let url = get_page_url(&page).await;
 			let title = page.title().await.unwrap_or_default();
-			let new_index = context.pages().len().saturating_sub(1);
+			let pages = context.pages();
+			let sorted = sort_pages_by_url(&pages).await;
+			let index = sorted
+				.iter()
+				.position(|(_, _, p)| p.guid() == page.guid())
+				.ok_or_else(|| PwError::Context("New tab is missing from the browser context".into()))?;
 			session.close().await?;
 
 			Ok(CommandOutcome {
@@ -258,9 +265,10 @@ impl CommandDef for TabsNewCommand {
 				},
 				data: json!({
 					"created": true,
-					"index": new_index,
+					"navigated": args.url.is_some(),
+					"index": index,
 					"title": title,
-					"url": final_url,
+					"url": url,
 				}),
 				delta: ContextDelta::default(),
 			})
@@ -268,11 +276,6 @@ impl CommandDef for TabsNewCommand {
 	}
 }
 
-fn is_protected(url: &str, protected_patterns: &[String]) -> bool {
-	let url_lower = url.to_lowercase();
-	protected_patterns.iter().any(|pattern| url_lower.contains(&pattern.to_lowercase()))
-}
-
 async fn get_page_url(page: &pw_rs::Page) -> String {
 	page.evaluate_value("window.location.href")
 		.await
@@ -297,13 +300,13 @@ async fn sort_pages_by_url(pages: &[pw_rs::Page]) -> Vec<(String, String, &pw_rs
 fn find_page<'a>(
 	sorted_pages: &'a [(String, String, &'a pw_rs::Page)],
 	target: &str,
-	protected_patterns: &[String],
+	protected_patterns: &[ProtectedUrl],
 ) -> Result<(usize, String, String, &'a pw_rs::Page)> {
 	if let Ok(index) = target.parse::<usize>() {
 		let (url, title, page) = sorted_pages
 			.get(index)
 			.ok_or_else(|| PwError::Context(format!("Tab index {} out of range (0-{})", index, sorted_pages.len().saturating_sub(1))))?;
-		if is_protected(url, protected_patterns) {
+		if is_protected_url(url, protected_patterns) {
 			return Err(PwError::Context(format!(
 				"Tab {} is protected (URL '{}' matches a protected pattern)",
 				index, url
@@ -318,7 +321,7 @@ fn find_page<'a>(
 		let title_lower = title.to_lowercase();
 
 		if url_lower.contains(&target_lower) || title_lower.contains(&target_lower) {
-			if is_protected(url, protected_patterns) {
+			if is_protected_url(url, protected_patterns) {
 				continue;
 			}
 			return Ok((i, url.clone(), title.clone(), page));
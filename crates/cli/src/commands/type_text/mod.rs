@@ -0,0 +1,203 @@
+//! Type-into-element command.
+//!
+//! Types text into an element one character at a time, sending real
+//! `keydown`/`keypress`/`input`/`keyup` events for each character. Use it
+//! instead of `fill` for autocomplete fields, masked inputs, and other
+//! elements that react to individual keystrokes.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw type --selector "input[name=city]" "Amsterdam"
+//! pw type --selector "#card" "4242424242424242" --delay-ms 50
+//! ```
+
+use clap::Args;
+use pw_rs::{PressSequentiallyOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{describe_target, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Explanation, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::output::{CommandInputs, TypeData};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeRaw {
+	/// Text to type into the element
+	pub text: Option<String>,
+
+	/// CSS selector for the element
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Set to false to wait for the element instead of requiring exactly one match up front
+	#[arg(long, value_name = "BOOL")]
+	#[serde(default)]
+	pub strict: Option<bool>,
+
+	/// Milliseconds to wait between key presses (default: 0)
+	#[arg(long, value_name = "MS")]
+	#[serde(default, alias = "delay_ms")]
+	pub delay_ms: Option<u64>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct TypeResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// CSS selector for the target element.
+	pub selector: String,
+
+	/// Text to type into the element.
+	pub text: String,
+
+	/// Fail immediately unless the selector matches exactly one element.
+	pub strict: bool,
+
+	/// Pause between key presses; `None` types without pausing.
+	pub delay_ms: Option<u64>,
+}
+
+impl Resolve for TypeRaw {
+	type Output = TypeResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
+		let selector = env.resolve_selector(self.selector, None)?;
+		let text = self.text.unwrap_or_default();
+
+		Ok(TypeResolved {
+			target,
+			selector,
+			text,
+			strict: self.strict.unwrap_or(true),
+			delay_ms: self.delay_ms,
+		})
+	}
+}
+
+pub struct TypeCommand;
+
+impl CommandDef for TypeCommand {
+	const NAME: &'static str = "type";
+
+	type Raw = TypeRaw;
+	type Resolved = TypeResolved;
+	type Data = TypeData;
+
+	fn explain(args: &Self::Resolved) -> Option<Explanation> {
+		let matching = if args.strict { "the single element" } else { "the first element" };
+		Some(Explanation {
+			summary: format!(
+				"Will {}, type {} characters into {matching} matching '{}' one key at a time.",
+				describe_target(&args.target),
+				args.text.chars().count(),
+				args.selector
+			),
+			inputs: type_inputs(args),
+		})
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, delay_ms = ?args.delay_ms, "type");
+
+			let selector = args.selector.clone();
+			let text = args.text.clone();
+			let strict = args.strict;
+			let type_opts = args.delay_ms.map(|delay| PressSequentiallyOptions::builder().delay(delay as f64).build());
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "type" },
+				move |session, flow| {
+					let selector = selector.clone();
+					let text = text.clone();
+					let type_opts = type_opts.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let locator = if strict {
+							session.single_locator(&selector).await?
+						} else {
+							session.locator(&selector).await
+						};
+						locator.press_sequentially(&text, type_opts).await?;
+						// Only form controls have a value; contenteditable targets report none,
+						// and password fields keep theirs out of the response.
+						let is_password = locator
+							.get_attribute("type")
+							.await
+							.ok()
+							.flatten()
+							.is_some_and(|t| t.eq_ignore_ascii_case("password"));
+						let value = if is_password { None } else { locator.input_value(None).await.ok() };
+
+						Ok(TypeData {
+							selector,
+							text_length: text.chars().count(),
+							value,
+						})
+					})
+				},
+			)
+			.await?;
+
+			let inputs = type_inputs(args);
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, Some(&args.selector), None),
+			})
+		})
+	}
+}
+
+/// Inputs echoed for a type. The text is reported by length only, as `fill` does.
+fn type_inputs(args: &TypeResolved) -> CommandInputs {
+	let mut extra = serde_json::json!({ "textLength": args.text.chars().count() });
+	if !args.strict {
+		extra["strict"] = false.into();
+	}
+	if let Some(delay_ms) = args.delay_ms {
+		extra["delayMs"] = delay_ms.into();
+	}
+	standard_inputs(&args.target, Some(&args.selector), None, None, Some(extra))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn type_raw_deserialize_from_json() {
+		let raw: TypeRaw = serde_json::from_str(r#"{"selector": "input", "text": "hello", "delayMs": 40}"#).unwrap();
+		assert_eq!(raw.selector, Some("input".into()));
+		assert_eq!(raw.text, Some("hello".into()));
+		assert_eq!(raw.delay_ms, Some(40));
+
+		let raw: TypeRaw = serde_json::from_str(r#"{"delay_ms": 10}"#).unwrap();
+		assert_eq!(raw.delay_ms, Some(10));
+	}
+}
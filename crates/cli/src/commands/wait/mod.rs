@@ -3,7 +3,11 @@
 //! Waits for a specified condition before continuing. Supports:
 //! * Timeout: numeric milliseconds (e.g., `"1000"`)
 //! * Load state: `"load"`, `"domcontentloaded"`, `"networkidle"`
-//! * Selector: CSS selector to wait for element presence
+//! * Selector: selector to wait for, present by default or in `--state`
+//!   `detached`, `visible` or `hidden`
+//! * URL: `url=<glob>` or `url=/<regex>/` to wait for a client-side route change
+//! * Response: `response:<url-substring>` to wait for a matching network response
+//! * Function: `function:<js>` to poll a JavaScript predicate until truthy
 //!
 //! # Examples
 //!
@@ -11,15 +15,20 @@
 //! pw wait --condition 2000           # wait 2 seconds
 //! pw wait --condition networkidle    # wait for network idle
 //! pw wait --condition ".loaded"      # wait for element
+//! pw wait --condition ".spinner" --state detached  # wait for element removal
+//! pw wait --condition "url=**/done"  # wait for the URL to match
+//! pw wait --condition "response:/api/items"        # wait for an API response
+//! pw wait --condition "function:window.appReady"   # wait for a predicate
 //! ```
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use clap::Args;
-use pw_rs::WaitUntil;
+use clap::{Args, ValueEnum};
+use pw_rs::{UrlPattern, WaitForSelectorOptions, WaitForSelectorState, WaitUntil};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{debug, info};
 
+use crate::browser::js;
 use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
@@ -37,7 +46,7 @@ pub struct WaitRaw {
 	#[serde(default)]
 	pub url: Option<String>,
 
-	/// Condition to wait for (selector, timeout ms, or load state)
+	/// Condition to wait for (selector, timeout ms, load state, url=<pattern>, response:<url>, or function:<js>)
 	#[arg(default_value = "networkidle")]
 	#[serde(default)]
 	pub condition: Option<String>,
@@ -46,6 +55,37 @@ pub struct WaitRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Element state a selector condition waits for (default: attached)
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub state: Option<SelectorState>,
+}
+
+/// Element state awaited by a selector condition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectorState {
+	/// Present in the DOM.
+	#[default]
+	Attached,
+	/// Removed from the DOM.
+	Detached,
+	/// Present and rendered.
+	Visible,
+	/// Absent or not rendered.
+	Hidden,
+}
+
+impl From<SelectorState> for WaitForSelectorState {
+	fn from(state: SelectorState) -> Self {
+		match state {
+			SelectorState::Attached => Self::Attached,
+			SelectorState::Detached => Self::Detached,
+			SelectorState::Visible => Self::Visible,
+			SelectorState::Hidden => Self::Hidden,
+		}
+	}
 }
 
 /// Resolved inputs ready for execution.
@@ -54,8 +94,11 @@ pub struct WaitResolved {
 	/// Navigation target (URL or current page).
 	pub target: ResolvedTarget,
 
-	/// Wait condition (timeout ms, load state, or CSS selector).
+	/// Wait condition (timeout ms, load state, `url=<pattern>`, `response:<url>`, `function:<js>`, or CSS selector).
 	pub condition: String,
+
+	/// Element state for a selector condition; `None` for other conditions.
+	pub state: Option<SelectorState>,
 }
 
 impl Resolve for WaitRaw {
@@ -67,7 +110,15 @@ impl Resolve for WaitRaw {
 			.condition
 			.ok_or_else(|| PwError::Context("No condition provided for wait command".into()))?;
 
-		Ok(WaitResolved { target, condition })
+		let state = if is_selector_condition(&condition) {
+			Some(self.state.unwrap_or_default())
+		} else if self.state.is_some() {
+			return Err(PwError::Context(format!("state only applies to selector conditions, not '{condition}'")));
+		} else {
+			None
+		};
+
+		Ok(WaitResolved { target, condition, state })
 	}
 }
 
@@ -80,6 +131,15 @@ pub struct WaitData {
 	waited_ms: Option<u64>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	selector_found: Option<bool>,
+	/// URL that satisfied a `url=` or `response:` condition.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	url: Option<String>,
+	/// HTTP status of the response that satisfied a `response:` condition.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	status: Option<u16>,
+	/// Element state reached by a selector condition.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	state: Option<SelectorState>,
 }
 
 pub struct WaitCommand;
@@ -100,10 +160,22 @@ impl CommandDef for WaitCommand {
 			info!(target = "pw", url = %url_display, condition = %args.condition, browser = %exec.ctx.browser, "wait");
 
 			let condition = args.condition.clone();
+			let state = args.state.unwrap_or_default();
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let condition = condition.clone();
 				Box::pin(async move {
+					let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+
+					// Listen before navigating so responses to the page load count.
+					if let Some(needle) = condition.strip_prefix("response:") {
+						let (data, ()) = tokio::try_join!(wait_for_response(session, needle, timeout_ms), async {
+							session.goto_target(&flow.target, flow.timeout_ms).await?;
+							Ok(())
+						})?;
+						return Ok(data);
+					}
+
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					if let Ok(ms) = condition.parse::<u64>() {
@@ -113,6 +185,9 @@ impl CommandDef for WaitCommand {
 							condition: format!("timeout:{ms}ms"),
 							waited_ms: Some(ms),
 							selector_found: None,
+							url: None,
+							status: None,
+							state: None,
 						});
 					}
 
@@ -121,15 +196,26 @@ impl CommandDef for WaitCommand {
 							condition: format!("loadstate:{condition}"),
 							waited_ms: None,
 							selector_found: None,
+							url: None,
+							status: None,
+							state: None,
 						});
 					}
 
-					wait_for_selector(session, &condition).await
+					if let Some(pattern) = condition.strip_prefix("url=") {
+						return wait_for_url(session, pattern, timeout_ms).await;
+					}
+
+					if let Some(predicate) = condition.strip_prefix("function:") {
+						return wait_for_function(session, predicate, timeout_ms).await;
+					}
+
+					wait_for_selector(session, &condition, state, timeout_ms).await
 				})
 			})
 			.await?;
 
-			let inputs = build_inputs(&args.target, args.condition.as_str());
+			let inputs = build_inputs(&args.target, args.condition.as_str(), args.state);
 
 			Ok(CommandOutcome {
 				inputs,
@@ -140,41 +226,224 @@ impl CommandDef for WaitCommand {
 	}
 }
 
-fn build_inputs(target: &ResolvedTarget, condition: &str) -> CommandInputs {
-	if condition.parse::<u64>().is_ok() || matches!(condition, "load" | "domcontentloaded" | "networkidle") {
-		standard_inputs(target, None, None, None, Some(serde_json::json!({ "condition": condition })))
-	} else {
-		standard_inputs(target, Some(condition), None, None, None)
+/// Whether `condition` names a selector rather than a timeout, load state or prefixed condition.
+fn is_selector_condition(condition: &str) -> bool {
+	let prefixed = ["url=", "response:", "function:"].iter().any(|prefix| condition.starts_with(prefix));
+	!(condition.parse::<u64>().is_ok() || matches!(condition, "load" | "domcontentloaded" | "networkidle") || prefixed)
+}
+
+fn build_inputs(target: &ResolvedTarget, condition: &str, state: Option<SelectorState>) -> CommandInputs {
+	if !is_selector_condition(condition) {
+		return standard_inputs(target, None, None, None, Some(serde_json::json!({ "condition": condition })));
 	}
+	let extra = state.filter(|s| *s != SelectorState::Attached).map(|s| serde_json::json!({ "state": s }));
+	standard_inputs(target, Some(condition), None, None, extra)
+}
+
+/// Waits for the page URL to match `pattern`, a glob or a `/regex/`.
+async fn wait_for_url(session: &SessionHandle, pattern: &str, timeout_ms: u64) -> Result<WaitData> {
+	let url_pattern = match pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+		Some(re) => UrlPattern::regex(re).map_err(|e| PwError::Context(e.to_string()))?,
+		None => UrlPattern::from(pattern),
+	};
+	let start = Instant::now();
+	let url = session
+		.page()
+		.wait_for_url(url_pattern, Duration::from_millis(timeout_ms))
+		.await
+		.map_err(|err| match err {
+			pw_rs::Error::Timeout(_) => PwError::Timeout {
+				ms: timeout_ms,
+				condition: format!("url={pattern}"),
+			},
+			other => other.into(),
+		})?;
+
+	Ok(WaitData {
+		condition: format!("url:{pattern}"),
+		waited_ms: Some(start.elapsed().as_millis() as u64),
+		selector_found: None,
+		url: Some(url),
+		status: None,
+		state: None,
+	})
+}
+
+/// Waits for a response from the page whose URL contains `needle`.
+async fn wait_for_response(session: &SessionHandle, needle: &str, timeout_ms: u64) -> Result<WaitData> {
+	let start = Instant::now();
+	let response = session
+		.page()
+		.wait_for_response(|response| response.url().contains(needle), Duration::from_millis(timeout_ms))
+		.await
+		.map_err(|err| match err {
+			pw_rs::Error::Timeout(_) => PwError::Timeout {
+				ms: timeout_ms,
+				condition: format!("response:{needle}"),
+			},
+			other => other.into(),
+		})?;
+
+	Ok(WaitData {
+		condition: format!("response:{needle}"),
+		waited_ms: Some(start.elapsed().as_millis() as u64),
+		selector_found: None,
+		url: Some(response.url().to_string()),
+		status: Some(response.status()),
+		state: None,
+	})
 }
 
-/// Polls for a CSS selector until it appears or times out.
-async fn wait_for_selector(session: &SessionHandle, selector: &str) -> Result<WaitData> {
-	let escaped = selector.replace('\\', "\\\\").replace('\'', "\\'");
-	let max_attempts = 30u64;
+/// Polls the JavaScript `predicate` until it is truthy.
+///
+/// A predicate that evaluates to a function is called and its result tested.
+/// Evaluation errors count as falsy, so the predicate may reference globals
+/// the page has yet to define; the last error is reported on timeout.
+async fn wait_for_function(session: &SessionHandle, predicate: &str, timeout_ms: u64) -> Result<WaitData> {
+	let expression = format!("(() => {{ const value = ({predicate}); return !!(typeof value === 'function' ? value() : value); }})()");
+	let start = Instant::now();
+	let timeout = Duration::from_millis(timeout_ms);
+
+	loop {
+		let last_error = match session.page().evaluate_value(&expression).await {
+			Ok(value) if value == "true" => break,
+			Ok(_) => None,
+			Err(err) => Some(err.to_string()),
+		};
+		if start.elapsed() >= timeout {
+			let condition = match last_error {
+				Some(err) => format!("function:{predicate} (last error: {err})"),
+				None => format!("function:{predicate}"),
+			};
+			return Err(PwError::Timeout { ms: timeout_ms, condition });
+		}
+
+		tokio::time::sleep(POLL_INTERVAL).await;
+	}
+
+	Ok(WaitData {
+		condition: format!("function:{predicate}"),
+		waited_ms: Some(start.elapsed().as_millis() as u64),
+		selector_found: None,
+		url: None,
+		status: None,
+		state: None,
+	})
+}
+
+/// Waits for `selector` to reach `state`.
+async fn wait_for_selector(session: &SessionHandle, selector: &str, state: SelectorState, timeout_ms: u64) -> Result<WaitData> {
+	let start = Instant::now();
+	let options = WaitForSelectorOptions::builder().state(state.into()).timeout(timeout_ms as f64).build();
+	let element = session.page().wait_for_selector(selector, Some(options)).await.map_err(|err| match err {
+		err if err.is_timeout() => PwError::Timeout {
+			ms: timeout_ms,
+			condition: selector.to_string(),
+		},
+		other => other.into(),
+	})?;
+	if let Some(element) = element {
+		element.release().await?;
+	}
 
-	for attempt in 0..max_attempts {
-		let visible = session
+	Ok(WaitData {
+		condition: format!("selector:{selector}"),
+		waited_ms: Some(start.elapsed().as_millis() as u64),
+		selector_found: Some(matches!(state, SelectorState::Attached | SelectorState::Visible)),
+		url: None,
+		status: None,
+		state: Some(state),
+	})
+}
+
+/// Interval between selector presence and predicate checks.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Polls until an element matches the CSS `selector`, returning the milliseconds waited.
+///
+/// # Errors
+///
+/// Returns [`PwError::Timeout`] if nothing matches within `timeout_ms`.
+pub(crate) async fn wait_for_selector_present(session: &SessionHandle, selector: &str, timeout_ms: u64) -> Result<u64> {
+	let escaped = js::escape_selector(selector);
+	let start = Instant::now();
+	let timeout = Duration::from_millis(timeout_ms);
+
+	loop {
+		let present = session
 			.page()
 			.evaluate_value(&format!("document.querySelector('{escaped}') !== null"))
 			.await
 			.unwrap_or_else(|_| "false".to_string());
 
-		if visible == "true" {
-			return Ok(WaitData {
-				condition: format!("selector:{selector}"),
-				waited_ms: Some(attempt * 1000),
-				selector_found: Some(true),
+		if present == "true" {
+			return Ok(start.elapsed().as_millis() as u64);
+		}
+		if start.elapsed() >= timeout {
+			return Err(PwError::Timeout {
+				ms: timeout_ms,
+				condition: selector.to_string(),
 			});
 		}
 
-		tokio::time::sleep(Duration::from_secs(1)).await;
+		tokio::time::sleep(POLL_INTERVAL).await;
 	}
+}
 
-	Err(PwError::Timeout {
-		ms: max_attempts * 1000,
-		condition: selector.to_string(),
-	})
+/// Outcome of [`settle_dom`].
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SettleOutcome {
+	/// False when `max_ms` elapsed before the DOM went quiet.
+	pub settled: bool,
+	pub waited_ms: u64,
+}
+
+/// Waits until the DOM goes `quiet_ms` without mutations, giving up after `max_ms`.
+pub(crate) async fn settle_dom(session: &SessionHandle, quiet_ms: u64, max_ms: u64) -> Result<SettleOutcome> {
+	let raw = session.page().evaluate_value(&js::settle_dom_js(quiet_ms, max_ms)).await?;
+	Ok(serde_json::from_str(&raw)?)
+}
+
+/// Readiness gates applied before extracting page content.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReadyGates {
+	/// CSS selector that must be present.
+	pub wait_for: Option<String>,
+	/// Quiet window in milliseconds the DOM must stay unmutated.
+	pub settle_ms: Option<u64>,
+}
+
+impl ReadyGates {
+	/// Runs the gates in order: selector presence first, then DOM settling.
+	///
+	/// Both share `timeout_ms`. A DOM that never settles is not an error; the
+	/// caller extracts whatever is there once the budget is spent.
+	pub(crate) async fn apply(&self, session: &SessionHandle, timeout_ms: Option<u64>) -> Result<()> {
+		let budget = timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+		let mut waited = 0;
+
+		if let Some(selector) = &self.wait_for {
+			waited = wait_for_selector_present(session, selector, budget).await?;
+		}
+		if let Some(quiet_ms) = self.settle_ms {
+			let outcome = settle_dom(session, quiet_ms, budget.saturating_sub(waited).max(quiet_ms)).await?;
+			if !outcome.settled {
+				debug!(target = "pw", quiet_ms, waited_ms = outcome.waited_ms, "dom did not settle before timeout");
+			}
+		}
+		Ok(())
+	}
+
+	/// Inputs recorded for the gates that are set.
+	pub(crate) fn record(&self, extra: &mut serde_json::Map<String, serde_json::Value>) {
+		if let Some(selector) = &self.wait_for {
+			extra.insert("waitFor".into(), selector.clone().into());
+		}
+		if let Some(quiet_ms) = self.settle_ms {
+			extra.insert("settle".into(), quiet_ms.into());
+		}
+	}
 }
 
 #[cfg(test)]
@@ -189,10 +458,57 @@ mod tests {
 		assert_eq!(raw.condition, Some("1000".into()));
 	}
 
+	#[test]
+	fn url_condition_is_recorded_as_condition_input() {
+		let target = ResolvedTarget {
+			target: crate::target::Target::CurrentPage,
+			source: crate::target::TargetSource::Explicit,
+		};
+		let inputs = build_inputs(&target, "url=**/done", None);
+		assert_eq!(inputs.selector, None);
+		assert_eq!(inputs.extra, Some(serde_json::json!({ "condition": "url=**/done" })));
+	}
+
+	#[test]
+	fn response_and_function_conditions_are_recorded_as_condition_input() {
+		let target = ResolvedTarget {
+			target: crate::target::Target::CurrentPage,
+			source: crate::target::TargetSource::Explicit,
+		};
+		for condition in ["response:/api/items", "function:window.ready"] {
+			let inputs = build_inputs(&target, condition, None);
+			assert_eq!(inputs.selector, None);
+			assert_eq!(inputs.extra, Some(serde_json::json!({ "condition": condition })));
+		}
+	}
+
 	#[test]
 	fn wait_raw_deserialize_selector_condition() {
 		let json = r#"{"condition": ".loaded"}"#;
 		let raw: WaitRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.condition, Some(".loaded".into()));
 	}
+
+	#[test]
+	fn selector_state_is_recorded_unless_default() {
+		let raw: WaitRaw = serde_json::from_str(r#"{"condition": ".spinner", "state": "detached"}"#).unwrap();
+		assert_eq!(raw.state, Some(SelectorState::Detached));
+
+		let target = ResolvedTarget {
+			target: crate::target::Target::CurrentPage,
+			source: crate::target::TargetSource::Explicit,
+		};
+		let inputs = build_inputs(&target, ".spinner", Some(SelectorState::Detached));
+		assert_eq!(inputs.selector.as_deref(), Some(".spinner"));
+		assert_eq!(inputs.extra, Some(serde_json::json!({ "state": "detached" })));
+		assert_eq!(build_inputs(&target, ".spinner", Some(SelectorState::Attached)).extra, None);
+	}
+
+	#[test]
+	fn only_plain_conditions_are_selectors() {
+		assert!(is_selector_condition("#target"));
+		for condition in ["500", "networkidle", "url=**/done", "response:/api", "function:window.ready"] {
+			assert!(!is_selector_condition(condition), "{condition}");
+		}
+	}
 }
@@ -4,11 +4,11 @@
 
 use std::path::{Component, Path, PathBuf};
 
-use pw_rs::{HarContentPolicy, HarMode};
+use pw_rs::{Geolocation, HarContentPolicy, HarMode, IgnoreDefaultArgs, LaunchOptions, Viewport};
 
 use crate::output::CdpEndpointSource;
 use crate::project::Project;
-use crate::types::BrowserKind;
+use crate::types::{BrowserKind, ColorScheme, ReducedMotion};
 use crate::workspace::STATE_VERSION_DIR;
 
 /// HAR recording configuration
@@ -31,15 +31,54 @@ impl HarConfig {
 	pub fn is_enabled(&self) -> bool {
 		self.path.is_some()
 	}
+
+	/// Returns a copy with `{timestamp}`, `{date}` and `{host}` in the path expanded.
+	///
+	/// `{timestamp}` is `YYYYMMDDTHHMMSSZ` and `{date}` is `YYYY-MM-DD`, both UTC at
+	/// `now`. `{host}` is the host of `url`, or `unknown` without one. Other text,
+	/// including unrecognized `{...}` tokens, is kept verbatim.
+	pub fn with_expanded_path(&self, url: Option<&str>, now: std::time::SystemTime) -> Self {
+		let mut expanded = self.clone();
+		if let Some(path) = &self.path {
+			expanded.path = Some(expand_har_path(path, url, now));
+		}
+		expanded
+	}
+}
+
+fn expand_har_path(template: &Path, url: Option<&str>, now: std::time::SystemTime) -> PathBuf {
+	let raw = template.to_string_lossy();
+	if !raw.contains('{') {
+		return template.to_path_buf();
+	}
+
+	let secs = now.duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+	let utc = chrono::DateTime::from_timestamp(secs as i64, 0).unwrap_or_default();
+	let host = url
+		.and_then(|url| url::Url::parse(url).ok())
+		.and_then(|url| url.host_str().map(str::to_string))
+		.unwrap_or_else(|| "unknown".to_string());
+
+	PathBuf::from(
+		raw.replace("{timestamp}", &utc.format("%Y%m%dT%H%M%SZ").to_string())
+			.replace("{date}", &utc.format("%Y-%m-%d").to_string())
+			.replace("{host}", &host),
+	)
 }
 
 /// Configuration for request blocking via [`Page::route`].
 ///
-/// Patterns use glob syntax matching against full URLs:
+/// Patterns containing `/` are Playwright URL globs, matched by the browser:
 /// * `**/*.png` - block all PNG images
 /// * `*://ads.*/**` - block ad domains
 /// * `*://google-analytics.com/**` - block analytics
 ///
+/// Patterns without `/` are matched by pw against the whole URL, with `*`
+/// spanning any characters and the rest of the glob syntax unchanged (see
+/// [`BlockConfig::is_url_glob`]):
+/// * `*.png` - URLs ending in `.png`
+/// * `*googletagmanager*` - any URL mentioning `googletagmanager`
+///
 /// [`Page::route`]: pw_rs::Page::route
 #[derive(Debug, Clone, Default)]
 pub struct BlockConfig {
@@ -53,6 +92,15 @@ impl BlockConfig {
 		!self.patterns.is_empty()
 	}
 
+	/// Returns `true` if `pattern` is handed to the browser as a Playwright
+	/// URL glob.
+	///
+	/// Playwright's `*` stops at `/`, so a bare `*.png` would never match a
+	/// full URL; such patterns are checked by pw instead.
+	pub fn is_url_glob(pattern: &str) -> bool {
+		pattern.contains('/')
+	}
+
 	/// Loads patterns from `path`, one per line.
 	///
 	/// Empty lines and lines starting with `#` are ignored.
@@ -83,12 +131,158 @@ impl DownloadConfig {
 	}
 }
 
+/// Browser launch tuning applied when `pw` launches a browser itself.
+///
+/// These settings only apply to launch flows (fresh launch, launch-server,
+/// persistent debug). Attaching to an existing browser over CDP or a daemon
+/// lease cannot change how that browser was started.
+///
+/// `channel` selects a branded browser distribution instead of the bundled
+/// Chromium build. Playwright accepts:
+/// * `chrome`, `chrome-beta`, `chrome-dev`, `chrome-canary`
+/// * `msedge`, `msedge-beta`, `msedge-dev`, `msedge-canary`
+/// * `chromium` (bundled Chromium using the new headless mode)
+///
+/// `executable_path` points Playwright at a custom browser build and works for
+/// all three engines. It must match the selected browser kind, and Playwright
+/// only guarantees compatibility with its bundled revisions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LaunchConfig {
+	/// Delay applied to every Playwright operation (milliseconds).
+	pub slow_mo_ms: Option<f64>,
+	/// Auto-open DevTools for each tab (Chromium only, implies headful).
+	pub devtools: Option<bool>,
+	/// Browser distribution channel (for example `chrome` or `msedge`).
+	pub channel: Option<String>,
+	/// Default browser arguments to drop, or `Bool(true)` to drop all of them.
+	pub ignore_default_args: Option<IgnoreDefaultArgs>,
+	/// Custom browser executable used instead of the bundled build.
+	pub executable_path: Option<PathBuf>,
+}
+
+impl LaunchConfig {
+	/// Returns `true` if any launch setting deviates from Playwright defaults.
+	pub fn is_customized(&self) -> bool {
+		self.slow_mo_ms.is_some() || self.devtools.is_some() || self.channel.is_some() || self.ignore_default_args.is_some() || self.executable_path.is_some()
+	}
+
+	/// Checks that a configured executable path points at an existing file.
+	pub fn validate(&self) -> crate::error::Result<()> {
+		if let Some(path) = &self.executable_path {
+			if !path.is_file() {
+				return Err(crate::error::PwError::BrowserLaunch(format!(
+					"browser executable not found: {}",
+					path.display()
+				)));
+			}
+		}
+		Ok(())
+	}
+
+	/// Builds Playwright launch options for the given headless mode.
+	pub fn launch_options(&self, headless: bool) -> LaunchOptions {
+		LaunchOptions {
+			headless: Some(headless),
+			slow_mo: self.slow_mo_ms,
+			devtools: self.devtools,
+			channel: self.channel.clone(),
+			ignore_default_args: self.ignore_default_args.clone(),
+			executable_path: self.executable_path.as_ref().map(|path| path.to_string_lossy().into_owned()),
+			..Default::default()
+		}
+	}
+}
+
+/// Browser context emulation applied when `pw` creates a context.
+///
+/// `timezone_id` must be an IANA zone name (for example `America/New_York`);
+/// `locale` is a BCP 47 tag such as `fr-FR` and drives `navigator.language`,
+/// `Accept-Language`, and `Intl` formatting.
+///
+/// Values come from request overrides first, then the profile's persisted
+/// `emulation` config, so settings survive across invocations.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmulationConfig {
+	/// Locale for the browser context.
+	pub locale: Option<String>,
+	/// IANA timezone identifier for the browser context.
+	pub timezone_id: Option<String>,
+	/// Emulated `prefers-color-scheme`.
+	pub color_scheme: Option<ColorScheme>,
+	/// Emulated `prefers-reduced-motion`.
+	pub reduced_motion: Option<ReducedMotion>,
+	/// Fixed viewport size.
+	pub viewport: Option<Viewport>,
+	/// User agent string, set by a `--device` preset.
+	pub user_agent: Option<String>,
+	/// Device pixel ratio, set by a `--device` preset.
+	pub device_scale_factor: Option<f64>,
+	/// Whether the `meta viewport` tag is honored, set by a `--device` preset.
+	pub is_mobile: Option<bool>,
+	/// Whether touch events are supported, set by a `--device` preset.
+	pub has_touch: Option<bool>,
+	/// Position reported by `navigator.geolocation`; setting it grants the permission.
+	pub geolocation: Option<Geolocation>,
+}
+
+impl EmulationConfig {
+	/// Returns `true` if any emulation setting is configured.
+	pub fn is_enabled(&self) -> bool {
+		*self != Self::default()
+	}
+
+	/// Applies a device preset, replacing any configured viewport.
+	pub fn apply_device(&mut self, device: &crate::devices::DeviceDescriptor) {
+		self.viewport = Some(device.viewport());
+		self.user_agent = Some(device.user_agent.to_string());
+		self.device_scale_factor = Some(device.device_scale_factor);
+		self.is_mobile = Some(device.is_mobile);
+		self.has_touch = Some(device.has_touch);
+	}
+
+	/// Checks that the timezone is a known IANA zone, the locale is non-empty,
+	/// and the geolocation is in range.
+	pub fn validate(&self) -> crate::error::Result<()> {
+		if let Some(tz) = &self.timezone_id {
+			if tz.parse::<chrono_tz::Tz>().is_err() {
+				return Err(crate::error::PwError::Context(format!(
+					"unknown timezone ID '{tz}' (expected an IANA name such as America/New_York)"
+				)));
+			}
+		}
+		if self.locale.as_deref().is_some_and(|locale| locale.trim().is_empty()) {
+			return Err(crate::error::PwError::Context("locale must not be empty".to_string()));
+		}
+		if let Some(geolocation) = &self.geolocation {
+			validate_geolocation(geolocation)?;
+		}
+		Ok(())
+	}
+}
+
+/// Checks that latitude is within -90..=90 and longitude within -180..=180.
+pub fn validate_geolocation(geolocation: &Geolocation) -> crate::error::Result<()> {
+	if !(-90.0..=90.0).contains(&geolocation.latitude) {
+		return Err(crate::error::PwError::Context(format!(
+			"latitude {} is out of range (expected -90 to 90)",
+			geolocation.latitude
+		)));
+	}
+	if !(-180.0..=180.0).contains(&geolocation.longitude) {
+		return Err(crate::error::PwError::Context(format!(
+			"longitude {} is out of range (expected -180 to 180)",
+			geolocation.longitude
+		)));
+	}
+	Ok(())
+}
+
 /// Configuration for creating a [`CommandContext`].
 #[derive(Debug, Clone, Default)]
 pub struct CommandContextConfig {
 	pub browser: BrowserKind,
 	pub no_project: bool,
-	pub auth_file: Option<PathBuf>,
+	pub auth_files: Vec<PathBuf>,
 	pub cdp_endpoint: Option<String>,
 	pub cdp_endpoint_source: CdpEndpointSource,
 	pub launch_server: bool,
@@ -96,6 +290,8 @@ pub struct CommandContextConfig {
 	pub har_config: HarConfig,
 	pub block_config: BlockConfig,
 	pub download_config: DownloadConfig,
+	pub launch_config: LaunchConfig,
+	pub emulation_config: EmulationConfig,
 	pub timeout_ms: Option<u64>,
 	pub workspace_root: Option<PathBuf>,
 	pub workspace_id: Option<String>,
@@ -117,8 +313,8 @@ pub struct CommandContext {
 	launch_server: bool,
 	/// Whether daemon usage is disabled
 	no_daemon: bool,
-	/// Auth file to use (resolved path)
-	auth_file: Option<PathBuf>,
+	/// Auth files to merge into the storage state (resolved paths)
+	auth_files: Vec<PathBuf>,
 	/// Whether project detection is disabled
 	pub no_project: bool,
 	/// HAR recording configuration
@@ -127,6 +323,10 @@ pub struct CommandContext {
 	block_config: BlockConfig,
 	/// Download management configuration
 	download_config: DownloadConfig,
+	/// Browser launch tuning
+	launch_config: LaunchConfig,
+	/// Browser context emulation
+	emulation_config: EmulationConfig,
 	/// Timeout for navigation and wait operations (milliseconds)
 	timeout_ms: Option<u64>,
 	/// Workspace root used for strict state/session isolation.
@@ -143,7 +343,7 @@ impl CommandContext {
 		Self::with_config(CommandContextConfig {
 			browser,
 			no_project,
-			auth_file,
+			auth_files: auth_file.into_iter().collect(),
 			cdp_endpoint,
 			launch_server,
 			no_daemon,
@@ -164,7 +364,7 @@ impl CommandContext {
 		Self::with_config(CommandContextConfig {
 			browser,
 			no_project,
-			auth_file,
+			auth_files: auth_file.into_iter().collect(),
 			cdp_endpoint,
 			launch_server,
 			no_daemon,
@@ -178,7 +378,7 @@ impl CommandContext {
 		let CommandContextConfig {
 			browser,
 			no_project,
-			auth_file,
+			auth_files,
 			cdp_endpoint,
 			cdp_endpoint_source,
 			launch_server,
@@ -186,6 +386,8 @@ impl CommandContext {
 			har_config,
 			block_config,
 			download_config,
+			launch_config,
+			emulation_config,
 			timeout_ms,
 			workspace_root,
 			workspace_id,
@@ -198,17 +400,20 @@ impl CommandContext {
 
 		let project = if no_project { None } else { Project::detect_from(&resolved_workspace_root) };
 
-		// Resolve auth file path based on project
-		let resolved_auth = auth_file.map(|auth| {
-			let auth = expand_home_path(auth);
-			if auth.is_absolute() {
-				auth
-			} else if let Some(ref proj) = project {
-				proj.paths.root.join(&auth)
-			} else {
-				resolved_workspace_root.join(auth)
-			}
-		});
+		// Resolve auth file paths based on project
+		let resolved_auth = auth_files
+			.into_iter()
+			.map(|auth| {
+				let auth = expand_home_path(auth);
+				if auth.is_absolute() {
+					auth
+				} else if let Some(ref proj) = project {
+					proj.paths.root.join(&auth)
+				} else {
+					resolved_workspace_root.join(auth)
+				}
+			})
+			.collect();
 
 		// Resolve HAR path based on project
 		let resolved_har_config = HarConfig {
@@ -237,6 +442,14 @@ impl CommandContext {
 			}),
 		};
 
+		let resolved_launch_config = LaunchConfig {
+			executable_path: launch_config.executable_path.map(|path| {
+				let path = expand_home_path(path);
+				if path.is_absolute() { path } else { resolved_workspace_root.join(path) }
+			}),
+			..launch_config
+		};
+
 		Self {
 			project,
 			browser,
@@ -244,11 +457,13 @@ impl CommandContext {
 			cdp_endpoint_source,
 			launch_server,
 			no_daemon,
-			auth_file: resolved_auth,
+			auth_files: resolved_auth,
 			no_project,
 			har_config: resolved_har_config,
 			block_config,
 			download_config: resolved_download_config,
+			launch_config: resolved_launch_config,
+			emulation_config,
 			timeout_ms,
 			workspace_root: resolved_workspace_root,
 			workspace_id: resolved_workspace_id,
@@ -256,9 +471,9 @@ impl CommandContext {
 		}
 	}
 
-	/// Get the auth file path
-	pub fn auth_file(&self) -> Option<&Path> {
-		self.auth_file.as_deref()
+	/// Get the auth file paths, in merge order
+	pub fn auth_files(&self) -> &[PathBuf] {
+		&self.auth_files
 	}
 
 	/// Get the CDP endpoint URL if provided
@@ -294,6 +509,16 @@ impl CommandContext {
 		&self.download_config
 	}
 
+	/// Get the browser launch configuration
+	pub fn launch_config(&self) -> &LaunchConfig {
+		&self.launch_config
+	}
+
+	/// Get the browser context emulation configuration
+	pub fn emulation_config(&self) -> &EmulationConfig {
+		&self.emulation_config
+	}
+
 	/// Get the timeout for navigation and wait operations
 	pub fn timeout_ms(&self) -> Option<u64> {
 		self.timeout_ms
@@ -353,7 +578,7 @@ impl CommandContext {
 	}
 
 	/// Get all auth files in the profile auth directory (*.json)
-	pub fn auth_files(&self) -> Vec<PathBuf> {
+	pub fn namespace_auth_files(&self) -> Vec<PathBuf> {
 		let auth_dir = self.namespace_auth_dir();
 
 		if !auth_dir.exists() {
@@ -431,6 +656,14 @@ mod tests {
 		assert_eq!(ctx.cdp_endpoint(), Some("ws://localhost:19988/cdp"));
 	}
 
+	#[test]
+	fn test_block_patterns_with_slash_are_url_globs() {
+		assert!(BlockConfig::is_url_glob("**/*.png"));
+		assert!(BlockConfig::is_url_glob("*://ads.example.com/**"));
+		assert!(!BlockConfig::is_url_glob("*.png"));
+		assert!(!BlockConfig::is_url_glob("*googletagmanager*"));
+	}
+
 	#[test]
 	fn test_screenshot_path_absolute() {
 		let ctx = CommandContext::new(BrowserKind::Chromium, true, None, None, false, false);
@@ -471,16 +704,119 @@ mod tests {
 		result.unwrap();
 	}
 
+	#[test]
+	fn test_launch_config_reaches_launch_options() {
+		let launch = LaunchConfig {
+			slow_mo_ms: Some(250.0),
+			devtools: Some(true),
+			channel: Some("chrome".to_string()),
+			ignore_default_args: Some(IgnoreDefaultArgs::Array(vec!["--mute-audio".to_string()])),
+			executable_path: None,
+		};
+		assert!(launch.is_customized());
+
+		let options = launch.launch_options(false);
+		assert_eq!(options.headless, Some(false));
+		assert_eq!(options.slow_mo, Some(250.0));
+		assert_eq!(options.devtools, Some(true));
+		assert_eq!(options.channel.as_deref(), Some("chrome"));
+		assert_eq!(options.ignore_default_args, Some(IgnoreDefaultArgs::Array(vec!["--mute-audio".to_string()])));
+	}
+
+	#[test]
+	fn test_default_launch_config_only_sets_headless() {
+		let launch = LaunchConfig::default();
+		assert!(!launch.is_customized());
+
+		let options = launch.launch_options(true);
+		assert_eq!(options.headless, Some(true));
+		assert!(options.slow_mo.is_none());
+		assert!(options.channel.is_none());
+		assert!(options.ignore_default_args.is_none());
+	}
+
+	#[test]
+	fn test_executable_path_reaches_launch_options() {
+		let temp = TempDir::new().unwrap();
+		let exe = temp.path().join("chrome");
+		fs::write(&exe, "").unwrap();
+
+		let ctx = CommandContext::with_config(CommandContextConfig {
+			browser: BrowserKind::Webkit,
+			no_project: true,
+			launch_config: LaunchConfig {
+				executable_path: Some(PathBuf::from("chrome")),
+				..Default::default()
+			},
+			workspace_root: Some(temp.path().to_path_buf()),
+			..Default::default()
+		});
+
+		let launch = ctx.launch_config();
+		assert_eq!(launch.executable_path.as_deref(), Some(exe.as_path()));
+		assert!(launch.is_customized());
+		launch.validate().unwrap();
+
+		let options = launch.launch_options(true);
+		assert_eq!(options.executable_path.as_deref(), Some(exe.to_string_lossy().as_ref()));
+	}
+
+	#[test]
+	fn test_missing_executable_path_fails_validation() {
+		let launch = LaunchConfig {
+			executable_path: Some(PathBuf::from("/definitely/missing/chrome")),
+			..Default::default()
+		};
+		let err = launch.validate().unwrap_err();
+		assert!(err.to_string().contains("browser executable not found"));
+	}
+
+	#[test]
+	fn test_emulation_config_accepts_known_timezone() {
+		let emulation = EmulationConfig {
+			locale: Some("fr-FR".to_string()),
+			timezone_id: Some("America/New_York".to_string()),
+			..Default::default()
+		};
+		assert!(emulation.is_enabled());
+		emulation.validate().unwrap();
+		assert!(!EmulationConfig::default().is_enabled());
+	}
+
+	#[test]
+	fn test_emulation_config_rejects_out_of_range_geolocation() {
+		let emulation = EmulationConfig {
+			geolocation: Some(Geolocation {
+				latitude: 91.0,
+				longitude: 4.89,
+				accuracy: None,
+			}),
+			..Default::default()
+		};
+		let err = emulation.validate().unwrap_err();
+		assert!(err.to_string().contains("latitude 91 is out of range"));
+	}
+
+	#[test]
+	fn test_emulation_config_rejects_unknown_timezone() {
+		let emulation = EmulationConfig {
+			timezone_id: Some("Mars/Olympus_Mons".to_string()),
+			..Default::default()
+		};
+		let err = emulation.validate().unwrap_err();
+		assert!(err.to_string().contains("unknown timezone ID 'Mars/Olympus_Mons'"));
+	}
+
 	#[test]
 	fn test_auth_file_tilde_expands_to_home() {
 		let home = ::dirs::home_dir().expect("home directory should be available for tests");
 		let expected = home.join("pw-auth.json");
 		let ctx = CommandContext::with_config(CommandContextConfig {
 			no_project: true,
-			auth_file: Some(PathBuf::from("~/pw-auth.json")),
+			auth_files: vec![PathBuf::from("~/pw-auth.json")],
 			..Default::default()
 		});
-		assert_eq!(ctx.auth_file(), Some(expected.as_path()));
+		assert_eq!(ctx.auth_files(), [expected]);
 	}
 
 	#[test]
@@ -488,9 +824,24 @@ mod tests {
 		let home = ::dirs::home_dir().expect("home directory should be available for tests");
 		let ctx = CommandContext::with_config(CommandContextConfig {
 			no_project: true,
-			auth_file: Some(PathBuf::from("~")),
+			auth_files: vec![PathBuf::from("~")],
 			..Default::default()
 		});
-		assert_eq!(ctx.auth_file(), Some(home.as_path()));
+		assert_eq!(ctx.auth_files(), [home]);
+	}
+
+	#[test]
+	fn test_har_path_tokens_expand() {
+		let har = HarConfig {
+			path: Some(PathBuf::from("hars/{host}-{date}-{timestamp}-{run}.har")),
+			..Default::default()
+		};
+		let now = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+		let expanded = har.with_expanded_path(Some("https://app.example.com/login"), now);
+		assert_eq!(expanded.path, Some(PathBuf::from("hars/app.example.com-2023-11-14-20231114T221320Z-{run}.har")));
+		assert_eq!(har.path, Some(PathBuf::from("hars/{host}-{date}-{timestamp}-{run}.har")));
+
+		let no_url = har.with_expanded_path(None, now);
+		assert!(no_url.path.unwrap().to_string_lossy().starts_with("hars/unknown-"));
 	}
 }
@@ -8,7 +8,7 @@ use std::path::{Path, PathBuf};
 
 use crate::context::CommandContext;
 use crate::error::{PwError, Result};
-use crate::types::BrowserKind;
+use crate::types::{BrowserKind, ProtectedUrl, is_protected_url};
 
 pub mod storage;
 pub mod types;
@@ -17,7 +17,7 @@ pub mod types;
 mod tests;
 
 pub use storage::LoadedState;
-pub use types::{CliCache, CliConfig, Defaults, HarDefaults};
+pub use types::{CliCache, CliConfig, Defaults, EmulationDefaults, HarDefaults};
 
 const SESSION_TIMEOUT_SECS: u64 = 3600;
 
@@ -101,6 +101,11 @@ impl ContextState {
 		&self.state.paths.workspace_root
 	}
 
+	/// Returns the versioned state root holding every profile of this workspace.
+	pub fn state_root(&self) -> &Path {
+		&self.state.paths.state_root
+	}
+
 	pub fn session_key(&self, browser: BrowserKind, headless: bool) -> String {
 		format!("{}:{}:{}", self.profile_id(), browser, if headless { "headless" } else { "headful" })
 	}
@@ -155,6 +160,14 @@ impl ContextState {
 		self.state.config.defaults.cdp_endpoint.as_deref()
 	}
 
+	/// Returns the text extraction cap from config defaults.
+	pub fn max_text_length(&self) -> Option<usize> {
+		if self.no_context {
+			return None;
+		}
+		self.state.config.defaults.max_text_length
+	}
+
 	/// Returns the last URL from cache.
 	pub fn last_url(&self) -> Option<&str> {
 		if self.no_context {
@@ -175,7 +188,7 @@ impl ContextState {
 	}
 
 	/// Returns protected URL patterns from config.
-	pub fn protected_urls(&self) -> &[String] {
+	pub fn protected_urls(&self) -> &[ProtectedUrl] {
 		if self.no_context {
 			return &[];
 		}
@@ -229,19 +242,119 @@ impl ContextState {
 		}
 	}
 
+	/// Returns the persisted download directory from config.
+	pub fn download_dir(&self) -> Option<&Path> {
+		if self.no_context {
+			return None;
+		}
+		self.state.config.downloads.dir.as_deref()
+	}
+
+	/// Sets the persisted download directory. Returns `true` when the value changed.
+	pub fn set_download_dir(&mut self, dir: PathBuf) -> bool {
+		if self.no_save || self.no_context {
+			return false;
+		}
+		let changed = self.state.config.downloads.dir.as_ref() != Some(&dir);
+		self.state.config.downloads.dir = Some(dir);
+		if changed {
+			self.dirty = true;
+		}
+		changed
+	}
+
+	/// Returns persisted request-blocking patterns from config.
+	pub fn block_patterns(&self) -> &[String] {
+		if self.no_context {
+			return &[];
+		}
+		&self.state.config.network.block_patterns
+	}
+
+	/// Adds a request-blocking pattern. Returns `true` if it was not already present.
+	pub fn add_block_pattern(&mut self, pattern: String) -> bool {
+		if self.no_save || self.no_context {
+			return false;
+		}
+		if self.state.config.network.block_patterns.contains(&pattern) {
+			return false;
+		}
+		self.state.config.network.block_patterns.push(pattern);
+		self.dirty = true;
+		true
+	}
+
+	/// Removes all request-blocking patterns. Returns the number removed.
+	pub fn clear_block_patterns(&mut self) -> usize {
+		if self.no_save || self.no_context {
+			return 0;
+		}
+		let removed = std::mem::take(&mut self.state.config.network.block_patterns).len();
+		if removed > 0 {
+			self.dirty = true;
+		}
+		removed
+	}
+
+	/// Returns persisted emulation defaults from config.
+	pub fn emulation_defaults(&self) -> Option<&EmulationDefaults> {
+		if self.no_context {
+			return None;
+		}
+		Some(&self.state.config.emulation)
+	}
+
+	/// Sets persisted emulation defaults. Returns `true` when the value changed.
+	pub fn set_emulation_defaults(&mut self, emulation: EmulationDefaults) -> bool {
+		if self.no_save || self.no_context {
+			return false;
+		}
+		let changed = self.state.config.emulation != emulation;
+		self.state.config.emulation = emulation;
+		if changed {
+			self.dirty = true;
+		}
+		changed
+	}
+
+	/// Clears persisted emulation defaults. Returns `true` when a value was removed.
+	pub fn clear_emulation_defaults(&mut self) -> bool {
+		if self.no_save || self.no_context {
+			return false;
+		}
+		let cleared = !std::mem::take(&mut self.state.config.emulation).is_empty();
+		if cleared {
+			self.dirty = true;
+		}
+		cleared
+	}
+
+	/// Builds effective runtime emulation config from persisted defaults.
+	pub fn effective_emulation_config(&self) -> crate::context::EmulationConfig {
+		let Some(emulation) = self.emulation_defaults() else {
+			return crate::context::EmulationConfig::default();
+		};
+		crate::context::EmulationConfig {
+			locale: emulation.locale.clone(),
+			timezone_id: emulation.timezone_id.clone(),
+			color_scheme: emulation.color_scheme,
+			reduced_motion: emulation.reduced_motion,
+			viewport: emulation.viewport.clone(),
+			..Default::default()
+		}
+	}
+
 	/// Returns true if the URL matches any protected pattern.
 	pub fn is_protected(&self, url: &str) -> bool {
-		let url_lower = url.to_lowercase();
-		self.protected_urls().iter().any(|pattern| url_lower.contains(&pattern.to_lowercase()))
+		is_protected_url(url, self.protected_urls())
 	}
 
 	/// Adds a URL pattern to the protected list. Returns true if added.
-	pub fn add_protected(&mut self, pattern: String) -> bool {
+	pub fn add_protected(&mut self, pattern: ProtectedUrl) -> bool {
 		if self.no_save || self.no_context {
 			return false;
 		}
-		let pattern_lower = pattern.to_lowercase();
-		if self.state.config.protected_urls.iter().any(|p| p.to_lowercase() == pattern_lower) {
+		if self.state.config.protected_urls.iter().any(|p| p.same_as(&pattern)) {
 			return false;
 		}
 		self.state.config.protected_urls.push(pattern);
@@ -250,13 +363,12 @@ impl ContextState {
 	}
 
 	/// Removes a URL pattern from the protected list. Returns true if removed.
-	pub fn remove_protected(&mut self, pattern: &str) -> bool {
+	pub fn remove_protected(&mut self, pattern: &ProtectedUrl) -> bool {
 		if self.no_save || self.no_context {
 			return false;
 		}
-		let pattern_lower = pattern.to_lowercase();
 		let before_len = self.state.config.protected_urls.len();
-		self.state.config.protected_urls.retain(|p| p.to_lowercase() != pattern_lower);
+		self.state.config.protected_urls.retain(|p| !p.same_as(pattern));
 		let removed = self.state.config.protected_urls.len() < before_len;
 		if removed {
 			self.dirty = true;
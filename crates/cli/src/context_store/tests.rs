@@ -1,10 +1,11 @@
 use std::path::PathBuf;
 
-use pw_rs::{HarContentPolicy, HarMode};
+use pw_rs::{HarContentPolicy, HarMode, Viewport};
 
 use super::ContextState;
 use super::storage::{LoadedState, StatePaths};
-use super::types::{CliCache, CliConfig, HarDefaults, SCHEMA_VERSION};
+use super::types::{CliCache, CliConfig, EmulationDefaults, HarDefaults, SCHEMA_VERSION};
+use crate::types::{ColorScheme, PatternKind, ProtectedUrl, ReducedMotion};
 
 fn test_state() -> LoadedState {
 	let root = PathBuf::from("/tmp/test-workspace");
@@ -36,6 +37,16 @@ fn cdp_endpoint_writes_to_config_defaults() {
 	assert_eq!(ctx_state.state().config.defaults.cdp_endpoint, Some("ws://new-endpoint".to_string()));
 }
 
+#[test]
+fn max_text_length_reads_from_config_defaults() {
+	let mut state = test_state();
+	state.config.defaults.max_text_length = Some(0);
+
+	let ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+
+	assert_eq!(ctx_state.max_text_length(), Some(0));
+}
+
 #[test]
 fn last_url_reads_from_cache() {
 	let mut state = test_state();
@@ -70,15 +81,58 @@ fn base_url_falls_back_to_config() {
 #[test]
 fn protected_urls_from_config() {
 	let mut state = test_state();
-	state.config.protected_urls = vec!["admin".to_string(), "settings".to_string()];
+	state.config.protected_urls = vec![ProtectedUrl::parse("admin"), ProtectedUrl::parse("settings")];
 
 	let ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
 
-	assert_eq!(ctx_state.protected_urls(), &["admin", "settings"]);
+	assert_eq!(ctx_state.protected_urls(), &[ProtectedUrl::parse("admin"), ProtectedUrl::parse("settings")]);
 	assert!(ctx_state.is_protected("https://example.com/admin/dashboard"));
 	assert!(!ctx_state.is_protected("https://example.com/public"));
 }
 
+#[test]
+fn protected_urls_migrate_from_plain_strings() {
+	let config: CliConfig = serde_json::from_str(r#"{"protectedUrls": ["admin", {"pattern": "^https://mail\\.", "kind": "regex"}]}"#).unwrap();
+	assert_eq!(
+		config.protected_urls,
+		vec![
+			ProtectedUrl::new("admin", PatternKind::Substring),
+			ProtectedUrl::new("^https://mail\\.", PatternKind::Regex)
+		]
+	);
+
+	let json = serde_json::to_value(&config).unwrap();
+	assert_eq!(json["protectedUrls"][0], serde_json::json!({ "pattern": "admin", "kind": "substring" }));
+}
+
+#[test]
+fn download_dir_round_trip() {
+	let state = test_state();
+	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+	assert_eq!(ctx_state.download_dir(), None);
+
+	assert!(ctx_state.set_download_dir(PathBuf::from("downloads")));
+	assert!(!ctx_state.set_download_dir(PathBuf::from("downloads")));
+	assert_eq!(ctx_state.download_dir(), Some(std::path::Path::new("downloads")));
+	assert_eq!(ctx_state.state().config.downloads.dir, Some(PathBuf::from("downloads")));
+}
+
+#[test]
+fn block_patterns_add_dedupes_and_clear_counts() {
+	let state = test_state();
+	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+
+	assert!(ctx_state.add_block_pattern("*.png".to_string()));
+	assert!(ctx_state.add_block_pattern("*googletagmanager*".to_string()));
+	assert!(!ctx_state.add_block_pattern("*.png".to_string()));
+	assert_eq!(ctx_state.block_patterns(), ["*.png", "*googletagmanager*"]);
+	assert_eq!(ctx_state.state().config.network.block_patterns.len(), 2);
+
+	assert_eq!(ctx_state.clear_block_patterns(), 2);
+	assert!(ctx_state.block_patterns().is_empty());
+	assert_eq!(ctx_state.clear_block_patterns(), 0);
+}
+
 #[test]
 fn har_defaults_round_trip() {
 	let state = test_state();
@@ -118,6 +172,55 @@ fn clear_har_defaults_removes_state() {
 	assert_eq!(ctx_state.effective_har_config().path, None);
 }
 
+#[test]
+fn emulation_defaults_round_trip() {
+	let state = test_state();
+	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+	let expected = EmulationDefaults {
+		locale: Some("fr-FR".to_string()),
+		timezone_id: Some("Europe/Paris".to_string()),
+		color_scheme: Some(ColorScheme::Dark),
+		reduced_motion: Some(ReducedMotion::Reduce),
+		viewport: Some(Viewport { width: 1280, height: 720 }),
+	};
+
+	assert!(ctx_state.set_emulation_defaults(expected.clone()));
+	assert!(!ctx_state.set_emulation_defaults(expected.clone()));
+	assert_eq!(ctx_state.emulation_defaults(), Some(&expected));
+
+	let effective = ctx_state.effective_emulation_config();
+	assert_eq!(effective.locale.as_deref(), Some("fr-FR"));
+	assert_eq!(effective.timezone_id.as_deref(), Some("Europe/Paris"));
+	assert_eq!(effective.color_scheme, Some(ColorScheme::Dark));
+	assert_eq!(effective.reduced_motion, Some(ReducedMotion::Reduce));
+	assert_eq!(effective.viewport, Some(Viewport { width: 1280, height: 720 }));
+	assert!(effective.is_enabled());
+
+	assert!(ctx_state.clear_emulation_defaults());
+	assert!(!ctx_state.effective_emulation_config().is_enabled());
+}
+
+#[test]
+fn cli_config_with_emulation_round_trips() {
+	let config = CliConfig {
+		schema: SCHEMA_VERSION,
+		emulation: EmulationDefaults {
+			color_scheme: Some(ColorScheme::NoPreference),
+			viewport: Some(Viewport { width: 390, height: 844 }),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	let json = serde_json::to_value(&config).unwrap();
+	assert_eq!(json["emulation"]["colorScheme"], "no-preference");
+	let decoded: CliConfig = serde_json::from_value(json).unwrap();
+	assert_eq!(decoded, config);
+
+	let empty = serde_json::to_value(CliConfig::new()).unwrap();
+	assert!(empty.get("emulation").is_none());
+}
+
 #[test]
 fn cli_config_with_har_round_trips() {
 	let config = CliConfig {
@@ -142,26 +245,29 @@ fn add_protected_url() {
 	let state = test_state();
 	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
 
-	assert!(ctx_state.add_protected("admin".to_string()));
-	assert!(ctx_state.protected_urls().contains(&"admin".to_string()));
+	assert!(ctx_state.add_protected(ProtectedUrl::parse("admin")));
+	assert!(ctx_state.protected_urls().contains(&ProtectedUrl::parse("admin")));
 
 	// Adding duplicate returns false
-	assert!(!ctx_state.add_protected("admin".to_string()));
+	assert!(!ctx_state.add_protected(ProtectedUrl::parse("ADMIN")));
+
+	// The same text as another kind is a different pattern
+	assert!(ctx_state.add_protected(ProtectedUrl::parse("re:admin")));
 }
 
 #[test]
 fn remove_protected_url() {
 	let mut state = test_state();
-	state.config.protected_urls = vec!["admin".to_string(), "settings".to_string()];
+	state.config.protected_urls = vec![ProtectedUrl::parse("admin"), ProtectedUrl::parse("settings")];
 
 	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
 
-	assert!(ctx_state.remove_protected("admin"));
-	assert!(!ctx_state.protected_urls().contains(&"admin".to_string()));
-	assert!(ctx_state.protected_urls().contains(&"settings".to_string()));
+	assert!(ctx_state.remove_protected(&ProtectedUrl::parse("admin")));
+	assert!(!ctx_state.protected_urls().contains(&ProtectedUrl::parse("admin")));
+	assert!(ctx_state.protected_urls().contains(&ProtectedUrl::parse("settings")));
 
 	// Removing non-existent returns false
-	assert!(!ctx_state.remove_protected("admin"));
+	assert!(!ctx_state.remove_protected(&ProtectedUrl::parse("admin")));
 }
 
 #[test]
@@ -233,7 +339,7 @@ fn no_context_mode_disables_everything() {
 	let mut state = test_state();
 	state.config.defaults.cdp_endpoint = Some("ws://test".to_string());
 	state.cache.last_url = Some("https://example.com".to_string());
-	state.config.protected_urls = vec!["admin".to_string()];
+	state.config.protected_urls = vec![ProtectedUrl::parse("admin")];
 
 	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
 	ctx_state.no_context = true;
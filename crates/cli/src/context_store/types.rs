@@ -2,10 +2,10 @@
 
 use std::path::PathBuf;
 
-use pw_rs::{HarContentPolicy, HarMode};
+use pw_rs::{HarContentPolicy, HarMode, IgnoreDefaultArgs, Viewport};
 use serde::{Deserialize, Serialize};
 
-use crate::types::BrowserKind;
+use crate::types::{BrowserKind, ColorScheme, ProtectedUrl, ReducedMotion};
 
 /// Schema version for config/cache files.
 pub const SCHEMA_VERSION: u32 = 4;
@@ -30,6 +30,9 @@ pub struct Defaults {
 	pub use_daemon: Option<bool>,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub launch_server: Option<bool>,
+	/// Text extraction cap for navigate/snapshot/page.text (0 = unbounded).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_text_length: Option<usize>,
 }
 
 /// Persisted network defaults scoped to a profile.
@@ -48,6 +51,49 @@ pub struct DownloadDefaults {
 	pub dir: Option<PathBuf>,
 }
 
+/// Persisted browser launch defaults scoped to a profile.
+///
+/// See [`crate::context::LaunchConfig`] for field semantics and accepted `channel` values.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchDefaults {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub slow_mo_ms: Option<f64>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub devtools: Option<bool>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub channel: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub ignore_default_args: Option<IgnoreDefaultArgs>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub executable_path: Option<PathBuf>,
+}
+
+/// Persisted browser context emulation scoped to a profile.
+///
+/// See [`crate::context::EmulationConfig`] for how these reach new contexts.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulationDefaults {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub locale: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub timezone_id: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub color_scheme: Option<ColorScheme>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reduced_motion: Option<ReducedMotion>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub viewport: Option<Viewport>,
+}
+
+impl EmulationDefaults {
+	/// Returns `true` if no emulation setting is persisted.
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
 /// Persisted HAR recording defaults scoped to a profile.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -75,8 +121,12 @@ pub struct CliConfig {
 	pub network: NetworkDefaults,
 	#[serde(default)]
 	pub downloads: DownloadDefaults,
+	#[serde(default)]
+	pub launch: LaunchDefaults,
+	#[serde(default, skip_serializing_if = "EmulationDefaults::is_empty")]
+	pub emulation: EmulationDefaults,
 	#[serde(default, skip_serializing_if = "Vec::is_empty")]
-	pub protected_urls: Vec<String>,
+	pub protected_urls: Vec<ProtectedUrl>,
 }
 
 impl CliConfig {
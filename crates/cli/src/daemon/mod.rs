@@ -7,6 +7,7 @@ use jsonrpsee::core::ClientError;
 use jsonrpsee::http_client::HttpClient;
 use rpc::DaemonRpcClient as _;
 pub use rpc::{BrowserInfo, BrowserLease};
+use serde::Serialize;
 pub use server::Daemon;
 use tracing::debug;
 
@@ -50,16 +51,40 @@ pub async fn try_connect() -> Option<DaemonClient> {
 
 /// Request a browser from the daemon with a deterministic session key.
 ///
-/// Browsers are reused only when session keys match exactly.
+/// Browsers are reused only when session keys match exactly. This process
+/// holds the browser until [`return_browser`] or until it exits.
 pub async fn request_browser(client: &DaemonClient, kind: BrowserKind, headless: bool, session_key: &str) -> Result<String> {
 	let lease = client
 		.client
-		.acquire_browser(kind, headless, session_key.to_string())
+		.acquire_browser(kind, headless, session_key.to_string(), std::process::id())
 		.await
 		.map_err(|err| anyhow!("daemon RPC acquire_browser failed: {err}"))?;
 	Ok(lease.cdp_endpoint)
 }
 
+/// Records this process as another holder of the browser leased under `session_key`.
+///
+/// The daemon drops the hold on [`return_browser`] or once this process exits.
+///
+/// Returns `false` when the daemon has no live browser for that key.
+pub async fn hold_browser(client: &DaemonClient, session_key: &str) -> Result<bool> {
+	client
+		.client
+		.hold_browser(session_key.to_string(), std::process::id())
+		.await
+		.map_err(|err| anyhow!("daemon RPC hold_browser failed: {err}"))
+}
+
+/// Returns one hold this process has on `session_key`'s browser.
+pub async fn return_browser(session_key: &str) -> Result<()> {
+	let client = client::connect_client()?;
+	match client.return_browser(session_key.to_string(), std::process::id()).await {
+		Ok(()) => Ok(()),
+		Err(err) if is_not_running(&err) => Ok(()),
+		Err(err) => Err(anyhow!("daemon RPC return_browser failed: {err}")),
+	}
+}
+
 pub async fn ping() -> Result<Option<bool>> {
 	let client = client::connect_probe_client()?;
 	match client.ping().await {
@@ -95,6 +120,66 @@ pub async fn list_browsers() -> Result<Option<Vec<BrowserInfo>>> {
 	}
 }
 
+/// A browser lease as reported by `daemon.status`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeaseStatus {
+	pub session_key: String,
+	pub browser: BrowserKind,
+	pub headless: bool,
+	pub cdp_endpoint: String,
+	pub holders: usize,
+	/// Seconds since the browser was last acquired or returned.
+	pub idle_secs: u64,
+}
+
+/// Lease rows for browsers still bound to a session key, longest idle first.
+pub fn lease_statuses(browsers: &[BrowserInfo], now: u64) -> Vec<LeaseStatus> {
+	let mut leases: Vec<LeaseStatus> = browsers
+		.iter()
+		.filter(|info| !info.session_key.is_empty())
+		.map(|info| LeaseStatus {
+			session_key: info.session_key.clone(),
+			browser: info.browser,
+			headless: info.headless,
+			cdp_endpoint: format!("http://127.0.0.1:{}", info.port),
+			holders: info.holder_pids.len(),
+			idle_secs: now.saturating_sub(info.last_used_at),
+		})
+		.collect();
+	leases.sort_by(|a, b| b.idle_secs.cmp(&a.idle_secs).then_with(|| a.session_key.cmp(&b.session_key)));
+	leases
+}
+
 fn is_not_running(err: &ClientError) -> bool {
 	client::is_not_running_error(err)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lease_statuses_skip_released_browsers() {
+		let info = |port, session_key: &str, last_used_at| BrowserInfo {
+			port,
+			browser: BrowserKind::Chromium,
+			headless: true,
+			created_at: 0,
+			session_key: session_key.to_string(),
+			last_used_at,
+			holder_pids: Vec::new(),
+		};
+		let browsers = [
+			info(9222, "a:chromium:headless", 990),
+			info(9223, "", 0),
+			info(9224, "b:chromium:headless", 400),
+		];
+
+		let leases = lease_statuses(&browsers, 1000);
+		assert_eq!(leases.len(), 2);
+		assert_eq!(leases[0].session_key, "b:chromium:headless");
+		assert_eq!(leases[0].idle_secs, 600);
+		assert_eq!(leases[0].cdp_endpoint, "http://127.0.0.1:9224");
+		assert_eq!(leases[1].idle_secs, 10);
+	}
+}
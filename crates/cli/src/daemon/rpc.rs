@@ -22,6 +22,10 @@ pub struct BrowserInfo {
 	/// Last time this browser was used (unix timestamp).
 	#[serde(default)]
 	pub last_used_at: u64,
+	/// Pids of commands currently attached to this browser through its lease,
+	/// one entry per hold.
+	#[serde(default)]
+	pub holder_pids: Vec<u32>,
 }
 
 #[rpc(client, server)]
@@ -30,7 +34,7 @@ pub trait DaemonRpc {
 	async fn ping(&self) -> RpcResult<bool>;
 
 	#[method(name = "daemon_acquire_browser")]
-	async fn acquire_browser(&self, browser: BrowserKind, headless: bool, session_key: String) -> RpcResult<BrowserLease>;
+	async fn acquire_browser(&self, browser: BrowserKind, headless: bool, session_key: String, holder_pid: u32) -> RpcResult<BrowserLease>;
 
 	#[method(name = "daemon_spawn_browser")]
 	async fn spawn_browser(&self, browser: BrowserKind, headless: bool, port: Option<u16>) -> RpcResult<BrowserLease>;
@@ -41,6 +45,12 @@ pub trait DaemonRpc {
 	#[method(name = "daemon_kill_browser")]
 	async fn kill_browser(&self, port: u16) -> RpcResult<()>;
 
+	#[method(name = "daemon_hold_browser")]
+	async fn hold_browser(&self, session_key: String, holder_pid: u32) -> RpcResult<bool>;
+
+	#[method(name = "daemon_return_browser")]
+	async fn return_browser(&self, session_key: String, holder_pid: u32) -> RpcResult<()>;
+
 	#[method(name = "daemon_release_browser")]
 	async fn release_browser(&self, session_key: String) -> RpcResult<()>;
 
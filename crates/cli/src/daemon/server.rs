@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
 use jsonrpsee::core::{RpcResult, async_trait};
 use jsonrpsee::server::ServerBuilder;
 use jsonrpsee::types::error::ErrorObjectOwned;
 use pw_rs::{LaunchOptions, Playwright};
-use pw_runtime::port_available;
+use pw_runtime::{pid_is_alive, port_available};
 use serde_json::json;
 use tokio::sync::{Mutex, oneshot, watch};
 use tracing::{debug, info, warn};
@@ -18,6 +19,9 @@ use crate::types::BrowserKind;
 const PORT_RANGE_START: u16 = 9222;
 const PORT_RANGE_END: u16 = 10221;
 
+/// Longest wait between idle-browser sweeps.
+const REAP_INTERVAL_MAX: Duration = Duration::from_secs(30);
+
 const RPC_ACQUIRE_FAILED: i32 = -32050;
 const RPC_SPAWN_FAILED: i32 = -32051;
 const RPC_KILL_FAILED: i32 = -32052;
@@ -47,10 +51,10 @@ impl DaemonRpcServer for DaemonRpcHandler {
 		Ok(true)
 	}
 
-	async fn acquire_browser(&self, browser: BrowserKind, headless: bool, session_key: String) -> RpcResult<BrowserLease> {
+	async fn acquire_browser(&self, browser: BrowserKind, headless: bool, session_key: String, holder_pid: u32) -> RpcResult<BrowserLease> {
 		let mut daemon = self.state.lock().await;
 		daemon
-			.acquire_browser(browser, headless, session_key)
+			.acquire_browser(browser, headless, session_key, holder_pid)
 			.await
 			.map(|(port, cdp_endpoint)| BrowserLease { cdp_endpoint, port })
 			.map_err(|err| rpc_error("acquire_failed", RPC_ACQUIRE_FAILED, err))
@@ -83,6 +87,17 @@ impl DaemonRpcServer for DaemonRpcHandler {
 		daemon.kill_browser(port).await.map_err(|err| rpc_error("kill_failed", RPC_KILL_FAILED, err))
 	}
 
+	async fn hold_browser(&self, session_key: String, holder_pid: u32) -> RpcResult<bool> {
+		let mut daemon = self.state.lock().await;
+		Ok(daemon.hold_browser(&session_key, holder_pid))
+	}
+
+	async fn return_browser(&self, session_key: String, holder_pid: u32) -> RpcResult<()> {
+		let mut daemon = self.state.lock().await;
+		daemon.return_browser(&session_key, holder_pid);
+		Ok(())
+	}
+
 	async fn release_browser(&self, session_key: String) -> RpcResult<()> {
 		let mut daemon = self.state.lock().await;
 		daemon.release_browser(&session_key);
@@ -90,7 +105,8 @@ impl DaemonRpcServer for DaemonRpcHandler {
 	}
 
 	async fn list_browsers(&self) -> RpcResult<Vec<BrowserInfo>> {
-		let daemon = self.state.lock().await;
+		let mut daemon = self.state.lock().await;
+		daemon.drop_exited_holders();
 		Ok(daemon.browsers.values().map(|instance| instance.info.clone()).collect())
 	}
 
@@ -106,6 +122,7 @@ pub struct Daemon {
 	state: Arc<Mutex<DaemonState>>,
 	shutdown_tx: watch::Sender<bool>,
 	shutdown_rx: watch::Receiver<bool>,
+	idle_timeout: Option<Duration>,
 }
 
 impl Daemon {
@@ -121,9 +138,16 @@ impl Daemon {
 			state: Arc::new(Mutex::new(state)),
 			shutdown_tx,
 			shutdown_rx,
+			idle_timeout: None,
 		})
 	}
 
+	/// Closes browsers nobody holds once they have been unused for `idle_timeout`.
+	pub fn with_idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+		self.idle_timeout = idle_timeout;
+		self
+	}
+
 	pub async fn run(self) -> Result<()> {
 		self.run_with_ready(None).await
 	}
@@ -145,6 +169,9 @@ impl Daemon {
 			let _ = tx.send(());
 		}
 
+		let idle_timeout = self.idle_timeout;
+		let mut reaper = tokio::time::interval(idle_timeout.map_or(REAP_INTERVAL_MAX, reap_interval));
+
 		#[cfg(unix)]
 		{
 			use tokio::signal::unix::{SignalKind, signal};
@@ -160,6 +187,9 @@ impl Daemon {
 							break;
 						}
 					}
+					_ = reaper.tick(), if idle_timeout.is_some() => {
+						reap_idle_browsers(&self.state, idle_timeout).await;
+					}
 					_ = sigterm.recv() => {
 						info!(target = "pw.daemon", "received SIGTERM, shutting down");
 						shutdown_daemon_state(&self.state).await;
@@ -186,6 +216,9 @@ impl Daemon {
 							break;
 						}
 					}
+					_ = reaper.tick(), if idle_timeout.is_some() => {
+						reap_idle_browsers(&self.state, idle_timeout).await;
+					}
 					_ = tokio::signal::ctrl_c() => {
 						info!(target = "pw.daemon", "received Ctrl+C, shutting down");
 						shutdown_daemon_state(&self.state).await;
@@ -204,14 +237,14 @@ impl Daemon {
 
 impl DaemonState {
 	/// Acquire a browser, reusing an existing one if session_key matches.
-	async fn acquire_browser(&mut self, browser_kind: BrowserKind, headless: bool, session_key: String) -> Result<(u16, String)> {
+	async fn acquire_browser(&mut self, browser_kind: BrowserKind, headless: bool, session_key: String, holder_pid: u32) -> Result<(u16, String)> {
 		// Check for existing browser with matching session_key.
 		if let Some(&port) = self.session_index.get(&session_key) {
 			if let Some(instance) = self.browsers.get_mut(&port) {
 				// Verify browser is still connected.
 				if instance.browser.is_connected() {
 					debug!(target = "pw.daemon", port, session_key = %session_key, "reusing existing browser");
-					instance.info.last_used_at = now_ts();
+					instance.info.hold(holder_pid, now_ts());
 					let cdp_endpoint = format!("http://127.0.0.1:{}", port);
 					return Ok((port, cdp_endpoint));
 				}
@@ -224,7 +257,41 @@ impl DaemonState {
 		}
 
 		// No existing browser found, spawn a new one.
-		self.spawn_browser(browser_kind, headless, None, session_key).await
+		let (port, cdp_endpoint) = self.spawn_browser(browser_kind, headless, None, session_key).await?;
+		if let Some(instance) = self.browsers.get_mut(&port) {
+			instance.info.hold(holder_pid, now_ts());
+		}
+		Ok((port, cdp_endpoint))
+	}
+
+	/// Counts another holder on the browser bound to `session_key`, returning whether it is still live.
+	fn hold_browser(&mut self, session_key: &str, holder_pid: u32) -> bool {
+		let Some(instance) = self.session_index.get(session_key).and_then(|port| self.browsers.get_mut(port)) else {
+			return false;
+		};
+		if !instance.browser.is_connected() {
+			return false;
+		}
+		instance.info.hold(holder_pid, now_ts());
+		true
+	}
+
+	/// Drops one hold by `holder_pid` on the browser bound to `session_key`, restarting its idle clock.
+	fn return_browser(&mut self, session_key: &str, holder_pid: u32) {
+		if let Some(instance) = self.session_index.get(session_key).and_then(|port| self.browsers.get_mut(port)) {
+			instance.info.release(holder_pid, now_ts());
+		}
+	}
+
+	/// Drops holds whose process has exited without returning them, e.g. after a crash or kill.
+	fn drop_exited_holders(&mut self) {
+		let now = now_ts();
+		for instance in self.browsers.values_mut() {
+			let dropped = instance.info.drop_holders_where(|pid| !pid_is_alive(pid), now);
+			if dropped > 0 {
+				debug!(target = "pw.daemon", port = instance.info.port, dropped, "dropped holds of exited processes");
+			}
+		}
 	}
 
 	/// Spawn a new browser bound to `session_key`.
@@ -273,6 +340,7 @@ impl DaemonState {
 			created_at: now,
 			session_key: session_key.clone(),
 			last_used_at: now,
+			holder_pids: Vec::new(),
 		};
 
 		self.browsers.insert(port, BrowserInstance { info: info.clone(), browser });
@@ -316,6 +384,19 @@ impl DaemonState {
 		Ok(())
 	}
 
+	/// Closes browsers that nobody holds and that went unused for `idle_timeout`.
+	async fn reap_idle(&mut self, idle_timeout: Duration) {
+		self.drop_exited_holders();
+		let ports = idle_browsers(self.browsers.values().map(|instance| &instance.info), idle_timeout, now_ts());
+		for port in ports {
+			info!(target = "pw.daemon", port, "closing idle browser");
+			if let Err(err) = self.kill_browser(port).await {
+				warn!(target = "pw.daemon", port, error = %err, "failed to close idle browser");
+				self.browsers.remove(&port);
+			}
+		}
+	}
+
 	fn find_available_port(&self) -> Option<u16> {
 		(PORT_RANGE_START..=PORT_RANGE_END).find(|port| !self.browsers.contains_key(port) && port_available(*port))
 	}
@@ -328,6 +409,52 @@ async fn shutdown_daemon_state(state: &Arc<Mutex<DaemonState>>) {
 	}
 }
 
+async fn reap_idle_browsers(state: &Arc<Mutex<DaemonState>>, idle_timeout: Option<Duration>) {
+	if let Some(idle_timeout) = idle_timeout {
+		state.lock().await.reap_idle(idle_timeout).await;
+	}
+}
+
+/// How often to look for idle browsers: half the timeout, between 1 and 30 seconds.
+fn reap_interval(idle_timeout: Duration) -> Duration {
+	(idle_timeout / 2).clamp(Duration::from_secs(1), REAP_INTERVAL_MAX)
+}
+
+/// Ports of browsers without holders whose last use is at least `idle_timeout` before `now`.
+fn idle_browsers<'a>(browsers: impl Iterator<Item = &'a BrowserInfo>, idle_timeout: Duration, now: u64) -> Vec<u16> {
+	browsers
+		.filter(|info| info.holder_pids.is_empty() && now.saturating_sub(info.last_used_at) >= idle_timeout.as_secs())
+		.map(|info| info.port)
+		.collect()
+}
+
+impl BrowserInfo {
+	/// Records a hold by `pid`.
+	fn hold(&mut self, pid: u32, now: u64) {
+		self.holder_pids.push(pid);
+		self.last_used_at = now;
+	}
+
+	/// Removes one hold by `pid`, if any, and restarts the idle clock.
+	fn release(&mut self, pid: u32, now: u64) {
+		if let Some(index) = self.holder_pids.iter().position(|&held| held == pid) {
+			self.holder_pids.swap_remove(index);
+		}
+		self.last_used_at = now;
+	}
+
+	/// Removes holds whose pid matches `exited`, restarting the idle clock if any were removed.
+	fn drop_holders_where(&mut self, exited: impl Fn(u32) -> bool, now: u64) -> usize {
+		let before = self.holder_pids.len();
+		self.holder_pids.retain(|&pid| !exited(pid));
+		let dropped = before - self.holder_pids.len();
+		if dropped > 0 {
+			self.last_used_at = now;
+		}
+		dropped
+	}
+}
+
 fn rpc_error(code: &str, rpc_code: i32, err: anyhow::Error) -> ErrorObjectOwned {
 	ErrorObjectOwned::owned(rpc_code, err.to_string(), Some(json!({ "code": code })))
 }
@@ -335,3 +462,67 @@ fn rpc_error(code: &str, rpc_code: i32, err: anyhow::Error) -> ErrorObjectOwned
 fn now_ts() -> u64 {
 	std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn info(port: u16, holder_pids: &[u32], last_used_at: u64) -> BrowserInfo {
+		BrowserInfo {
+			port,
+			browser: BrowserKind::Chromium,
+			headless: true,
+			created_at: 0,
+			session_key: format!("ns:chromium:headless:{port}"),
+			last_used_at,
+			holder_pids: holder_pids.to_vec(),
+		}
+	}
+
+	#[test]
+	fn idle_browsers_skips_held_and_recent() {
+		let browsers = [info(9222, &[], 100), info(9223, &[42], 100), info(9224, &[], 950)];
+		let idle = idle_browsers(browsers.iter(), Duration::from_secs(600), 1000);
+		assert_eq!(idle, vec![9222]);
+	}
+
+	#[test]
+	fn hold_and_release_track_each_holder() {
+		let mut browser = info(9222, &[], 100);
+		browser.hold(10, 200);
+		browser.hold(10, 300);
+		browser.hold(11, 400);
+		assert_eq!(browser.holder_pids.len(), 3);
+
+		browser.release(10, 500);
+		browser.release(99, 600);
+		let mut pids = browser.holder_pids.clone();
+		pids.sort_unstable();
+		assert_eq!(pids, vec![10, 11]);
+		assert_eq!(browser.last_used_at, 600);
+	}
+
+	#[test]
+	fn exited_holders_stop_pinning_the_browser() {
+		let mut browser = info(9222, &[], 100);
+		browser.hold(10, 100);
+		browser.hold(11, 100);
+		assert!(idle_browsers([&browser].into_iter(), Duration::from_secs(60), 1000).is_empty());
+
+		assert_eq!(browser.drop_holders_where(|pid| pid == 10, 500), 1);
+		assert_eq!(browser.holder_pids, vec![11]);
+		assert_eq!(browser.drop_holders_where(|pid| pid == 11, 900), 1);
+		assert_eq!(browser.drop_holders_where(|_| true, 950), 0);
+		assert_eq!(browser.last_used_at, 900);
+
+		assert!(idle_browsers([&browser].into_iter(), Duration::from_secs(60), 959).is_empty());
+		assert_eq!(idle_browsers([&browser].into_iter(), Duration::from_secs(60), 960), vec![9222]);
+	}
+
+	#[test]
+	fn reap_interval_is_clamped() {
+		assert_eq!(reap_interval(Duration::from_secs(1)), Duration::from_secs(1));
+		assert_eq!(reap_interval(Duration::from_secs(20)), Duration::from_secs(10));
+		assert_eq!(reap_interval(Duration::from_secs(3600)), Duration::from_secs(30));
+	}
+}
@@ -0,0 +1,114 @@
+//! Driver request timings for `--debug-stats`.
+//!
+//! Sessions launch Playwright with stats collection when the flag is set and
+//! fold their connection's counters in here on shutdown. The totals are
+//! printed to stderr once the command finishes.
+
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use pw_rs::Playwright;
+use pw_runtime::{ConnectionConfig, ConnectionStats, LATENCY_BUCKETS_MS};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TOTALS: Mutex<Option<ConnectionStats>> = Mutex::new(None);
+
+/// Turns on stats collection for Playwright connections launched after this call.
+pub fn enable() {
+	ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Connection options for launching Playwright, collecting stats if enabled.
+pub fn connection_config() -> ConnectionConfig {
+	ConnectionConfig {
+		collect_stats: ENABLED.load(Ordering::Relaxed),
+		..ConnectionConfig::default()
+	}
+}
+
+/// Adds `playwright`'s connection stats to the running totals.
+pub fn record(playwright: &Playwright) {
+	let Some(stats) = playwright.connection_stats() else {
+		return;
+	};
+	let mut totals = TOTALS.lock().unwrap_or_else(|e| e.into_inner());
+	totals.get_or_insert_with(ConnectionStats::default).merge(&stats);
+}
+
+/// Prints the totals to stderr and resets them, if any were recorded.
+pub fn print_report() {
+	let totals = TOTALS.lock().unwrap_or_else(|e| e.into_inner()).take();
+	if let Some(totals) = totals {
+		eprint!("{}", format_report(&totals));
+	}
+}
+
+fn format_report(stats: &ConnectionStats) -> String {
+	let mut report = format!("driver requests: {} completed, {} in flight\n", stats.requests(), stats.in_flight);
+	let width = stats.methods.iter().map(|m| m.method.len()).max().unwrap_or(0).max("method".len());
+	let buckets = bucket_labels();
+	let _ = write!(report, "  {:<width$}  {:>5}  {:>9}  {:>9}  {:>9}", "method", "count", "total", "mean", "max");
+	for label in &buckets {
+		let _ = write!(report, "  {label}");
+	}
+	report.push('\n');
+	for method in &stats.methods {
+		let _ = write!(
+			report,
+			"  {:<width$}  {:>5}  {:>9}  {:>9}  {:>9}",
+			method.method,
+			method.count,
+			millis(method.total),
+			millis(method.mean()),
+			millis(method.max),
+		);
+		for (label, count) in buckets.iter().zip(method.histogram) {
+			let _ = write!(report, "  {count:>0$}", label.len());
+		}
+		report.push('\n');
+	}
+	report
+}
+
+/// Column headers for the latency histogram: one per bucket bound, then the overflow bucket.
+fn bucket_labels() -> Vec<String> {
+	let overflow = LATENCY_BUCKETS_MS.last().map(|bound| format!(">{bound}ms"));
+	LATENCY_BUCKETS_MS.iter().map(|bound| format!("<={bound}ms")).chain(overflow).collect()
+}
+
+fn millis(duration: Duration) -> String {
+	format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+	use pw_runtime::MethodStats;
+
+	use super::*;
+
+	#[test]
+	fn report_lists_methods_with_timings_and_histogram() {
+		let stats = ConnectionStats {
+			in_flight: 0,
+			methods: vec![MethodStats {
+				method: "goto".into(),
+				count: 2,
+				total: Duration::from_millis(300),
+				max: Duration::from_millis(250),
+				histogram: [0, 0, 1, 0, 1, 0, 0],
+			}],
+		};
+		let report = format_report(&stats);
+		assert!(report.starts_with("driver requests: 2 completed, 0 in flight\n"), "{report}");
+		assert!(
+			report.contains("method  count      total       mean        max  <=1ms  <=10ms  <=50ms  <=100ms  <=500ms  <=1000ms  >1000ms\n"),
+			"{report}"
+		);
+		assert!(
+			report.contains("  goto        2    300.0ms    150.0ms    250.0ms      0       0       1        0        1         0        0\n"),
+			"{report}"
+		);
+	}
+}
@@ -0,0 +1,99 @@
+//! Device emulation presets for `--device`.
+//!
+//! Each preset mirrors the matching entry in Playwright's device descriptors:
+//! viewport, user agent, device scale factor, and mobile/touch support.
+
+use pw_rs::Viewport;
+
+/// Browser context settings that emulate a physical device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceDescriptor {
+	/// Preset name accepted by `--device`.
+	pub name: &'static str,
+	pub user_agent: &'static str,
+	pub width: u32,
+	pub height: u32,
+	pub device_scale_factor: f64,
+	pub is_mobile: bool,
+	pub has_touch: bool,
+}
+
+impl DeviceDescriptor {
+	pub fn viewport(&self) -> Viewport {
+		Viewport {
+			width: self.width,
+			height: self.height,
+		}
+	}
+}
+
+/// Known presets, in the order shown in help.
+pub const DEVICES: &[DeviceDescriptor] = &[
+	DeviceDescriptor {
+		name: "iPhone 13",
+		user_agent: "Mozilla/5.0 (iPhone; CPU iPhone OS 15_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.0 Mobile/15E148 Safari/604.1",
+		width: 390,
+		height: 664,
+		device_scale_factor: 3.0,
+		is_mobile: true,
+		has_touch: true,
+	},
+	DeviceDescriptor {
+		name: "iPhone SE",
+		user_agent: "Mozilla/5.0 (iPhone; CPU iPhone OS 14_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/14.1.1 Mobile/15E148 Safari/604.1",
+		width: 320,
+		height: 568,
+		device_scale_factor: 2.0,
+		is_mobile: true,
+		has_touch: true,
+	},
+	DeviceDescriptor {
+		name: "Pixel 5",
+		user_agent: "Mozilla/5.0 (Linux; Android 11; Pixel 5) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Mobile Safari/537.36",
+		width: 393,
+		height: 727,
+		device_scale_factor: 2.75,
+		is_mobile: true,
+		has_touch: true,
+	},
+	DeviceDescriptor {
+		name: "iPad",
+		user_agent: "Mozilla/5.0 (iPad; CPU OS 12_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/12.0 Mobile/15E148 Safari/604.1",
+		width: 810,
+		height: 1080,
+		device_scale_factor: 2.0,
+		is_mobile: true,
+		has_touch: true,
+	},
+];
+
+/// Finds a preset by name, ignoring ASCII case.
+pub fn lookup(name: &str) -> Option<DeviceDescriptor> {
+	DEVICES.iter().find(|device| device.name.eq_ignore_ascii_case(name.trim())).copied()
+}
+
+/// Preset names, for help text and error messages.
+pub fn names() -> Vec<&'static str> {
+	DEVICES.iter().map(|device| device.name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lookup_ignores_case() {
+		let device = lookup("pixel 5").unwrap();
+		assert_eq!(device.name, "Pixel 5");
+		assert_eq!(device.viewport(), Viewport { width: 393, height: 727 });
+		assert!(lookup("Nokia 3310").is_none());
+	}
+
+	#[test]
+	fn preset_names_are_unique() {
+		let names = names();
+		for name in &names {
+			assert_eq!(names.iter().filter(|other| other.eq_ignore_ascii_case(name)).count(), 1, "{name}");
+		}
+	}
+}
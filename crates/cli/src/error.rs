@@ -31,6 +31,9 @@ pub enum PwError {
 	#[error("element not found: {selector}")]
 	ElementNotFound { selector: String },
 
+	#[error("selector {selector} matched {count} elements, expected exactly 1")]
+	StrictMismatch { selector: String, count: usize },
+
 	#[error("javascript evaluation failed: {0}")]
 	JsEval(String),
 
@@ -124,7 +127,34 @@ fn clean_strict_mode_error(msg: &str) -> Option<String> {
 	))
 }
 
+/// Timeouts and connection losses reported by Playwright.
+fn is_transient(err: &pw_rs::Error) -> bool {
+	match err {
+		pw_rs::Error::Timeout(_) | pw_rs::Error::NavigationTimeout { .. } => true,
+		pw_rs::Error::ChannelClosed | pw_rs::Error::ConnectionFailed(_) | pw_rs::Error::TransportError(_) => true,
+		pw_rs::Error::Remote { name, .. } if name == "TimeoutError" => true,
+		_ => err.is_target_closed(),
+	}
+}
+
 impl PwError {
+	/// Returns true if the browser, context, or page went away mid-command.
+	pub fn is_target_closed(&self) -> bool {
+		matches!(self, PwError::Playwright(err) if err.is_target_closed())
+	}
+
+	/// Returns true for transient failures worth retrying: timeouts and a lost
+	/// browser connection. Invalid input and unsupported modes never are.
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			PwError::Timeout { .. } => true,
+			PwError::FailureWithArtifacts { failure, .. } => failure.error.code == ErrorCode::Timeout,
+			PwError::Navigation { source, .. } => source.downcast_ref::<pw_rs::Error>().is_some_and(is_transient),
+			PwError::Playwright(err) => is_transient(err),
+			_ => false,
+		}
+	}
+
 	pub fn failure_with_artifacts(&self) -> Option<&crate::output::FailureWithArtifacts> {
 		match self {
 			PwError::FailureWithArtifacts { failure, .. } => Some(failure),
@@ -153,6 +183,13 @@ impl PwError {
 				format!("No elements matched selector: {selector}"),
 				Some(serde_json::json!({ "selector": selector })),
 			),
+			PwError::StrictMismatch { selector, count } => (
+				ErrorCode::SelectorNotFound,
+				format!(
+					"Selector {selector} matched {count} elements (strict mode requires exactly 1). Set strict to false to wait for or act on the first match."
+				),
+				Some(serde_json::json!({ "selector": selector, "count": count })),
+			),
 			PwError::JsEval(msg) => (ErrorCode::JsEvalFailed, msg.clone(), None),
 			PwError::Screenshot { path, source } => (
 				ErrorCode::ScreenshotFailed,
@@ -168,6 +205,11 @@ impl PwError {
 			PwError::UnsupportedMode(msg) => (ErrorCode::UnsupportedMode, msg.clone(), None),
 			PwError::Io(err) => (ErrorCode::IoError, err.to_string(), None),
 			PwError::Json(err) => (ErrorCode::InternalError, format!("JSON error: {err}"), None),
+			PwError::Playwright(pw_rs::Error::IndexOutOfRange { selector, index, count }) => (
+				ErrorCode::SelectorNotFound,
+				format!("index {index} out of range (count {count}) for selector '{selector}'"),
+				Some(serde_json::json!({ "selector": selector, "index": index, "count": count })),
+			),
 			PwError::Playwright(err) => {
 				let msg = err.to_string();
 				let (code, clean_msg) = classify_and_clean_playwright_error(&msg);
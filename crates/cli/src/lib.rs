@@ -6,6 +6,8 @@ pub mod commands;
 pub mod context;
 pub mod context_store;
 pub mod daemon;
+pub mod debug_stats;
+pub mod devices;
 pub mod error;
 pub mod logging;
 pub mod output;
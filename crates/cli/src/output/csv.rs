@@ -0,0 +1,108 @@
+//! CSV rendering of result data for spreadsheet import.
+//!
+//! Tabular data (an array of objects, or an object holding exactly one such
+//! array, like `page.elements` and `tabs.list`) becomes one row per entry with
+//! a header of field names. Anything else falls back to `key,value` rows.
+
+use serde_json::Value;
+
+/// Renders result data as CSV, including the trailing newline.
+pub fn data_to_csv(data: &Value) -> String {
+	match table_rows(data) {
+		Some(rows) => rows_to_csv(rows),
+		None => key_values_to_csv(data),
+	}
+}
+
+/// Renders a failed result's error code and message as `key,value` rows.
+pub fn error_to_csv(code: &str, message: &str) -> String {
+	let mut out = String::from("key,value\n");
+	push_row(&mut out, ["code", code].into_iter().map(String::from));
+	push_row(&mut out, ["message".to_string(), defuse_formula(message)].into_iter());
+	out
+}
+
+/// Finds the rows of tabular data, if `data` has exactly one array of objects.
+fn table_rows(data: &Value) -> Option<&[Value]> {
+	let is_table = |items: &[Value]| !items.is_empty() && items.iter().all(Value::is_object);
+	match data {
+		Value::Array(items) if is_table(items) => Some(items),
+		Value::Object(fields) => {
+			let mut tables = fields.values().filter_map(|value| value.as_array().filter(|items| is_table(items)));
+			match (tables.next(), tables.next()) {
+				(Some(rows), None) => Some(rows),
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+/// One row per object, with columns in the order their keys first appear.
+fn rows_to_csv(rows: &[Value]) -> String {
+	let mut columns: Vec<&str> = Vec::new();
+	for row in rows.iter().filter_map(Value::as_object) {
+		for key in row.keys() {
+			if !columns.contains(&key.as_str()) {
+				columns.push(key);
+			}
+		}
+	}
+
+	let mut out = String::new();
+	push_row(&mut out, columns.iter().map(|column| column.to_string()));
+	for row in rows {
+		push_row(&mut out, columns.iter().map(|column| cell(row.get(*column).unwrap_or(&Value::Null))));
+	}
+	out
+}
+
+fn key_values_to_csv(data: &Value) -> String {
+	let mut out = String::from("key,value\n");
+	match data {
+		Value::Object(fields) => {
+			for (key, value) in fields {
+				push_row(&mut out, [key.clone(), cell(value)].into_iter());
+			}
+		}
+		Value::Null => {}
+		other => push_row(&mut out, ["value".to_string(), cell(other)].into_iter()),
+	}
+	out
+}
+
+/// Cell text: strings as-is (see [`defuse_formula`]), nulls empty, nested values as compact JSON.
+fn cell(value: &Value) -> String {
+	match value {
+		Value::Null => String::new(),
+		Value::String(s) => defuse_formula(s),
+		other => other.to_string(),
+	}
+}
+
+/// Prefixes text a spreadsheet would evaluate as a formula with `'`.
+///
+/// Page text ends up in these cells, so `=HYPERLINK(...)` scraped from a page
+/// must not run when the CSV is opened.
+fn defuse_formula(text: &str) -> String {
+	if text.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+		format!("'{text}")
+	} else {
+		text.to_string()
+	}
+}
+
+fn push_row(out: &mut String, cells: impl Iterator<Item = String>) {
+	let line: Vec<String> = cells.map(|cell| escape(&cell)).collect();
+	out.push_str(&line.join(","));
+	out.push('\n');
+}
+
+/// Quotes a field containing a comma, quote, or line break, doubling inner quotes (RFC 4180).
+fn escape(field: &str) -> String {
+	if field.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_string()
+	}
+}
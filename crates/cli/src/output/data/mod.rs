@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -26,6 +27,23 @@ pub struct ClickData {
 	pub selector: String,
 	#[serde(skip_serializing_if = "Vec::is_empty", default)]
 	pub downloads: Vec<DownloadedFile>,
+	/// Number of elements clicked when every match was targeted.
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub clicked: Option<usize>,
+	/// Page opened by the click when popup capture was requested; `Some(None)`
+	/// (serialized as `null`) when no popup opened within the wait.
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub popup: Option<Option<PopupPage>>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub warnings: Vec<String>,
+}
+
+/// A popup page opened by a click.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopupPage {
+	pub url: String,
+	pub title: String,
 }
 
 /// Information about a downloaded file.
@@ -37,6 +55,16 @@ pub struct DownloadedFile {
 	pub path: PathBuf,
 }
 
+impl From<crate::browser::DownloadInfo> for DownloadedFile {
+	fn from(info: crate::browser::DownloadInfo) -> Self {
+		Self {
+			url: info.url,
+			suggested_filename: info.suggested_filename,
+			path: info.path,
+		}
+	}
+}
+
 /// Result data for screenshot command.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -47,6 +75,18 @@ pub struct ScreenshotData {
 	pub width: Option<u32>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub height: Option<u32>,
+	/// Page region captured by `clip`, in CSS pixels.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub clip: Option<ClipRegion>,
+}
+
+/// Rectangle passed to the screenshot `clip` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClipRegion {
+	pub x: u32,
+	pub y: u32,
+	pub width: u32,
+	pub height: u32,
 }
 
 /// Result data for text command.
@@ -56,6 +96,79 @@ pub struct TextData {
 	pub text: String,
 	pub selector: String,
 	pub match_count: usize,
+	/// Length in characters of the full extracted text before slicing.
+	#[serde(default)]
+	pub total_length: usize,
+	/// Offset of the next slice, absent when this slice reaches the end.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub next_offset: Option<usize>,
+	/// URLs of cross-origin frames skipped by `includeIframes`.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub skipped_frames: Vec<String>,
+	/// Per-element text in document order, set by `all` in place of `text`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub texts: Option<Vec<String>>,
+}
+
+/// Result data for diff-text command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffTextData {
+	pub url_a: String,
+	pub url_b: String,
+	pub selector: String,
+	pub identical: bool,
+	pub added: usize,
+	pub removed: usize,
+	/// Changed lines, numbered from 1 within their own side.
+	pub changes: Vec<TextLineChange>,
+	/// Unified diff with three lines of context (empty when identical).
+	pub unified: String,
+}
+
+/// Single added or removed line in a [`DiffTextData`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextLineChange {
+	pub kind: LineChangeKind,
+	pub line: usize,
+	pub text: String,
+}
+
+/// Side of a [`TextLineChange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineChangeKind {
+	Added,
+	Removed,
+}
+
+/// Result data for page.hover command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoverData {
+	pub url: String,
+	pub selector: String,
+}
+
+/// Result data for page.select command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectData {
+	pub url: String,
+	pub selector: String,
+	/// Values of the options selected afterwards, as reported by Playwright.
+	pub values: Vec<String>,
+}
+
+/// Result data for page.upload command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadData {
+	pub url: String,
+	pub selector: String,
+	/// Names of the uploaded files, as the page sees them.
+	pub files: Vec<String>,
 }
 
 /// Result data for fill command.
@@ -63,7 +176,78 @@ pub struct TextData {
 #[serde(rename_all = "camelCase")]
 pub struct FillData {
 	pub selector: String,
-	pub text: String,
+	/// Characters filled; the text itself is not echoed since it may be a password.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub text_length: Option<usize>,
+	/// State a checkbox or radio button was set to, when filling with `checked`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub checked: Option<bool>,
+	/// Where the page ended up, when the fill also submitted the form.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub submit: Option<SubmitData>,
+}
+
+/// Outcome of submitting a form after `fill` or `form-fill`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitData {
+	/// Page URL once the submit settled.
+	pub url: String,
+	/// Whether the submit started a navigation within the wait.
+	pub navigated: bool,
+}
+
+/// Result data for type command.
+///
+/// `value` is the element's value after typing, absent for elements such as
+/// contenteditable regions that have none and for password fields.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeData {
+	pub selector: String,
+	/// Characters typed; the text itself is not echoed since it may be a password.
+	pub text_length: usize,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub value: Option<String>,
+}
+
+/// Result data for form-fill command.
+///
+/// `fields` lists every field in map order; `filled` and `failed` count them.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormFillData {
+	pub url: String,
+	pub fields: Vec<FormFieldResult>,
+	pub filled: usize,
+	pub failed: usize,
+	/// Where the page ended up, when the form was also submitted.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub submit: Option<SubmitData>,
+}
+
+/// Outcome for one form-fill field.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormFieldResult {
+	/// Key as written in the field map.
+	pub field: String,
+	pub selector: String,
+	pub ok: bool,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub action: Option<FormFieldAction>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+/// How a form-fill field was applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FormFieldAction {
+	Fill,
+	Check,
+	Uncheck,
+	Select,
 }
 
 /// Result data for eval command.
@@ -72,6 +256,11 @@ pub struct FillData {
 pub struct EvalData {
 	pub result: serde_json::Value,
 	pub expression: String,
+	/// File the decoded bytes were written to with `base64`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<PathBuf>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub size_bytes: Option<usize>,
 }
 
 /// Result data for session start command.
@@ -96,12 +285,20 @@ pub struct SessionStartData {
 pub struct ElementsData {
 	pub elements: Vec<InteractiveElement>,
 	pub count: usize,
+	/// URLs of cross-origin frames skipped by `includeIframes`.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub skipped_frames: Vec<String>,
 }
 
 /// An interactive element found on the page.
+///
+/// `stable_id` is a content hash of the tag, text, selector, name, and a
+/// 100px position bucket, so it survives re-snapshots of an unchanged page.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InteractiveElement {
+	#[serde(default)]
+	pub stable_id: String,
 	pub tag: String,
 	pub selector: String,
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -118,7 +315,102 @@ pub struct InteractiveElement {
 	pub height: i32,
 }
 
+/// Viewport dimensions in CSS pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewportSize {
+	pub width: u32,
+	pub height: u32,
+}
+
+/// One HTTP redirect observed during main-frame navigation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectHop {
+	/// URL that answered with the redirect.
+	pub from: String,
+	/// Absolute `Location` target (empty when the header was missing).
+	pub to: String,
+	/// Redirect status code (301, 302, 303, 307, or 308).
+	pub status: u16,
+}
+
+/// Result data for page meta command.
+///
+/// `og` and `twitter` are keyed without their `og:`/`twitter:` prefix.
+/// `json_ld` holds each parseable `application/ld+json` block; blocks that
+/// fail to parse are counted in `invalid_json_ld`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageMetaData {
+	pub url: String,
+	pub title: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub canonical: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub robots: Option<String>,
+	#[serde(default)]
+	pub og: BTreeMap<String, String>,
+	#[serde(default)]
+	pub twitter: BTreeMap<String, String>,
+	#[serde(default)]
+	pub json_ld: Vec<serde_json::Value>,
+	#[serde(default)]
+	pub invalid_json_ld: usize,
+	/// Declared `<html lang>` or language detected from the body text.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub language: Option<crate::readable::Language>,
+}
+
+/// Result data for page.schema command.
+///
+/// `items` holds every distinct structured-data item in document order,
+/// JSON-LD first. `errors` lists JSON-LD blocks that could not be parsed.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaData {
+	pub url: String,
+	pub items: Vec<SchemaItem>,
+	pub item_count: usize,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub errors: Vec<SchemaError>,
+}
+
+/// Syntax a structured-data item was embedded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaSource {
+	JsonLd,
+	Microdata,
+	Rdfa,
+}
+
+/// One structured-data item.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaItem {
+	pub source: SchemaSource,
+	/// `@type` for JSON-LD, `itemtype`/`typeof` for microdata and RDFa.
+	#[serde(default, rename = "type", skip_serializing_if = "Vec::is_empty")]
+	pub types: Vec<String>,
+	pub value: serde_json::Value,
+}
+
+/// A JSON-LD block that failed to parse.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaError {
+	/// Zero-based index among the page's JSON-LD blocks.
+	pub block: usize,
+	pub error: String,
+}
+
 /// Result data for snapshot command.
+///
+/// `viewport_width`/`viewport_height` are `window.innerWidth`/`innerHeight`
+/// (scrollbars included), `client_width`/`client_height` are the
+/// `document.documentElement` client size (scrollbars excluded), and
+/// `configured_viewport` is the size set on the browser context.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SnapshotData {
@@ -126,7 +418,99 @@ pub struct SnapshotData {
 	pub title: String,
 	pub viewport_width: i32,
 	pub viewport_height: i32,
+	#[serde(default)]
+	pub client_width: i32,
+	#[serde(default)]
+	pub client_height: i32,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub configured_viewport: Option<ViewportSize>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub redirects: Vec<RedirectHop>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub security: Option<pw_rs::SecurityDetails>,
+	/// HTTP status of the final main-frame response (navigate only).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub status: Option<u16>,
+	/// `Content-Type` of the final main-frame response (navigate only).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub content_type: Option<String>,
+	/// `Content-Length` header of the final main-frame response (navigate only).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub content_length: Option<u64>,
+	/// Uncaught exceptions thrown while the page loaded (navigate only).
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub page_errors: Vec<crate::types::PageError>,
 	pub text: String,
 	pub elements: Vec<InteractiveElement>,
 	pub element_count: usize,
 }
+
+/// Result data for page.scroll command.
+///
+/// `scroll_x`/`scroll_y` are the window's scroll offset in CSS pixels once
+/// scrolling has settled. `selector` is set when an element was scrolled into view.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollData {
+	pub url: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub selector: Option<String>,
+	pub scroll_x: f64,
+	pub scroll_y: f64,
+}
+
+/// Result data for scroll-to-load command.
+///
+/// `scrolls` counts every scroll, including the last one that loaded nothing.
+/// `capped` is true when `maxScrolls` ran out while the page was still growing.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollToLoadData {
+	pub url: String,
+	pub title: String,
+	pub scrolls: u32,
+	/// Document `scrollHeight` in CSS pixels after the last scroll.
+	pub scroll_height: u64,
+	pub capped: bool,
+	pub text: String,
+	pub elements: Vec<InteractiveElement>,
+	pub element_count: usize,
+}
+
+/// Result data for install command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallData {
+	pub driver: DriverInfo,
+	pub browsers: Vec<BrowserInstall>,
+	/// Browser cache directory passed to the driver, when one was configured.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cache_dir: Option<PathBuf>,
+	/// Whether the driver's installer ran (false for `check`).
+	pub installed: bool,
+	/// Whether every reported browser is present on disk.
+	pub ready: bool,
+}
+
+/// The Playwright driver `pw` talks to.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverInfo {
+	/// Version read from the driver's `package.json`, if readable.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub version: Option<String>,
+	/// Version this build of `pw` was pinned to.
+	pub pinned_version: String,
+	pub cli_path: PathBuf,
+}
+
+/// One browser build the driver manages.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserInstall {
+	pub name: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub version: Option<String>,
+	pub location: PathBuf,
+	pub installed: bool,
+}
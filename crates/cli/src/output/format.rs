@@ -12,6 +12,8 @@ pub enum OutputFormat {
 	Ndjson,
 	/// Human-readable text
 	Text,
+	/// Comma-separated values for spreadsheets
+	Csv,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -23,6 +25,7 @@ impl std::str::FromStr for OutputFormat {
 			"json" => Ok(OutputFormat::Json),
 			"ndjson" => Ok(OutputFormat::Ndjson),
 			"text" => Ok(OutputFormat::Text),
+			"csv" => Ok(OutputFormat::Csv),
 			_ => Err(format!("unknown format: {s}")),
 		}
 	}
@@ -35,6 +38,7 @@ impl std::fmt::Display for OutputFormat {
 			OutputFormat::Json => write!(f, "json"),
 			OutputFormat::Ndjson => write!(f, "ndjson"),
 			OutputFormat::Text => write!(f, "text"),
+			OutputFormat::Csv => write!(f, "csv"),
 		}
 	}
 }
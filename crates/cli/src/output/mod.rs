@@ -3,11 +3,13 @@
 #[cfg(test)]
 mod tests;
 
+mod csv;
 mod data;
 mod format;
 mod model;
 mod result_builder;
 
+pub use csv::{data_to_csv, error_to_csv};
 pub use data::*;
 pub use format::OutputFormat;
 pub use model::*;
@@ -3,6 +3,7 @@ use std::time::Instant;
 
 use serde::Serialize;
 
+use crate::output::csv::{data_to_csv, error_to_csv};
 use crate::output::format::OutputFormat;
 use crate::output::model::{
 	Artifact, CommandError, CommandInputs, CommandResult, Diagnostic, DiagnosticLevel, EffectiveConfig, ErrorCode, FailureWithArtifacts, SCHEMA_VERSION,
@@ -149,6 +150,13 @@ pub fn print_result<T: Serialize>(result: &CommandResult<T>, format: OutputForma
 		OutputFormat::Text => {
 			print_result_text(result);
 		}
+		OutputFormat::Csv => {
+			if let Some(ref error) = result.error {
+				print!("{}", error_to_csv(&error.code.to_string(), &error.message));
+			} else if let Ok(data) = serde_json::to_value(&result.data) {
+				print!("{}", data_to_csv(&data));
+			}
+		}
 	}
 }
 
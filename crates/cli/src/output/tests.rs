@@ -51,11 +51,55 @@ fn output_format_parse() {
 	assert_eq!("json".parse::<OutputFormat>().expect("json format should parse"), OutputFormat::Json);
 	assert_eq!("ndjson".parse::<OutputFormat>().expect("ndjson format should parse"), OutputFormat::Ndjson);
 	assert_eq!("text".parse::<OutputFormat>().expect("text format should parse"), OutputFormat::Text);
+	assert_eq!("csv".parse::<OutputFormat>().expect("csv format should parse"), OutputFormat::Csv);
 	assert!("json-v1".parse::<OutputFormat>().is_err());
 	assert!("ndjson-v1".parse::<OutputFormat>().is_err());
 	assert!("invalid".parse::<OutputFormat>().is_err());
 }
 
+#[test]
+fn csv_flattens_tabular_data_into_rows() {
+	let data = serde_json::json!({
+		"count": 2,
+		"elements": [
+			{ "tag": "a", "text": "Home, sweet \"home\"", "x": 10 },
+			{ "tag": "button", "text": "Go", "x": 20, "disabled": true }
+		]
+	});
+	assert_eq!(
+		data_to_csv(&data),
+		"tag,text,x,disabled\na,\"Home, sweet \"\"home\"\"\",10,\nbutton,Go,20,true\n"
+	);
+}
+
+#[test]
+fn csv_falls_back_to_key_value_rows() {
+	let data = serde_json::json!({ "url": "https://example.com", "count": 3, "tabs": [] });
+	assert_eq!(data_to_csv(&data), "key,value\ncount,3\ntabs,[]\nurl,https://example.com\n");
+	assert_eq!(
+		error_to_csv("SELECTOR_NOT_FOUND", "no match\nfor #x"),
+		"key,value\ncode,SELECTOR_NOT_FOUND\nmessage,\"no match\nfor #x\"\n"
+	);
+}
+
+#[test]
+fn csv_defuses_cells_spreadsheets_would_evaluate() {
+	let data = serde_json::json!({
+		"elements": [
+			{ "text": "=HYPERLINK(\"https://evil.test\")", "x": -5 },
+			{ "text": "+1", "x": 0 },
+			{ "text": "-1", "x": 0 },
+			{ "text": "@SUM(A1)", "x": 0 },
+			{ "text": "a=b", "x": 0 }
+		]
+	});
+	assert_eq!(
+		data_to_csv(&data),
+		"text,x\n\"'=HYPERLINK(\"\"https://evil.test\"\")\",-5\n'+1,0\n'-1,0\n'@SUM(A1),0\na=b,0\n"
+	);
+	assert_eq!(error_to_csv("NAVIGATION_FAILED", "=1+1"), "key,value\ncode,NAVIGATION_FAILED\nmessage,'=1+1\n");
+}
+
 #[test]
 fn serialize_command_result() {
 	let result: CommandResult<ClickData> = ResultBuilder::new("click")
@@ -65,6 +109,9 @@ fn serialize_command_result() {
 			navigated: true,
 			selector: "a.link".into(),
 			downloads: Vec::new(),
+			clicked: None,
+			popup: None,
+			warnings: Vec::new(),
 		})
 		.build();
 
@@ -85,6 +132,7 @@ fn artifacts_included() {
 			full_page: false,
 			width: Some(1920),
 			height: Some(1080),
+			clip: None,
 		})
 		.artifact(Artifact {
 			artifact_type: ArtifactType::Screenshot,
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::commands::def::ContextDelta;
-use crate::output::{Artifact, CommandError, CommandInputs, Diagnostic, OutputFormat};
+use crate::output::{Artifact, CommandError, CommandInputs, Diagnostic, DownloadedFile, OutputFormat, data_to_csv, error_to_csv};
 use crate::runtime::RuntimeOverrides;
 
 /// Current request/response schema for protocol-first CLI execution.
@@ -33,6 +33,9 @@ pub struct CommandRequest {
 	pub input: Value,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub runtime: Option<RuntimeSpec>,
+	/// Extra attempts after a timeout or lost connection (batch only; overrides `--retries`).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub retry: Option<u32>,
 }
 
 /// Effective runtime returned in responses for observability.
@@ -81,6 +84,9 @@ pub struct CommandResponse {
 	pub ok: bool,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub inputs: Option<CommandInputs>,
+	/// Prose summary of the command, present under `--explain`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub explanation: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub data: Option<Value>,
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -89,12 +95,18 @@ pub struct CommandResponse {
 	pub duration_ms: Option<u64>,
 	#[serde(default, skip_serializing_if = "Vec::is_empty")]
 	pub artifacts: Vec<Artifact>,
+	/// Files downloaded while the command ran, when a download directory is set.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub downloads: Vec<DownloadedFile>,
 	#[serde(default, skip_serializing_if = "Vec::is_empty")]
 	pub diagnostics: Vec<Diagnostic>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub context_delta: Option<ContextDeltaView>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub effective_runtime: Option<EffectiveRuntime>,
+	/// Times the request ran, present when batch retries were allowed.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub attempts: Option<u32>,
 }
 
 impl CommandResponse {
@@ -112,13 +124,16 @@ impl CommandResponse {
 			op,
 			ok: true,
 			inputs: Some(inputs),
+			explanation: None,
 			data: Some(data),
 			error: None,
 			duration_ms: None,
 			artifacts: Vec::new(),
+			downloads: Vec::new(),
 			diagnostics: Vec::new(),
 			context_delta: Some(delta.into()),
 			effective_runtime: Some(effective_runtime),
+			attempts: None,
 		}
 	}
 
@@ -129,13 +144,16 @@ impl CommandResponse {
 			op,
 			ok: false,
 			inputs: None,
+			explanation: None,
 			data: None,
 			error: Some(error),
 			duration_ms: None,
 			artifacts: Vec::new(),
+			downloads: Vec::new(),
 			diagnostics: Vec::new(),
 			context_delta: None,
 			effective_runtime,
+			attempts: None,
 		}
 	}
 }
@@ -159,6 +177,16 @@ pub fn print_response(response: &CommandResponse, format: OutputFormat) {
 			}
 		}
 		OutputFormat::Text => print_response_text(response),
+		OutputFormat::Csv => print!("{}", response_csv(response)),
+	}
+}
+
+/// Renders a response's data, or its error when it failed, as CSV.
+fn response_csv(response: &CommandResponse) -> String {
+	match (&response.error, &response.data) {
+		(Some(error), _) => error_to_csv(&error.code.to_string(), &error.message),
+		(None, Some(data)) => data_to_csv(data),
+		(None, None) => data_to_csv(&serde_json::Value::Null),
 	}
 }
 
@@ -166,6 +194,9 @@ fn print_response_text(response: &CommandResponse) {
 	let mut stdout = io::stdout().lock();
 
 	if response.ok {
+		if let Some(ref explanation) = response.explanation {
+			let _ = writeln!(stdout, "{explanation}");
+		}
 		if let Some(ref data) = response.data {
 			if let Ok(json) = serde_json::to_string_pretty(data) {
 				let _ = writeln!(stdout, "{json}");
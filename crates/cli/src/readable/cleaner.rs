@@ -9,17 +9,43 @@ use crate::readable::entities::collapse_whitespace;
 use crate::readable::selector::{extract_body, try_extract_by_selector};
 
 pub(crate) fn remove_clutter(html: &str) -> String {
-	let mut result = html.to_string();
+	let result = strip_clutter(html);
+	let result = extract_main_content(&result);
+
+	collapse_whitespace(&result).trim().to_string()
+}
+
+/// Like [`remove_clutter`] for the `outerHTML` of an element the caller
+/// already picked as the content root.
+///
+/// The root's own tag is dropped first, so a scoped `<aside>` or `<header>`
+/// keeps its content, and main-content detection is skipped.
+pub(crate) fn remove_clutter_scoped(html: &str) -> String {
+	let result = strip_clutter(unwrap_root(html));
+
+	collapse_whitespace(&result).trim().to_string()
+}
 
-	result = remove_tags(&result, &["script", "style", "noscript", "svg"]);
+fn strip_clutter(html: &str) -> String {
+	let mut result = remove_tags(html, &["script", "style", "noscript", "svg"]);
 	result = remove_tags(
 		&result,
 		&["nav", "header", "footer", "aside", "form", "button", "input", "select", "textarea", "iframe"],
 	);
-	result = remove_elements_by_attribute(&result);
-	result = extract_main_content(&result);
+	remove_elements_by_attribute(&result)
+}
 
-	collapse_whitespace(&result).trim().to_string()
+/// Returns the content of the outermost element, or `html` itself when it is
+/// not a single element.
+fn unwrap_root(html: &str) -> &str {
+	let html = html.trim();
+	let (Some(open_end), Some(close_start)) = (html.find('>'), html.rfind("</")) else {
+		return html;
+	};
+	if !html.starts_with('<') || !html.ends_with('>') || close_start <= open_end {
+		return html;
+	}
+	&html[open_end + 1..close_start]
 }
 
 pub(crate) fn remove_tags(html: &str, tags: &[&str]) -> String {
@@ -33,6 +59,124 @@ pub(crate) fn remove_tags(html: &str, tags: &[&str]) -> String {
 	result
 }
 
+/// Elements that never have children or a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// Elements whose content is raw text that may contain unescaped `<`.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Drops every element nested more than `max_depth` levels deep, along with its content.
+///
+/// Walks the markup once without recursion, so pathological nesting cannot
+/// exhaust the stack or blow up the regex stages that follow. Returns the
+/// pruned HTML and whether anything was dropped. Unmatched closing tags are
+/// ignored.
+pub(crate) fn limit_depth(html: &str, max_depth: usize) -> (String, bool) {
+	let mut out = String::with_capacity(html.len());
+	let mut depth = 0usize;
+	let mut truncated = false;
+	let mut pos = 0;
+
+	while let Some(offset) = html[pos..].find('<') {
+		let start = pos + offset;
+		if depth <= max_depth {
+			out.push_str(&html[pos..start]);
+		}
+		let rest = &html[start..];
+
+		if rest.starts_with("<!--") {
+			let end = rest.find("-->").map_or(html.len(), |i| start + i + 3);
+			if depth <= max_depth {
+				out.push_str(&html[start..end]);
+			}
+			pos = end;
+			continue;
+		}
+
+		if rest.starts_with("<!") || rest.starts_with("<?") {
+			let end = tag_end(html, start + 2);
+			if depth <= max_depth {
+				out.push_str(&html[start..end]);
+			}
+			pos = end;
+			continue;
+		}
+
+		let closing = rest.starts_with("</");
+		let name_start = start + if closing { 2 } else { 1 };
+		let name_len = html[name_start..]
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+			.unwrap_or(html.len() - name_start);
+		if name_len == 0 {
+			if depth <= max_depth {
+				out.push('<');
+			}
+			pos = start + 1;
+			continue;
+		}
+		let name = html[name_start..name_start + name_len].to_ascii_lowercase();
+		let end = tag_end(html, name_start + name_len);
+		let tag = &html[start..end];
+		pos = end;
+
+		if closing {
+			depth = depth.saturating_sub(1);
+			if depth < max_depth {
+				out.push_str(tag);
+			}
+			continue;
+		}
+		if VOID_ELEMENTS.contains(&name.as_str()) || tag.ends_with("/>") {
+			if depth < max_depth {
+				out.push_str(tag);
+			} else {
+				truncated = true;
+			}
+			continue;
+		}
+
+		if depth < max_depth {
+			out.push_str(tag);
+		} else {
+			truncated = true;
+		}
+		depth += 1;
+
+		if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+			let close = format!("</{name}");
+			let text_end = html[pos..].to_ascii_lowercase().find(&close).map_or(html.len(), |i| pos + i);
+			if depth <= max_depth {
+				out.push_str(&html[pos..text_end]);
+			}
+			pos = text_end;
+		}
+	}
+
+	if depth <= max_depth {
+		out.push_str(&html[pos..]);
+	}
+	(out, truncated)
+}
+
+/// Returns the index just past the `>` closing the tag whose name ends at `from`.
+///
+/// Skips `>` inside quoted attribute values. An unterminated tag runs to the end.
+fn tag_end(html: &str, from: usize) -> usize {
+	let mut quote = None;
+	for (i, c) in html[from..].char_indices() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(Some(_), _) => {}
+			(None, '"' | '\'') => quote = Some(c),
+			(None, '>') => return from + i + 1,
+			_ => {}
+		}
+	}
+	html.len()
+}
+
 fn remove_elements_by_attribute(html: &str) -> String {
 	let non_content: HashSet<&str> = clutter().scoring.non_content_patterns.iter().map(|s| s.as_str()).collect();
 
@@ -85,6 +229,19 @@ fn extract_main_content(html: &str) -> String {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn scoped_cleanup_keeps_root_and_skips_main_content_detection() {
+		let html = "<aside id='notes'><p>Kept note</p><nav>Menu</nav><script>x()</script></aside>";
+		let cleaned = remove_clutter_scoped(html);
+		assert!(cleaned.contains("Kept note"), "{cleaned}");
+		assert!(!cleaned.contains("Menu"), "{cleaned}");
+		assert!(!cleaned.contains("x()"), "{cleaned}");
+		assert!(remove_clutter(html).is_empty());
+
+		assert_eq!(unwrap_root("plain text"), "plain text");
+		assert_eq!(unwrap_root(" <div class='a'>inner</div> "), "inner");
+	}
+
 	#[test]
 	fn removes_script_tags() {
 		let html = "<div>Before<script>alert('x');</script>After</div>";
@@ -94,6 +251,24 @@ mod tests {
 		assert!(cleaned.contains("After"));
 	}
 
+	#[test]
+	fn limit_depth_keeps_shallow_markup_intact() {
+		let html = "<div><p>One<br>two <img src='a.png'/> <a title='x > y'>link</a></p><!-- <div> --></div>";
+		assert_eq!(limit_depth(html, 3), (html.to_string(), false));
+	}
+
+	#[test]
+	fn limit_depth_drops_elements_past_the_limit() {
+		let html = "<div>a<section>b<div>c<span>d</span></div>e</section>f</div>g";
+		assert_eq!(limit_depth(html, 2), ("<div>a<section>be</section>f</div>g".to_string(), true));
+	}
+
+	#[test]
+	fn limit_depth_treats_script_content_as_text() {
+		let html = "<div><script>if (a <b) { x = '<div><div>'; }</script>ok</div>";
+		assert_eq!(limit_depth(html, 2), (html.to_string(), false));
+	}
+
 	#[test]
 	fn extracts_main_article_over_header_navigation() {
 		let html = "<body><header>Top Nav</header><article><p>This is main content that is intentionally long enough to pass the extraction threshold and remain selected.</p></article></body>";
@@ -0,0 +1,86 @@
+//! Markdown documents with a YAML frontmatter header.
+
+use std::fmt::Write;
+
+use crate::readable::render_markdown::html_to_markdown;
+use crate::readable::types::ReadableContent;
+
+/// Renders `readable` as markdown preceded by a frontmatter block.
+///
+/// The block holds the title, byline, site name, source `url` and
+/// `extracted_at` (RFC 3339), skipping fields the page did not provide.
+/// Values are always double-quoted so titles containing `:` or `"` stay valid YAML.
+pub fn markdown_document(readable: &ReadableContent, url: Option<&str>, extracted_at: &str) -> String {
+	let metadata = &readable.metadata;
+	let fields = [
+		("title", metadata.title.as_deref()),
+		("byline", metadata.author.as_deref()),
+		("site_name", metadata.site.as_deref()),
+		("url", url),
+		("extracted_at", Some(extracted_at)),
+	];
+
+	let mut document = String::from("---\n");
+	for (key, value) in fields {
+		if let Some(value) = value {
+			let _ = writeln!(document, "{key}: {}", yaml_quote(value));
+		}
+	}
+	document.push_str("---\n\n");
+
+	match &readable.markdown {
+		Some(markdown) => document.push_str(markdown),
+		None => document.push_str(&html_to_markdown(&readable.html)),
+	}
+	if !document.ends_with('\n') {
+		document.push('\n');
+	}
+	document
+}
+
+/// Quotes `value` as a YAML double-quoted scalar.
+fn yaml_quote(value: &str) -> String {
+	let mut quoted = String::with_capacity(value.len() + 2);
+	quoted.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => quoted.push_str("\\\""),
+			'\\' => quoted.push_str("\\\\"),
+			'\n' => quoted.push_str("\\n"),
+			'\r' => quoted.push_str("\\r"),
+			'\t' => quoted.push_str("\\t"),
+			c if c.is_control() => {
+				let _ = write!(quoted, "\\u{:04x}", c as u32);
+			}
+			c => quoted.push(c),
+		}
+	}
+	quoted.push('"');
+	quoted
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::readable::extract_readable;
+
+	#[test]
+	fn frontmatter_quotes_colons_and_quotes() {
+		let html = r#"<html><head><title>Rust: the "fast" parts</title><meta name="author" content="Ada"></head><body><article><h1>Intro</h1><p>This paragraph is long enough to be picked as the main readable content of the page by extraction.</p></article></body></html>"#;
+		let readable = extract_readable(html, Some("https://example.com/rust"));
+		let document = markdown_document(&readable, Some("https://example.com/rust"), "2026-01-02T03:04:05Z");
+
+		let (frontmatter, body) = document.strip_prefix("---\n").unwrap().split_once("---\n\n").unwrap();
+		assert!(frontmatter.contains("title: \"Rust: the \\\"fast\\\" parts\"\n"), "{frontmatter}");
+		assert!(frontmatter.contains("byline: \"Ada\"\n"), "{frontmatter}");
+		assert!(frontmatter.contains("site_name: \"example.com\"\n"), "{frontmatter}");
+		assert!(frontmatter.contains("url: \"https://example.com/rust\"\n"), "{frontmatter}");
+		assert!(frontmatter.ends_with("extracted_at: \"2026-01-02T03:04:05Z\"\n"), "{frontmatter}");
+		assert!(body.contains("# Intro"), "{body}");
+	}
+
+	#[test]
+	fn yaml_quote_escapes_control_characters() {
+		assert_eq!(yaml_quote("a\\b\nc\u{7}"), r#""a\\b\nc\u0007""#);
+	}
+}
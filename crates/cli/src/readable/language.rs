@@ -0,0 +1,277 @@
+//! Page language detection.
+//!
+//! Prefers the declared `<html lang>` and falls back to guessing from the
+//! extracted text: the dominant Unicode script identifies most non-Latin
+//! languages outright, and Latin-script text is scored by stop-word hits.
+
+use std::sync::LazyLock;
+
+use regex_lite::Regex;
+
+use crate::readable::types::Language;
+
+static HTML_LANG_RE: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r#"(?is)<html\b[^>]*?\slang\s*=\s*["']?([a-z]{2,3})\b"#).expect("HTML_LANG_RE should compile"));
+
+/// Stop-word hits needed before a Latin-script guess earns full confidence.
+const FULL_CONFIDENCE_HITS: usize = 10;
+
+/// Common function words per language, used to score Latin-script text.
+const STOP_WORDS: &[(&str, &[&str])] = &[
+	(
+		"en",
+		&[
+			"the", "and", "of", "to", "is", "in", "that", "it", "was", "for", "with", "as", "on", "are", "this", "be", "by", "have", "from", "which", "not",
+			"or",
+		],
+	),
+	(
+		"fr",
+		&[
+			"le", "la", "les", "et", "des", "est", "une", "dans", "que", "qui", "pour", "pas", "sur", "du", "au", "avec", "ce", "sont", "il", "ne", "nous",
+			"vous",
+		],
+	),
+	(
+		"de",
+		&[
+			"der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "den", "mit", "sich", "auf", "dem", "des", "für", "im", "auch", "es", "sie",
+			"wird", "von",
+		],
+	),
+	(
+		"es",
+		&[
+			"el", "los", "las", "y", "es", "una", "que", "por", "con", "para", "del", "se", "su", "al", "como", "más", "pero", "sus", "lo", "está", "muy", "en",
+		],
+	),
+	(
+		"it",
+		&[
+			"il", "di", "che", "è", "la", "per", "gli", "una", "sono", "non", "della", "con", "del", "le", "anche", "come", "nel", "alla", "più", "questo",
+			"si", "ma",
+		],
+	),
+	(
+		"pt",
+		&[
+			"o", "os", "as", "da", "do", "que", "não", "uma", "é", "em", "para", "com", "dos", "das", "se", "mais", "por", "como", "foi", "ao", "mas", "são",
+		],
+	),
+	(
+		"nl",
+		&[
+			"de", "het", "een", "en", "van", "is", "dat", "niet", "op", "te", "zijn", "met", "voor", "die", "ook", "er", "maar", "aan", "bij", "wordt", "hij",
+			"je",
+		],
+	),
+	(
+		"sv",
+		&[
+			"och", "att", "det", "som", "en", "är", "på", "för", "av", "med", "till", "den", "inte", "har", "om", "ett", "var", "jag", "de", "så", "men", "vi",
+		],
+	),
+	(
+		"pl",
+		&[
+			"i", "w", "się", "na", "nie", "z", "do", "jest", "to", "że", "o", "jak", "po", "co", "ale", "od", "przez", "czy", "tak", "są", "jej", "który",
+		],
+	),
+	(
+		"tr",
+		&[
+			"ve", "bir", "bu", "da", "de", "için", "ile", "çok", "ne", "gibi", "daha", "olan", "ama", "kadar", "her", "olarak", "değil", "var", "en", "sonra",
+			"mi", "o",
+		],
+	),
+];
+
+/// Returns the primary subtag of the `<html lang>` attribute, lowercased.
+pub(crate) fn declared_language(html: &str) -> Option<String> {
+	HTML_LANG_RE.captures(html).and_then(|c| c.get(1)).map(|m| m.as_str().to_ascii_lowercase())
+}
+
+/// Resolves the page language: `declared` with full confidence, else a guess from `text`.
+///
+/// `declared` may be a full BCP 47 tag such as `en-US`; only its primary
+/// subtag is kept so codes compare equal with detected ones.
+pub fn page_language(declared: Option<&str>, text: &str) -> Option<Language> {
+	let declared = declared
+		.and_then(|tag| tag.split(['-', '_']).next())
+		.map(str::trim)
+		.filter(|code| (2..=3).contains(&code.len()) && code.chars().all(|c| c.is_ascii_alphabetic()));
+	match declared {
+		Some(code) => Some(Language {
+			code: code.to_ascii_lowercase(),
+			confidence: 1.0,
+		}),
+		None => detect_language(text),
+	}
+}
+
+/// Guesses the language of `text`, or `None` when there is too little to go on.
+pub(crate) fn detect_language(text: &str) -> Option<Language> {
+	let mut scripts = ScriptCounts::default();
+	for c in text.chars().filter(|c| c.is_alphabetic()) {
+		scripts.add(c);
+	}
+	if scripts.total == 0 {
+		return None;
+	}
+
+	let (code, count) = scripts.dominant_non_latin();
+	if count * 2 > scripts.total {
+		return Some(Language {
+			code: code.to_string(),
+			confidence: round(count as f64 / scripts.total as f64),
+		});
+	}
+
+	detect_latin(text)
+}
+
+/// Scores `text` against [`STOP_WORDS`].
+///
+/// Confidence is the margin of the best language over the runner-up, scaled
+/// down when fewer than [`FULL_CONFIDENCE_HITS`] stop words matched.
+fn detect_latin(text: &str) -> Option<Language> {
+	let words: Vec<String> = text
+		.split(|c: char| !c.is_alphabetic())
+		.filter(|w| !w.is_empty())
+		.map(str::to_lowercase)
+		.collect();
+
+	let mut scores: Vec<(&str, usize)> = STOP_WORDS
+		.iter()
+		.map(|(code, stop_words)| (*code, words.iter().filter(|w| stop_words.contains(&w.as_str())).count()))
+		.collect();
+	scores.sort_by(|a, b| b.1.cmp(&a.1));
+
+	let (code, best) = scores[0];
+	if best == 0 {
+		return None;
+	}
+	let second = scores[1].1;
+	let margin = (best - second) as f64 / best as f64;
+	let coverage = (best as f64 / FULL_CONFIDENCE_HITS as f64).min(1.0);
+
+	Some(Language {
+		code: code.to_string(),
+		confidence: round(margin * coverage),
+	})
+}
+
+/// Letter counts per Unicode script.
+#[derive(Debug, Default)]
+struct ScriptCounts {
+	total: usize,
+	kana: usize,
+	han: usize,
+	hangul: usize,
+	cyrillic: usize,
+	ukrainian: usize,
+	greek: usize,
+	arabic: usize,
+	hebrew: usize,
+	devanagari: usize,
+	thai: usize,
+}
+
+impl ScriptCounts {
+	fn add(&mut self, c: char) {
+		self.total += 1;
+		match c {
+			'\u{3040}'..='\u{30FF}' => self.kana += 1,
+			'\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' => self.han += 1,
+			'\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => self.hangul += 1,
+			'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ' => {
+				self.cyrillic += 1;
+				self.ukrainian += 1;
+			}
+			'\u{0400}'..='\u{04FF}' => self.cyrillic += 1,
+			'\u{0370}'..='\u{03FF}' => self.greek += 1,
+			'\u{0600}'..='\u{06FF}' => self.arabic += 1,
+			'\u{0590}'..='\u{05FF}' => self.hebrew += 1,
+			'\u{0900}'..='\u{097F}' => self.devanagari += 1,
+			'\u{0E00}'..='\u{0E7F}' => self.thai += 1,
+			_ => {}
+		}
+	}
+
+	/// Returns the most common non-Latin script as a language code with its letter count.
+	///
+	/// Japanese mixes kana with Han characters, so any kana makes CJK text
+	/// Japanese; likewise Ukrainian-only letters make Cyrillic text Ukrainian.
+	fn dominant_non_latin(&self) -> (&'static str, usize) {
+		let cjk = self.kana + self.han;
+		let candidates = [
+			(if self.kana > 0 { "ja" } else { "zh" }, cjk),
+			("ko", self.hangul),
+			(if self.ukrainian > 0 { "uk" } else { "ru" }, self.cyrillic),
+			("el", self.greek),
+			("ar", self.arabic),
+			("he", self.hebrew),
+			("hi", self.devanagari),
+			("th", self.thai),
+		];
+		candidates.into_iter().max_by_key(|(_, count)| *count).unwrap_or(("", 0))
+	}
+}
+
+/// Rounds a confidence to two decimal places.
+fn round(value: f64) -> f64 {
+	(value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn reads_primary_subtag_of_html_lang() {
+		assert_eq!(
+			declared_language(r#"<html class="x" lang="en-US"><body></body></html>"#),
+			Some("en".to_string())
+		);
+		assert_eq!(declared_language("<html LANG=FR>"), Some("fr".to_string()));
+		assert_eq!(declared_language("<html><body lang='de'></body></html>"), None);
+	}
+
+	#[test]
+	fn declared_language_wins_over_text() {
+		let language = page_language(Some("de-AT"), "The quick brown fox jumps over the lazy dog.").unwrap();
+		assert_eq!(language.code, "de");
+		assert_eq!(language.confidence, 1.0);
+	}
+
+	#[test]
+	fn detects_latin_script_languages_from_stop_words() {
+		let english =
+			detect_language("The history of the city is closely tied to the river, which was the main route for trade and travel in the region.").unwrap();
+		assert_eq!(english.code, "en");
+		assert!(english.confidence > 0.5);
+
+		let french =
+			detect_language("La ville est située sur les bords de la rivière et elle est connue pour ses marchés qui sont ouverts tous les jours.").unwrap();
+		assert_eq!(french.code, "fr");
+
+		let german = detect_language("Die Stadt liegt an dem Fluss und ist für ihre Märkte bekannt, die auch im Winter nicht geschlossen sind.").unwrap();
+		assert_eq!(german.code, "de");
+	}
+
+	#[test]
+	fn detects_non_latin_scripts() {
+		assert_eq!(detect_language("東京は日本の首都です。").unwrap().code, "ja");
+		assert_eq!(detect_language("北京是中国的首都。").unwrap().code, "zh");
+		assert_eq!(detect_language("Москва — столица России.").unwrap().code, "ru");
+		assert_eq!(detect_language("Київ є столицею України.").unwrap().code, "uk");
+		assert_eq!(detect_language("서울은 한국의 수도입니다.").unwrap().code, "ko");
+	}
+
+	#[test]
+	fn gives_up_without_evidence() {
+		assert_eq!(detect_language(""), None);
+		assert_eq!(detect_language("12345 !!!"), None);
+		assert_eq!(detect_language("Xyzzy plugh"), None);
+	}
+}
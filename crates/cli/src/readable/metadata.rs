@@ -1,33 +1,55 @@
 //! Metadata extraction from source HTML.
+//!
+//! When several sources disagree, the first available one wins:
+//!
+//! * author: JSON-LD `author`, `meta[name=author]`, `meta[property=article:author]`
+//!   (unless it is a profile URL), then the text of the first `rel=author` link
+//! * published: JSON-LD `datePublished`, `meta[property=article:published_time]`,
+//!   `meta[itemprop=datePublished]`, then the first `<time datetime>`
+//!
+//! Structured data goes first because it is the least ambiguous. Dates are
+//! normalized to RFC 3339; sources that do not parse as a date are skipped.
 
 use std::sync::LazyLock;
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat};
 use regex_lite::Regex;
+use serde_json::Value;
 
 use crate::readable::entities::decode_html_entities;
-use crate::readable::types::PageMetadata;
+use crate::readable::language::declared_language;
+use crate::readable::types::{Language, PageMetadata};
 
 static META_TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<meta\b[^>]*>").expect("META_TAG_RE should compile"));
 static META_ATTR_RE: LazyLock<Regex> =
 	LazyLock::new(|| Regex::new(r#"(?i)([a-zA-Z_:][-a-zA-Z0-9_:.]*)\s*=\s*["']([^"']*)["']"#).expect("META_ATTR_RE should compile"));
 static TITLE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<title[^>]*>([^<]+)</title>").expect("TITLE_RE should compile"));
+static JSON_LD_RE: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r#"(?is)<script\b[^>]*type\s*=\s*["']application/ld\+json["'][^>]*>(.*?)</script>"#).expect("JSON_LD_RE should compile"));
+static REL_AUTHOR_RE: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r#"(?is)<a\b[^>]*\brel\s*=\s*["'][^"']*\bauthor\b[^"']*["'][^>]*>(.*?)</a>"#).expect("REL_AUTHOR_RE should compile"));
+static TIME_DATETIME_RE: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r#"(?is)<time\b[^>]*\bdatetime\s*=\s*["']([^"']+)["']"#).expect("TIME_DATETIME_RE should compile"));
+static ANY_TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]+>").expect("ANY_TAG_RE should compile"));
 
 pub(crate) fn extract_metadata(html: &str, url: Option<&str>) -> PageMetadata {
 	let site = extract_meta_content(html, "og:site_name")
 		.or_else(|| extract_meta_content(html, "twitter:site"))
 		.or_else(|| url.and_then(extract_domain));
+	let json_ld = json_ld_objects(html);
 
 	PageMetadata {
 		title: extract_meta_content(html, "og:title")
 			.or_else(|| extract_meta_content(html, "twitter:title"))
 			.or_else(|| extract_title_tag(html)),
-		author: extract_meta_content(html, "author").or_else(|| extract_meta_content(html, "article:author")),
+		author: extract_author(html, &json_ld),
 		description: extract_meta_content(html, "og:description")
 			.or_else(|| extract_meta_content(html, "description"))
 			.or_else(|| extract_meta_content(html, "twitter:description")),
 		image: extract_meta_content(html, "og:image").or_else(|| extract_meta_content(html, "twitter:image")),
 		site,
-		published: extract_meta_content(html, "article:published_time").or_else(|| extract_meta_content(html, "datePublished")),
+		published: extract_published(html, &json_ld),
+		language: declared_language(html).map(|code| Language { code, confidence: 1.0 }),
 	}
 }
 
@@ -67,6 +89,96 @@ fn extract_meta_content(html: &str, name: &str) -> Option<String> {
 	None
 }
 
+fn extract_author(html: &str, json_ld: &[Value]) -> Option<String> {
+	json_ld
+		.iter()
+		.find_map(|object| object.get("author").and_then(json_ld_author))
+		.or_else(|| extract_meta_content(html, "author"))
+		.or_else(|| extract_meta_content(html, "article:author").filter(|value| !value.starts_with("http")))
+		.or_else(|| {
+			REL_AUTHOR_RE
+				.captures_iter(html)
+				.filter_map(|caps| caps.get(1))
+				.map(|m| decode_html_entities(ANY_TAG_RE.replace_all(m.as_str(), "").trim()))
+				.find(|name| !name.is_empty())
+		})
+}
+
+fn extract_published(html: &str, json_ld: &[Value]) -> Option<String> {
+	let json_ld_dates = json_ld
+		.iter()
+		.filter_map(|object| object.get("datePublished").and_then(Value::as_str))
+		.map(str::to_string);
+	let meta_dates = ["article:published_time", "datePublished"]
+		.into_iter()
+		.filter_map(|name| extract_meta_content(html, name));
+	let time_dates = TIME_DATETIME_RE
+		.captures_iter(html)
+		.filter_map(|caps| caps.get(1))
+		.map(|m| decode_html_entities(m.as_str()));
+
+	json_ld_dates.chain(meta_dates).chain(time_dates).find_map(|raw| normalize_date(&raw))
+}
+
+/// Normalizes common date formats to RFC 3339.
+///
+/// Accepts RFC 3339, RFC 2822, and ISO 8601 dates or date-times without an
+/// offset, which are taken as UTC.
+pub(crate) fn normalize_date(raw: &str) -> Option<String> {
+	let raw = raw.trim();
+	if let Ok(dt) = DateTime::parse_from_rfc3339(raw).or_else(|_| DateTime::parse_from_rfc2822(raw)) {
+		return Some(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+	}
+	let naive = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+		.iter()
+		.find_map(|format| NaiveDateTime::parse_from_str(raw, format).ok())
+		.or_else(|| NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok().and_then(|date| date.and_hms_opt(0, 0, 0)))?;
+	Some(naive.and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+/// Parses every JSON-LD block, flattening top-level arrays and `@graph` entries into one list of objects.
+fn json_ld_objects(html: &str) -> Vec<Value> {
+	fn collect(value: Value, out: &mut Vec<Value>) {
+		match value {
+			Value::Array(items) => items.into_iter().for_each(|item| collect(item, out)),
+			Value::Object(mut map) => {
+				if let Some(graph) = map.remove("@graph") {
+					collect(graph, out);
+				}
+				out.push(Value::Object(map));
+			}
+			_ => {}
+		}
+	}
+
+	let mut objects = Vec::new();
+	for caps in JSON_LD_RE.captures_iter(html) {
+		if let Some(value) = caps.get(1).and_then(|m| serde_json::from_str(m.as_str()).ok()) {
+			collect(value, &mut objects);
+		}
+	}
+	objects
+}
+
+/// Reads a JSON-LD `author`: a name, a `Person`/`Organization` object, or a list of either.
+fn json_ld_author(author: &Value) -> Option<String> {
+	let names: Vec<&str> = match author {
+		Value::Array(items) => items.iter().filter_map(author_name).collect(),
+		other => author_name(other).into_iter().collect(),
+	};
+	(!names.is_empty()).then(|| names.join(", "))
+}
+
+fn author_name(author: &Value) -> Option<&str> {
+	match author {
+		Value::String(name) => Some(name.as_str()),
+		Value::Object(map) => map.get("name").and_then(Value::as_str),
+		_ => None,
+	}
+	.map(str::trim)
+	.filter(|name| !name.is_empty())
+}
+
 fn extract_title_tag(html: &str) -> Option<String> {
 	TITLE_RE.captures(html).and_then(|c| c.get(1)).map(|m| decode_html_entities(m.as_str().trim()))
 }
@@ -89,6 +201,70 @@ mod tests {
 		assert_eq!(meta.title, Some("OG Title".to_string()));
 	}
 
+	#[test]
+	fn extracts_author_from_each_source() {
+		let json_ld =
+			r#"<script type="application/ld+json">{"@type":"Article","author":[{"@type":"Person","name":"Ada Lovelace"},{"name":"Charles Babbage"}]}</script>"#;
+		assert_eq!(extract_metadata(json_ld, None).author.as_deref(), Some("Ada Lovelace, Charles Babbage"));
+
+		let graph = r#"<script type="application/ld+json">{"@graph":[{"@type":"WebSite"},{"@type":"Article","author":"Grace Hopper"}]}</script>"#;
+		assert_eq!(extract_metadata(graph, None).author.as_deref(), Some("Grace Hopper"));
+
+		let meta = r#"<meta name="author" content="Alan Turing">"#;
+		assert_eq!(extract_metadata(meta, None).author.as_deref(), Some("Alan Turing"));
+
+		let rel = r#"<p>By <a href="/people/kj" rel="author"><span>Katherine Johnson</span></a></p>"#;
+		assert_eq!(extract_metadata(rel, None).author.as_deref(), Some("Katherine Johnson"));
+	}
+
+	#[test]
+	fn author_precedence_prefers_json_ld_then_meta() {
+		let html = r#"<html><head>
+			<meta property="article:author" content="https://example.com/authors/someone">
+			<meta name="author" content="Meta Author">
+			<script type="application/ld+json">{"@type":"NewsArticle","author":{"@type":"Person","name":"Structured Author"}}</script>
+			</head><body><a rel="author" href="/a">Link Author</a></body></html>"#;
+		assert_eq!(extract_metadata(html, None).author.as_deref(), Some("Structured Author"));
+
+		let without_json_ld = JSON_LD_RE.replace_all(html, "");
+		assert_eq!(extract_metadata(&without_json_ld, None).author.as_deref(), Some("Meta Author"));
+	}
+
+	#[test]
+	fn extracts_published_from_each_source() {
+		let json_ld = r#"<script type="application/ld+json">{"@type":"Article","datePublished":"2024-03-05T09:30:00+01:00"}</script>"#;
+		assert_eq!(extract_metadata(json_ld, None).published.as_deref(), Some("2024-03-05T09:30:00+01:00"));
+
+		let meta = r#"<meta property="article:published_time" content="2025-01-02">"#;
+		assert_eq!(extract_metadata(meta, None).published.as_deref(), Some("2025-01-02T00:00:00Z"));
+
+		let time = r#"<article><time datetime="2023-11-20T18:05:00Z">Nov 20</time></article>"#;
+		assert_eq!(extract_metadata(time, None).published.as_deref(), Some("2023-11-20T18:05:00Z"));
+	}
+
+	#[test]
+	fn published_precedence_skips_unparseable_sources() {
+		let html = r#"<meta property="article:published_time" content="last Tuesday">
+			<script type="application/ld+json">{"datePublished":"2022-07-01T12:00:00Z"}</script>
+			<time datetime="2020-01-01">old</time>"#;
+		assert_eq!(extract_metadata(html, None).published.as_deref(), Some("2022-07-01T12:00:00Z"));
+
+		let html = r#"<meta property="article:published_time" content="last Tuesday"><time datetime="2020-01-01">old</time>"#;
+		assert_eq!(extract_metadata(html, None).published.as_deref(), Some("2020-01-01T00:00:00Z"));
+	}
+
+	#[test]
+	fn normalizes_dates_to_rfc3339() {
+		assert_eq!(
+			normalize_date("2024-02-29T23:59:59.250-05:00").as_deref(),
+			Some("2024-02-29T23:59:59.250-05:00")
+		);
+		assert_eq!(normalize_date("Tue, 1 Jul 2003 10:52:37 +0200").as_deref(), Some("2003-07-01T10:52:37+02:00"));
+		assert_eq!(normalize_date("2024-02-29T08:15").as_deref(), Some("2024-02-29T08:15:00Z"));
+		assert_eq!(normalize_date("2024-02-29 08:15:30").as_deref(), Some("2024-02-29T08:15:30Z"));
+		assert_eq!(normalize_date("yesterday"), None);
+	}
+
 	#[test]
 	fn extracts_site_from_url_when_meta_missing() {
 		let html = r#"<html><head><title>Example</title></head></html>"#;
@@ -7,7 +7,9 @@
 mod cleaner;
 mod config;
 mod entities;
+mod frontmatter;
 mod junk;
+mod language;
 mod metadata;
 mod pipeline;
 mod render_markdown;
@@ -15,5 +17,7 @@ mod render_text;
 mod selector;
 mod types;
 
-pub use pipeline::extract_readable;
-pub use types::{PageMetadata, ReadableContent};
+pub use frontmatter::markdown_document;
+pub use language::page_language;
+pub use pipeline::{extract_readable, extract_readable_with};
+pub use types::{DEFAULT_MAX_DEPTH, DEFAULT_WORDS_PER_MINUTE, Language, PageMetadata, ReadableContent, ReadableOptions};
@@ -1,44 +1,78 @@
 //! Orchestration for readable extraction stages.
 
-use crate::readable::cleaner::remove_clutter;
+use crate::readable::cleaner::{limit_depth, remove_clutter, remove_clutter_scoped};
+use crate::readable::language::detect_language;
 use crate::readable::metadata::extract_metadata;
 use crate::readable::render_markdown::html_to_markdown;
 use crate::readable::render_text::html_to_text;
-use crate::readable::types::{PageMetadata, ReadableContent};
+use crate::readable::types::{PageMetadata, ReadableContent, ReadableOptions};
 
 #[derive(Debug, Clone)]
 pub(crate) struct ReadableInput {
 	pub(crate) html: String,
 	pub(crate) url: Option<String>,
+	pub(crate) max_depth: usize,
+	pub(crate) scoped: bool,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct ReadableIntermediate {
 	pub(crate) metadata: PageMetadata,
 	pub(crate) cleaned_html: String,
+	pub(crate) truncated: bool,
 }
 
 pub fn extract_readable(html: &str, url: Option<&str>) -> ReadableContent {
+	extract_readable_with(html, url, ReadableOptions::default())
+}
+
+/// Like [`extract_readable`] with explicit [`ReadableOptions`].
+///
+/// [`ReadableContent::truncated`] reports whether anything past
+/// `max_depth` was dropped.
+pub fn extract_readable_with(html: &str, url: Option<&str>, options: ReadableOptions) -> ReadableContent {
 	let input = ReadableInput {
 		html: html.to_string(),
 		url: url.map(ToString::to_string),
+		max_depth: options.max_depth,
+		scoped: options.scoped,
 	};
-	let intermediate = run_pipeline(&input);
+	let mut intermediate = run_pipeline(&input);
 	let text = html_to_text(&intermediate.cleaned_html);
 	let markdown = Some(html_to_markdown(&intermediate.cleaned_html));
+	if intermediate.metadata.language.is_none() {
+		intermediate.metadata.language = detect_language(&text);
+	}
+	let word_count = count_words(&text);
 
 	ReadableContent {
 		html: intermediate.cleaned_html,
 		text,
 		markdown,
 		metadata: intermediate.metadata,
+		truncated: intermediate.truncated,
+		word_count,
+		reading_time_minutes: word_count.div_ceil(options.words_per_minute.max(1)),
 	}
 }
 
+/// Counts whitespace-separated tokens containing at least one letter or digit.
+///
+/// Stray punctuation such as a spaced dash or bullet is not a word, while
+/// `don't`, `e-mail` and `3.14` each count once.
+pub(crate) fn count_words(text: &str) -> usize {
+	text.split_whitespace().filter(|token| token.chars().any(char::is_alphanumeric)).count()
+}
+
 fn run_pipeline(input: &ReadableInput) -> ReadableIntermediate {
 	let metadata = extract_metadata(&input.html, input.url.as_deref());
-	let cleaned_html = remove_clutter(&input.html);
-	ReadableIntermediate { metadata, cleaned_html }
+	let (html, truncated) = limit_depth(&input.html, input.max_depth);
+	let cleaned_html = if input.scoped { remove_clutter_scoped(&html) } else { remove_clutter(&html) };
+	ReadableIntermediate {
+		metadata,
+		cleaned_html,
+		truncated,
+	}
 }
 
 #[cfg(test)]
@@ -68,6 +102,86 @@ mod tests {
 		assert!(readable.text.contains("Still readable"));
 	}
 
+	#[test]
+	fn max_depth_guards_deeply_nested_markup() {
+		let depth = 10_000;
+		let html = format!(
+			"<html><body><p>Shallow paragraph stays</p>{}<p>Buried paragraph</p>{}</body></html>",
+			"<div>".repeat(depth),
+			"</div>".repeat(depth)
+		);
+		let readable = extract_readable(&html, None);
+		assert!(readable.truncated);
+		assert!(readable.text.contains("Shallow paragraph stays"));
+		assert!(!readable.text.contains("Buried paragraph"));
+
+		let options = ReadableOptions {
+			max_depth: 2,
+			..ReadableOptions::default()
+		};
+		let readable = extract_readable_with("<div><div><p>Deep enough</p></div></div>", None, options);
+		assert!(readable.truncated);
+		assert!(!readable.text.contains("Deep enough"));
+	}
+
+	#[test]
+	fn regular_pages_are_not_truncated() {
+		let readable = extract_readable("<body><div><p>Plain page</p></div></body>", None);
+		assert!(!readable.truncated);
+	}
+
+	#[test]
+	fn reports_declared_then_detected_language() {
+		let html = "<html lang='en-GB'><body><p>Ceci est un texte qui est écrit en français pour les lecteurs.</p></body></html>";
+		let language = extract_readable(html, None).metadata.language.unwrap();
+		assert_eq!((language.code.as_str(), language.confidence), ("en", 1.0));
+
+		let html = "<html><body><article><p>The museum is open every day of the week, and the collection of paintings in the east wing is one of the largest in the country.</p></article></body></html>";
+		assert_eq!(extract_readable(html, None).metadata.language.unwrap().code, "en");
+
+		let html = "<html><body><article><p>El museo está abierto todos los días de la semana y la colección de pinturas del ala este es una de las más grandes del país.</p></article></body></html>";
+		assert_eq!(extract_readable(html, None).metadata.language.unwrap().code, "es");
+	}
+
+	#[test]
+	fn counts_words_and_estimates_reading_time() {
+		let html = format!("<html><body><article><p>{}</p></article></body></html>", "word ".repeat(450));
+		let readable = extract_readable(&html, None);
+		assert_eq!(readable.word_count, 450);
+		assert_eq!(readable.reading_time_minutes, 3);
+
+		let options = ReadableOptions {
+			words_per_minute: 450,
+			..ReadableOptions::default()
+		};
+		assert_eq!(extract_readable_with(&html, None, options).reading_time_minutes, 1);
+
+		let empty = extract_readable("<html><body></body></html>", None);
+		assert_eq!((empty.word_count, empty.reading_time_minutes), (0, 0));
+	}
+
+	#[test]
+	fn scoped_extraction_keeps_the_chosen_element() {
+		let html = "<div class='comments'><header>Comments</header><p>First comment on the story.</p></div>";
+		let options = ReadableOptions {
+			scoped: true,
+			..ReadableOptions::default()
+		};
+		let readable = extract_readable_with(html, Some("https://example.com/story"), options);
+		assert_eq!(readable.text, "First comment on the story.");
+		assert_eq!(readable.metadata.title, None);
+		assert_eq!(readable.metadata.site.as_deref(), Some("example.com"));
+	}
+
+	#[test]
+	fn count_words_ignores_punctuation_and_repeated_whitespace() {
+		let sample = "  Hello,   world!\n\nIt's a  well-known fact — prices rose 3.5% in 2024 …\t(see: below) * - \"quoted\"  ";
+		assert_eq!(count_words(sample), 14);
+		assert_eq!(count_words("\n \t "), 0);
+		assert_eq!(count_words("— – - * • …"), 0);
+		assert_eq!(count_words("naïve café 東京"), 3);
+	}
+
 	#[test]
 	fn extracts_metadata_only_pages() {
 		let html = "<html><head><meta property='og:title' content='Meta Title'><meta name='author' content='Ada'><meta property='og:description' content='desc'></head><body></body></html>";
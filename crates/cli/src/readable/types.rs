@@ -1,5 +1,7 @@
 //! Public types returned by readable extraction.
 
+use serde::{Deserialize, Serialize};
+
 /// Metadata extracted from the source page.
 #[derive(Debug, Default, Clone)]
 pub struct PageMetadata {
@@ -9,6 +11,18 @@ pub struct PageMetadata {
 	pub description: Option<String>,
 	pub image: Option<String>,
 	pub site: Option<String>,
+	/// Declared or detected page language.
+	pub language: Option<Language>,
+}
+
+/// Page language as an ISO 639 code.
+///
+/// `confidence` is 1.0 for a declared `<html lang>` and between 0 and 1 for
+/// a guess from the page text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Language {
+	pub code: String,
+	pub confidence: f64,
 }
 
 /// Result of readable content extraction.
@@ -22,4 +36,39 @@ pub struct ReadableContent {
 	pub markdown: Option<String>,
 	/// Metadata extracted from the original source HTML.
 	pub metadata: PageMetadata,
+	/// Whether elements nested deeper than the depth limit were dropped.
+	pub truncated: bool,
+	/// Whitespace-separated words in `text`.
+	pub word_count: usize,
+	/// `word_count` at the configured reading speed, rounded up to whole minutes.
+	pub reading_time_minutes: usize,
+}
+
+/// Element nesting depth past which readable extraction drops content.
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// Reading speed used for [`ReadableContent::reading_time_minutes`] by default.
+pub const DEFAULT_WORDS_PER_MINUTE: usize = 200;
+
+/// Tunables for [`extract_readable_with`](crate::readable::extract_readable_with).
+#[derive(Debug, Clone, Copy)]
+pub struct ReadableOptions {
+	/// Elements nested more than this many levels deep are dropped.
+	pub max_depth: usize,
+	/// Reading speed for the reading-time estimate; must be non-zero.
+	pub words_per_minute: usize,
+	/// The input is the `outerHTML` of a chosen content element rather than a
+	/// whole document. Metadata is read from that markup only, so head-only
+	/// fields such as `<title>` and `og:*` tags are usually missing.
+	pub scoped: bool,
+}
+
+impl Default for ReadableOptions {
+	fn default() -> Self {
+		Self {
+			max_depth: DEFAULT_MAX_DEPTH,
+			words_per_minute: DEFAULT_WORDS_PER_MINUTE,
+			scoped: false,
+		}
+	}
 }
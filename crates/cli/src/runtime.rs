@@ -2,11 +2,14 @@
 
 use std::path::PathBuf;
 
+use pw_rs::{Geolocation, IgnoreDefaultArgs};
 use serde::{Deserialize, Serialize};
 
-use crate::context::{BlockConfig, CommandContext, CommandContextConfig, DownloadConfig};
+use crate::commands::contract::one_or_many;
+use crate::context::{BlockConfig, CommandContext, CommandContextConfig, DownloadConfig, EmulationConfig, LaunchConfig};
 use crate::context_store::ContextState;
-use crate::error::Result;
+use crate::devices;
+use crate::error::{PwError, Result};
 use crate::output::CdpEndpointSource;
 use crate::types::BrowserKind;
 use crate::workspace::WorkspaceScope;
@@ -21,8 +24,9 @@ pub struct RuntimeOverrides {
 	pub base_url: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub cdp_endpoint: Option<String>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub auth_file: Option<PathBuf>,
+	/// Storage-state files merged in order, later files winning conflicts.
+	#[serde(default, alias = "authFile", deserialize_with = "one_or_many", skip_serializing_if = "Vec::is_empty")]
+	pub auth_files: Vec<PathBuf>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub timeout_ms: Option<u64>,
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +37,24 @@ pub struct RuntimeOverrides {
 	pub block_patterns: Option<Vec<String>>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub downloads_dir: Option<PathBuf>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub slow_mo_ms: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub devtools: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub channel: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ignore_default_args: Option<IgnoreDefaultArgs>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub executable_path: Option<PathBuf>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub locale: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub timezone_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub device: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub geolocation: Option<Geolocation>,
 }
 
 /// Configuration for building a runtime.
@@ -74,6 +96,7 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 	let defaults = &ctx_state.state().config.defaults;
 	let network = &ctx_state.state().config.network;
 	let downloads = &ctx_state.state().config.downloads;
+	let launch = &ctx_state.state().config.launch;
 
 	let browser = config.overrides.browser.or(defaults.browser).unwrap_or(BrowserKind::Chromium);
 	let timeout_ms = config.overrides.timeout_ms.or(defaults.timeout_ms);
@@ -88,14 +111,44 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 
 	let use_daemon = config.overrides.use_daemon.or(defaults.use_daemon).unwrap_or(true);
 	let launch_server = config.overrides.launch_server.or(defaults.launch_server).unwrap_or(false);
-	let auth_file = config.overrides.auth_file.clone().or_else(|| defaults.auth_file.clone());
+	let auth_files = if config.overrides.auth_files.is_empty() {
+		defaults.auth_file.iter().cloned().collect()
+	} else {
+		config.overrides.auth_files.clone()
+	};
 	let block_patterns = config.overrides.block_patterns.clone().unwrap_or_else(|| network.block_patterns.clone());
 	let downloads_dir = config.overrides.downloads_dir.clone().or_else(|| downloads.dir.clone());
+	let launch_config = LaunchConfig {
+		slow_mo_ms: config.overrides.slow_mo_ms.or(launch.slow_mo_ms),
+		devtools: config.overrides.devtools.or(launch.devtools),
+		channel: config.overrides.channel.clone().or_else(|| launch.channel.clone()),
+		ignore_default_args: config.overrides.ignore_default_args.clone().or_else(|| launch.ignore_default_args.clone()),
+		executable_path: config.overrides.executable_path.clone().or_else(|| launch.executable_path.clone()),
+	};
+	let persisted_emulation = ctx_state.effective_emulation_config();
+	let mut emulation_config = EmulationConfig {
+		locale: config.overrides.locale.clone().or(persisted_emulation.locale.clone()),
+		timezone_id: config.overrides.timezone_id.clone().or(persisted_emulation.timezone_id.clone()),
+		geolocation: config.overrides.geolocation.clone(),
+		..persisted_emulation
+	};
+	if let Some(name) = &config.overrides.device {
+		let device = devices::lookup(name).ok_or_else(|| PwError::Context(format!("unknown device '{name}' (available: {})", devices::names().join(", "))))?;
+		if browser == BrowserKind::Firefox {
+			return Err(PwError::Context(format!("device '{}' cannot be emulated in Firefox", device.name)));
+		}
+		emulation_config.apply_device(&device);
+	}
+	// Attaching to an existing browser applies neither launch flags nor context emulation.
+	let attaching = resolved_cdp.is_some();
+	if !attaching {
+		emulation_config.validate()?;
+	}
 
 	let ctx = CommandContext::with_config(CommandContextConfig {
 		browser,
 		no_project: false,
-		auth_file,
+		auth_files,
 		cdp_endpoint: resolved_cdp.clone(),
 		cdp_endpoint_source,
 		launch_server,
@@ -103,12 +156,18 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 		har_config: ctx_state.effective_har_config(),
 		block_config: BlockConfig { patterns: block_patterns },
 		download_config: DownloadConfig { dir: downloads_dir },
+		launch_config,
+		emulation_config,
 		timeout_ms,
 		workspace_root: Some(scope.root().to_path_buf()),
 		workspace_id: Some(scope.workspace_id().to_string()),
 		namespace: Some(scope.profile().to_string()),
 	});
 
+	if !attaching {
+		ctx.launch_config().validate()?;
+	}
+
 	if let Some(ref endpoint) = resolved_cdp {
 		ctx_state.set_cdp_endpoint(Some(endpoint.clone()));
 	}
@@ -8,7 +8,7 @@ use crate::error::{PwError, Result};
 
 #[derive(Debug, Clone)]
 pub(super) struct AuthApplySummary {
-	pub auth_file: PathBuf,
+	pub auth_files: Vec<PathBuf>,
 	pub cookies_applied: usize,
 	pub origins_present: usize,
 }
@@ -17,7 +17,7 @@ pub(super) fn load_auth_state(auth_file: &Path) -> Result<StorageState> {
 	StorageState::from_file(auth_file).map_err(|e| PwError::BrowserLaunch(format!("Failed to load auth file: {}", e)))
 }
 
-async fn apply_auth_state_to_cdp(endpoint: &str, auth_file: &Path, state: StorageState) -> Result<AuthApplySummary> {
+async fn apply_auth_state_to_cdp(endpoint: &str, auth_files: &[PathBuf], state: StorageState) -> Result<AuthApplySummary> {
 	let cookies_applied = state.cookies.len();
 	let origins_present = state.origins.len();
 
@@ -38,22 +38,34 @@ async fn apply_auth_state_to_cdp(endpoint: &str, auth_file: &Path, state: Storag
 		context
 			.add_cookies(state.cookies)
 			.await
-			.map_err(|e| PwError::Context(format!("Failed to inject auth cookies from {}: {}", auth_file.display(), e)))?;
+			.map_err(|e| PwError::Context(format!("Failed to inject auth cookies: {}", e)))?;
 	}
 
 	Ok(AuthApplySummary {
-		auth_file: auth_file.to_path_buf(),
+		auth_files: auth_files.to_vec(),
 		cookies_applied,
 		origins_present,
 	})
 }
 
-pub(super) async fn maybe_apply_auth(endpoint: &str, auth_file: Option<&Path>) -> Result<Option<AuthApplySummary>> {
-	let Some(path) = auth_file else {
+/// Loads `auth_files` in order and merges them, later files winning conflicts.
+pub(crate) fn load_merged_auth_state(auth_files: &[PathBuf]) -> Result<Option<StorageState>> {
+	let mut merged: Option<StorageState> = None;
+	for path in auth_files {
+		let state = load_auth_state(path)?;
+		match &mut merged {
+			Some(merged) => merged.merge(state),
+			None => merged = Some(state),
+		}
+	}
+	Ok(merged)
+}
+
+pub(super) async fn maybe_apply_auth(endpoint: &str, auth_files: &[PathBuf]) -> Result<Option<AuthApplySummary>> {
+	let Some(state) = load_merged_auth_state(auth_files)? else {
 		return Ok(None);
 	};
-	let state = load_auth_state(path)?;
-	let summary = apply_auth_state_to_cdp(endpoint, path, state).await?;
+	let summary = apply_auth_state_to_cdp(endpoint, auth_files, state).await?;
 	Ok(Some(summary))
 }
 
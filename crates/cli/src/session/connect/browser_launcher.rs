@@ -5,7 +5,7 @@ use std::process::{Command, Stdio};
 use std::time::Duration;
 
 use super::browser_finder::find_chrome_executable;
-use super::cdp_probe::{CdpVersionInfo, fetch_cdp_endpoint};
+use super::cdp_probe::{CdpVersionInfo, ProbeRetry, fetch_cdp_endpoint_with_retry};
 use super::wsl;
 use crate::error::{PwError, Result};
 
@@ -55,7 +55,7 @@ pub(super) async fn launch_chrome(port: u16, user_data_dir: Option<&Path>) -> Re
 			)));
 		}
 
-		match fetch_cdp_endpoint(port).await {
+		match fetch_cdp_endpoint_with_retry(port, ProbeRetry::once()).await {
 			Ok(info) => return Ok(info),
 			Err(e) => {
 				last_error = match e {
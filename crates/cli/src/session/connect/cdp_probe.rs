@@ -1,8 +1,10 @@
 //! CDP endpoint probing and discovery helpers.
 
+use std::ops::RangeInclusive;
 use std::time::Duration;
 
-use serde::Deserialize;
+use futures::stream::{FuturesUnordered, StreamExt};
+use serde::{Deserialize, Serialize};
 
 use crate::error::{PwError, Result};
 
@@ -16,12 +18,114 @@ pub struct CdpVersionInfo {
 	pub browser: Option<String>,
 }
 
-/// Resolves CDP version metadata from `/json/version` on `port`.
+/// Ports probed by `connect --list`: Chrome's default 9222 and the next eight.
+pub const LIST_PORTS: RangeInclusive<u16> = 9222..=9230;
+
+/// Upper bound on probing a single port while listing.
+const LIST_PROBE_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// A live CDP endpoint found by [`list_endpoints`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ListedEndpoint {
+	pub endpoint: String,
+	pub browser: Option<String>,
+	pub port: u16,
+}
+
+/// Retry schedule for [`fetch_cdp_endpoint_with_retry`].
+///
+/// A freshly started browser can reset connections to `/json/version` for a
+/// moment; retrying with a short doubling delay rides that out without making
+/// `connect --discover` slow to report a port that is really closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeRetry {
+	/// Total attempts, clamped to `1..=MAX_PROBE_ATTEMPTS`.
+	pub attempts: u32,
+	/// Delay before the second attempt; doubled for each attempt after that.
+	pub initial_delay: Duration,
+	/// Upper bound on a single delay.
+	pub max_delay: Duration,
+}
+
+/// Hard cap on probe attempts so a misconfigured retry cannot stall discovery.
+pub const MAX_PROBE_ATTEMPTS: u32 = 5;
+
+impl Default for ProbeRetry {
+	fn default() -> Self {
+		Self {
+			attempts: 3,
+			initial_delay: Duration::from_millis(100),
+			max_delay: Duration::from_millis(400),
+		}
+	}
+}
+
+impl ProbeRetry {
+	/// A single attempt, for callers that already poll on their own schedule.
+	pub fn once() -> Self {
+		Self {
+			attempts: 1,
+			..Self::default()
+		}
+	}
+
+	/// Sets the total number of attempts.
+	pub fn attempts(mut self, attempts: u32) -> Self {
+		self.attempts = attempts;
+		self
+	}
+
+	fn effective_attempts(&self) -> u32 {
+		self.attempts.clamp(1, MAX_PROBE_ATTEMPTS)
+	}
+
+	/// Delay to wait after the zero-based `attempt` fails.
+	fn delay(&self, attempt: u32) -> Duration {
+		self.initial_delay.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_delay)
+	}
+}
+
+/// Resolves CDP version metadata from `/json/version` on `port`, retrying per [`ProbeRetry::default`].
 pub async fn fetch_cdp_endpoint(port: u16) -> Result<CdpVersionInfo> {
+	fetch_cdp_endpoint_with_retry(port, ProbeRetry::default()).await
+}
+
+/// Resolves CDP version metadata from `/json/version` on `port` with an explicit retry schedule.
+pub async fn fetch_cdp_endpoint_with_retry(port: u16, retry: ProbeRetry) -> Result<CdpVersionInfo> {
 	let client = reqwest::Client::builder()
 		.timeout(Duration::from_millis(400))
 		.build()
 		.map_err(|e| PwError::Context(format!("Failed to create HTTP client: {}", e)))?;
+
+	let attempts = retry.effective_attempts();
+	let mut last_error = "no response".to_string();
+	for attempt in 0..attempts {
+		if attempt > 0 {
+			tokio::time::sleep(retry.delay(attempt - 1)).await;
+		}
+		match probe_once(&client, port).await {
+			Ok(info) => return Ok(info),
+			Err(ProbeError::Unreachable(e)) => last_error = e,
+			Err(ProbeError::Invalid(e)) => return Err(e),
+		}
+	}
+
+	let suffix = if attempts > 1 {
+		format!(" (after {} attempts)", attempts)
+	} else {
+		String::new()
+	};
+	Err(PwError::Context(format!("Failed to connect to port {}: {}{}", port, last_error, suffix)))
+}
+
+enum ProbeError {
+	/// Nothing answered; worth retrying.
+	Unreachable(String),
+	/// Something answered but not with CDP metadata; retrying will not help.
+	Invalid(PwError),
+}
+
+async fn probe_once(client: &reqwest::Client, port: u16) -> std::result::Result<CdpVersionInfo, ProbeError> {
 	let mut last_error = "no response".to_string();
 
 	for url in [
@@ -42,25 +146,24 @@ pub async fn fetch_cdp_endpoint(port: u16) -> Result<CdpVersionInfo> {
 			continue;
 		}
 
-		let info: CdpVersionInfo = response
+		return response
 			.json()
 			.await
-			.map_err(|e| PwError::Context(format!("Failed to parse CDP response: {}", e)))?;
-		return Ok(info);
+			.map_err(|e| ProbeError::Invalid(PwError::Context(format!("Failed to parse CDP response: {}", e))));
 	}
 
-	Err(PwError::Context(format!("Failed to connect to port {}: {}", port, last_error)))
+	Err(ProbeError::Unreachable(last_error))
 }
 
 /// Discovers an existing debug browser and returns endpoint metadata.
-pub async fn discover_chrome(port: u16) -> Result<CdpVersionInfo> {
+pub async fn discover_chrome(port: u16, retry: ProbeRetry) -> Result<CdpVersionInfo> {
 	let launch_hint = if cfg!(target_os = "windows") {
 		format!("msedge.exe --remote-debugging-port={}", port)
 	} else {
 		format!("google-chrome --remote-debugging-port={}", port)
 	};
 
-	fetch_cdp_endpoint(port).await.map_err(|e| {
+	fetch_cdp_endpoint_with_retry(port, retry).await.map_err(|e| {
 		PwError::Context(format!(
 			"No Chrome instance with remote debugging found on port {}. \n\
 	             Last error: {}\n\
@@ -70,3 +173,64 @@ pub async fn discover_chrome(port: u16) -> Result<CdpVersionInfo> {
 		))
 	})
 }
+
+/// Probes `ports` concurrently and returns every live endpoint, sorted by port.
+///
+/// Ports that refuse, time out, or answer with something other than CDP are
+/// left out rather than reported as errors.
+pub async fn list_endpoints(ports: impl IntoIterator<Item = u16>) -> Vec<ListedEndpoint> {
+	let mut probes: FuturesUnordered<_> = ports
+		.into_iter()
+		.map(|port| async move { (port, tokio::time::timeout(LIST_PROBE_TIMEOUT, fetch_cdp_endpoint(port)).await) })
+		.collect();
+
+	let mut endpoints = Vec::new();
+	while let Some((port, probe)) = probes.next().await {
+		if let Ok(Ok(info)) = probe {
+			endpoints.push(ListedEndpoint {
+				endpoint: info.web_socket_debugger_url,
+				browser: info.browser,
+				port,
+			});
+		}
+	}
+	endpoints.sort_by_key(|endpoint| endpoint.port);
+	endpoints
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn list_endpoints_skips_closed_ports() {
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+
+		assert!(list_endpoints([port]).await.is_empty());
+	}
+
+	#[test]
+	fn probe_retry_backs_off_up_to_cap() {
+		let retry = ProbeRetry::default();
+		let delays: Vec<_> = (0..4).map(|attempt| retry.delay(attempt).as_millis()).collect();
+		assert_eq!(delays, vec![100, 200, 400, 400]);
+	}
+
+	#[test]
+	fn probe_retry_attempts_are_bounded() {
+		assert_eq!(ProbeRetry::default().attempts(0).effective_attempts(), 1);
+		assert_eq!(ProbeRetry::default().attempts(50).effective_attempts(), MAX_PROBE_ATTEMPTS);
+	}
+
+	#[tokio::test]
+	async fn fetch_reports_attempt_count_for_closed_port() {
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+
+		let err = fetch_cdp_endpoint_with_retry(port, ProbeRetry::default().attempts(2)).await.unwrap_err();
+		assert!(err.to_string().contains("after 2 attempts"), "unexpected error: {err}");
+	}
+}
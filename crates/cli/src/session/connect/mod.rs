@@ -18,20 +18,27 @@ mod process_killer;
 mod user_data_dir;
 pub mod wsl;
 
-pub use cdp_probe::{CdpVersionInfo, fetch_cdp_endpoint};
+pub(crate) use auth_injector::load_merged_auth_state;
+pub use cdp_probe::{CdpVersionInfo, LIST_PORTS, ListedEndpoint, MAX_PROBE_ATTEMPTS, ProbeRetry, fetch_cdp_endpoint, fetch_cdp_endpoint_with_retry};
 pub use user_data_dir::{resolve_connect_port, resolve_user_data_dir};
 
 #[derive(Debug, Clone)]
 struct ConnectAuthPayload {
-	auth_file: PathBuf,
+	auth_files: Vec<PathBuf>,
 	cookies_applied: usize,
 	origins_present: usize,
 }
 
+impl ConnectAuthPayload {
+	fn display_files(&self) -> String {
+		self.auth_files.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+	}
+}
+
 impl From<auth_injector::AuthApplySummary> for ConnectAuthPayload {
 	fn from(summary: auth_injector::AuthApplySummary) -> Self {
 		Self {
-			auth_file: summary.auth_file,
+			auth_files: summary.auth_files,
 			cookies_applied: summary.cookies_applied,
 			origins_present: summary.origins_present,
 		}
@@ -61,6 +68,9 @@ enum ConnectResult {
 		port: u16,
 		auth: Option<ConnectAuthPayload>,
 	},
+	Listed {
+		endpoints: Vec<ListedEndpoint>,
+	},
 	Set {
 		endpoint: String,
 	},
@@ -99,7 +109,7 @@ impl ConnectResult {
 						"Chrome launched and connected on port {} (applied {} auth cookies from {})",
 						port,
 						summary.cookies_applied,
-						summary.auth_file.display()
+						summary.display_files()
 					)
 				} else {
 					format!("Chrome launched and connected on port {}", port)
@@ -112,7 +122,7 @@ impl ConnectResult {
 					"port": port,
 					"user_data_dir": user_data_dir,
 					"auth": auth.as_ref().map(|summary| json!({
-						"file": summary.auth_file,
+						"files": summary.auth_files,
 						"cookiesApplied": summary.cookies_applied,
 						"originsPresent": summary.origins_present
 					})),
@@ -124,7 +134,7 @@ impl ConnectResult {
 					format!(
 						"Connected to existing Chrome instance (applied {} auth cookies from {})",
 						summary.cookies_applied,
-						summary.auth_file.display()
+						summary.display_files()
 					)
 				} else {
 					"Connected to existing Chrome instance".to_string()
@@ -136,13 +146,24 @@ impl ConnectResult {
 					"browser": browser,
 					"port": port,
 					"auth": auth.as_ref().map(|summary| json!({
-						"file": summary.auth_file,
+						"files": summary.auth_files,
 						"cookiesApplied": summary.cookies_applied,
 						"originsPresent": summary.origins_present
 					})),
 					"message": message,
 				})
 			}
+			ConnectResult::Listed { endpoints } => {
+				let message = match endpoints.len() {
+					0 => "No CDP endpoints found. Use --launch to start a browser with remote debugging.".to_string(),
+					count => format!("Found {} CDP endpoint(s)", count),
+				};
+				json!({
+					"action": "list",
+					"endpoints": endpoints,
+					"message": message,
+				})
+			}
 			ConnectResult::Set { endpoint } => json!({
 				"action": "set",
 				"endpoint": endpoint,
@@ -183,10 +204,10 @@ pub fn clear_cdp_endpoint(ctx_state: &mut ContextState) -> Value {
 }
 
 /// Launches a browser with remote debugging and stores discovered endpoint.
-pub async fn launch_and_connect(ctx_state: &mut ContextState, port: u16, user_data_dir: Option<&Path>, auth_file: Option<&Path>) -> Result<Value> {
+pub async fn launch_and_connect(ctx_state: &mut ContextState, port: u16, user_data_dir: Option<&Path>, auth_files: &[PathBuf]) -> Result<Value> {
 	let launch_data_dir = resolve_user_data_dir(ctx_state, user_data_dir)?;
 	let info = browser_launcher::launch_chrome(port, Some(launch_data_dir.as_path())).await?;
-	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_file)
+	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_files)
 		.await?
 		.map(ConnectAuthPayload::from);
 	ctx_state.set_cdp_endpoint(Some(info.web_socket_debugger_url.clone()));
@@ -202,9 +223,9 @@ pub async fn launch_and_connect(ctx_state: &mut ContextState, port: u16, user_da
 }
 
 /// Discovers an existing remote-debugging browser and stores endpoint.
-pub async fn discover_and_connect(ctx_state: &mut ContextState, port: u16, auth_file: Option<&Path>) -> Result<Value> {
-	let info = cdp_probe::discover_chrome(port).await?;
-	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_file)
+pub async fn discover_and_connect(ctx_state: &mut ContextState, port: u16, auth_files: &[PathBuf]) -> Result<Value> {
+	let info = cdp_probe::discover_chrome(port, ProbeRetry::default()).await?;
+	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_files)
 		.await?
 		.map(ConnectAuthPayload::from);
 	ctx_state.set_cdp_endpoint(Some(info.web_socket_debugger_url.clone()));
@@ -218,6 +239,14 @@ pub async fn discover_and_connect(ctx_state: &mut ContextState, port: u16, auth_
 	.into_json())
 }
 
+/// Lists every live CDP endpoint among `ports` without storing any of them.
+pub async fn list_cdp_endpoints(ports: impl IntoIterator<Item = u16>) -> Value {
+	ConnectResult::Listed {
+		endpoints: cdp_probe::list_endpoints(ports).await,
+	}
+	.into_json()
+}
+
 /// Stores an explicit CDP endpoint in context defaults.
 pub fn set_cdp_endpoint(ctx_state: &mut ContextState, endpoint: &str) -> Value {
 	ctx_state.set_cdp_endpoint(Some(endpoint.to_string()));
@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use tracing::debug;
 
-use super::cdp_probe::{CdpVersionInfo, fetch_cdp_endpoint};
+use super::cdp_probe::{CdpVersionInfo, ProbeRetry, fetch_cdp_endpoint_with_retry};
 use crate::context_store::ContextState;
 use crate::error::{PwError, Result};
 
@@ -200,7 +200,7 @@ pub(super) async fn launch_windows_chrome_from_wsl(port: u16, user_data_dir: Opt
 	let mut last_error = "endpoint not reachable".to_string();
 	for _ in 0..max_attempts {
 		tokio::time::sleep(Duration::from_millis(250)).await;
-		match fetch_cdp_endpoint(port).await {
+		match fetch_cdp_endpoint_with_retry(port, ProbeRetry::once()).await {
 			Ok(info) => return Ok(info),
 			Err(e) => {
 				last_error = match e {
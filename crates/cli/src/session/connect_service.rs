@@ -1,23 +1,25 @@
 //! High-level connect/session orchestration over CDP endpoint state.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde_json::Value;
 
-use super::connect::{clear_cdp_endpoint, discover_and_connect, kill_browser_on_port, launch_and_connect, set_cdp_endpoint, show_cdp_endpoint};
+use super::connect::{
+	clear_cdp_endpoint, discover_and_connect, kill_browser_on_port, launch_and_connect, list_cdp_endpoints, set_cdp_endpoint, show_cdp_endpoint,
+};
 use crate::context_store::ContextState;
 use crate::error::Result;
 
 /// Service facade used by protocol/CLI commands for connect flows.
 pub struct ConnectService<'a> {
 	ctx_state: &'a mut ContextState,
-	auth_file: Option<&'a Path>,
+	auth_files: &'a [PathBuf],
 }
 
 impl<'a> ConnectService<'a> {
 	/// Creates a service for the current mutable context state.
-	pub fn new(ctx_state: &'a mut ContextState, auth_file: Option<&'a Path>) -> Self {
-		Self { ctx_state, auth_file }
+	pub fn new(ctx_state: &'a mut ContextState, auth_files: &'a [PathBuf]) -> Self {
+		Self { ctx_state, auth_files }
 	}
 
 	/// Kills a browser bound to `port` and clears stored endpoint when found.
@@ -32,12 +34,17 @@ impl<'a> ConnectService<'a> {
 
 	/// Launches browser and stores discovered endpoint.
 	pub async fn launch(&mut self, port: u16, user_data_dir: Option<&Path>) -> Result<Value> {
-		launch_and_connect(self.ctx_state, port, user_data_dir, self.auth_file).await
+		launch_and_connect(self.ctx_state, port, user_data_dir, self.auth_files).await
 	}
 
 	/// Discovers an existing debug browser and stores endpoint.
 	pub async fn discover(&mut self, port: u16) -> Result<Value> {
-		discover_and_connect(self.ctx_state, port, self.auth_file).await
+		discover_and_connect(self.ctx_state, port, self.auth_files).await
+	}
+
+	/// Lists live debug browsers on `ports` without storing an endpoint.
+	pub async fn list(&self, ports: impl IntoIterator<Item = u16>) -> Value {
+		list_cdp_endpoints(ports).await
 	}
 
 	/// Stores explicit endpoint.
@@ -1,4 +1,4 @@
-//! Daemon browser lease acquisition helpers.
+//! Daemon browser lease acquisition and holder bookkeeping helpers.
 
 use tracing::debug;
 
@@ -48,3 +48,27 @@ pub(super) async fn acquire_daemon_lease(namespace_id: Option<&str>, request: &S
 		}
 	}
 }
+
+/// Registers a reused descriptor session as a holder of its daemon lease, if it has one.
+///
+/// Returns the session key to hand back on close, keeping the daemon's idle
+/// clock from running while the browser is attached through a descriptor.
+pub(super) async fn hold_reused_lease(session_key: Option<&str>, try_daemon_lease: bool) -> Option<String> {
+	let session_key = session_key.filter(|_| try_daemon_lease)?;
+	let client = daemon::try_connect().await?;
+	match daemon::hold_browser(&client, session_key).await {
+		Ok(true) => Some(session_key.to_string()),
+		Ok(false) => None,
+		Err(err) => {
+			debug!(target = "pw.session", error = %err, "daemon hold failed; not tracking lease");
+			None
+		}
+	}
+}
+
+/// Hands a daemon lease back so the daemon can count the browser as idle.
+pub(super) async fn return_lease(session_key: &str) {
+	if let Err(err) = daemon::return_browser(session_key).await {
+		debug!(target = "pw.session", error = %err, session_key, "failed to return daemon lease");
+	}
+}
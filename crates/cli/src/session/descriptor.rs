@@ -50,9 +50,34 @@ pub struct SessionDescriptor {
 	pub created_at: u64,
 }
 
+/// Contents of a descriptor file, read without modifying it.
+#[derive(Debug, Clone)]
+pub enum StoredDescriptor {
+	/// Written before descriptors carried a `schema_version`.
+	Unversioned,
+	Current(SessionDescriptor),
+}
+
 impl SessionDescriptor {
-	/// Loads a descriptor from disk, handling old/missing schema versions.
+	/// Loads a descriptor from disk, removing one written before schema versioning.
 	pub fn load(path: &Path) -> Result<Option<Self>> {
+		match Self::read(path)? {
+			Some(StoredDescriptor::Current(descriptor)) => Ok(Some(descriptor)),
+			Some(StoredDescriptor::Unversioned) => {
+				debug!(target = "pw.session", path = %path.display(), "removing v0 session descriptor without schema_version");
+				let _ = fs::remove_file(path);
+				Ok(None)
+			}
+			None => Ok(None),
+		}
+	}
+
+	/// Reads a descriptor from disk without removing outdated files.
+	///
+	/// # Errors
+	///
+	/// Returns an error for unreadable files, invalid JSON, and unknown schema versions.
+	pub fn read(path: &Path) -> Result<Option<StoredDescriptor>> {
 		let content = match fs::read_to_string(path) {
 			Ok(c) => c,
 			Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
@@ -62,9 +87,7 @@ impl SessionDescriptor {
 		let value: serde_json::Value = serde_json::from_str(&content)?;
 		let schema_version = value.get("schema_version").and_then(|v| v.as_u64()).unwrap_or(0);
 		if schema_version == 0 {
-			debug!(target = "pw.session", path = %path.display(), "removing v0 session descriptor without schema_version");
-			let _ = fs::remove_file(path);
-			return Ok(None);
+			return Ok(Some(StoredDescriptor::Unversioned));
 		}
 		if schema_version != SESSION_DESCRIPTOR_SCHEMA_VERSION as u64 {
 			return Err(PwError::Context(format!(
@@ -73,7 +96,7 @@ impl SessionDescriptor {
 		}
 
 		let parsed: Self = serde_json::from_value(value)?;
-		Ok(Some(parsed))
+		Ok(Some(StoredDescriptor::Current(parsed)))
 	}
 
 	/// Saves a descriptor to disk and ensures state-root `.gitignore` exists.
@@ -157,6 +180,9 @@ mod tests {
 		value.as_object_mut().unwrap().remove("schema_version");
 		std::fs::write(&path, serde_json::to_string(&value).unwrap()).unwrap();
 
+		assert!(matches!(SessionDescriptor::read(&path).unwrap(), Some(StoredDescriptor::Unversioned)));
+		assert!(path.exists(), "read must leave the file in place");
+
 		let loaded = SessionDescriptor::load(&path).unwrap();
 		assert!(loaded.is_none());
 		assert!(!path.exists());
@@ -101,10 +101,10 @@ impl<'a> DescriptorLifecycle<'a> {
 		}
 	}
 
-	/// Persists descriptor metadata for a newly acquired session.
-	pub(super) fn persist_for_session(&self, request: &SessionRequest<'_>, session: &BrowserSession, daemon_lease: Option<&DaemonLease>) {
+	/// Persists descriptor metadata for a newly acquired session, returning whether it was saved.
+	pub(super) fn persist_for_session(&self, request: &SessionRequest<'_>, session: &BrowserSession, daemon_lease: Option<&DaemonLease>) -> bool {
 		if self.path().is_none() {
-			return;
+			return false;
 		}
 
 		let endpoints = session.endpoints();
@@ -112,7 +112,7 @@ impl<'a> DescriptorLifecycle<'a> {
 		let ws = endpoints.ws.clone();
 		if cdp.is_none() && ws.is_none() {
 			debug!(target = "pw.session", "no endpoint available; skipping descriptor save");
-			return;
+			return false;
 		}
 
 		let descriptor = SessionDescriptor {
@@ -142,6 +142,7 @@ impl<'a> DescriptorLifecycle<'a> {
 			} else {
 				warn!(target = "pw.session", error = %err, "failed to save session descriptor");
 			}
+			false
 		} else {
 			debug!(
 				target = "pw.session",
@@ -149,6 +150,7 @@ impl<'a> DescriptorLifecycle<'a> {
 				ws = ?descriptor.ws_endpoint,
 				"saved session descriptor"
 			);
+			true
 		}
 	}
 }
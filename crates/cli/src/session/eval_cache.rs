@@ -0,0 +1,115 @@
+//! Per-flow memoization of read-only page evaluations.
+//!
+//! Command flows often ask the page the same question more than once (the
+//! current URL before and after a navigation check, for example). The cache
+//! keys results by `(page guid, expression)` and lives only as long as the
+//! owning [`SessionHandle`](super::SessionHandle), so each command starts
+//! from an empty cache. Anything that may change the page must call
+//! [`EvalCache::invalidate`].
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+
+use crate::error::Result;
+
+/// Memoized evaluation results keyed by page guid and expression.
+#[derive(Debug, Default)]
+pub struct EvalCache {
+	entries: Mutex<HashMap<(String, String), String>>,
+}
+
+impl EvalCache {
+	/// Returns the cached result for `expression` on `page_guid`, running `eval` on a miss.
+	///
+	/// Errors are returned as-is and never cached.
+	pub async fn get_or_eval<F, Fut>(&self, page_guid: &str, expression: &str, eval: F) -> Result<String>
+	where
+		F: FnOnce() -> Fut,
+		Fut: Future<Output = Result<String>>,
+	{
+		let key = (page_guid.to_string(), expression.to_string());
+		if let Some(hit) = self.entries.lock().unwrap().get(&key) {
+			return Ok(hit.clone());
+		}
+
+		let value = eval().await?;
+		self.entries.lock().unwrap().insert(key, value.clone());
+		Ok(value)
+	}
+
+	/// Drops every cached result.
+	pub fn invalidate(&self) {
+		self.entries.lock().unwrap().clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	use super::*;
+	use crate::error::PwError;
+
+	async fn counted(cache: &EvalCache, calls: &AtomicUsize, guid: &str, expression: &str) -> Result<String> {
+		cache
+			.get_or_eval(guid, expression, || async {
+				calls.fetch_add(1, Ordering::SeqCst);
+				Ok(format!("{guid}:{expression}"))
+			})
+			.await
+	}
+
+	#[tokio::test]
+	async fn repeated_evaluate_issues_single_call() {
+		let cache = EvalCache::default();
+		let calls = AtomicUsize::new(0);
+
+		let first = counted(&cache, &calls, "page@1", "window.location.href").await.unwrap();
+		let second = counted(&cache, &calls, "page@1", "window.location.href").await.unwrap();
+
+		assert_eq!(first, second);
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn keys_on_page_and_expression() {
+		let cache = EvalCache::default();
+		let calls = AtomicUsize::new(0);
+
+		counted(&cache, &calls, "page@1", "document.title").await.unwrap();
+		counted(&cache, &calls, "page@2", "document.title").await.unwrap();
+		counted(&cache, &calls, "page@1", "window.location.href").await.unwrap();
+
+		assert_eq!(calls.load(Ordering::SeqCst), 3);
+	}
+
+	#[tokio::test]
+	async fn invalidate_forces_reevaluation() {
+		let cache = EvalCache::default();
+		let calls = AtomicUsize::new(0);
+
+		counted(&cache, &calls, "page@1", "window.location.href").await.unwrap();
+		cache.invalidate();
+		counted(&cache, &calls, "page@1", "window.location.href").await.unwrap();
+
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn errors_are_not_cached() {
+		let cache = EvalCache::default();
+		let calls = AtomicUsize::new(0);
+
+		let failed = cache
+			.get_or_eval("page@1", "boom()", || async {
+				calls.fetch_add(1, Ordering::SeqCst);
+				Err(PwError::Context("boom".into()))
+			})
+			.await;
+		assert!(failed.is_err());
+
+		counted(&cache, &calls, "page@1", "boom()").await.unwrap();
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+	}
+}
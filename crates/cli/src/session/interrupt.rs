@@ -0,0 +1,248 @@
+//! Ctrl-C and SIGTERM cleanup for browsers launched by the running command.
+//!
+//! [`SessionManager`](super::SessionManager) registers each browser it
+//! launches on an [`InterruptToken`] for as long as the session handle lives.
+//! [`InterruptToken::guard`] installs the signal listener only once something
+//! is registered, so commands that never launch a browser (such as a
+//! foreground `daemon.start`) keep their own signal handling. On a signal it
+//! closes the registered browsers, removes the descriptors pointing at them,
+//! and fails the command.
+//!
+//! Sessions attached to a daemon lease, a reused descriptor, a CDP endpoint or
+//! a `keep_browser_running` launch or browser server are never registered: they outlive the
+//! command by design.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::{debug, warn};
+
+use super::repository::SessionRepository;
+use crate::error::{PwError, Result};
+use crate::output::SessionSource;
+
+/// How long each browser gets to close before the command exits anyway.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Shared cancellation token tracking browsers to close on interrupt.
+#[derive(Clone, Default)]
+pub struct InterruptToken {
+	inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+	next_id: AtomicU64,
+	/// Flips to `true` once the first browser is registered.
+	armed: watch::Sender<bool>,
+	launched: Mutex<HashMap<u64, LaunchedBrowser>>,
+}
+
+/// Closes a registered browser; built at registration, polled only on interrupt.
+type CloseBrowser = Pin<Box<dyn Future<Output = pw_rs::Result<()>> + Send>>;
+
+struct LaunchedBrowser {
+	close: CloseBrowser,
+	/// Descriptor saved for this browser, removed after closing it.
+	descriptor: Option<PathBuf>,
+}
+
+/// Unregisters a browser from its [`InterruptToken`] when dropped.
+pub(crate) struct InterruptRegistration {
+	token: InterruptToken,
+	id: u64,
+}
+
+impl Drop for InterruptRegistration {
+	fn drop(&mut self) {
+		self.token.launched().remove(&self.id);
+	}
+}
+
+/// Whether a session from `source` belongs to the command and should close on interrupt.
+pub(crate) fn closes_on_interrupt(source: SessionSource, keep_browser_running: bool) -> bool {
+	match source {
+		SessionSource::Fresh => true,
+		SessionSource::BrowserServer | SessionSource::PersistentDebug => !keep_browser_running,
+		SessionSource::Daemon | SessionSource::CachedDescriptor | SessionSource::CdpConnect => false,
+	}
+}
+
+impl InterruptToken {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Tracks `browser` until the returned registration drops, arming the signal listener.
+	pub(crate) fn register(&self, browser: pw_rs::Browser, descriptor: Option<PathBuf>) -> InterruptRegistration {
+		self.register_close(Box::pin(async move { browser.close().await }), descriptor)
+	}
+
+	fn register_close(&self, close: CloseBrowser, descriptor: Option<PathBuf>) -> InterruptRegistration {
+		let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+		self.launched().insert(id, LaunchedBrowser { close, descriptor });
+		self.inner.armed.send_replace(true);
+		InterruptRegistration { token: self.clone(), id }
+	}
+
+	/// Runs `command`, closing registered browsers and failing if SIGINT or SIGTERM arrives first.
+	pub async fn guard<T>(&self, command: impl Future<Output = Result<T>>) -> Result<T> {
+		self.guard_until(command, wait_for_signal()).await
+	}
+
+	/// Like [`guard`](Self::guard), with `signal` standing in for the OS signal.
+	async fn guard_until<T>(&self, command: impl Future<Output = Result<T>>, signal: impl Future<Output = ()>) -> Result<T> {
+		tokio::pin!(command);
+		tokio::select! {
+			result = &mut command => result,
+			() = self.interrupted(signal) => {
+				// `command` still holds the sessions, so their connections stay open while closing.
+				let closed = self.close_launched().await;
+				Err(PwError::Context(format!("interrupted; closed {closed} browser(s) launched by this command")))
+			}
+		}
+	}
+
+	/// Resolves once `signal` does, provided a browser was registered before.
+	async fn interrupted(&self, signal: impl Future<Output = ()>) {
+		let mut armed = self.inner.armed.subscribe();
+		if armed.wait_for(|armed| *armed).await.is_err() {
+			return std::future::pending().await;
+		}
+		signal.await;
+	}
+
+	/// Closes every registered browser and clears its descriptor, returning how many closed.
+	async fn close_launched(&self) -> usize {
+		let launched: Vec<LaunchedBrowser> = self.launched().drain().map(|(_, launched)| launched).collect();
+		let mut closed = 0;
+		for LaunchedBrowser { close, descriptor } in launched {
+			match tokio::time::timeout(CLOSE_TIMEOUT, close).await {
+				Ok(Ok(())) => closed += 1,
+				Ok(Err(err)) => warn!(target = "pw.session", error = %err, "failed to close browser on interrupt"),
+				Err(_) => warn!(target = "pw.session", "timed out closing browser on interrupt"),
+			}
+			if let Some(path) = descriptor {
+				clear_own_descriptor(path);
+			}
+		}
+		closed
+	}
+
+	fn launched(&self) -> std::sync::MutexGuard<'_, HashMap<u64, LaunchedBrowser>> {
+		self.inner.launched.lock().unwrap_or_else(|e| e.into_inner())
+	}
+}
+
+/// Removes the descriptor at `path` unless another process has since replaced it.
+fn clear_own_descriptor(path: PathBuf) {
+	let repository = SessionRepository::new(Some(path));
+	match repository.load() {
+		Ok(Some(descriptor)) if descriptor.pid == std::process::id() => {
+			if let Err(err) = repository.clear() {
+				warn!(target = "pw.session", error = %err, "failed to clear session descriptor on interrupt");
+			}
+		}
+		Ok(_) => debug!(target = "pw.session", "descriptor not owned by this process; leaving it"),
+		Err(err) => warn!(target = "pw.session", error = %err, "failed to read session descriptor on interrupt"),
+	}
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() {
+	use tokio::signal::unix::{SignalKind, signal};
+
+	let (Ok(mut sigint), Ok(mut sigterm)) = (signal(SignalKind::interrupt()), signal(SignalKind::terminate())) else {
+		warn!(
+			target = "pw.session",
+			"failed to install signal handlers; browsers will not be closed on interrupt"
+		);
+		return std::future::pending().await;
+	};
+	tokio::select! {
+		_ = sigint.recv() => debug!(target = "pw.session", "received SIGINT"),
+		_ = sigterm.recv() => debug!(target = "pw.session", "received SIGTERM"),
+	}
+}
+
+#[cfg(windows)]
+async fn wait_for_signal() {
+	if tokio::signal::ctrl_c().await.is_err() {
+		warn!(
+			target = "pw.session",
+			"failed to install Ctrl+C handler; browsers will not be closed on interrupt"
+		);
+		std::future::pending::<()>().await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::AtomicBool;
+
+	use super::*;
+	use crate::session::descriptor::{DRIVER_HASH, SESSION_DESCRIPTOR_SCHEMA_VERSION, SessionDescriptor};
+	use crate::types::BrowserKind;
+
+	#[test]
+	fn only_browsers_launched_for_the_command_close_on_interrupt() {
+		assert!(closes_on_interrupt(SessionSource::Fresh, false));
+		assert!(closes_on_interrupt(SessionSource::BrowserServer, false));
+		assert!(!closes_on_interrupt(SessionSource::BrowserServer, true));
+		assert!(closes_on_interrupt(SessionSource::PersistentDebug, false));
+		assert!(!closes_on_interrupt(SessionSource::PersistentDebug, true));
+		assert!(!closes_on_interrupt(SessionSource::Daemon, false));
+		assert!(!closes_on_interrupt(SessionSource::CachedDescriptor, false));
+		assert!(!closes_on_interrupt(SessionSource::CdpConnect, false));
+	}
+
+	#[tokio::test]
+	async fn guard_without_registered_browsers_runs_command() {
+		let token = InterruptToken::new();
+		assert_eq!(token.guard(async { Ok(7) }).await.unwrap(), 7);
+		assert!(!*token.inner.armed.borrow());
+	}
+
+	#[tokio::test]
+	async fn interrupt_closes_registered_browser_and_clears_its_descriptor() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("session.json");
+		let descriptor = SessionDescriptor {
+			schema_version: SESSION_DESCRIPTOR_SCHEMA_VERSION,
+			pid: std::process::id(),
+			browser: BrowserKind::Chromium,
+			headless: true,
+			cdp_endpoint: None,
+			ws_endpoint: Some("ws://localhost:1234".into()),
+			workspace_id: Some("ws".into()),
+			namespace: Some("default".into()),
+			session_key: Some("ws:default:chromium:headless".into()),
+			driver_hash: Some(DRIVER_HASH.to_string()),
+			created_at: 123,
+		};
+		descriptor.save(&path).unwrap();
+
+		let token = InterruptToken::new();
+		let closed = Arc::new(AtomicBool::new(false));
+		let flag = Arc::clone(&closed);
+		let _registration = token.register_close(
+			Box::pin(async move {
+				flag.store(true, Ordering::SeqCst);
+				Ok(())
+			}),
+			Some(path.clone()),
+		);
+
+		let err = token.guard_until(std::future::pending::<Result<()>>(), async {}).await.unwrap_err();
+		assert!(err.to_string().contains("closed 1 browser"), "{err}");
+		assert!(closed.load(Ordering::SeqCst), "browser was not closed");
+		assert!(!path.exists(), "descriptor was not cleared");
+		assert!(token.launched().is_empty());
+	}
+}
@@ -5,16 +5,21 @@ use std::path::Path;
 use pw_rs::WaitUntil;
 use serde_json::json;
 
-use super::daemon_lease::acquire_daemon_lease;
+use super::connect::load_merged_auth_state;
+use super::daemon_lease::{acquire_daemon_lease, hold_reused_lease, return_lease};
 use super::descriptor::SessionDescriptor;
 use super::descriptor_lifecycle::DescriptorLifecycle;
+use super::interrupt::{InterruptToken, closes_on_interrupt};
 use super::outcome::SessionHandle;
 use super::repository::SessionRepository;
 use super::session_factory::SessionFactory;
+use super::shared_lock::{is_shared, lock_endpoint};
 use super::spec::SessionRequest;
 use super::strategy::{SessionStrategyInput, resolve_session_strategy};
+use crate::browser::DownloadInfo;
 use crate::context::CommandContext;
 use crate::error::Result;
+use crate::output::DownloadedFile;
 
 /// Session manager that applies strategy selection and orchestrates acquisition.
 pub struct SessionManager<'a> {
@@ -22,6 +27,8 @@ pub struct SessionManager<'a> {
 	repository: SessionRepository,
 	namespace_id: Option<String>,
 	refresh: bool,
+	downloads: Vec<DownloadedFile>,
+	interrupt: Option<InterruptToken>,
 }
 
 impl<'a> SessionManager<'a> {
@@ -32,14 +39,32 @@ impl<'a> SessionManager<'a> {
 			repository: SessionRepository::new(descriptor_path),
 			namespace_id,
 			refresh,
+			downloads: Vec::new(),
+			interrupt: None,
 		}
 	}
 
+	/// Registers browsers this manager launches on `token`, so an interrupt closes them.
+	pub fn with_interrupt(mut self, token: InterruptToken) -> Self {
+		self.interrupt = Some(token);
+		self
+	}
+
 	/// Returns immutable command context used by this manager.
 	pub fn context(&self) -> &'a CommandContext {
 		self.ctx
 	}
 
+	/// Records downloads observed by a session before it closes.
+	pub fn record_downloads(&mut self, downloads: Vec<DownloadInfo>) {
+		self.downloads.extend(downloads.into_iter().map(DownloadedFile::from));
+	}
+
+	/// Takes downloads recorded during this command, leaving none behind.
+	pub fn take_downloads(&mut self) -> Vec<DownloadedFile> {
+		std::mem::take(&mut self.downloads)
+	}
+
 	/// Returns descriptor path when persistence is enabled.
 	pub fn descriptor_path(&self) -> Option<&Path> {
 		self.repository.path()
@@ -109,7 +134,7 @@ impl<'a> SessionManager<'a> {
 
 	/// Acquires a session using descriptor reuse, daemon leasing, or launch flows.
 	pub async fn session(&mut self, request: SessionRequest<'_>) -> Result<SessionHandle> {
-		let storage_state = request.auth_file.map(SessionFactory::load_storage_state).transpose()?;
+		let storage_state = load_merged_auth_state(request.auth_files)?;
 		let strategy = resolve_session_strategy(SessionStrategyInput {
 			has_descriptor_path: self.descriptor_path().is_some(),
 			refresh: self.refresh,
@@ -118,6 +143,7 @@ impl<'a> SessionManager<'a> {
 			cdp_endpoint: request.cdp_endpoint,
 			remote_debugging_port: request.remote_debugging_port,
 			launch_server: request.launch_server,
+			custom_launch: request.launch_config.is_customized(),
 		});
 
 		if self.refresh {
@@ -125,7 +151,9 @@ impl<'a> SessionManager<'a> {
 		} else if strategy.try_descriptor_reuse {
 			if let Some(descriptor) = self.load_descriptor()? {
 				let factory = SessionFactory::new(self.ctx);
-				if let Some(handle) = factory.acquire_from_descriptor(&descriptor, &request, storage_state.clone()).await? {
+				if let Some(mut handle) = factory.acquire_from_descriptor(&descriptor, &request, storage_state.clone()).await? {
+					handle.daemon_lease = hold_reused_lease(descriptor.session_key.as_deref(), strategy.try_daemon_lease).await;
+					lock_if_shared(&mut handle).await;
 					return Ok(handle);
 				}
 			}
@@ -133,14 +161,36 @@ impl<'a> SessionManager<'a> {
 
 		let daemon_lease = acquire_daemon_lease(self.namespace_id.as_deref(), &request, strategy.try_daemon_lease).await?;
 		let factory = SessionFactory::new(self.ctx);
-		let (mut session, source) = factory
-			.acquire_primary(&request, strategy.primary, storage_state, daemon_lease.as_ref())
-			.await?;
-
-		factory.auto_inject_auth_if_needed(&request, daemon_lease.as_ref(), &mut session).await?;
-		self.descriptors().persist_for_session(&request, &session, daemon_lease.as_ref());
+		let acquired: Result<_> = async {
+			let (mut session, source) = factory
+				.acquire_primary(&request, strategy.primary, storage_state, daemon_lease.as_ref())
+				.await?;
+			factory.auto_inject_auth_if_needed(&request, daemon_lease.as_ref(), &mut session).await?;
+			Ok((session, source))
+		}
+		.await;
+		let (session, source) = match acquired {
+			Ok(acquired) => acquired,
+			Err(err) => {
+				// Don't leave the daemon counting a holder that never attached.
+				if let Some(lease) = &daemon_lease {
+					return_lease(&lease.session_key).await;
+				}
+				return Err(err);
+			}
+		};
+		let persisted = self.descriptors().persist_for_session(&request, &session, daemon_lease.as_ref());
 
-		Ok(SessionHandle { session, source })
+		let mut handle = SessionHandle::new(session, source);
+		handle.daemon_lease = daemon_lease.map(|lease| lease.session_key);
+		if let Some(interrupt) = &self.interrupt
+			&& closes_on_interrupt(source, request.keep_browser_running)
+		{
+			let descriptor = self.descriptor_path().filter(|_| persisted).map(Path::to_path_buf);
+			handle.interrupt = Some(interrupt.register(handle.browser().clone(), descriptor));
+		}
+		lock_if_shared(&mut handle).await;
+		Ok(handle)
 	}
 
 	fn descriptors(&self) -> DescriptorLifecycle<'_> {
@@ -148,12 +198,23 @@ impl<'a> SessionManager<'a> {
 	}
 }
 
+/// Holds `handle`'s endpoint lock when its browser may be shared with concurrent batch requests.
+async fn lock_if_shared(handle: &mut SessionHandle) {
+	if !is_shared(handle.source()) {
+		return;
+	}
+	let endpoints = handle.endpoints();
+	if let Some(endpoint) = endpoints.cdp.or(endpoints.ws) {
+		handle.shared_lock = Some(lock_endpoint(&endpoint).await);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use pw_rs::WaitUntil;
 
 	use super::*;
-	use crate::context::{BlockConfig, DownloadConfig, HarConfig};
+	use crate::context::{BlockConfig, DownloadConfig, EmulationConfig, HarConfig, LaunchConfig};
 	use crate::types::BrowserKind;
 
 	static DEFAULT_HAR_CONFIG: HarConfig = HarConfig {
@@ -166,6 +227,25 @@ mod tests {
 
 	static DEFAULT_BLOCK_CONFIG: BlockConfig = BlockConfig { patterns: Vec::new() };
 	static DEFAULT_DOWNLOAD_CONFIG: DownloadConfig = DownloadConfig { dir: None };
+	static DEFAULT_LAUNCH_CONFIG: LaunchConfig = LaunchConfig {
+		slow_mo_ms: None,
+		devtools: None,
+		channel: None,
+		ignore_default_args: None,
+		executable_path: None,
+	};
+	static DEFAULT_EMULATION_CONFIG: EmulationConfig = EmulationConfig {
+		locale: None,
+		timezone_id: None,
+		color_scheme: None,
+		reduced_motion: None,
+		viewport: None,
+		user_agent: None,
+		device_scale_factor: None,
+		is_mobile: None,
+		has_touch: None,
+		geolocation: None,
+	};
 
 	#[test]
 	fn session_request_builders_round_trip() {
@@ -173,7 +253,7 @@ mod tests {
 		let request = SessionRequest::from_context(WaitUntil::NetworkIdle, &ctx)
 			.with_headless(false)
 			.with_browser(BrowserKind::Chromium)
-			.with_auth_file(None)
+			.with_auth_files(&[])
 			.with_cdp_endpoint(Some("http://127.0.0.1:9222"))
 			.with_remote_debugging_port(Some(9555))
 			.with_keep_browser_running(true)
@@ -191,7 +271,7 @@ mod tests {
 		let request = SessionRequest {
 			wait_until: WaitUntil::NetworkIdle,
 			headless: true,
-			auth_file: None,
+			auth_files: &[],
 			browser: BrowserKind::Chromium,
 			cdp_endpoint: None,
 			launch_server: false,
@@ -202,9 +282,12 @@ mod tests {
 			har_config: &DEFAULT_HAR_CONFIG,
 			block_config: &DEFAULT_BLOCK_CONFIG,
 			download_config: &DEFAULT_DOWNLOAD_CONFIG,
+			launch_config: &DEFAULT_LAUNCH_CONFIG,
+			emulation_config: &DEFAULT_EMULATION_CONFIG,
 		};
 		assert_eq!(request.block_config.patterns.len(), 0);
 		assert!(request.download_config.dir.is_none());
+		assert!(!request.launch_config.is_customized());
 	}
 
 	#[test]
@@ -15,6 +15,10 @@ mod daemon_lease;
 pub mod descriptor;
 /// Descriptor lifecycle orchestration for status/clear/persist.
 mod descriptor_lifecycle;
+/// Per-flow memoization of read-only page evaluations.
+pub mod eval_cache;
+/// Browser cleanup on Ctrl-C and SIGTERM.
+pub mod interrupt;
 /// Session request/manager/handle types and orchestration.
 pub mod manager;
 /// Active session handle and acquisition result types.
@@ -23,6 +27,8 @@ pub mod outcome;
 pub mod repository;
 /// Browser session acquisition helpers used by the manager.
 mod session_factory;
+/// Per-endpoint locks for sessions sharing a browser.
+mod shared_lock;
 /// Session request specification and builder helpers.
 pub mod spec;
 /// Pure strategy selection for session acquisition.
@@ -30,6 +36,8 @@ pub mod strategy;
 
 /// Persisted session descriptor metadata.
 pub use descriptor::SessionDescriptor;
+/// Cancellation token closing launched browsers on interrupt.
+pub use interrupt::InterruptToken;
 /// Session manager and orchestration service.
 pub use manager::SessionManager;
 /// Session acquisition handle.
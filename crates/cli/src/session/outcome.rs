@@ -2,9 +2,15 @@
 
 use std::path::Path;
 
+use pw_rs::pw_runtime::ChannelOwner;
+use tokio::sync::OwnedMutexGuard;
+
+use super::daemon_lease::return_lease;
+use super::eval_cache::EvalCache;
+use super::interrupt::InterruptRegistration;
 use crate::artifact_collector::{CollectedArtifacts, collect_failure_artifacts};
 use crate::browser::{BrowserSession, DownloadInfo, SessionEndpoints, ShutdownMode};
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::output::SessionSource;
 use crate::target::Target;
 
@@ -12,32 +18,71 @@ use crate::target::Target;
 pub struct SessionHandle {
 	pub(crate) session: BrowserSession,
 	pub(crate) source: SessionSource,
+	pub(crate) eval_cache: EvalCache,
+	#[allow(dead_code, reason = "RAII: keeps the browser registered for interrupt cleanup until drop")]
+	pub(crate) interrupt: Option<InterruptRegistration>,
+	/// Daemon session key to hand back when this session closes.
+	pub(crate) daemon_lease: Option<String>,
+	#[allow(dead_code, reason = "RAII: keeps other sessions off this shared browser until drop")]
+	pub(crate) shared_lock: Option<OwnedMutexGuard<()>>,
 }
 
 impl SessionHandle {
+	pub(crate) fn new(session: BrowserSession, source: SessionSource) -> Self {
+		Self {
+			session,
+			source,
+			eval_cache: EvalCache::default(),
+			interrupt: None,
+			daemon_lease: None,
+			shared_lock: None,
+		}
+	}
+
 	/// Returns where this session was sourced from.
 	pub fn source(&self) -> SessionSource {
 		self.source
 	}
 
-	/// Navigates to a URL.
-	pub async fn goto(&self, url: &str, timeout_ms: Option<u64>) -> Result<()> {
+	/// Navigates to a URL, returning the main resource response if there was one.
+	pub async fn goto(&self, url: &str, timeout_ms: Option<u64>) -> Result<Option<pw_rs::Response>> {
+		self.eval_cache.invalidate();
 		self.session.goto(url, timeout_ms).await
 	}
 
+	/// Whether the active page is already on `url`.
+	pub async fn is_current_url(&self, url: &str) -> bool {
+		let current_url = self.evaluate_cached("window.location.href").await.unwrap_or_else(|_| self.page().url());
+		urls_match(current_url.trim_matches('"'), url)
+	}
+
 	/// Navigates only when current URL differs from `url`.
 	pub async fn goto_if_needed(&self, url: &str, timeout_ms: Option<u64>) -> Result<bool> {
-		let current_url = self.page().evaluate_value("window.location.href").await.unwrap_or_else(|_| self.page().url());
-		let current = current_url.trim_matches('"');
-
-		if urls_match(current, url) {
+		if self.is_current_url(url).await {
 			Ok(false)
 		} else {
-			self.session.goto(url, timeout_ms).await?;
+			self.goto(url, timeout_ms).await?;
 			Ok(true)
 		}
 	}
 
+	/// Evaluates a read-only `expression` on the active page, reusing an
+	/// earlier result from this flow when nothing invalidated it since.
+	///
+	/// Only use this for expressions without side effects; call
+	/// [`Self::invalidate_evaluations`] after anything that mutates the page.
+	pub async fn evaluate_cached(&self, expression: &str) -> Result<String> {
+		let page = self.page();
+		self.eval_cache
+			.get_or_eval(page.guid(), expression, || async { Ok(page.evaluate_value(expression).await?) })
+			.await
+	}
+
+	/// Forgets cached evaluation results after the page may have changed.
+	pub fn invalidate_evaluations(&self) {
+		self.eval_cache.invalidate();
+	}
+
 	/// Navigates according to typed [`Target`] semantics.
 	pub async fn goto_target(&self, target: &Target, timeout_ms: Option<u64>) -> Result<bool> {
 		match target {
@@ -51,6 +96,32 @@ impl SessionHandle {
 		self.session.page()
 	}
 
+	/// Returns a locator for `selector` on the active page.
+	///
+	/// `a || b` matches elements of either alternative, for pages that ship
+	/// one of several UI variants.
+	pub async fn locator(&self, selector: &str) -> pw_rs::Locator {
+		let alternatives = split_alternatives(selector);
+		let mut locator = self.page().locator(alternatives[0]).await;
+		for alternative in &alternatives[1..] {
+			locator = locator.or(&self.page().locator(alternative).await);
+		}
+		locator
+	}
+
+	/// Like [`Self::locator`], but fails immediately with
+	/// [`PwError::StrictMismatch`] unless `selector` matches exactly one element.
+	pub async fn single_locator(&self, selector: &str) -> Result<pw_rs::Locator> {
+		let locator = self.locator(selector).await;
+		match locator.count().await? {
+			1 => Ok(locator),
+			count => Err(PwError::StrictMismatch {
+				selector: selector.to_string(),
+				count,
+			}),
+		}
+	}
+
 	/// Returns the active browser context.
 	pub fn context(&self) -> &pw_rs::BrowserContext {
 		self.session.context()
@@ -73,18 +144,22 @@ impl SessionHandle {
 
 	/// Shuts down session resources with an explicit mode.
 	pub async fn shutdown(self, mode: ShutdownMode) -> Result<()> {
-		self.session.shutdown(mode).await
+		let result = self.session.shutdown(mode).await;
+		if let Some(session_key) = &self.daemon_lease {
+			return_lease(session_key).await;
+		}
+		result
 	}
 
 	/// Closes session resources.
 	pub async fn close(self) -> Result<()> {
 		let mode = self.session.shutdown_mode();
-		self.session.shutdown(mode).await
+		self.shutdown(mode).await
 	}
 
 	/// Shuts down launched browser server (when applicable).
 	pub async fn shutdown_server(self) -> Result<()> {
-		self.session.shutdown(ShutdownMode::ShutdownServer).await
+		self.shutdown(ShutdownMode::ShutdownServer).await
 	}
 
 	/// Collects failure artifacts from current page state.
@@ -107,10 +182,69 @@ fn urls_match(current: &str, target: &str) -> bool {
 	current_normalized == target_normalized
 }
 
+/// Splits `selector` on top-level `||`, ignoring quoted text and brackets.
+///
+/// Always returns at least one entry; a selector without a usable
+/// alternative is returned unchanged.
+fn split_alternatives(selector: &str) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut quote = None;
+	let mut depth = 0usize;
+	let mut start = 0;
+	let bytes = selector.as_bytes();
+	let mut i = 0;
+
+	while i < bytes.len() {
+		let b = bytes[i];
+		match quote {
+			Some(_) if b == b'\\' => i += 1,
+			Some(q) if b == q => quote = None,
+			Some(_) => {}
+			None => match b {
+				b'"' | b'\'' => quote = Some(b),
+				b'[' | b'(' => depth += 1,
+				b']' | b')' => depth = depth.saturating_sub(1),
+				b'|' if depth == 0 && bytes.get(i + 1) == Some(&b'|') => {
+					parts.push(selector[start..i].trim());
+					i += 1;
+					start = i + 1;
+				}
+				_ => {}
+			},
+		}
+		i += 1;
+	}
+	parts.push(selector[start..].trim());
+
+	if parts.len() < 2 || parts.iter().any(|part| part.is_empty()) {
+		return vec![selector];
+	}
+	parts
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn split_alternatives_on_top_level_pipes() {
+		assert_eq!(split_alternatives("#new-btn || #old-btn"), vec!["#new-btn", "#old-btn"]);
+		assert_eq!(split_alternatives("a||b || c"), vec!["a", "b", "c"]);
+	}
+
+	#[test]
+	fn split_alternatives_keeps_plain_selectors() {
+		assert_eq!(split_alternatives("button.submit"), vec!["button.submit"]);
+		assert_eq!(split_alternatives("|| #dangling"), vec!["|| #dangling"]);
+	}
+
+	#[test]
+	fn split_alternatives_ignores_quoted_and_bracketed_pipes() {
+		assert_eq!(split_alternatives(r#"text="a || b""#), vec![r#"text="a || b""#]);
+		assert_eq!(split_alternatives("[data-x='a||b'] || #y"), vec!["[data-x='a||b']", "#y"]);
+		assert_eq!(split_alternatives(":is(a || b)"), vec![":is(a || b)"]);
+	}
+
 	#[test]
 	fn urls_match_treats_trailing_slash_as_equal() {
 		assert!(urls_match("https://example.com", "https://example.com"));
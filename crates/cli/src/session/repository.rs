@@ -2,7 +2,7 @@
 
 use std::path::{Path, PathBuf};
 
-use super::descriptor::SessionDescriptor;
+use super::descriptor::{SessionDescriptor, StoredDescriptor};
 use crate::error::Result;
 
 /// Repository wrapper for profile-scoped session descriptor persistence.
@@ -17,6 +17,22 @@ impl SessionRepository {
 		Self { path }
 	}
 
+	/// Returns one repository per `*.json` descriptor under every profile of `state_root`.
+	///
+	/// Profiles without a `sessions` directory are skipped; results are sorted by path.
+	pub fn discover(state_root: &Path) -> Result<Vec<Self>> {
+		let mut paths = Vec::new();
+		for profile in read_dir_if_exists(&state_root.join("profiles"))? {
+			for entry in read_dir_if_exists(&profile.join("sessions"))? {
+				if entry.extension().is_some_and(|ext| ext == "json") && entry.is_file() {
+					paths.push(entry);
+				}
+			}
+		}
+		paths.sort();
+		Ok(paths.into_iter().map(|path| Self::new(Some(path))).collect())
+	}
+
 	/// Returns descriptor path when descriptor persistence is enabled.
 	pub fn path(&self) -> Option<&Path> {
 		self.path.as_deref()
@@ -30,6 +46,14 @@ impl SessionRepository {
 		SessionDescriptor::load(path)
 	}
 
+	/// Reads the persisted descriptor without removing outdated files.
+	pub fn read(&self) -> Result<Option<StoredDescriptor>> {
+		let Some(path) = self.path() else {
+			return Ok(None);
+		};
+		SessionDescriptor::read(path)
+	}
+
 	/// Persists a descriptor to disk.
 	pub fn save(&self, descriptor: &SessionDescriptor) -> Result<()> {
 		let Some(path) = self.path() else {
@@ -50,3 +74,50 @@ impl SessionRepository {
 		}
 	}
 }
+
+fn read_dir_if_exists(dir: &Path) -> Result<Vec<PathBuf>> {
+	match std::fs::read_dir(dir) {
+		Ok(entries) => Ok(entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect()),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+		Err(err) => Err(err.into()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::tempdir;
+
+	use super::*;
+
+	#[test]
+	fn discover_collects_descriptors_across_profiles() {
+		let dir = tempdir().unwrap();
+		let root = dir.path();
+		for profile in ["default", "staging"] {
+			let sessions = root.join("profiles").join(profile).join("sessions");
+			std::fs::create_dir_all(&sessions).unwrap();
+			std::fs::write(sessions.join("session.json"), "{}").unwrap();
+			std::fs::write(sessions.join("notes.txt"), "").unwrap();
+		}
+		std::fs::create_dir_all(root.join("profiles").join("empty")).unwrap();
+
+		let found: Vec<_> = SessionRepository::discover(root)
+			.unwrap()
+			.into_iter()
+			.map(|repo| repo.path().unwrap().to_path_buf())
+			.collect();
+		assert_eq!(
+			found,
+			vec![
+				root.join("profiles/default/sessions/session.json"),
+				root.join("profiles/staging/sessions/session.json"),
+			]
+		);
+	}
+
+	#[test]
+	fn discover_without_state_root_is_empty() {
+		let dir = tempdir().unwrap();
+		assert!(SessionRepository::discover(&dir.path().join("missing")).unwrap().is_empty());
+	}
+}
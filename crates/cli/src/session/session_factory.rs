@@ -1,7 +1,5 @@
 //! Browser session acquisition helpers extracted from session manager orchestration.
 
-use std::path::Path;
-
 use pw_rs::StorageState;
 use tracing::debug;
 
@@ -27,11 +25,6 @@ impl<'a> SessionFactory<'a> {
 		Self { ctx }
 	}
 
-	/// Loads storage-state used by session acquisition requests.
-	pub(super) fn load_storage_state(path: &Path) -> Result<StorageState> {
-		StorageState::from_file(path).map_err(|e| PwError::BrowserLaunch(format!("Failed to load auth file: {}", e)))
-	}
-
 	/// Attempts session reuse from a descriptor when metadata still matches request constraints.
 	pub(super) async fn acquire_from_descriptor(
 		&self,
@@ -61,10 +54,7 @@ impl<'a> SessionFactory<'a> {
 		let mut session = self.session_with_config(request, storage_state, Some(endpoint)).await?;
 		session.set_shutdown_mode(ShutdownMode::KeepBrowserAlive);
 
-		Ok(Some(SessionHandle {
-			session,
-			source: SessionSource::CachedDescriptor,
-		}))
+		Ok(Some(SessionHandle::new(session, SessionSource::CachedDescriptor)))
 	}
 
 	/// Acquires a new or attached browser session based on the selected primary strategy.
@@ -99,12 +89,28 @@ impl<'a> SessionFactory<'a> {
 						"Persistent sessions with remote_debugging_port require Chromium".to_string(),
 					));
 				}
-				let session =
-					BrowserSession::launch_persistent(request.wait_until, storage_state, request.headless, port, request.keep_browser_running).await?;
+				let session = BrowserSession::launch_persistent(
+					request.wait_until,
+					storage_state,
+					request.headless,
+					port,
+					request.keep_browser_running,
+					request.launch_config,
+					request.emulation_config,
+				)
+				.await?;
 				Ok((session, SessionSource::PersistentDebug))
 			}
 			PrimarySessionStrategy::LaunchServer => {
-				let session = BrowserSession::launch_server_session(request.wait_until, storage_state, request.headless, request.browser).await?;
+				let session = BrowserSession::launch_server_session(
+					request.wait_until,
+					storage_state,
+					request.headless,
+					request.browser,
+					request.launch_config,
+					request.emulation_config,
+				)
+				.await?;
 				Ok((session, SessionSource::BrowserServer))
 			}
 			PrimarySessionStrategy::FreshLaunch => {
@@ -122,8 +128,8 @@ impl<'a> SessionFactory<'a> {
 		session: &mut BrowserSession,
 	) -> Result<()> {
 		let attached_endpoint = request.cdp_endpoint.is_some() || daemon_lease.is_some();
-		if attached_endpoint && request.auth_file.is_none() {
-			let auth_files = self.ctx.auth_files();
+		if attached_endpoint && request.auth_files.is_empty() {
+			let auth_files = self.ctx.namespace_auth_files();
 			if !auth_files.is_empty() {
 				debug!(
 					target = "pw.session",
@@ -161,6 +167,8 @@ impl<'a> SessionFactory<'a> {
 			har: request.har_config.clone(),
 			block: request.block_config.clone(),
 			download: request.download_config.clone(),
+			launch: request.launch_config.clone(),
+			emulation: request.emulation_config.clone(),
 		})
 		.await
 	}
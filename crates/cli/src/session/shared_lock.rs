@@ -0,0 +1,64 @@
+//! Serializes commands that drive the same shared browser.
+//!
+//! Sessions attached to a daemon lease, a reused descriptor, a CDP endpoint
+//! or a persistent debug browser all act on that browser's first tab. Batch
+//! requests running concurrently would race on it, so the session manager
+//! holds a per-endpoint lock for as long as such a session handle lives.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use tokio::sync::{Mutex as TokioMutex, OwnedMutexGuard};
+
+use crate::output::SessionSource;
+
+static LOCKS: LazyLock<Mutex<HashMap<String, Arc<TokioMutex<()>>>>> = LazyLock::new(Mutex::default);
+
+/// Whether sessions from `source` attach to a browser other commands may also use.
+pub(crate) fn is_shared(source: SessionSource) -> bool {
+	!matches!(source, SessionSource::Fresh | SessionSource::BrowserServer)
+}
+
+/// Waits until no other session in this process holds `endpoint`, then holds it until the guard drops.
+pub(crate) async fn lock_endpoint(endpoint: &str) -> OwnedMutexGuard<()> {
+	let lock = {
+		let mut locks = LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+		Arc::clone(locks.entry(endpoint.to_string()).or_default())
+	};
+	lock.lock_owned().await
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	#[test]
+	fn only_launched_sessions_are_unshared() {
+		assert!(!is_shared(SessionSource::Fresh));
+		assert!(!is_shared(SessionSource::BrowserServer));
+		assert!(is_shared(SessionSource::Daemon));
+		assert!(is_shared(SessionSource::CachedDescriptor));
+		assert!(is_shared(SessionSource::CdpConnect));
+		assert!(is_shared(SessionSource::PersistentDebug));
+	}
+
+	#[tokio::test]
+	async fn same_endpoint_waits_and_other_endpoints_do_not() {
+		let held = lock_endpoint("http://127.0.0.1:9301").await;
+		let _other = tokio::time::timeout(Duration::from_secs(1), lock_endpoint("http://127.0.0.1:9302"))
+			.await
+			.expect("a different endpoint should not wait");
+
+		let waiting = tokio::spawn(lock_endpoint("http://127.0.0.1:9301"));
+		tokio::time::sleep(Duration::from_millis(50)).await;
+		assert!(!waiting.is_finished());
+
+		drop(held);
+		tokio::time::timeout(Duration::from_secs(1), waiting)
+			.await
+			.expect("released endpoint should be acquired")
+			.unwrap();
+	}
+}
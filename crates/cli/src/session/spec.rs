@@ -1,20 +1,21 @@
 //! Session request specification used by CLI command execution.
 
-use std::path::Path;
+use std::path::PathBuf;
 
 use pw_rs::WaitUntil;
 
-use crate::context::{BlockConfig, CommandContext, DownloadConfig, HarConfig};
-use crate::types::BrowserKind;
+use crate::context::{BlockConfig, CommandContext, DownloadConfig, EmulationConfig, HarConfig, LaunchConfig};
+use crate::types::{BrowserKind, ProtectedUrl};
 
 /// Fully resolved request for acquiring a browser session.
+#[derive(Clone)]
 pub struct SessionRequest<'a> {
 	/// Navigation wait strategy used by session page operations.
 	pub wait_until: WaitUntil,
 	/// Whether the session should run headless.
 	pub headless: bool,
-	/// Optional auth file used to bootstrap storage state.
-	pub auth_file: Option<&'a Path>,
+	/// Auth files merged, later ones winning, to bootstrap storage state.
+	pub auth_files: &'a [PathBuf],
 	/// Browser engine to launch/connect.
 	pub browser: BrowserKind,
 	/// Optional CDP endpoint to attach to an existing browser.
@@ -26,7 +27,7 @@ pub struct SessionRequest<'a> {
 	/// Whether browser lifecycle should outlive the session handle.
 	pub keep_browser_running: bool,
 	/// URL patterns excluded from page-reuse selection.
-	pub protected_urls: &'a [String],
+	pub protected_urls: &'a [ProtectedUrl],
 	/// Preferred URL for page-reuse selection.
 	pub preferred_url: Option<&'a str>,
 	/// HAR recording configuration.
@@ -35,6 +36,10 @@ pub struct SessionRequest<'a> {
 	pub block_config: &'a BlockConfig,
 	/// Download-tracking configuration.
 	pub download_config: &'a DownloadConfig,
+	/// Browser launch tuning for launch flows.
+	pub launch_config: &'a LaunchConfig,
+	/// Browser context emulation (locale, timezone).
+	pub emulation_config: &'a EmulationConfig,
 }
 
 impl<'a> SessionRequest<'a> {
@@ -43,7 +48,7 @@ impl<'a> SessionRequest<'a> {
 		Self {
 			wait_until,
 			headless: true,
-			auth_file: ctx.auth_file(),
+			auth_files: ctx.auth_files(),
 			browser: ctx.browser,
 			cdp_endpoint: ctx.cdp_endpoint(),
 			launch_server: ctx.launch_server(),
@@ -54,11 +59,13 @@ impl<'a> SessionRequest<'a> {
 			har_config: ctx.har_config(),
 			block_config: ctx.block_config(),
 			download_config: ctx.download_config(),
+			launch_config: ctx.launch_config(),
+			emulation_config: ctx.emulation_config(),
 		}
 	}
 
 	/// Sets protected URL patterns for page-reuse filtering.
-	pub fn with_protected_urls(mut self, urls: &'a [String]) -> Self {
+	pub fn with_protected_urls(mut self, urls: &'a [ProtectedUrl]) -> Self {
 		self.protected_urls = urls;
 		self
 	}
@@ -69,9 +76,9 @@ impl<'a> SessionRequest<'a> {
 		self
 	}
 
-	/// Sets the auth storage-state file.
-	pub fn with_auth_file(mut self, auth_file: Option<&'a Path>) -> Self {
-		self.auth_file = auth_file;
+	/// Sets the auth storage-state files.
+	pub fn with_auth_files(mut self, auth_files: &'a [PathBuf]) -> Self {
+		self.auth_files = auth_files;
 		self
 	}
 
@@ -43,6 +43,11 @@ pub struct SessionStrategyInput<'a> {
 	pub remote_debugging_port: Option<u16>,
 	/// Whether launch-server mode was requested.
 	pub launch_server: bool,
+	/// Whether launch options (slow-mo, channel, ...) were customized.
+	///
+	/// Daemon and descriptor-backed browsers were launched with their own
+	/// options, so a customized launch skips both reuse paths to take effect.
+	pub custom_launch: bool,
 }
 
 /// Resolves acquisition strategy from normalized runtime/session inputs.
@@ -57,11 +62,12 @@ pub fn resolve_session_strategy(input: SessionStrategyInput<'_>) -> SessionStrat
 		PrimarySessionStrategy::FreshLaunch
 	};
 
-	let try_descriptor_reuse = input.has_descriptor_path && !input.refresh;
+	let try_descriptor_reuse = input.has_descriptor_path && !input.refresh && !input.custom_launch;
 	let try_daemon_lease = !input.no_daemon
 		&& input.cdp_endpoint.is_none()
 		&& input.remote_debugging_port.is_none()
 		&& !input.launch_server
+		&& !input.custom_launch
 		&& input.browser == BrowserKind::Chromium;
 
 	SessionStrategy {
@@ -84,6 +90,7 @@ mod tests {
 			cdp_endpoint: None,
 			remote_debugging_port: None,
 			launch_server: false,
+			custom_launch: false,
 		}
 	}
 
@@ -112,6 +119,16 @@ mod tests {
 		assert!(!strategy.try_daemon_lease);
 	}
 
+	#[test]
+	fn daemon_lease_disabled_for_custom_launch() {
+		let mut input = base_input();
+		input.custom_launch = true;
+		let strategy = resolve_session_strategy(input);
+		assert!(!strategy.try_descriptor_reuse);
+		assert!(!strategy.try_daemon_lease);
+		assert_eq!(strategy.primary, PrimarySessionStrategy::FreshLaunch);
+	}
+
 	#[test]
 	fn persistent_mode_wins_over_cdp_attach() {
 		let mut input = base_input();
@@ -1,8 +1,13 @@
 //! Session lifecycle helpers for command execution.
 
-use crate::commands::def::ExecCtx;
+use std::future::Future;
+use std::pin::Pin;
+
+use tracing::warn;
+
+use crate::commands::def::{ExecCtx, ExecMode};
 use crate::error::{PwError, Result};
-use crate::output::FailureWithArtifacts;
+use crate::output::{FailureWithArtifacts, SessionSource};
 use crate::session::{SessionHandle, SessionRequest};
 
 /// When to collect failure artifacts (screenshots, traces).
@@ -13,18 +18,41 @@ pub enum ArtifactsPolicy {
 }
 
 /// Execute a callback with a session, collecting artifacts on failure.
+///
+/// If a reused (descriptor or daemon) session turns out to be dead, the
+/// callback fails with a target-closed error. The stale descriptor is then
+/// cleared and the callback runs once more against a freshly acquired
+/// session; a second failure is returned as-is.
+///
+/// With `--pause`, a headed session stays open after the callback until the
+/// user presses Enter.
+///
+/// Downloads the session saved are recorded on the session manager, so any
+/// command reports them in the response's `downloads`.
 pub async fn with_session<'exec, 'ctx, T>(
 	exec: &mut ExecCtx<'exec, 'ctx>,
 	req: SessionRequest<'_>,
 	artifacts: ArtifactsPolicy,
-	f: impl for<'s> FnOnce(&'s SessionHandle) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T>> + 's>>,
+	f: impl for<'s> Fn(&'s SessionHandle) -> Pin<Box<dyn Future<Output = Result<T>> + 's>>,
 ) -> Result<T>
 where
 	'ctx: 'exec,
 {
-	let session = exec.session.session(req).await?;
+	let headed = is_headed(&req);
+	let session = exec.session.session(req.clone()).await?;
+	let sessions = &mut *exec.session;
+	let (session, res) = run_reacquiring(session, SessionHandle::source, &f, |stale| async move {
+		let _ = stale.close().await;
+		let _ = sessions.clear_descriptor();
+		sessions.session(req).await
+	})
+	.await?;
 
-	let res = f(&session).await;
+	if exec.pause {
+		pause_for_inspection(exec.mode, headed).await;
+	}
+
+	exec.session.record_downloads(session.downloads());
 
 	match res {
 		Ok(v) => {
@@ -49,3 +77,193 @@ where
 		}
 	}
 }
+
+/// Runs `f` on `session`, and once more on the session returned by
+/// `reacquire` if the first failure shows the reused session was stale.
+///
+/// `reacquire` takes ownership of the stale session so it can close it.
+async fn run_reacquiring<S, T, R, RFut>(
+	session: S,
+	source: impl Fn(&S) -> SessionSource,
+	f: &impl for<'s> Fn(&'s S) -> Pin<Box<dyn Future<Output = Result<T>> + 's>>,
+	reacquire: R,
+) -> Result<(S, Result<T>)>
+where
+	R: FnOnce(S) -> RFut,
+	RFut: Future<Output = Result<S>>,
+{
+	let res = f(&session).await;
+	if let Err(e) = &res
+		&& should_reacquire(e, source(&session))
+	{
+		warn!(target = "pw.session", source = ?source(&session), error = %e, "reused session is gone; reacquiring once");
+		let session = reacquire(session).await?;
+		let res = f(&session).await;
+		return Ok((session, res));
+	}
+	Ok((session, res))
+}
+
+/// Whether a failed command should be retried on a freshly acquired session.
+///
+/// Only sessions reused from a descriptor or the daemon can be stale; a
+/// target closing under a session launched for this command is a real failure.
+fn should_reacquire(error: &PwError, source: SessionSource) -> bool {
+	matches!(source, SessionSource::CachedDescriptor | SessionSource::Daemon) && error.is_target_closed()
+}
+
+/// Whether the browser behind `req` is visible to the user.
+///
+/// CDP attach targets the user's own browser window; DevTools forces a
+/// headed launch.
+fn is_headed(req: &SessionRequest<'_>) -> bool {
+	!req.headless || req.cdp_endpoint.is_some() || req.launch_config.devtools == Some(true)
+}
+
+/// Why `--pause` cannot block for this invocation, if it cannot.
+fn pause_skip_reason(mode: ExecMode, headed: bool) -> Option<&'static str> {
+	match (mode, headed) {
+		(ExecMode::Batch, _) => Some("--pause is ignored in batch mode"),
+		(_, false) => Some("--pause is ignored for headless browsers; use --devtools or connect"),
+		_ => None,
+	}
+}
+
+/// Blocks on stdin so the user can interact with the browser before it closes.
+async fn pause_for_inspection(mode: ExecMode, headed: bool) {
+	if let Some(reason) = pause_skip_reason(mode, headed) {
+		eprintln!("Warning: {reason}");
+		return;
+	}
+
+	eprintln!("Paused. Inspect the browser, then press Enter to continue...");
+	let _ = tokio::task::spawn_blocking(|| std::io::stdin().read_line(&mut String::new())).await;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn target_closed() -> PwError {
+		PwError::Playwright(pw_rs::Error::TargetClosed {
+			target_type: "page".into(),
+			context: "page.evaluate".into(),
+		})
+	}
+
+	#[test]
+	fn reused_sessions_reacquire_after_target_closed() {
+		assert!(should_reacquire(&target_closed(), SessionSource::Daemon));
+		assert!(should_reacquire(&target_closed(), SessionSource::CachedDescriptor));
+
+		let remote = PwError::Playwright(pw_rs::Error::Remote {
+			name: "TargetClosedError".into(),
+			message: "Target page, context or browser has been closed".into(),
+			stack: None,
+		});
+		assert!(should_reacquire(&remote, SessionSource::CachedDescriptor));
+	}
+
+	#[test]
+	fn fresh_sessions_do_not_reacquire() {
+		assert!(!should_reacquire(&target_closed(), SessionSource::Fresh));
+		assert!(!should_reacquire(&target_closed(), SessionSource::CdpConnect));
+	}
+
+	#[test]
+	fn other_errors_do_not_reacquire() {
+		let err = PwError::ElementNotFound { selector: "#missing".into() };
+		assert!(!should_reacquire(&err, SessionSource::Daemon));
+	}
+
+	struct FakeSession {
+		id: u32,
+		source: SessionSource,
+		alive: bool,
+	}
+
+	fn fake_source(session: &FakeSession) -> SessionSource {
+		session.source
+	}
+
+	fn evaluate(session: &FakeSession) -> Pin<Box<dyn Future<Output = Result<u32>> + '_>> {
+		Box::pin(async move { if session.alive { Ok(session.id) } else { Err(target_closed()) } })
+	}
+
+	#[tokio::test]
+	async fn stale_reused_session_is_replaced_and_command_rerun() {
+		let stale = FakeSession {
+			id: 1,
+			source: SessionSource::CachedDescriptor,
+			alive: false,
+		};
+		let mut reacquired_from = None;
+		let (session, res) = run_reacquiring(stale, fake_source, &evaluate, |old| {
+			reacquired_from = Some(old.id);
+			async {
+				Ok(FakeSession {
+					id: 2,
+					source: SessionSource::Fresh,
+					alive: true,
+				})
+			}
+		})
+		.await
+		.unwrap();
+
+		assert_eq!(reacquired_from, Some(1));
+		assert_eq!(session.id, 2);
+		assert_eq!(res.unwrap(), 2);
+	}
+
+	#[tokio::test]
+	async fn second_failure_after_reacquire_is_returned() {
+		let stale = FakeSession {
+			id: 1,
+			source: SessionSource::Daemon,
+			alive: false,
+		};
+		let (session, res) = run_reacquiring(stale, fake_source, &evaluate, |_| async {
+			Ok(FakeSession {
+				id: 2,
+				source: SessionSource::Fresh,
+				alive: false,
+			})
+		})
+		.await
+		.unwrap();
+
+		assert_eq!(session.id, 2);
+		assert!(res.unwrap_err().is_target_closed());
+	}
+
+	#[tokio::test]
+	async fn fresh_session_failure_is_not_retried() {
+		let fresh = FakeSession {
+			id: 1,
+			source: SessionSource::Fresh,
+			alive: false,
+		};
+		let (session, res) = run_reacquiring(fresh, fake_source, &evaluate, |_| async { panic!("fresh sessions must not be reacquired") })
+			.await
+			.unwrap();
+
+		assert_eq!(session.id, 1);
+		assert!(res.is_err());
+	}
+
+	#[test]
+	fn pause_is_skipped_in_headless_mode() {
+		assert!(pause_skip_reason(ExecMode::Cli, false).is_some());
+	}
+
+	#[test]
+	fn pause_is_skipped_in_batch_mode() {
+		assert!(pause_skip_reason(ExecMode::Batch, true).is_some());
+	}
+
+	#[test]
+	fn pause_blocks_for_headed_cli_sessions() {
+		assert_eq!(pause_skip_reason(ExecMode::Cli, true), None);
+	}
+}
@@ -24,6 +24,184 @@ impl std::fmt::Display for BrowserKind {
 	}
 }
 
+/// Emulated `prefers-color-scheme` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorScheme {
+	Light,
+	Dark,
+	NoPreference,
+}
+
+impl ColorScheme {
+	/// Returns the Playwright wire value.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			ColorScheme::Light => "light",
+			ColorScheme::Dark => "dark",
+			ColorScheme::NoPreference => "no-preference",
+		}
+	}
+}
+
+/// Emulated `prefers-reduced-motion` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReducedMotion {
+	Reduce,
+	NoPreference,
+}
+
+impl ReducedMotion {
+	/// Returns the Playwright wire value.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			ReducedMotion::Reduce => "reduce",
+			ReducedMotion::NoPreference => "no-preference",
+		}
+	}
+}
+
+/// How a protected URL pattern is matched against page URLs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PatternKind {
+	/// Case-insensitive substring of the URL.
+	#[default]
+	Substring,
+	/// Case-insensitive glob over the whole URL, where `*` matches any run of characters.
+	Glob,
+	/// Case-insensitive regular expression found anywhere in the URL.
+	Regex,
+}
+
+/// A protected URL pattern and how it matches.
+///
+/// Also deserializes from a plain string, the form older configs stored,
+/// which reads as a substring pattern. Globs and regexes are compiled once
+/// when the pattern is built.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "ProtectedUrlRepr")]
+pub struct ProtectedUrl {
+	pattern: String,
+	kind: PatternKind,
+	#[serde(skip)]
+	matcher: Matcher,
+}
+
+/// Compiled form of a [`ProtectedUrl`].
+#[derive(Clone, Debug)]
+enum Matcher {
+	/// Lowercased substring.
+	Substring(String),
+	Regex(regex_lite::Regex),
+	/// A regex that failed to compile, with the compiler's message.
+	Invalid(String),
+}
+
+impl Matcher {
+	fn compile(source: &str) -> Self {
+		match regex_lite::Regex::new(source) {
+			Ok(regex) => Self::Regex(regex),
+			Err(err) => Self::Invalid(err.to_string()),
+		}
+	}
+}
+
+impl PartialEq for ProtectedUrl {
+	fn eq(&self, other: &Self) -> bool {
+		self.pattern == other.pattern && self.kind == other.kind
+	}
+}
+
+impl Eq for ProtectedUrl {}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProtectedUrlRepr {
+	Plain(String),
+	Full {
+		pattern: String,
+		#[serde(default)]
+		kind: PatternKind,
+	},
+}
+
+impl From<ProtectedUrlRepr> for ProtectedUrl {
+	fn from(repr: ProtectedUrlRepr) -> Self {
+		match repr {
+			ProtectedUrlRepr::Plain(pattern) => Self::new(pattern, PatternKind::Substring),
+			ProtectedUrlRepr::Full { pattern, kind } => Self::new(pattern, kind),
+		}
+	}
+}
+
+impl ProtectedUrl {
+	pub fn new(pattern: impl Into<String>, kind: PatternKind) -> Self {
+		let pattern = pattern.into();
+		let matcher = match kind {
+			PatternKind::Substring => Matcher::Substring(pattern.to_lowercase()),
+			PatternKind::Glob => {
+				let parts: Vec<String> = pattern.split('*').map(regex_lite::escape).collect();
+				Matcher::compile(&format!("(?i)^{}$", parts.join(".*")))
+			}
+			PatternKind::Regex => Matcher::compile(&format!("(?i){pattern}")),
+		};
+		Self { pattern, kind, matcher }
+	}
+
+	/// Reads a pattern as typed: a `re:` prefix makes a regex, a `*` makes a glob,
+	/// and anything else is a substring.
+	pub fn parse(input: &str) -> Self {
+		if let Some(regex) = input.strip_prefix("re:") {
+			Self::new(regex, PatternKind::Regex)
+		} else if input.contains('*') {
+			Self::new(input, PatternKind::Glob)
+		} else {
+			Self::new(input, PatternKind::Substring)
+		}
+	}
+
+	/// The pattern as written, without any `re:` prefix.
+	pub fn pattern(&self) -> &str {
+		&self.pattern
+	}
+
+	pub fn kind(&self) -> PatternKind {
+		self.kind
+	}
+
+	/// Returns an error message if the pattern is not a valid regex.
+	pub fn validate(&self) -> std::result::Result<(), String> {
+		match &self.matcher {
+			Matcher::Invalid(err) => Err(format!("invalid regex '{}': {err}", self.pattern)),
+			_ => Ok(()),
+		}
+	}
+
+	/// Returns true if `url` matches.
+	///
+	/// An invalid regex, which `protect.add` rejects but a hand-edited config
+	/// can still contain, matches every URL so it fails closed.
+	pub fn matches(&self, url: &str) -> bool {
+		match &self.matcher {
+			Matcher::Substring(needle) => url.to_lowercase().contains(needle),
+			Matcher::Regex(regex) => regex.is_match(url),
+			Matcher::Invalid(_) => true,
+		}
+	}
+
+	/// Returns true if `other` is the same pattern, ignoring case.
+	pub fn same_as(&self, other: &ProtectedUrl) -> bool {
+		self.kind == other.kind && self.pattern.to_lowercase() == other.pattern.to_lowercase()
+	}
+}
+
+/// Returns true if `url` matches any of `patterns`.
+pub fn is_protected_url(url: &str, patterns: &[ProtectedUrl]) -> bool {
+	patterns.iter().any(|pattern| pattern.matches(url))
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct NavigateResult {
@@ -40,10 +218,55 @@ pub struct ConsoleMessage {
 	#[serde(rename = "type")]
 	pub msg_type: String,
 	pub text: String,
+	/// Script position that logged the message, when the browser reports one.
 	#[serde(skip_serializing_if = "Option::is_none")]
+	pub location: Option<ConsoleLocation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsoleLocation {
+	pub url: String,
+	pub line_number: u32,
+	pub column_number: u32,
+}
+
+impl From<pw_rs::ConsoleMessage> for ConsoleMessage {
+	fn from(message: pw_rs::ConsoleMessage) -> Self {
+		Self {
+			msg_type: message.kind().to_string(),
+			text: message.text().to_string(),
+			location: message.location().map(|loc| ConsoleLocation {
+				url: loc.url.clone(),
+				line_number: loc.line_number,
+				column_number: loc.column_number,
+			}),
+		}
+	}
+}
+
+/// Uncaught exception thrown by page JavaScript.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PageError {
+	/// Error name such as `TypeError`; empty when a non-`Error` value was thrown.
+	#[serde(default, skip_serializing_if = "String::is_empty")]
+	pub name: String,
+	pub message: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub stack: Option<String>,
 }
 
+impl From<pw_rs::PageError> for PageError {
+	fn from(error: pw_rs::PageError) -> Self {
+		Self {
+			name: error.name,
+			message: error.message,
+			stack: error.stack,
+		}
+	}
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ElementCoords {
@@ -75,6 +298,41 @@ pub struct IndexedElementCoords {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn protected_url_parse_infers_kind() {
+		assert_eq!(ProtectedUrl::parse("admin").kind(), PatternKind::Substring);
+		assert_eq!(ProtectedUrl::parse("*://*/admin/*").kind(), PatternKind::Glob);
+		assert_eq!(ProtectedUrl::parse("re:/admin($|/)"), ProtectedUrl::new("/admin($|/)", PatternKind::Regex));
+	}
+
+	#[test]
+	fn protected_url_matching_by_kind() {
+		let substring = ProtectedUrl::parse("Admin");
+		assert!(substring.matches("https://example.com/admin/panel"));
+		assert!(substring.matches("https://example.com/administrator-docs"));
+
+		let glob = ProtectedUrl::parse("*://example.com/admin/*");
+		assert!(glob.matches("https://EXAMPLE.com/admin/panel"));
+		assert!(!glob.matches("https://example.com/administrator-docs"));
+
+		let regex = ProtectedUrl::parse("re:/admin($|/)");
+		assert!(regex.matches("https://example.com/Admin/panel"));
+		assert!(!regex.matches("https://example.com/administrator-docs"));
+		assert!(!is_protected_url("https://example.com/public", &[substring, glob, regex]));
+	}
+
+	#[test]
+	fn protected_url_invalid_regex_fails_closed() {
+		let invalid = ProtectedUrl::parse("re:(admin");
+		assert!(invalid.validate().is_err());
+		assert!(invalid.matches("https://example.com/public"));
+		assert!(ProtectedUrl::parse("a.b*").validate().is_ok());
+
+		let loaded: ProtectedUrl = serde_json::from_str(r#"{"pattern":"(admin","kind":"regex"}"#).unwrap();
+		assert!(loaded.validate().is_err());
+		assert!(loaded.matches("https://example.com/public"));
+	}
+
 	#[test]
 	fn navigate_result_serializes() {
 		let result = NavigateResult {
@@ -91,15 +349,15 @@ mod tests {
 	}
 
 	#[test]
-	fn console_message_skips_none_stack() {
+	fn console_message_skips_none_location() {
 		let msg = ConsoleMessage {
 			msg_type: "log".into(),
 			text: "Test log".into(),
-			stack: None,
+			location: None,
 		};
 
 		let json = serde_json::to_string(&msg).unwrap();
-		assert!(!json.contains("stack"));
+		assert!(!json.contains("location"));
 	}
 
 	#[test]
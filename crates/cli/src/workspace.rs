@@ -5,7 +5,7 @@
 use std::collections::hash_map::DefaultHasher;
 use std::ffi::OsString;
 use std::hash::{Hash, Hasher};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use pw_rs::dirs;
 
@@ -35,7 +35,7 @@ impl WorkspaceScope {
 	/// * `profile`: optional profile; defaults to [`DEFAULT_PROFILE`].
 	/// * `no_project`: when true, skip playwright project-root detection.
 	pub fn resolve(workspace: Option<&str>, profile: Option<&str>, no_project: bool) -> Result<Self> {
-		let profile = normalize_profile(profile.unwrap_or(DEFAULT_PROFILE));
+		let profile = checked_profile(profile.unwrap_or(DEFAULT_PROFILE))?;
 		let root = resolve_workspace_root(workspace, no_project)?;
 		Ok(Self::from_parts(root, profile))
 	}
@@ -114,6 +114,19 @@ pub fn normalize_profile(profile: &str) -> String {
 	}
 }
 
+/// Normalizes `profile` and rejects names that are not a single directory name.
+///
+/// [`normalize_profile`] keeps `.`, so `.` and `..` would otherwise resolve to
+/// the `profiles/` directory or the whole state root.
+pub fn checked_profile(profile: &str) -> Result<String> {
+	let name = normalize_profile(profile);
+	let mut components = Path::new(&name).components();
+	match (components.next(), components.next()) {
+		(Some(Component::Normal(_)), None) if !name.chars().all(|c| c == '.') => Ok(name),
+		_ => Err(PwError::Context(format!("invalid profile name '{profile}'"))),
+	}
+}
+
 /// Backward compatibility alias.
 pub fn normalize_namespace(namespace: &str) -> String {
 	normalize_profile(namespace)
@@ -242,6 +255,14 @@ mod tests {
 		assert_eq!(profile, DEFAULT_PROFILE);
 	}
 
+	#[test]
+	fn checked_profile_rejects_dot_names() {
+		assert_eq!(checked_profile("work.v2").unwrap(), "work.v2");
+		for name in [".", "..", "..."] {
+			assert!(checked_profile(name).is_err(), "{name}");
+		}
+	}
+
 	#[test]
 	fn session_key_is_deterministic() {
 		let scope = WorkspaceScope::from_parts(PathBuf::from("/tmp/ws"), "abc".to_string());
@@ -1,7 +1,10 @@
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 fn pw_binary() -> PathBuf {
 	let mut path = std::env::current_exe().unwrap();
@@ -26,12 +29,17 @@ fn clear_context_store() {
 }
 
 fn run_pw_batch(lines: &[&str]) -> (bool, String, String) {
+	run_pw_batch_with_args(&[], lines)
+}
+
+fn run_pw_batch_with_args(extra_args: &[&str], lines: &[&str]) -> (bool, String, String) {
 	let workspace = workspace_root();
 	let _ = std::fs::create_dir_all(&workspace);
 
 	let mut child = Command::new(pw_binary())
 		.current_dir(&workspace)
 		.args(["-f", "ndjson", "batch", "--profile", "default"])
+		.args(extra_args)
 		.stdin(Stdio::piped())
 		.stdout(Stdio::piped())
 		.stderr(Stdio::piped())
@@ -121,3 +129,334 @@ fn batch_alias_is_rejected() {
 	assert_eq!(first["op"], "har-show");
 	assert_eq!(first["error"]["code"], "INVALID_INPUT");
 }
+
+/// Replays `lines` from an NDJSON file passed with `--input`.
+fn run_pw_batch_file(extra_args: &[&str], lines: &[&str]) -> (bool, String, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let script = workspace.join("script.ndjson");
+	std::fs::write(&script, lines.join("\n")).expect("failed to write batch script");
+
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "ndjson", "batch", "--profile", "default", "--input"])
+		.arg(&script)
+		.args(extra_args)
+		.stdin(Stdio::null())
+		.output()
+		.expect("failed to run pw batch");
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	(output.status.success(), stdout, stderr)
+}
+
+const SCRIPT_WITH_FAILURE: [&str; 3] = [
+	r#"{"schemaVersion":5,"requestId":"1","op":"ping","input":{}}"#,
+	r#"{"schemaVersion":5,"requestId":"2","op":"har-show","input":{}}"#,
+	r#"{"schemaVersion":5,"requestId":"3","op":"har.show","input":{}}"#,
+];
+
+#[test]
+fn batch_input_file_stops_at_first_failure() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch_file(&[], &SCRIPT_WITH_FAILURE);
+
+	assert!(!success, "a halted batch should exit non-zero");
+	assert!(stderr.contains("--continue-on-error"), "{stderr}");
+	let lines = parse_ndjson(&stdout);
+	assert_eq!(lines.len(), 2, "expected the batch to stop after request 2, got: {stdout}");
+	assert_eq!(lines[0]["requestId"], "1");
+	assert_eq!(lines[1]["requestId"], "2");
+	assert_eq!(lines[1]["error"]["code"], "INVALID_INPUT");
+}
+
+#[test]
+fn batch_input_file_continue_on_error_runs_every_line() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch_file(&["--continue-on-error"], &SCRIPT_WITH_FAILURE);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let summary: Vec<_> = lines.iter().map(|line| (line["requestId"].clone(), line["ok"].clone())).collect();
+	assert_eq!(
+		summary,
+		vec![
+			(serde_json::json!("1"), serde_json::json!(true)),
+			(serde_json::json!("2"), serde_json::json!(false)),
+			(serde_json::json!("3"), serde_json::json!(true)),
+		]
+	);
+}
+
+#[test]
+fn batch_eval_reports_downloads_it_triggers() {
+	clear_context_store();
+	let downloads_dir = std::env::temp_dir().join("pw-cli-batch-downloads");
+	let _ = std::fs::remove_dir_all(&downloads_dir);
+
+	let request = serde_json::json!({
+		"schemaVersion": 5,
+		"requestId": "1",
+		"op": "page.eval",
+		"input": {
+			"url": "data:text/html,<body></body>",
+			"expression": "new Promise(resolve => { const a = document.createElement('a'); a.href = 'data:text/plain,hello'; a.download = 'hello.txt'; document.body.appendChild(a); a.click(); setTimeout(() => resolve(true), 1000); })"
+		},
+		"runtime": { "overrides": { "downloadsDir": downloads_dir } }
+	})
+	.to_string();
+	let (success, stdout, stderr) = run_pw_batch(&[&request, r#"{"schemaVersion":5,"requestId":"2","op":"quit","input":{}}"#]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let first = &lines[0];
+	assert_eq!(first["ok"], true, "page.eval failed: {first}");
+	assert_eq!(first["downloads"][0]["suggestedFilename"], "hello.txt");
+	assert!(downloads_dir.join("hello.txt").exists());
+}
+
+#[test]
+fn batch_tabs_new_reports_indices_matching_tabs_list() {
+	clear_context_store();
+
+	// `session.start` keeps one browser running so every line below shares its tabs.
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r#"{"schemaVersion":5,"requestId":"1","op":"session.start","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"tabs.new","input":{"url":"data:text/html,<title>First</title>"}}"#,
+		r#"{"schemaVersion":5,"requestId":"3","op":"tabs.new","input":{"url":"data:text/html,<title>Second</title>"}}"#,
+		r#"{"schemaVersion":5,"requestId":"4","op":"tabs.new","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"5","op":"tabs.list","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"6","op":"session.stop","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"7","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let response = |id: &str| {
+		let line = lines
+			.iter()
+			.find(|line| line["requestId"] == id)
+			.unwrap_or_else(|| panic!("response {id} missing: {stdout}"));
+		assert_eq!(line["ok"], true, "request {id} failed: {line}");
+		&line["data"]
+	};
+	let first = response("2");
+	let second = response("3");
+	let blank = response("4");
+	assert_eq!(first["title"], "First");
+	assert_eq!(second["title"], "Second");
+	assert_eq!(second["url"], "data:text/html,<title>Second</title>");
+	assert_eq!(blank["navigated"], false);
+	assert_eq!(blank["url"], "about:blank");
+
+	let tabs = response("5")["tabs"].as_array().expect("tabs.list returns tabs");
+	for created in [first, second] {
+		let index = created["index"].as_u64().unwrap() as usize;
+		assert_eq!(tabs[index]["title"], created["title"], "tabs.list disagrees at index {index}: {tabs:?}");
+		assert_eq!(tabs[index]["url"], created["url"]);
+	}
+	assert_ne!(first["index"], second["index"]);
+}
+
+#[test]
+fn batch_scrolls_page_by_wheel_delta() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r#"{"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":"data:text/html,<div style='height:5000px'>Tall</div>"}}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"page.scroll","input":{"y":1500}}"#,
+		r#"{"schemaVersion":5,"requestId":"3","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let scroll = lines.iter().find(|line| line["requestId"] == "2").expect("scroll response missing");
+	assert_eq!(scroll["ok"], true, "scroll failed: {scroll}");
+	assert_eq!(scroll["op"], "page.scroll");
+	assert!(scroll["data"]["scrollY"].as_f64().unwrap() >= 1000.0, "page did not scroll: {scroll}");
+}
+
+#[test]
+fn batch_hover_opens_css_submenu() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r#"{"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":"data:text/html,<style>.sub{display:none}.menu:hover .sub{display:block}</style><div class=menu>Account<ul class=sub><li>Settings</li></ul></div>"}}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"page.eval","input":{"expression":"getComputedStyle(document.querySelector('.sub')).display"}}"#,
+		r#"{"schemaVersion":5,"requestId":"3","op":"page.hover","input":{"selector":".menu"}}"#,
+		r#"{"schemaVersion":5,"requestId":"4","op":"page.eval","input":{"expression":"getComputedStyle(document.querySelector('.sub')).display"}}"#,
+		r#"{"schemaVersion":5,"requestId":"5","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let response = |id: &str| {
+		lines
+			.iter()
+			.find(|line| line["requestId"] == id)
+			.unwrap_or_else(|| panic!("response {id} missing: {stdout}"))
+	};
+	assert_eq!(response("2")["data"]["result"], "none");
+	assert_eq!(response("3")["ok"], true, "hover failed: {}", response("3"));
+	assert_eq!(response("3")["data"]["selector"], ".menu");
+	assert_eq!(response("4")["data"]["result"], "block");
+}
+
+#[test]
+fn batch_select_and_upload_set_form_controls() {
+	clear_context_store();
+
+	let upload_dir = std::env::temp_dir().join("pw-cli-batch-upload");
+	std::fs::create_dir_all(&upload_dir).unwrap();
+	let notes = upload_dir.join("notes.txt");
+	std::fs::write(&notes, "hello").unwrap();
+
+	let page = "<select id=size><option value=s>Small</option><option value=l>Large</option></select>\
+		<select id=tags multiple><option value=a>A</option><option value=b>B</option><option value=c>C</option></select>\
+		<input type=file id=doc>";
+	let navigate = serde_json::json!({"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":format!("data:text/html,{page}")}}).to_string();
+	let upload = serde_json::json!({"schemaVersion":5,"requestId":"4","op":"page.upload","input":{"selector":"#doc","file":notes}}).to_string();
+	let (success, stdout, stderr) = run_pw_batch(&[
+		&navigate,
+		r##"{"schemaVersion":5,"requestId":"2","op":"page.select","input":{"selector":"#size","label":"Large"}}"##,
+		r##"{"schemaVersion":5,"requestId":"3","op":"page.select","input":{"selector":"#tags","value":"a","index":2}}"##,
+		&upload,
+		r#"{"schemaVersion":5,"requestId":"5","op":"page.eval","input":{"expression":"document.querySelector('#doc').files[0].name"}}"#,
+		r#"{"schemaVersion":5,"requestId":"6","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let response = |id: &str| {
+		lines
+			.iter()
+			.find(|line| line["requestId"] == id)
+			.unwrap_or_else(|| panic!("response {id} missing: {stdout}"))
+	};
+	assert_eq!(response("2")["data"]["values"], serde_json::json!(["l"]), "select failed: {}", response("2"));
+	assert_eq!(response("3")["data"]["values"], serde_json::json!(["a", "c"]));
+	assert_eq!(
+		response("4")["data"]["files"],
+		serde_json::json!(["notes.txt"]),
+		"upload failed: {}",
+		response("4")
+	);
+	assert_eq!(response("5")["data"]["result"], "notes.txt");
+}
+
+#[test]
+fn batch_form_fill_handles_text_checkbox_and_select() {
+	clear_context_store();
+
+	let form = concat!(
+		"<form><input name=email><input type=checkbox name=terms checked><input type=checkbox id=news>",
+		"<select name=plan><option value=basic>Basic</option><option value=pro>Pro</option></select></form>"
+	);
+	let navigate = serde_json::json!({"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":format!("data:text/html,{form}")}}).to_string();
+	let fill = serde_json::json!({"schemaVersion":5,"requestId":"2","op":"form-fill","input":{"fields":{
+		"email": "me@example.com",
+		"terms": false,
+		"#news": true,
+		"plan": "Pro",
+		"missing": "x"
+	}}})
+	.to_string();
+	let (success, stdout, stderr) = run_pw_batch(&[
+		&navigate,
+		&fill,
+		r#"{"schemaVersion":5,"requestId":"3","op":"page.eval","input":{"expression":"[document.querySelector('[name=email]').value, document.querySelector('[name=terms]').checked, document.querySelector('#news').checked, document.querySelector('[name=plan]').value].join('|')"}}"#,
+		r#"{"schemaVersion":5,"requestId":"4","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let response = |id: &str| {
+		lines
+			.iter()
+			.find(|line| line["requestId"] == id)
+			.unwrap_or_else(|| panic!("response {id} missing: {stdout}"))
+	};
+
+	let data = &response("2")["data"];
+	assert_eq!(data["filled"], 4, "unexpected form-fill result: {data}");
+	assert_eq!(data["failed"], 1);
+	let actions: Vec<&str> = data["fields"].as_array().unwrap().iter().map(|f| f["action"].as_str().unwrap_or("-")).collect();
+	assert_eq!(actions, vec!["check", "fill", "-", "select", "uncheck"]);
+	assert_eq!(response("3")["data"]["result"], "me@example.com|false|true|pro");
+}
+
+type ArrivalLog = Arc<Mutex<Vec<(String, Instant)>>>;
+
+/// Serves `<p>{path}</p>` after `delay`, one thread per connection, recording
+/// when each path was first requested. Returns the base URL and the log.
+fn serve_slowly(delay: Duration) -> (String, ArrivalLog) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base = format!("http://{}", listener.local_addr().unwrap());
+	let log = Arc::new(Mutex::new(Vec::new()));
+	let server_log = Arc::clone(&log);
+
+	std::thread::spawn(move || {
+		for stream in listener.incoming() {
+			let Ok(mut stream) = stream else { continue };
+			let log = Arc::clone(&server_log);
+			std::thread::spawn(move || {
+				let mut reader = BufReader::new(stream.try_clone().unwrap());
+				let mut request_line = String::new();
+				if reader.read_line(&mut request_line).is_err() {
+					return;
+				}
+				let mut header = String::new();
+				while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+					header.clear();
+				}
+
+				let path = request_line.split_whitespace().nth(1).unwrap_or("/").to_string();
+				{
+					let mut log = log.lock().unwrap();
+					if !log.iter().any(|(seen, _)| *seen == path) {
+						log.push((path.clone(), Instant::now()));
+					}
+				}
+				std::thread::sleep(delay);
+				let body = format!("<p>{path}</p>");
+				let response = format!(
+					"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+					body.len()
+				);
+				let _ = stream.write_all(response.as_bytes());
+			});
+		}
+	});
+
+	(base, log)
+}
+
+#[test]
+fn batch_max_concurrency_overlaps_reads_of_different_pages() {
+	clear_context_store();
+	let delay = Duration::from_secs(3);
+	let (base, log) = serve_slowly(delay);
+
+	let first = format!(r#"{{"schemaVersion":5,"requestId":"a","op":"page.text","input":{{"url":"{base}/a","selector":"p"}}}}"#);
+	let second = format!(r#"{{"schemaVersion":5,"requestId":"b","op":"page.text","input":{{"url":"{base}/b","selector":"p"}}}}"#);
+	let (success, stdout, stderr) = run_pw_batch_with_args(
+		&["--max-concurrency", "2"],
+		&[&first, &second, r#"{"schemaVersion":5,"requestId":"q","op":"quit","input":{}}"#],
+	);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	for id in ["a", "b"] {
+		let response = lines.iter().find(|line| line["requestId"] == id).expect("missing response");
+		assert_eq!(response["ok"], true, "request {id} failed: {response}");
+	}
+	assert_eq!(lines.last().unwrap()["op"], "quit");
+
+	let log = log.lock().unwrap();
+	let arrival = |path: &str| log.iter().find(|(seen, _)| seen == path).map(|(_, at)| *at).expect("page was not requested");
+	let (a, b) = (arrival("/a"), arrival("/b"));
+	let gap = if a > b { a - b } else { b - a };
+	assert!(gap < delay, "second read started {gap:?} after the first; expected overlap within {delay:?}");
+}
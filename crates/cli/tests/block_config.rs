@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
+
+use serde_json::json;
+
+static BLOCK_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_block() -> std::sync::MutexGuard<'static, ()> {
+	BLOCK_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().unwrap();
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-block-config")
+}
+
+fn clear_context_store() {
+	let _ = std::fs::remove_dir_all(workspace_root());
+}
+
+fn run_exec_json(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str::<serde_json::Value>(&stdout).expect("expected valid JSON output");
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn block_list_is_empty_by_default() {
+	let _lock = lock_block();
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec_json("block.list", json!({}));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["count"], 0);
+	assert_eq!(json["data"]["patterns"], json!([]));
+}
+
+#[test]
+fn block_set_persists_across_invocations() {
+	let _lock = lock_block();
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec_json("block.set", json!({ "pattern": "*.png" }));
+	assert!(success, "block.set failed: {stderr}");
+	assert_eq!(json["data"]["added"], true);
+	assert_eq!(json["data"]["urlGlob"], false);
+
+	let (success, json, stderr) = run_exec_json("block.set", json!({ "pattern": "**/analytics/**" }));
+	assert!(success, "block.set failed: {stderr}");
+	assert_eq!(json["data"]["urlGlob"], true);
+
+	let (success, json, stderr) = run_exec_json("block.set", json!({ "pattern": "*.png" }));
+	assert!(success, "block.set failed: {stderr}");
+	assert_eq!(json["data"]["added"], false, "duplicate patterns are not added twice");
+
+	let (success, json, stderr) = run_exec_json("block.list", json!({}));
+	assert!(success, "block.list failed: {stderr}");
+	assert_eq!(json["data"]["patterns"], json!(["*.png", "**/analytics/**"]));
+
+	let config_path = workspace_root()
+		.join("playwright")
+		.join(".pw-cli-v4")
+		.join("profiles")
+		.join("default")
+		.join("config.json");
+	let config = std::fs::read_to_string(&config_path).unwrap_or_else(|e| panic!("failed to read {}: {}", config_path.display(), e));
+	let value: serde_json::Value = serde_json::from_str(&config).expect("expected valid config JSON");
+	assert_eq!(value["network"]["blockPatterns"], json!(["*.png", "**/analytics/**"]));
+}
+
+#[test]
+fn block_clear_removes_all_patterns() {
+	let _lock = lock_block();
+	clear_context_store();
+
+	let (success, _json, stderr) = run_exec_json("block.set", json!({ "pattern": "*googletagmanager*" }));
+	assert!(success, "block.set failed: {stderr}");
+
+	let (success, json, stderr) = run_exec_json("block.clear", json!({}));
+	assert!(success, "block.clear failed: {stderr}");
+	assert_eq!(json["data"]["cleared"], true);
+	assert_eq!(json["data"]["removed"], 1);
+
+	let (success, json, stderr) = run_exec_json("block.list", json!({}));
+	assert!(success, "block.list failed: {stderr}");
+	assert_eq!(json["data"]["count"], 0);
+}
+
+#[test]
+fn block_set_rejects_empty_pattern() {
+	let _lock = lock_block();
+	clear_context_store();
+
+	let (_success, json, _stderr) = run_exec_json("block.set", json!({ "pattern": "  " }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
@@ -1,18 +1,12 @@
 //! Integration tests for click navigation detection in protocol v2.
 
+mod common;
+
 use std::path::PathBuf;
-use std::process::Command;
 
+use common::{run_exec_in, serve_html};
 use serde_json::json;
 
-fn pw_binary() -> PathBuf {
-	let mut path = std::env::current_exe().unwrap();
-	path.pop();
-	path.pop();
-	path.push("pw");
-	path
-}
-
 fn workspace_root() -> PathBuf {
 	std::env::temp_dir().join("pw-cli-click-navigation")
 }
@@ -22,19 +16,177 @@ fn clear_context_store() {
 }
 
 fn run_exec(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
-	let workspace = workspace_root();
-	let _ = std::fs::create_dir_all(&workspace);
-	let output = Command::new(pw_binary())
-		.current_dir(&workspace)
-		.args(["-f", "json", "exec", op, "--input"])
-		.arg(input.to_string())
-		.output()
-		.expect("failed to execute pw");
-
-	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-	let parsed = serde_json::from_str::<serde_json::Value>(&stdout).unwrap_or_else(|_| json!({ "raw": stdout }));
-	(output.status.success(), parsed, stderr)
+	run_exec_in(&workspace_root(), op, input)
+}
+
+#[test]
+fn click_link_to_same_path_with_new_query_reports_navigated() {
+	clear_context_store();
+	let base = serve_html(vec![
+		("/list?page=1", "<title>Page 1</title><a id='next' href='/list?page=2'>Next</a>"),
+		("/list?page=2", "<title>Page 2</title><p>Second page</p>"),
+	]);
+
+	let (success, json, stderr) = run_exec("click", json!({ "url": format!("{base}/list?page=1"), "selector": "#next", "waitMs": 5000 }));
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["navigated"], true);
+	assert_eq!(json["data"]["beforeUrl"], format!("{base}/list?page=1"));
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/list?page=2"));
+}
+
+#[test]
+fn click_link_reloading_same_url_reports_navigated() {
+	clear_context_store();
+	let base = serve_html(vec![("/self", "<title>Self</title><a id='again' href='/self'>Reload</a>")]);
+
+	let (success, json, stderr) = run_exec("click", json!({ "url": format!("{base}/self"), "selector": "#again", "waitMs": 5000 }));
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["data"]["navigated"], true);
+	assert_eq!(json["data"]["beforeUrl"], json["data"]["afterUrl"]);
+}
+
+#[test]
+fn click_blank_link_captures_popup() {
+	clear_context_store();
+	let base = serve_html(vec![
+		(
+			"/opener",
+			"<title>Opener</title><a id='login' href='/oauth?client=pw' target='_blank'>Log in</a>",
+		),
+		("/oauth?client=pw", "<title>Sign in</title><p>Authorize</p>"),
+	]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/opener"), "selector": "#login", "capturePopup": true, "waitMs": 5000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["navigated"], false);
+	assert_eq!(json["data"]["popup"]["url"], format!("{base}/oauth?client=pw"));
+	assert_eq!(json["data"]["popup"]["title"], "Sign in");
+}
+
+#[test]
+fn force_click_reaches_element_behind_transparent_overlay() {
+	clear_context_store();
+	let base = serve_html(vec![(
+		"/covered",
+		"<button id='target' onclick=\"location.hash = 'hit'\">Buy</button>\
+		 <div style='position:fixed;inset:0;background:transparent' \
+		 onclick=\"if (document.elementsFromPoint(event.clientX, event.clientY).some(el => el.id === 'target')) location.hash = 'hit'\"></div>",
+	)]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/covered"), "selector": "#target", "force": true, "waitMs": 2000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["inputs"]["force"], true);
+	assert_eq!(json["data"]["navigated"], true);
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/covered#hit"));
+}
+
+#[test]
+fn click_canvas_at_position_records_offset() {
+	clear_context_store();
+	let base = serve_html(vec![(
+		"/canvas",
+		"<body style='margin:0'><canvas id='pad' width='200' height='100' \
+		 onclick=\"location.hash = event.offsetX + ',' + event.offsetY\"></canvas></body>",
+	)]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/canvas"), "selector": "#pad", "position": "30,40", "waitMs": 2000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/canvas#30,40"));
+	assert!(json["data"]["warnings"].is_null());
+}
+
+#[test]
+fn click_position_outside_element_warns() {
+	clear_context_store();
+	let base = serve_html(vec![("/small", "<button id='b' style='width:50px;height:20px'>Go</button>")]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/small"), "selector": "#b", "position": "80,5", "force": true }),
+	);
+	assert!(success, "click failed: {stderr}");
+	let warning = json["data"]["warnings"][0].as_str().unwrap_or_default();
+	assert!(warning.contains("outside"), "expected bounds warning: {warning}");
+}
+
+#[test]
+fn shift_click_reaches_modifier_aware_handler() {
+	clear_context_store();
+	let base = serve_html(vec![(
+		"/list",
+		"<ul><li id='row' onclick=\"location.hash = event.shiftKey && event.ctrlKey ? 'range' : 'single'\">Row</li></ul>",
+	)]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/list"), "selector": "#row", "modifiers": "shift,ctrl", "waitMs": 2000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["inputs"]["modifiers"], "shift,ctrl");
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/list#range"));
+}
+
+#[test]
+fn unknown_modifier_is_rejected() {
+	clear_context_store();
+	let (_success, json, _stderr) = run_exec("click", json!({ "url": "data:text/html,<a>x</a>", "selector": "a", "modifiers": "hyper" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+	assert!(json["error"]["message"].as_str().unwrap_or_default().contains("unknown modifier"));
+}
+
+#[test]
+fn right_click_fires_contextmenu_handler() {
+	clear_context_store();
+	let base = serve_html(vec![(
+		"/menu",
+		"<div id='file' style='width:100px;height:30px' \
+		 oncontextmenu=\"event.preventDefault(); location.hash = 'menu-' + event.button\">report.pdf</div>",
+	)]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/menu"), "selector": "#file", "button": "right", "waitMs": 2000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["inputs"]["button"], "right");
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/menu#menu-2"));
+}
+
+#[test]
+fn triple_click_selects_paragraph() {
+	clear_context_store();
+	let base = serve_html(vec![(
+		"/article",
+		"<p id='lead' onclick=\"if (event.detail === 3) location.hash = encodeURIComponent(getSelection().toString().trim())\">Select this line</p>",
+	)]);
+
+	let (success, json, stderr) = run_exec(
+		"click",
+		json!({ "url": format!("{base}/article"), "selector": "#lead", "clickCount": 3, "waitMs": 2000 }),
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["inputs"]["clickCount"], 3);
+	assert_eq!(json["data"]["afterUrl"], format!("{base}/article#Select%20this%20line"));
+}
+
+#[test]
+fn zero_click_count_is_rejected() {
+	clear_context_store();
+	let (_success, json, _stderr) = run_exec("click", json!({ "url": "data:text/html,<p>x</p>", "selector": "p", "clickCount": 0 }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
 }
 
 #[test]
@@ -0,0 +1,83 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::json;
+
+/// Path to the `pw` binary built alongside the test executable.
+pub fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().unwrap();
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+/// Runs `pw -f json exec <op>` in `workspace`, returning success, the parsed envelope and stderr.
+///
+/// Output that is not JSON comes back as `{"raw": stdout}`.
+pub fn run_exec_in(workspace: &Path, op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	let _ = std::fs::create_dir_all(workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str::<serde_json::Value>(&stdout).unwrap_or_else(|_| json!({ "raw": stdout }));
+	(output.status.success(), parsed, stderr)
+}
+
+/// Serves raw HTTP responses keyed by request path (including query) and returns the base URL.
+///
+/// Unknown paths get a 404. The server thread lives until the test process exits.
+pub fn serve(routes: Vec<(&'static str, String)>) -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base = format!("http://{}", listener.local_addr().unwrap());
+
+	std::thread::spawn(move || {
+		for stream in listener.incoming() {
+			let Ok(mut stream) = stream else { continue };
+			let mut reader = BufReader::new(stream.try_clone().unwrap());
+			let mut request_line = String::new();
+			if reader.read_line(&mut request_line).is_err() {
+				continue;
+			}
+			let mut header = String::new();
+			while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+				header.clear();
+			}
+
+			let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+			let response = routes
+				.iter()
+				.find(|(route, _)| *route == path)
+				.map(|(_, response)| response.clone())
+				.unwrap_or_else(|| "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string());
+			let _ = stream.write_all(response.as_bytes());
+		}
+	});
+
+	base
+}
+
+/// Serves HTML bodies keyed by request path; see [`serve`].
+pub fn serve_html(routes: Vec<(&'static str, &'static str)>) -> String {
+	serve(routes.into_iter().map(|(path, body)| (path, html(body))).collect())
+}
+
+/// A `200 OK` response carrying `body` as HTML.
+pub fn html(body: &str) -> String {
+	format!(
+		"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+		body.len()
+	)
+}
@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
+
+use serde_json::json;
+
+static DOWNLOAD_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_download() -> std::sync::MutexGuard<'static, ()> {
+	DOWNLOAD_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().unwrap();
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-download-config")
+}
+
+fn clear_context_store() {
+	let _ = std::fs::remove_dir_all(workspace_root());
+}
+
+fn run_exec_json(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str::<serde_json::Value>(&stdout).expect("expected valid JSON output");
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn download_list_without_dir_is_empty() {
+	let _lock = lock_download();
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec_json("download.list", json!({}));
+	assert!(success, "command failed: {stderr}");
+	assert!(json["data"]["dir"].is_null());
+	assert_eq!(json["data"]["count"], 0);
+}
+
+#[test]
+fn download_set_dir_persists_and_list_reports_sizes() {
+	let _lock = lock_download();
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec_json("download.set-dir", json!({ "path": "saved" }));
+	assert!(success, "download.set-dir failed: {stderr}");
+	assert_eq!(json["data"]["changed"], true);
+
+	let config_path = workspace_root()
+		.join("playwright")
+		.join(".pw-cli-v4")
+		.join("profiles")
+		.join("default")
+		.join("config.json");
+	let config = std::fs::read_to_string(&config_path).unwrap_or_else(|e| panic!("failed to read {}: {}", config_path.display(), e));
+	let value: serde_json::Value = serde_json::from_str(&config).expect("expected valid config JSON");
+	assert_eq!(value["downloads"]["dir"], "saved");
+
+	let saved = workspace_root().join("saved");
+	std::fs::create_dir_all(&saved).unwrap();
+	std::fs::write(saved.join("report.csv"), "id,name\n1,a\n").unwrap();
+	std::fs::write(saved.join("notes.txt"), "hi").unwrap();
+
+	let (success, json, stderr) = run_exec_json("download.list", json!({}));
+	assert!(success, "download.list failed: {stderr}");
+	assert_eq!(
+		json["data"]["dir"],
+		saved.to_string_lossy().as_ref(),
+		"relative dirs resolve against the workspace"
+	);
+	assert_eq!(json["data"]["count"], 2);
+	assert_eq!(json["data"]["totalBytes"], 14);
+	assert_eq!(json["data"]["files"][0]["name"], "notes.txt");
+	assert_eq!(json["data"]["files"][0]["sizeBytes"], 2);
+	assert_eq!(json["data"]["files"][1]["name"], "report.csv");
+}
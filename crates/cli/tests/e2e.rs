@@ -62,6 +62,314 @@ fn screenshot_creates_file() {
 	let _ = std::fs::remove_file(&output_path);
 }
 
+#[test]
+fn screenshot_clip_captures_region() {
+	let output_path = std::env::temp_dir().join("pw-test-screenshot-clip.png");
+	let _ = std::fs::remove_file(&output_path);
+
+	let (success, json, stderr) = run_exec(
+		"screenshot",
+		json!({
+			"url": "data:text/html,<h1>Clipped</h1>",
+			"output": output_path.to_string_lossy().to_string(),
+			"clip": "10,20,100,50"
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["clip"], json!({ "x": 10, "y": 20, "width": 100, "height": 50 }));
+	assert_eq!(json["inputs"]["clip"], "10,20,100,50");
+
+	// PNG IHDR stores width and height as big-endian u32s at offsets 16 and 20.
+	let bytes = std::fs::read(&output_path).expect("screenshot file was not created");
+	let _ = std::fs::remove_file(&output_path);
+	assert_eq!(u32::from_be_bytes(bytes[16..20].try_into().unwrap()), 100);
+	assert_eq!(u32::from_be_bytes(bytes[20..24].try_into().unwrap()), 50);
+}
+
+#[test]
+fn screenshot_clip_conflicts_with_full_page() {
+	let (_success, json, _stderr) = run_exec(
+		"screenshot",
+		json!({ "url": "data:text/html,<h1>x</h1>", "clip": "0,0,10,10", "fullPage": true }),
+	);
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
+
+#[test]
+fn screenshot_freeze_animations_is_stable() {
+	let temp_dir = std::env::temp_dir();
+	let fixture = "data:text/html,<style>.box{width:80px;height:80px;background:rgb(200,0,0);animation:slide 400ms linear infinite alternate}@keyframes slide{from{transform:translateX(0)}to{transform:translateX(300px)}}</style><div class='box'></div>";
+
+	let capture = |name: &str| {
+		let output_path = temp_dir.join(name);
+		let _ = std::fs::remove_file(&output_path);
+		let (success, json, stderr) = run_exec(
+			"screenshot",
+			json!({
+				"url": fixture,
+				"output": output_path.to_string_lossy().to_string(),
+				"freezeAnimations": true,
+				"animations": "disabled"
+			}),
+		);
+		assert!(success, "command failed: {stderr}");
+		assert_eq!(json["inputs"]["freezeAnimations"], true);
+		let bytes = std::fs::read(&output_path).expect("screenshot file was not created");
+		let _ = std::fs::remove_file(&output_path);
+		bytes
+	};
+
+	let first = capture("pw-test-frozen-1.png");
+	std::thread::sleep(std::time::Duration::from_millis(150));
+	let second = capture("pw-test-frozen-2.png");
+	assert!(!first.is_empty());
+	assert_eq!(first, second, "frozen captures of an animated page differ");
+}
+
+#[test]
+fn snapshot_reports_configured_and_measured_viewport() {
+	let (success, json, stderr) = run_exec(
+		"page.snapshot",
+		json!({
+			"url": "data:text/html,<body style='margin:0'><div style='height:5000px'>Tall</div></body>",
+			"textOnly": true
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let data = &json["data"];
+	let configured_width = data["configuredViewport"]["width"].as_i64().expect("configured viewport width");
+	let configured_height = data["configuredViewport"]["height"].as_i64().expect("configured viewport height");
+	assert_eq!(data["viewportWidth"].as_i64(), Some(configured_width));
+	assert_eq!(data["viewportHeight"].as_i64(), Some(configured_height));
+	let client_width = data["clientWidth"].as_i64().unwrap();
+	assert!(
+		client_width > 0 && client_width <= configured_width,
+		"scrollbar-adjusted width {client_width} exceeds {configured_width}"
+	);
+}
+
+#[test]
+fn page_meta_extracts_opengraph_and_json_ld() {
+	let html = concat!(
+		"<html><head><title>Meta Fixture</title>",
+		"<meta name='description' content='A fixture page'>",
+		"<meta name='robots' content='noindex'>",
+		"<link rel='canonical' href='https://example.com/fixture'>",
+		"<meta property='og:title' content='OG Fixture'>",
+		"<meta property='og:type' content='article'>",
+		"<meta name='twitter:card' content='summary'>",
+		"<script type='application/ld+json'>{\"@type\":\"Article\",\"headline\":\"Fixture\"}</script>",
+		"</head><body>Body</body></html>"
+	);
+	let (success, json, stderr) = run_exec("page.meta", json!({ "url": format!("data:text/html,{}", html.replace('#', "%23")) }));
+	assert!(success, "command failed: {stderr}");
+	let data = &json["data"];
+	assert_eq!(data["title"], "Meta Fixture");
+	assert_eq!(data["description"], "A fixture page");
+	assert_eq!(data["robots"], "noindex");
+	assert_eq!(data["canonical"], "https://example.com/fixture");
+	assert_eq!(data["og"]["title"], "OG Fixture");
+	assert_eq!(data["og"]["type"], "article");
+	assert_eq!(data["twitter"]["card"], "summary");
+	assert_eq!(data["jsonLd"][0]["@type"], "Article");
+	assert_eq!(data["jsonLd"][0]["headline"], "Fixture");
+}
+
+#[test]
+fn page_schema_extracts_product_json_ld_and_microdata() {
+	let html = concat!(
+		"<html><head><title>Product Fixture</title>",
+		"<script type='application/ld+json'>{\"@context\":\"https://schema.org\",\"@type\":\"Product\",\"name\":\"Kettle\",",
+		"\"offers\":{\"@type\":\"Offer\",\"price\":\"19.99\",\"priceCurrency\":\"EUR\"}}</script>",
+		"<script type='application/ld+json'>{\"@type\":\"Product\",</script>",
+		"</head><body>",
+		"<div itemscope itemtype='https://schema.org/Product'><span itemprop='name'>Kettle</span>",
+		"<div itemprop='offers' itemscope itemtype='https://schema.org/Offer'><meta itemprop='price' content='19.99'></div></div>",
+		"</body></html>"
+	);
+	let (success, json, stderr) = run_exec("page.schema", json!({ "url": format!("data:text/html,{}", html.replace('#', "%23")) }));
+	assert!(success, "command failed: {stderr}");
+	let data = &json["data"];
+	assert_eq!(data["itemCount"], 2);
+
+	let product = &data["items"][0];
+	assert_eq!(product["source"], "jsonLd");
+	assert_eq!(product["type"][0], "Product");
+	assert_eq!(product["value"]["name"], "Kettle");
+	assert_eq!(product["value"]["offers"]["price"], "19.99");
+
+	let microdata = &data["items"][1];
+	assert_eq!(microdata["source"], "microdata");
+	assert_eq!(microdata["value"]["properties"]["name"][0], "Kettle");
+	assert_eq!(microdata["value"]["properties"]["offers"][0]["properties"]["price"][0], "19.99");
+
+	assert_eq!(data["errors"][0]["block"], 1);
+}
+
+#[test]
+fn type_fires_key_events_per_character() {
+	// Each keydown replaces the default insertion with "<KEY>.", so the value shows one entry per event.
+	let url = "data:text/html,<input id=q onkeydown=\"if (event.key.length === 1) { event.preventDefault(); this.value += event.key.toUpperCase() + '.'; }\">";
+
+	let (success, json, stderr) = run_exec("type", json!({ "url": url, "selector": "#q", "text": "abc", "delayMs": 10 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["value"], "A.B.C.");
+	assert_eq!(json["inputs"]["textLength"], 3);
+	assert_eq!(json["data"]["textLength"], 3);
+}
+
+#[test]
+fn type_into_password_field_keeps_text_out_of_response() {
+	let url = "data:text/html,<input id=pw type=password>";
+
+	let (success, json, stderr) = run_exec("type", json!({ "url": url, "selector": "#pw", "text": "hunter2" }));
+	assert!(success, "command failed: {stderr}");
+	assert!(!json.to_string().contains("hunter2"), "response leaked the text: {json}");
+	assert_eq!(json["data"]["textLength"], 7);
+}
+
+#[test]
+fn page_scroll_brings_element_into_view() {
+	let url = "data:text/html,<div style='height:4000px'>Top</div><p id=end>End</p>";
+
+	let (success, json, stderr) = run_exec("page.scroll", json!({ "url": url, "selector": "#end" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["selector"], "#end");
+	assert!(json["data"]["scrollY"].as_f64().unwrap() > 3000.0);
+
+	let (success, json, _stderr) = run_exec("page.scroll", json!({ "url": url, "selector": "#end", "y": 100 }));
+	assert!(!success);
+	assert_eq!(json["ok"], false);
+}
+
+#[test]
+fn fill_checked_sets_checkbox_state_regardless_of_initial_state() {
+	for (initial, checked) in [("", true), (" checked", true), ("", false), (" checked", false)] {
+		let url = format!("data:text/html,<input type=checkbox id=terms{initial}>");
+		let (success, json, stderr) = run_exec("fill", json!({ "url": url, "selector": "#terms", "checked": checked }));
+		assert!(success, "initial{initial:?} -> {checked} failed: {stderr}");
+		assert_eq!(json["data"]["checked"], checked);
+		assert!(json["data"].get("text").is_none());
+	}
+
+	// Playwright verifies the final state, so a checkbox that refuses to toggle fails.
+	let url = "data:text/html,<input type=checkbox id=stuck onclick='return false'>";
+	let (success, json, _stderr) = run_exec("fill", json!({ "url": url, "selector": "#stuck", "checked": true }));
+	assert!(!success);
+	assert_eq!(json["ok"], false);
+}
+
+#[test]
+fn snapshot_element_ids_are_stable_across_snapshots() {
+	let url = "data:text/html,<button id=save>Save</button><a href='/docs'>Docs</a><input name=q placeholder=Search>";
+	let ids = || {
+		let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": url }));
+		assert!(success, "command failed: {stderr}");
+		json["data"]["elements"]
+			.as_array()
+			.expect("elements should be an array")
+			.iter()
+			.map(|e| e["stableId"].as_str().expect("stableId should be string").to_string())
+			.collect::<Vec<_>>()
+	};
+
+	let first = ids();
+	assert_eq!(first.len(), 3);
+	assert_eq!(first, ids());
+}
+
+#[test]
+fn diff_text_reports_single_changed_line() {
+	let (success, json, stderr) = run_exec(
+		"diff-text",
+		json!({
+			"urlA": "data:text/html,<h1>Pricing</h1><p>Basic plan 10 USD</p><p>Contact us</p>",
+			"urlB": "data:text/html,<h1>Pricing</h1><p>Basic plan 12 USD</p><p>Contact us</p>",
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let data = &json["data"];
+	assert_eq!(data["identical"], false);
+	assert_eq!(data["added"], 1);
+	assert_eq!(data["removed"], 1);
+	assert_eq!(data["changes"][0], json!({ "kind": "removed", "line": 2, "text": "Basic plan 10 USD" }));
+	assert_eq!(data["changes"][1], json!({ "kind": "added", "line": 2, "text": "Basic plan 12 USD" }));
+	assert!(data["unified"].as_str().unwrap().contains("-Basic plan 10 USD\n+Basic plan 12 USD\n"));
+
+	let (success, json, stderr) = run_exec(
+		"diff-text",
+		json!({
+			"urlA": "data:text/html,<p>Basic plan 10 USD</p>",
+			"urlB": "data:text/html,<p>Basic plan 12 USD</p>",
+			"ignoreNumbers": true,
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["identical"], true);
+}
+
+#[test]
+fn elements_wait_for_selector_sees_late_elements() {
+	let html = "data:text/html,<body><script>setTimeout(() => { const b = document.createElement('button'); b.className = 'late'; b.textContent = 'Late'; document.body.appendChild(b); }, 1500)</script></body>";
+	let (success, json, stderr) = run_exec("page.elements", json!({ "url": html, "waitFor": "button.late" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["count"], 1);
+	assert_eq!(json["data"]["elements"][0]["text"], "Late");
+	assert_eq!(json["inputs"]["waitFor"], "button.late");
+}
+
+#[test]
+fn elements_stream_writes_one_json_line_per_element() {
+	let buttons: String = (0..250).map(|i| format!("<button id='b{i}'>Item {i}</button>")).collect();
+	let html = format!("data:text/html,<body>{buttons}</body>");
+	let (success, stdout, stderr) = run_pw(&["exec", "page.elements", "--input", &json!({ "url": html, "stream": true }).to_string()]);
+	assert!(success, "command failed: {stderr}");
+
+	let lines: Vec<serde_json::Value> = stdout
+		.lines()
+		.map(|line| serde_json::from_str(line).expect("each line is an element"))
+		.collect();
+	assert_eq!(lines.len(), 250, "one line per element across chunks");
+	assert_eq!(lines[0]["selector"], "#b0");
+	assert_eq!(lines[249]["text"], "Item 249");
+	assert!(lines.iter().all(|line| line["stableId"].is_string()));
+}
+
+#[test]
+fn snapshot_settle_waits_for_dom_mutations_to_stop() {
+	let html = "data:text/html,<body><script>let n = 0; const t = setInterval(() => { const b = document.createElement('button'); b.textContent = 'Item ' + (++n); document.body.appendChild(b); if (n === 5) clearInterval(t); }, 300)</script></body>";
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html, "settle": 800 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["elementCount"], 5);
+	assert!(json["data"]["text"].as_str().unwrap().contains("Item 5"));
+}
+
+#[test]
+fn scroll_to_load_stops_when_feed_is_exhausted() {
+	let html = "data:text/html,<body><div id='feed'></div><script>let n = 0; const feed = document.getElementById('feed'); const more = () => { for (let i = 0; i < 10; i++) { const d = document.createElement('div'); d.style.height = '200px'; d.textContent = 'Item ' + (++n); feed.appendChild(d); } }; more(); window.addEventListener('scroll', () => { if (n < 40 && innerHeight + scrollY >= document.body.scrollHeight - 10) setTimeout(more, 100); });</script></body>";
+	let (success, json, stderr) = run_exec("page.scroll-to-load", json!({ "url": html, "settleMs": 400, "textOnly": true, "maxText": 0 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["capped"], false);
+	assert_eq!(json["data"]["scrolls"], 4);
+	assert!(json["data"]["text"].as_str().unwrap().contains("Item 40"));
+}
+
+#[test]
+fn scroll_to_load_reports_cap() {
+	let html = "data:text/html,<body><div id='feed'></div><script>let n = 0; const feed = document.getElementById('feed'); const more = () => { for (let i = 0; i < 10; i++) { const d = document.createElement('div'); d.style.height = '200px'; d.textContent = 'Item ' + (++n); feed.appendChild(d); } }; more(); window.addEventListener('scroll', () => { if (innerHeight + scrollY >= document.body.scrollHeight - 10) setTimeout(more, 100); });</script></body>";
+	let (success, json, stderr) = run_exec(
+		"page.scroll-to-load",
+		json!({ "url": html, "maxScrolls": 2, "settleMs": 400, "textOnly": true }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["capped"], true);
+	assert_eq!(json["data"]["scrolls"], 2);
+	assert!(json["data"]["text"].as_str().unwrap().contains("Item 30"));
+	assert_eq!(json["inputs"]["maxScrolls"], 2);
+}
+
 #[test]
 fn html_with_selector() {
 	let (success, json, stderr) = run_exec(
@@ -76,6 +384,76 @@ fn html_with_selector() {
 	assert!(json["data"]["html"].as_str().unwrap_or_default().contains("Found me"));
 }
 
+#[test]
+fn accessibility_snapshot_reports_form_roles() {
+	let html = "<form><label>Email <input name=email></label><button>Sign up</button></form>";
+	let url = format!("data:text/html,{html}");
+
+	let (success, json, stderr) = run_exec("page.accessibility", json!({ "url": url }));
+	assert!(success, "command failed: {stderr}");
+	let tree = json["data"]["tree"].to_string();
+	assert!(tree.contains(r#""role":"textbox""#), "missing textbox: {tree}");
+	assert!(tree.contains(r#""role":"button","name":"Sign up""#), "missing button: {tree}");
+	assert!(json["data"]["nodeCount"].as_u64().unwrap() >= 3);
+}
+
+#[test]
+fn accessibility_snapshot_scoped_to_selector() {
+	let url = "data:text/html,<h1>Account</h1><form><button>Sign up</button></form>";
+
+	let (success, json, stderr) = run_exec("page.accessibility", json!({ "url": url, "selector": "form" }));
+	assert!(success, "command failed: {stderr}");
+	let tree = json["data"]["tree"].to_string();
+	assert!(tree.contains(r#""name":"Sign up""#), "missing button: {tree}");
+	assert!(!tree.contains("heading"), "heading outside the root: {tree}");
+
+	let (_success, json, _stderr) = run_exec("page.accessibility", json!({ "url": url, "selector": "#missing" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "SELECTOR_NOT_FOUND");
+}
+
+#[test]
+fn capture_popup_without_popup_reports_null_within_timeout() {
+	let input = json!({ "url": "data:text/html,<button>Stay</button>", "selector": "button", "capturePopup": true });
+	let started = std::time::Instant::now();
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "--timeout", "500", "exec", "click", "--input", &input.to_string()]);
+	let elapsed = started.elapsed();
+
+	assert!(success, "click failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_else(|_| panic!("unparseable output: {stdout} {stderr}"));
+	assert_eq!(json["ok"], true);
+	assert!(
+		json["data"]["popup"].is_null() && json["data"].get("popup").is_some(),
+		"popup should be null: {json}"
+	);
+	assert!(elapsed < std::time::Duration::from_secs(15), "took {elapsed:?}");
+}
+
+#[test]
+fn global_timeout_fails_fast_on_missing_selector() {
+	let input = json!({ "url": "data:text/html,<p>No button here</p>", "selector": "#missing" });
+	let started = std::time::Instant::now();
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "--timeout", "500", "exec", "click", "--input", &input.to_string()]);
+	let elapsed = started.elapsed();
+
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_else(|_| panic!("unparseable output: {stdout} {stderr}"));
+	assert!(!success || json["ok"] == false, "click on a missing selector should fail");
+	assert_eq!(json["error"]["code"], "TIMEOUT");
+	assert!(elapsed < std::time::Duration::from_secs(15), "took {elapsed:?}");
+}
+
+#[test]
+fn global_timeout_bounds_wait_for_missing_selector() {
+	let input = json!({ "url": "data:text/html,<p>Nothing appears</p>", "condition": "#missing" });
+	let started = std::time::Instant::now();
+	let (_success, stdout, stderr) = run_pw(&["-f", "json", "--timeout", "500", "exec", "wait", "--input", &input.to_string()]);
+	let elapsed = started.elapsed();
+
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_else(|_| panic!("unparseable output: {stdout} {stderr}"));
+	assert_eq!(json["error"]["code"], "TIMEOUT");
+	assert!(elapsed < std::time::Duration::from_secs(15), "took {elapsed:?}");
+}
+
 #[test]
 fn text_simple() {
 	let (success, json, stderr) = run_exec(
@@ -93,6 +471,125 @@ fn text_simple() {
 	assert_eq!(json["data"]["matchCount"], 1);
 }
 
+#[test]
+fn text_all_returns_each_match_in_document_order() {
+	let url = "data:text/html,<p>First</p><div><p>Second</p></div><p>Third</p>";
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "p", "all": true }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["texts"], json!(["First", "Second", "Third"]));
+	assert_eq!(json["data"]["matchCount"], 3);
+	assert_eq!(json["inputs"]["all"], true);
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "p" }));
+	assert!(success, "command failed: {stderr}");
+	assert!(json["data"]["texts"].is_null());
+}
+
+#[test]
+fn text_visible_only_excludes_hidden_content() {
+	let html = concat!(
+		"<main id='root'><p>Shown</p>",
+		"<p style='display:none'>DisplayNone</p>",
+		"<p style='visibility:hidden'>VisibilityHidden</p>",
+		"<div style='width:0;height:0;overflow:hidden'>ZeroSize</div>",
+		"<p>Also <b>shown</b></p></main>"
+	);
+	let url = format!("data:text/html,{html}");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#root", "visibleOnly": true }));
+	assert!(success, "command failed: {stderr}");
+	let text = json["data"]["text"].as_str().expect("text should be string");
+	assert_eq!(text, "Shown\nAlso shown");
+	assert_eq!(json["inputs"]["visibleOnly"], true);
+
+	// Engine selectors resolve the same element the match count came from.
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#root >> text=Also", "visibleOnly": true }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], "Also shown");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#root" }));
+	assert!(success, "command failed: {stderr}");
+	assert!(json["data"]["text"].as_str().expect("text should be string").contains("ZeroSize"));
+}
+
+#[test]
+fn text_offset_and_limit_page_through_long_text() {
+	// Prose, so the garbage filter keeps it; one numbered sentence per line keeps offsets predictable.
+	let lines: Vec<String> = (0..300).map(|i| format!("Sentence {i:03} of the long article.")).collect();
+	let body = lines.join("<br>");
+	let total = lines.iter().map(String::len).sum::<usize>() + lines.len() - 1;
+	let url = format!("data:text/html,<p id='long'>{body}</p>");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#long" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["totalLength"], total);
+	let first_page = json["data"]["text"].as_str().unwrap();
+	assert_eq!(first_page.len(), 5000);
+	assert!(first_page.starts_with("Sentence 000 of the long article."));
+	assert_eq!(json["data"]["nextOffset"], 5000);
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#long", "offset": 5000, "limit": 100 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], &lines.join("\n")[5000..5100]);
+	assert_eq!(json["data"]["nextOffset"], 5100);
+
+	let last = lines.last().unwrap();
+	let (success, json, stderr) = run_exec(
+		"page.text",
+		json!({ "url": url, "selector": "#long", "offset": total - last.len(), "limit": 100 }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], last.as_str());
+	assert_eq!(json["data"]["totalLength"], total);
+	assert!(json["data"]["nextOffset"].is_null());
+}
+
+#[test]
+fn max_text_overrides_default_text_cap() {
+	let words = "word ".repeat(2000);
+	let url = format!("data:text/html,<p>{words}</p>");
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": url, "textOnly": true, "maxText": 100 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"].as_str().unwrap().len(), 100);
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": url, "maxText": 0 }));
+	assert!(success, "command failed: {stderr}");
+	assert!(json["data"]["text"].as_str().unwrap().len() > 5000);
+	assert_eq!(json["inputs"]["maxText"], 0);
+}
+
+#[test]
+fn include_iframes_reaches_same_origin_frame_content() {
+	let frame = "<p>Framed text</p><button id=inner>Inside</button>";
+	let html = format!("<main id=root><p>Top text</p><iframe id=child srcdoc='{frame}'></iframe></main>");
+	let url = format!("data:text/html,{html}");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "#root", "includeIframes": true }));
+	assert!(success, "command failed: {stderr}");
+	let text = json["data"]["text"].as_str().expect("text should be string");
+	assert!(text.contains("Top text") && text.contains("Framed text"), "unexpected text: {text}");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "internal:role=main", "includeIframes": true }));
+	assert!(success, "command failed: {stderr}");
+	let text = json["data"]["text"].as_str().expect("text should be string");
+	assert!(text.contains("Framed text"), "engine selector should reach frame content: {text}");
+
+	let (success, json, stderr) = run_exec("page.elements", json!({ "url": url, "includeIframes": true }));
+	assert!(success, "command failed: {stderr}");
+	let selectors: Vec<&str> = json["data"]["elements"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.filter_map(|e| e["selector"].as_str())
+		.collect();
+	assert!(
+		selectors.contains(&"#child >> internal:control=enter-frame >> #inner"),
+		"missing framed selector in {selectors:?}"
+	);
+}
+
 #[test]
 fn eval_simple_expression() {
 	let (success, json, stderr) = run_exec("page.eval", json!({ "expression": "1 + 1", "url": "data:text/html,<h1>Test</h1>" }));
@@ -101,6 +598,78 @@ fn eval_simple_expression() {
 	assert_eq!(json["data"]["result"], 2);
 }
 
+#[test]
+fn eval_reflects_timezone_and_locale_flags() {
+	let input = json!({
+		"expression": "[Intl.DateTimeFormat().resolvedOptions().timeZone, navigator.language]",
+		"url": "data:text/html,<h1>Test</h1>"
+	})
+	.to_string();
+	let (success, stdout, stderr) = run_pw(&[
+		"-f",
+		"json",
+		"exec",
+		"page.eval",
+		"--timezone",
+		"America/New_York",
+		"--locale",
+		"fr-FR",
+		"--input",
+		&input,
+	]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["data"]["result"], json!(["America/New_York", "fr-FR"]));
+}
+
+#[test]
+fn eval_reflects_device_preset() {
+	let input = json!({
+		"expression": "[innerWidth, devicePixelRatio, navigator.maxTouchPoints > 0, navigator.userAgent.includes('Pixel 5')]",
+		"url": "data:text/html,<meta name=viewport content='width=device-width'><h1>Test</h1>"
+	})
+	.to_string();
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "exec", "page.eval", "--device", "Pixel 5", "--input", &input]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["data"]["result"], json!([393, 2.75, true, true]));
+}
+
+#[test]
+fn unknown_device_in_envelope_is_rejected() {
+	let envelope = json!({
+		"schemaVersion": 5,
+		"op": "page.eval",
+		"input": { "expression": "1", "url": "data:text/html,<h1>Test</h1>" },
+		"runtime": { "overrides": { "device": "Nokia 3310" } }
+	});
+	let path = std::env::temp_dir().join("pw-e2e-unknown-device.json");
+	std::fs::write(&path, envelope.to_string()).unwrap();
+	let (_success, stdout, _stderr) = run_pw(&["-f", "json", "exec", "--file", path.to_str().unwrap()]);
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+	assert!(json["error"]["message"].as_str().unwrap_or_default().contains("available: iPhone 13"));
+}
+
+#[test]
+fn unknown_timezone_is_rejected() {
+	let input = json!({ "expression": "1", "url": "data:text/html,<h1>Test</h1>" }).to_string();
+	let (_success, stdout, _stderr) = run_pw(&["-f", "json", "exec", "page.eval", "--timezone", "Mars/Olympus_Mons", "--input", &input]);
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+	assert!(json["error"]["message"].as_str().unwrap_or_default().contains("unknown timezone ID"));
+}
+
+#[test]
+fn navigate_security_rejects_non_https() {
+	let (_success, json, _stderr) = run_exec("navigate", json!({ "url": "data:text/html,<h1>Plain</h1>", "security": true }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+	assert!(json["error"]["message"].as_str().unwrap_or_default().contains("https://"));
+}
+
 #[test]
 fn eval_document_title() {
 	let (success, json, stderr) = run_exec(
@@ -127,6 +696,50 @@ fn eval_query_selector() {
 	assert_eq!(json["data"]["result"], "Content");
 }
 
+#[test]
+fn eval_selector_runs_against_first_match() {
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({
+			"expression": "el => el.dataset.price * 2",
+			"selector": ".item",
+			"url": "data:text/html,<ul><li class='item' data-price='21'>A</li><li class='item' data-price='5'>B</li></ul>"
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], 42);
+	assert_eq!(json["inputs"]["selector"], ".item");
+}
+
+#[test]
+fn eval_selector_all_runs_against_every_match() {
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({
+			"expression": "els => els.map(el => el.textContent)",
+			"selector": "li",
+			"all": true,
+			"url": "data:text/html,<ul><li>one</li><li>two</li><li>three</li></ul>"
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], json!(["one", "two", "three"]));
+}
+
+#[test]
+fn eval_selector_without_match_reports_element_not_found() {
+	let (_success, json, _stderr) = run_exec(
+		"page.eval",
+		json!({
+			"expression": "el => el.id",
+			"selector": "#missing",
+			"url": "data:text/html,<div>empty</div>"
+		}),
+	);
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "SELECTOR_NOT_FOUND");
+}
+
 #[test]
 fn coords_finds_element() {
 	let (success, json, stderr) = run_exec(
@@ -143,6 +756,39 @@ fn coords_finds_element() {
 	assert!(json["data"]["coords"]["height"].is_number());
 }
 
+#[test]
+fn coords_match_fixture_positions() {
+	let (success, json, stderr) = run_exec(
+		"page.coords",
+		json!({
+			"url": "data:text/html,<body style='margin:0'><div id='box' style='position:absolute;left:40px;top:60px;width:100px;height:50px'>Box</div></body>",
+			"selector": "#box"
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let coords = &json["data"]["coords"];
+	assert_eq!(coords["x"], 90);
+	assert_eq!(coords["y"], 85);
+	assert_eq!(coords["width"], 100);
+	assert_eq!(coords["height"], 50);
+	assert_eq!(coords["text"], "Box");
+}
+
+#[test]
+fn coords_include_iframe_offset() {
+	let (success, json, stderr) = run_exec(
+		"page.coords",
+		json!({
+			"url": "data:text/html,<body style='margin:0'><iframe style='position:absolute;left:200px;top:100px;width:300px;height:200px;border:0' srcdoc=\"<body style='margin:0'><button style='position:absolute;left:10px;top:20px;width:80px;height:40px'>In</button></body>\"></iframe></body>",
+			"selector": "iframe >> internal:control=enter-frame >> button"
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let coords = &json["data"]["coords"];
+	assert_eq!(coords["x"], 250);
+	assert_eq!(coords["y"], 140);
+}
+
 #[test]
 fn coords_element_not_found() {
 	let (_success, json, _stderr) = run_exec(
@@ -217,6 +863,130 @@ fn wait_selector_found() {
 	assert_eq!(json["data"]["selectorFound"], true);
 }
 
+/// Runs `op` through an envelope whose runtime selects `browser`.
+fn run_exec_in_browser(browser: &str, op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	let workspace = unique_workspace();
+	let _ = std::fs::create_dir_all(&workspace);
+	let request_path = workspace.join("request.json");
+	let request = json!({
+		"schemaVersion": 5,
+		"op": op,
+		"input": input,
+		"runtime": { "overrides": { "browser": browser } }
+	});
+	std::fs::write(&request_path, request.to_string()).expect("failed to write request file");
+
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", "--file"])
+		.arg(&request_path)
+		.output()
+		.expect("failed to execute pw");
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str::<serde_json::Value>(&stdout).unwrap_or_else(|_| json!({ "raw": stdout }));
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn wait_selector_states_follow_dom_changes() {
+	let cases = [
+		(
+			"attached",
+			"<script>setTimeout(() => document.body.append(Object.assign(document.createElement('p'), { id: 'late' })), 300)</script>",
+			true,
+		),
+		(
+			"detached",
+			"<p id='late'>x</p><script>setTimeout(() => document.getElementById('late').remove(), 300)</script>",
+			false,
+		),
+		(
+			"visible",
+			"<p id='late' hidden>x</p><script>setTimeout(() => document.getElementById('late').hidden = false, 300)</script>",
+			true,
+		),
+		(
+			"hidden",
+			"<p id='late'>x</p><script>setTimeout(() => document.getElementById('late').hidden = true, 300)</script>",
+			false,
+		),
+	];
+	for browser in ["chromium", "firefox", "webkit"] {
+		for (state, body, found) in cases {
+			let url = format!("data:text/html,{body}");
+			let (success, json, stderr) = run_exec_in_browser(browser, "wait", json!({ "url": url, "condition": "#late", "state": state }));
+			assert!(success, "{browser} {state}: command failed: {stderr}");
+			assert_eq!(json["ok"], true, "{browser} {state}: {json}");
+			assert_eq!(json["data"]["state"], state);
+			assert_eq!(json["data"]["selectorFound"], found, "{browser} {state}");
+		}
+	}
+}
+
+#[test]
+fn wait_state_requires_selector_condition() {
+	let (_success, json, _stderr) = run_exec("wait", json!({ "url": "data:text/html,<p>x</p>", "condition": "500", "state": "hidden" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
+
+#[test]
+fn console_captures_errors_and_warnings_after_navigation() {
+	let html = "data:text/html,<script>console.log('ready'); console.warn('slow'); setTimeout(() => console.error('boom'), 100)</script>";
+	let (success, json, stderr) = run_exec("page.console", json!({ "url": html, "timeoutMs": 500 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	let data = &json["data"];
+	assert_eq!(data["count"], 3);
+	assert_eq!(data["errorCount"], 1);
+	assert_eq!(data["warningCount"], 1);
+	let error = data["messages"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.find(|m| m["type"] == "error")
+		.expect("error message captured");
+	assert_eq!(error["text"], "boom");
+	assert!(error["location"]["url"].as_str().unwrap_or_default().starts_with("data:"));
+}
+
+#[test]
+fn console_reports_uncaught_errors_as_page_errors() {
+	let html = "data:text/html,<script>console.log('ready'); setTimeout(() => { throw new Error('boom') })</script>";
+	let (success, json, stderr) = run_exec("page.console", json!({ "url": html, "timeoutMs": 500 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	let data = &json["data"];
+	assert_eq!(data["count"], 1);
+	assert_eq!(data["errorCount"], 0);
+	let errors = data["pageErrors"].as_array().expect("pageErrors present");
+	assert_eq!(errors.len(), 1, "{json}");
+	assert_eq!(errors[0]["message"], "boom");
+	assert_eq!(errors[0]["name"], "Error");
+	assert!(errors[0]["stack"].as_str().unwrap_or_default().contains("boom"));
+}
+
+#[test]
+fn navigate_reports_page_errors() {
+	let html = "data:text/html,<script>null.missing</script><h1>Broken</h1>";
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	let errors = json["data"]["pageErrors"].as_array().expect("pageErrors present");
+	assert_eq!(errors[0]["name"], "TypeError");
+}
+
+#[test]
+fn wait_url_after_push_state() {
+	let html = "data:text/html,<script>setTimeout(() => history.pushState({}, '', location.href + '#/checkout'), 300)</script><div>Cart</div>";
+	let (success, json, stderr) = run_exec("wait", json!({ "url": html, "condition": "url=**#/checkout" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["condition"], "url:**#/checkout");
+	assert!(json["data"]["url"].as_str().unwrap_or_default().ends_with("#/checkout"));
+}
+
 #[test]
 fn missing_required_exec_args() {
 	let (success, _stdout, _stderr) = run_pw(&["exec"]);
@@ -232,6 +1002,165 @@ fn unknown_command() {
 	assert!(msg.contains("unknown operation"), "expected unknown operation message, got: {msg}");
 }
 
+#[test]
+fn click_echoes_target_selector_and_wait_in_inputs() {
+	let url = "data:text/html,<button>Go</button>";
+	let (success, json, stderr) = run_exec("click", json!({ "url": url, "selector": "button", "waitMs": 50 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["inputs"], json!({ "url": url, "selector": "button", "waitMs": 50 }));
+}
+
+#[test]
+fn fill_echoes_target_selector_and_text_length_in_inputs() {
+	let url = "data:text/html,<input>";
+	let (success, json, stderr) = run_exec("fill", json!({ "url": url, "selector": "input", "text": "Ada" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["inputs"], json!({ "url": url, "selector": "input", "textLength": 3 }));
+	assert_eq!(json["data"]["textLength"], 3);
+}
+
+#[test]
+fn navigate_echoes_target_and_options_in_inputs() {
+	let url = "data:text/html,<h1>Inputs</h1>";
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": url, "maxText": 0 }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["inputs"], json!({ "url": url, "maxText": 0 }));
+}
+
+#[test]
+fn replay_reruns_captured_navigate() {
+	let url = "data:text/html,<title>Replay</title><h1>Again</h1>";
+	let (success, captured, stderr) = run_exec("navigate", json!({ "url": url }));
+	assert!(success, "command failed: {stderr}");
+
+	let result_path = std::env::temp_dir().join("pw-test-replay-navigate.json");
+	std::fs::write(&result_path, captured.to_string()).unwrap();
+
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "replay", &result_path.to_string_lossy()]);
+	let _ = std::fs::remove_file(&result_path);
+	assert!(success, "replay failed: {stderr}");
+	let replayed: serde_json::Value = serde_json::from_str(&stdout).expect("replay output should be JSON");
+
+	assert_eq!(replayed["ok"], true);
+	assert_eq!(replayed["op"], captured["op"]);
+	assert_eq!(replayed["inputs"], captured["inputs"]);
+	assert_eq!(replayed["data"]["title"], "Replay");
+}
+
+#[test]
+fn replay_rejects_failed_envelope() {
+	let result_path = std::env::temp_dir().join("pw-test-replay-failed.json");
+	let envelope = json!({
+		"schemaVersion": 5,
+		"op": "click",
+		"ok": false,
+		"error": { "code": "TIMEOUT", "message": "Timeout after 30000ms" }
+	});
+	std::fs::write(&result_path, envelope.to_string()).unwrap();
+
+	let (success, _stdout, stderr) = run_pw(&["-f", "json", "replay", &result_path.to_string_lossy()]);
+	let _ = std::fs::remove_file(&result_path);
+	assert!(!success, "replay of a failed envelope should fail");
+	assert!(stderr.contains("no inputs"), "unexpected stderr: {stderr}");
+}
+
+#[test]
+fn pause_is_skipped_for_headless_sessions() {
+	let input = json!({ "url": "data:text/html,<h1>Paused</h1>" }).to_string();
+	let (success, stdout, stderr) = run_pw(&["--pause", "-f", "json", "exec", "navigate", "--input", &input]);
+	assert!(success, "command failed: {stderr}");
+	assert!(stderr.contains("--pause is ignored for headless browsers"), "unexpected stderr: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).expect("output should be JSON");
+	assert_eq!(json["ok"], true);
+}
+
+#[test]
+fn or_selector_matches_whichever_alternative_exists() {
+	for (html, expected) in [
+		("data:text/html,<button id=new-btn>New</button>", "New"),
+		("data:text/html,<button id=old-btn>Old</button>", "Old"),
+	] {
+		let (success, json, stderr) = run_exec(
+			"page.eval",
+			json!({ "url": html, "selector": "#new-btn || #old-btn", "expression": "el => el.textContent" }),
+		);
+		assert!(success, "command failed: {stderr}");
+		assert_eq!(json["data"]["result"], expected);
+	}
+}
+
+#[test]
+fn and_selector_narrows_to_elements_matching_both() {
+	// The selector `Locator::and` builds for `button` and `[data-testid=save]`.
+	let selector = r#"button >> internal:and="[data-testid=save]""#;
+	let html = "data:text/html,<button data-testid=save>Save</button><button>Cancel</button><a data-testid=save>Link</a>";
+
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({ "url": html, "selector": selector, "all": true, "expression": "els => els.map(el => el.textContent)" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], json!(["Save"]));
+
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({ "url": "data:text/html,<button>Cancel</button>", "selector": selector, "all": true, "expression": "els => els.length" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], 0);
+}
+
+#[test]
+fn nth_beyond_match_count_reports_index_out_of_range() {
+	let request_path = std::env::temp_dir().join("pw-test-nth-out-of-range.json");
+	let request = json!({
+		"schemaVersion": 5,
+		"op": "click",
+		"input": { "url": "data:text/html,<ul><li>a</li><li>b</li><li>c</li></ul>", "selector": "li >> nth=10", "strict": false },
+		"runtime": { "overrides": { "timeoutMs": 1000 } }
+	});
+	std::fs::write(&request_path, request.to_string()).unwrap();
+
+	let (success, stdout, _stderr) = run_pw(&["-f", "json", "exec", "--file", &request_path.to_string_lossy()]);
+	let _ = std::fs::remove_file(&request_path);
+	assert!(!success, "click on a missing index should fail");
+	let json: serde_json::Value = serde_json::from_str(&stdout).expect("output should be JSON");
+	assert_eq!(json["error"]["code"], "SELECTOR_NOT_FOUND");
+	assert_eq!(json["error"]["details"], json!({ "selector": "li", "index": 10, "count": 3 }));
+	assert!(
+		json["error"]["message"]
+			.as_str()
+			.unwrap_or_default()
+			.contains("index 10 out of range (count 3)")
+	);
+}
+
+#[test]
+fn strict_click_fails_fast_on_zero_matches() {
+	let (success, json, _stderr) = run_exec("click", json!({ "url": "data:text/html,<p>nothing here</p>", "selector": "button" }));
+	assert!(!success, "click without a match should fail");
+	assert_eq!(json["error"]["code"], "SELECTOR_NOT_FOUND");
+	assert_eq!(json["error"]["details"], json!({ "selector": "button", "count": 0 }));
+}
+
+#[test]
+fn strict_fill_fails_on_multiple_matches() {
+	let (success, json, _stderr) = run_exec("fill", json!({ "url": "data:text/html,<input><input>", "selector": "input", "text": "x" }));
+	assert!(!success, "fill with two matches should fail");
+	assert_eq!(json["error"]["code"], "SELECTOR_NOT_FOUND");
+	assert_eq!(json["error"]["details"], json!({ "selector": "input", "count": 2 }));
+}
+
+#[test]
+fn click_all_clicks_every_match_in_order() {
+	let html = "data:text/html,<ul><li id=a onclick=location.hash+=this.id>a</li><li id=b onclick=location.hash+=this.id>b</li><li id=c onclick=location.hash+=this.id>c</li></ul>";
+	let (success, json, stderr) = run_exec("click", json!({ "url": html, "selector": "li", "all": true }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["clicked"], 3);
+	assert!(json["data"]["afterUrl"].as_str().unwrap_or_default().ends_with("#abc"));
+	assert_eq!(json["inputs"]["all"], true);
+}
+
 #[test]
 fn verbose_output() {
 	let temp_dir = std::env::temp_dir();
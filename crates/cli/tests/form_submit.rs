@@ -0,0 +1,62 @@
+//! Integration tests for submitting forms from fill and form-fill.
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{run_exec_in, serve_html};
+use serde_json::json;
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-form-submit")
+}
+
+fn clear_context_store() {
+	let _ = std::fs::remove_dir_all(workspace_root());
+}
+
+fn run_exec(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	run_exec_in(&workspace_root(), op, input)
+}
+
+#[test]
+fn fill_submit_presses_enter_and_reports_result_url() {
+	clear_context_store();
+	let base = serve_html(vec![
+		("/search", "<form action='/results'><input name='q'></form>"),
+		("/results?q=rust", "<title>Results</title>"),
+	]);
+
+	let (success, json, stderr) = run_exec(
+		"fill",
+		json!({ "url": format!("{base}/search"), "selector": "input[name=q]", "text": "rust", "submit": true }),
+	);
+	assert!(success, "fill failed: {stderr}");
+	assert_eq!(json["data"]["submit"]["navigated"], true, "unexpected data: {}", json["data"]);
+	assert_eq!(json["data"]["submit"]["url"], format!("{base}/results?q=rust"));
+}
+
+#[test]
+fn form_fill_submit_clicks_submit_button() {
+	clear_context_store();
+	let base = serve_html(vec![
+		(
+			"/signup",
+			"<form action='/welcome'><input name='user'><input type='checkbox' name='terms' value='yes'><button type='submit'>Join</button></form>",
+		),
+		("/welcome?user=ada&terms=yes", "<title>Welcome</title>"),
+	]);
+
+	let (success, json, stderr) = run_exec(
+		"form-fill",
+		json!({
+			"url": format!("{base}/signup"),
+			"fields": { "user": "ada", "terms": true },
+			"submitSelector": "[type=submit]"
+		}),
+	);
+	assert!(success, "form-fill failed: {stderr}");
+	assert_eq!(json["data"]["filled"], 2, "unexpected data: {}", json["data"]);
+	assert_eq!(json["data"]["url"], format!("{base}/signup"));
+	assert_eq!(json["data"]["submit"]["url"], format!("{base}/welcome?user=ada&terms=yes"));
+}
@@ -0,0 +1,117 @@
+//! Integration tests for navigation across redirects and response metadata.
+//!
+//! Redirects and response headers need real HTTP responses, so these tests
+//! serve fixtures from a throwaway localhost server instead of `data:` URLs.
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{html, run_exec_in, serve};
+use serde_json::json;
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-navigation-redirects")
+}
+
+fn clear_context_store() {
+	let _ = std::fs::remove_dir_all(workspace_root());
+}
+
+fn run_exec(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	run_exec_in(&workspace_root(), op, input)
+}
+
+fn redirect(status: u16, location: &str) -> String {
+	format!("HTTP/1.1 {status} Redirect\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+}
+
+#[test]
+fn navigate_succeeds_when_page_redirects_on_load() {
+	clear_context_store();
+	let base = serve(vec![
+		("/start", html("<script>location.replace('/final')</script><p>Redirecting</p>")),
+		("/final", html("<title>Final</title><h1>Arrived</h1>")),
+	]);
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": format!("{base}/start") }));
+	assert!(success, "navigate failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["url"], format!("{base}/final"));
+	assert_eq!(json["data"]["title"], "Final");
+}
+
+#[test]
+fn navigate_records_two_hop_redirect_chain() {
+	clear_context_store();
+	let base = serve(vec![
+		("/old", redirect(301, "/moved")),
+		("/moved", redirect(302, "/landing")),
+		("/landing", html("<title>Landing</title><h1>Landing</h1>")),
+	]);
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": format!("{base}/old"), "followRedirects": false }));
+	assert!(success, "navigate failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["url"], format!("{base}/landing"));
+	assert_eq!(
+		json["data"]["redirects"],
+		json!([
+			{ "from": format!("{base}/old"), "to": format!("{base}/moved"), "status": 301 },
+			{ "from": format!("{base}/moved"), "to": format!("{base}/landing"), "status": 302 },
+		])
+	);
+}
+
+#[test]
+fn navigate_reports_response_status_and_content_type() {
+	clear_context_store();
+	let body = "<title>Fixture</title><p>Not found here</p>";
+	let response = format!(
+		"HTTP/1.1 404 Not Found\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+		body.len()
+	);
+	let base = serve(vec![("/missing", response)]);
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": format!("{base}/missing") }));
+	assert!(success, "command failed: {stderr}");
+	let data = &json["data"];
+	assert_eq!(data["status"], 404);
+	assert_eq!(data["contentType"], "text/html; charset=utf-8");
+	assert_eq!(data["contentLength"], body.len());
+}
+
+#[test]
+fn navigate_fail_on_status_rejects_matching_status() {
+	clear_context_store();
+	let base = serve(vec![("/ok", html("<p>Fine</p>"))]);
+
+	let (success, json, _) = run_exec("navigate", json!({ "url": format!("{base}/gone"), "failOnStatus": "4xx,5xx" }));
+	assert!(!success, "404 should fail with failOnStatus");
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "NAVIGATION_FAILED");
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": format!("{base}/ok"), "failOnStatus": "4xx,5xx" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["status"], 200);
+}
+
+#[test]
+fn navigate_fail_on_status_rejects_navigation_without_response() {
+	clear_context_store();
+
+	let (success, json, _) = run_exec("navigate", json!({ "url": "data:text/html,<p>No server</p>", "failOnStatus": "5xx" }));
+	assert!(!success, "a navigation without a status should fail with failOnStatus");
+	assert_eq!(json["error"]["code"], "NAVIGATION_FAILED");
+}
+
+#[test]
+fn navigate_fails_when_response_aborts_without_redirect() {
+	clear_context_store();
+	// A 204 aborts the navigation (ERR_ABORTED / NS_BINDING_ABORTED) and the page stays put.
+	let base = serve(vec![("/empty", "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_string())]);
+
+	let (success, json, _stderr) = run_exec("navigate", json!({ "url": format!("{base}/empty") }));
+	assert!(!success || json["ok"] == false, "aborted navigation should fail: {json}");
+	assert_eq!(json["error"]["code"], "NAVIGATION_FAILED");
+}
@@ -76,7 +76,28 @@ fn read_supports_text_output() {
 	assert_eq!(json["data"]["format"], Value::String("text".to_string()));
 	let content = json["data"]["content"].as_str().expect("content should be string");
 	assert!(content.contains("Hello world from text output."));
-	assert!(json["data"]["wordCount"].as_u64().expect("wordCount should be a number") > 0);
+	assert!(json["data"]["stats"]["wordCount"].as_u64().expect("wordCount should be a number") > 0);
+	assert!(json["data"]["wordCount"].is_null());
+}
+
+#[test]
+fn read_selector_scopes_extraction() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec(
+		"page.read",
+		json!({
+			"url": "data:text/html,<article><p>The main story body that the heuristic would normally pick.</p></article><aside id='sidebar'><p>Sidebar note only.</p></aside>",
+			"selector": "#sidebar",
+			"outputFormat": "text"
+		}),
+	);
+
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], Value::Bool(true), "{json}");
+	assert_eq!(json["data"]["content"], "Sidebar note only.");
+	assert_eq!(json["inputs"]["selector"], "#sidebar");
 }
 
 #[test]
@@ -116,7 +137,7 @@ fn read_includes_metadata_when_requested() {
 	assert_eq!(json["ok"], Value::Bool(true));
 	assert_eq!(json["data"]["title"], Value::String("Meta Story".to_string()));
 	assert_eq!(json["data"]["author"], Value::String("Ada Lovelace".to_string()));
-	assert_eq!(json["data"]["published"], Value::String("2025-01-02".to_string()));
+	assert_eq!(json["data"]["published"], Value::String("2025-01-02T00:00:00Z".to_string()));
 }
 
 #[test]
@@ -138,3 +159,96 @@ fn read_omits_metadata_fields_without_flag() {
 	assert!(!data.contains_key("author"));
 	assert!(!data.contains_key("published"));
 }
+
+#[test]
+fn read_reports_declared_and_detected_language() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec(
+		"page.read",
+		json!({
+			"url": "data:text/html,<html lang='en-US'><body><article><p>Body</p></article></body></html>",
+			"metadata": true
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["language"], json!({ "code": "en", "confidence": 1.0 }));
+
+	let (success, json, stderr) = run_exec(
+		"page.read",
+		json!({
+			"url": "data:text/html,<article><p>La ville est située sur les bords de la rivière et elle est connue pour ses marchés qui sont ouverts tous les jours.</p></article>",
+			"metadata": true
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["language"]["code"], "fr");
+}
+
+#[test]
+fn read_reports_word_count_and_reading_time() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	clear_context_store();
+
+	let body = "word ".repeat(450);
+	let (success, json, stderr) = run_exec(
+		"page.read",
+		json!({
+			"url": format!("data:text/html,<article><p>{body}</p></article>"),
+			"outputFormat": "text",
+			"wordsPerMinute": 100
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["stats"], json!({ "wordCount": 450, "estimatedReadingMinutes": 5 }));
+	assert_eq!(json["inputs"]["wordsPerMinute"], 100);
+}
+
+#[test]
+fn read_urls_writes_one_markdown_file_per_page() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	clear_context_store();
+
+	let fixtures = workspace_root().join("fixtures");
+	std::fs::create_dir_all(&fixtures).unwrap();
+	let article = |name: &str, title: &str, body: &str| {
+		let path = fixtures.join(name);
+		std::fs::write(
+			&path,
+			format!("<html><head><title>{title}</title></head><body><article><h1>{title}</h1><p>{body}</p></article></body></html>"),
+		)
+		.unwrap();
+		format!("file://{}", path.display())
+	};
+	let first = article(
+		"first.html",
+		"First Story",
+		"The first fixture article has enough words to be extracted as readable content.",
+	);
+	let second = article(
+		"second.html",
+		"Second Story",
+		"The second fixture article is also long enough to survive the readable extraction.",
+	);
+	let missing = format!("file://{}", fixtures.join("missing.html").display());
+	let list = fixtures.join("urls.txt");
+	std::fs::write(&list, format!("# corpus\n{first}\n{missing}\n{second}\n")).unwrap();
+
+	let (success, json, stderr) = run_exec("page.read", json!({ "urls": list, "concurrency": 2, "outDir": "articles" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], Value::Bool(true));
+
+	let articles = json["data"]["articles"].as_array().expect("articles should be array");
+	assert_eq!(articles.len(), 2);
+	assert_eq!(articles[0]["url"], Value::String(first));
+	assert_eq!(articles[1]["title"], "Second Story");
+
+	let written = std::fs::read_to_string(workspace_root().join("articles/first-story.md")).expect("first article should be written");
+	assert!(written.contains("# First Story"));
+	assert!(workspace_root().join("articles/second-story.md").exists());
+
+	let failures = json["data"]["failures"].as_array().expect("failures should be array");
+	assert_eq!(failures.len(), 1);
+	assert_eq!(failures[0]["url"], Value::String(missing));
+}
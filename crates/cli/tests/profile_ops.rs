@@ -69,7 +69,7 @@ fn exec_profile_set_normalizes_schema_and_show_roundtrips() {
 	assert_eq!(json["ok"], true);
 	assert_eq!(json["data"]["schema"], 4);
 	assert_eq!(json["data"]["defaults"]["baseUrl"], "https://base.example");
-	assert_eq!(json["data"]["protectedUrls"], json!(["admin"]));
+	assert_eq!(json["data"]["protectedUrls"], json!([{ "pattern": "admin", "kind": "substring" }]));
 }
 
 #[test]
@@ -171,3 +171,40 @@ fn profile_delete_removes_profile_directory() {
 	assert_eq!(json["data"]["removed"], true);
 	assert!(!profile_dir.exists(), "profile directory should be removed after delete");
 }
+
+#[test]
+fn exec_emulation_set_persists_across_invocations() {
+	let tmp = TempDir::new().expect("temp dir should be created");
+
+	let (success, json, stderr) = run_exec_json(tmp.path(), "emulation.set", json!({ "colorScheme": "dark", "timezoneId": "Europe/Berlin" }));
+	assert!(success, "emulation.set failed: {stderr}");
+	assert_eq!(json["ok"], true, "unexpected response: {json}");
+	assert_eq!(json["data"]["changed"], true);
+
+	let (success, json, stderr) = run_exec_json(tmp.path(), "emulation.set", json!({ "viewport": "1280x720" }));
+	assert!(success, "emulation.set failed: {stderr}");
+	assert_eq!(json["data"]["emulation"]["colorScheme"], "dark");
+
+	let (success, json, stderr) = run_exec_json(tmp.path(), "emulation.show", json!({}));
+	assert!(success, "emulation.show failed: {stderr}");
+	assert_eq!(json["data"]["enabled"], true);
+	assert_eq!(json["data"]["emulation"]["timezoneId"], "Europe/Berlin");
+	assert_eq!(json["data"]["emulation"]["viewport"], json!({ "width": 1280, "height": 720 }));
+
+	let (_, json, _) = run_exec_json(tmp.path(), "profile.show", json!({ "name": "default" }));
+	assert_eq!(json["data"]["emulation"]["colorScheme"], "dark");
+
+	let (_, json, _) = run_exec_json(tmp.path(), "emulation.clear", json!({}));
+	assert_eq!(json["data"]["cleared"], true);
+	let (_, json, _) = run_exec_json(tmp.path(), "emulation.show", json!({}));
+	assert_eq!(json["data"]["enabled"], false);
+}
+
+#[test]
+fn exec_emulation_set_rejects_unknown_timezone() {
+	let tmp = TempDir::new().expect("temp dir should be created");
+
+	let (_, json, _) = run_exec_json(tmp.path(), "emulation.set", json!({ "timezoneId": "Nowhere/Special" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
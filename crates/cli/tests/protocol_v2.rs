@@ -68,3 +68,35 @@ fn batch_rejects_alias_operation_name() {
 	assert_eq!(json["op"], "har-show");
 	assert_eq!(json["error"]["code"], "INVALID_INPUT");
 }
+
+#[test]
+fn explain_only_describes_click_without_running_it() {
+	let tmp = TempDir::new().unwrap();
+	let input = r#"{"url":"https://example.com","selector":"button.submit","waitMs":500}"#;
+	let (success, stdout, stderr) = run_pw(tmp.path(), &["-f", "json", "--explain-only", "exec", "click", "--input", input]);
+
+	assert!(success, "exec failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["ok"], true);
+	assert_eq!(
+		json["explanation"],
+		"Will navigate to https://example.com/, click the single element matching 'button.submit', wait 500 ms."
+	);
+	assert_eq!(
+		json["inputs"],
+		serde_json::json!({ "url": "https://example.com/", "selector": "button.submit", "waitMs": 500 })
+	);
+	assert_eq!(json["data"], serde_json::json!({ "executed": false }));
+}
+
+#[test]
+fn explain_only_falls_back_to_raw_input() {
+	let tmp = TempDir::new().unwrap();
+	let input = r#"{"url":"https://example.com","selector":"h1"}"#;
+	let (success, stdout, stderr) = run_pw(tmp.path(), &["-f", "json", "--explain-only", "exec", "page.text", "--input", input]);
+
+	assert!(success, "exec failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+	assert_eq!(json["explanation"], "Will run page.text.");
+	assert_eq!(json["inputs"], serde_json::json!({ "url": "https://example.com", "selector": "h1" }));
+}
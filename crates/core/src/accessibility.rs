@@ -24,6 +24,15 @@
 //!         .root(root)
 //!         .build()
 //! )).await?;
+//!
+//! // Scope to a locator and keep uninteresting nodes
+//! let form = page.locator("form#signup").await;
+//! let snapshot = page.accessibility_snapshot(Some(
+//!     AccessibilitySnapshotOptions::builder()
+//!         .root_locator(form)
+//!         .interesting_only(false)
+//!         .build()
+//! )).await?;
 //! ```
 //!
 //! See: <https://playwright.dev/docs/api/class-accessibility>
@@ -65,6 +74,16 @@ impl Accessibility {
 	///
 	/// See: <https://playwright.dev/docs/api/class-accessibility#accessibility-snapshot>
 	pub async fn snapshot(&self, options: Option<AccessibilitySnapshotOptions>) -> Result<Option<AccessibilityNode>> {
+		let options = match options {
+			Some(mut opts) => {
+				if let Some(locator) = opts.root_locator.take() {
+					opts.root_guid = Some(locator.element_handle().await?.guid().to_string());
+				}
+				Some(opts)
+			}
+			None => None,
+		};
+
 		let params = options
 			.map(|o| serde_json::to_value(&o).unwrap_or_default())
 			.unwrap_or_else(|| serde_json::json!({}));
@@ -87,10 +106,11 @@ impl Accessibility {
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccessibilitySnapshotOptions {
-	/// Whether to include nodes that are not interesting for most users.
+	/// Whether to prune nodes that are not interesting for most users.
 	///
 	/// "Interesting" nodes are nodes with an accessible name or role that isn't
-	/// typically hidden (e.g., not "none" or "presentation").
+	/// typically hidden (e.g., not "none" or "presentation"). Playwright
+	/// defaults to `true` when unset.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub interesting_only: Option<bool>,
 
@@ -99,6 +119,10 @@ pub struct AccessibilitySnapshotOptions {
 	/// When specified, only the subtree rooted at this element is returned.
 	#[serde(skip_serializing_if = "Option::is_none", rename = "root")]
 	root_guid: Option<String>,
+
+	/// Locator resolved to the root element when the snapshot is taken.
+	#[serde(skip)]
+	root_locator: Option<crate::Locator>,
 }
 
 impl AccessibilitySnapshotOptions {
@@ -113,6 +137,7 @@ impl AccessibilitySnapshotOptions {
 pub struct AccessibilitySnapshotOptionsBuilder {
 	interesting_only: Option<bool>,
 	root_guid: Option<String>,
+	root_locator: Option<crate::Locator>,
 }
 
 impl AccessibilitySnapshotOptionsBuilder {
@@ -125,6 +150,17 @@ impl AccessibilitySnapshotOptionsBuilder {
 	/// Sets the root element for the snapshot.
 	pub fn root(mut self, element: Arc<crate::ElementHandle>) -> Self {
 		self.root_guid = Some(element.guid().to_string());
+		self.root_locator = None;
+		self
+	}
+
+	/// Sets the root to the first element matching `locator`.
+	///
+	/// The locator is resolved when the snapshot is taken; the snapshot fails
+	/// with [`Error::ElementNotFound`](pw_runtime::Error::ElementNotFound) if nothing matches.
+	pub fn root_locator(mut self, locator: crate::Locator) -> Self {
+		self.root_locator = Some(locator);
+		self.root_guid = None;
 		self
 	}
 
@@ -133,6 +169,7 @@ impl AccessibilitySnapshotOptionsBuilder {
 		AccessibilitySnapshotOptions {
 			interesting_only: self.interesting_only,
 			root_guid: self.root_guid,
+			root_locator: self.root_locator,
 		}
 	}
 }
@@ -142,96 +179,113 @@ impl AccessibilitySnapshotOptionsBuilder {
 /// Represents an element as seen by assistive technologies like screen readers.
 ///
 /// See: <https://playwright.dev/docs/api/class-accessibility#accessibility-snapshot>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccessibilityNode {
 	/// The ARIA role of the node (e.g., "button", "heading", "link").
 	pub role: String,
 
 	/// The accessible name of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub name: Option<String>,
 
 	/// The accessible value of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value: Option<AccessibilityValue>,
 
 	/// The accessible description of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub description: Option<String>,
 
 	/// Keyboard shortcut associated with the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub key_shortcuts: Option<String>,
 
 	/// Role description override.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub role_description: Option<String>,
 
 	/// Value text for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_text: Option<String>,
 
 	/// Whether the node is disabled.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub disabled: bool,
 
 	/// Whether the node is expanded (for expandable elements).
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub expanded: Option<bool>,
 
 	/// Whether the node is focused.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub focused: bool,
 
 	/// Whether the node is modal.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub modal: bool,
 
 	/// Whether the node supports multiple selection.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub multiselectable: bool,
 
 	/// Whether the node is readonly.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub readonly: bool,
 
 	/// Whether the node is required.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub required: bool,
 
 	/// Whether the node is selected.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub selected: Option<bool>,
 
 	/// The checked state for checkboxes and radio buttons.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub checked: Option<CheckedState>,
 
 	/// The pressed state for toggle buttons.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub pressed: Option<PressedState>,
 
 	/// The heading level (1-6).
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub level: Option<u8>,
 
 	/// Minimum value for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_min: Option<f64>,
 
 	/// Maximum value for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_max: Option<f64>,
 
 	/// The autocomplete behavior.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub autocomplete: Option<String>,
 
 	/// The haspopup behavior.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub haspopup: Option<String>,
 
 	/// Whether the node is invalid.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub invalid: Option<String>,
 
 	/// The orientation for sliders and scrollbars.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub orientation: Option<String>,
 
 	/// Child nodes.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub children: Option<Vec<AccessibilityNode>>,
 }
 
 /// The value of an accessibility node.
 ///
 /// Can be a string or a number depending on the node type.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum AccessibilityValue {
 	/// String value (e.g., text content)
@@ -241,7 +295,7 @@ pub enum AccessibilityValue {
 }
 
 /// The checked state of a checkbox or radio button.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckedState {
 	/// The element is checked
@@ -253,7 +307,7 @@ pub enum CheckedState {
 }
 
 /// The pressed state of a toggle button.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PressedState {
 	/// The button is pressed
@@ -263,3 +317,38 @@ pub enum PressedState {
 	/// The button is in a mixed state
 	Mixed,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_snapshot_options_serialize_interesting_only() {
+		let opts = AccessibilitySnapshotOptions::builder().interesting_only(false).build();
+		assert_eq!(serde_json::to_value(&opts).unwrap(), serde_json::json!({ "interestingOnly": false }));
+	}
+
+	#[test]
+	fn test_snapshot_options_default_is_empty() {
+		let opts = AccessibilitySnapshotOptions::builder().build();
+		assert_eq!(serde_json::to_value(&opts).unwrap(), serde_json::json!({}));
+	}
+
+	#[test]
+	fn test_node_serializes_only_set_fields() {
+		let node: AccessibilityNode = serde_json::from_value(serde_json::json!({
+			"role": "WebArea",
+			"name": "Signup",
+			"children": [{ "role": "textbox", "name": "Email", "required": true, "value": "a@b.c" }]
+		}))
+		.unwrap();
+		assert_eq!(
+			serde_json::to_value(&node).unwrap(),
+			serde_json::json!({
+				"role": "WebArea",
+				"name": "Signup",
+				"children": [{ "role": "textbox", "name": "Email", "required": true, "value": "a@b.c" }]
+			})
+		);
+	}
+}
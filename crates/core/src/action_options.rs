@@ -17,6 +17,8 @@ use super::click::{KeyboardModifier, Position};
 pub struct FillOptions {
 	/// Whether to bypass actionability checks
 	pub force: Option<bool>,
+	/// Don't wait for navigation after fill
+	pub no_wait_after: Option<bool>,
 	/// Maximum time in milliseconds
 	pub timeout: Option<f64>,
 }
@@ -35,6 +37,10 @@ impl FillOptions {
 			json["force"] = serde_json::json!(force);
 		}
 
+		if let Some(no_wait_after) = self.no_wait_after {
+			json["noWaitAfter"] = serde_json::json!(no_wait_after);
+		}
+
 		// Timeout is required in Playwright 1.56.1+
 		if let Some(timeout) = self.timeout {
 			json["timeout"] = serde_json::json!(timeout);
@@ -50,6 +56,7 @@ impl FillOptions {
 #[derive(Debug, Clone, Default)]
 pub struct FillOptionsBuilder {
 	force: Option<bool>,
+	no_wait_after: Option<bool>,
 	timeout: Option<f64>,
 }
 
@@ -60,6 +67,12 @@ impl FillOptionsBuilder {
 		self
 	}
 
+	/// Don't wait for navigation after fill
+	pub fn no_wait_after(mut self, no_wait_after: bool) -> Self {
+		self.no_wait_after = Some(no_wait_after);
+		self
+	}
+
 	/// Set timeout in milliseconds
 	pub fn timeout(mut self, timeout: f64) -> Self {
 		self.timeout = Some(timeout);
@@ -70,6 +83,7 @@ impl FillOptionsBuilder {
 	pub fn build(self) -> FillOptions {
 		FillOptions {
 			force: self.force,
+			no_wait_after: self.no_wait_after,
 			timeout: self.timeout,
 		}
 	}
@@ -142,6 +156,73 @@ impl PressOptionsBuilder {
 	}
 }
 
+/// Press sequentially options
+///
+/// Configuration options for press_sequentially() action.
+///
+/// See: <https://playwright.dev/docs/api/class-locator#locator-press-sequentially>
+#[derive(Debug, Clone, Default)]
+pub struct PressSequentiallyOptions {
+	/// Time to wait between key presses in milliseconds
+	pub delay: Option<f64>,
+	/// Maximum time in milliseconds
+	pub timeout: Option<f64>,
+}
+
+impl PressSequentiallyOptions {
+	/// Create a new builder for PressSequentiallyOptions
+	pub fn builder() -> PressSequentiallyOptionsBuilder {
+		PressSequentiallyOptionsBuilder::default()
+	}
+
+	/// Convert options to JSON value for protocol
+	pub(crate) fn to_json(&self) -> serde_json::Value {
+		let mut json = serde_json::json!({});
+
+		if let Some(delay) = self.delay {
+			json["delay"] = serde_json::json!(delay);
+		}
+
+		// Timeout is required in Playwright 1.56.1+
+		if let Some(timeout) = self.timeout {
+			json["timeout"] = serde_json::json!(timeout);
+		} else {
+			json["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+		}
+
+		json
+	}
+}
+
+/// Builder for PressSequentiallyOptions
+#[derive(Debug, Clone, Default)]
+pub struct PressSequentiallyOptionsBuilder {
+	delay: Option<f64>,
+	timeout: Option<f64>,
+}
+
+impl PressSequentiallyOptionsBuilder {
+	/// Set delay between key presses in milliseconds
+	pub fn delay(mut self, delay: f64) -> Self {
+		self.delay = Some(delay);
+		self
+	}
+
+	/// Set timeout in milliseconds
+	pub fn timeout(mut self, timeout: f64) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Build the PressSequentiallyOptions
+	pub fn build(self) -> PressSequentiallyOptions {
+		PressSequentiallyOptions {
+			delay: self.delay,
+			timeout: self.timeout,
+		}
+	}
+}
+
 /// Check options
 ///
 /// Configuration options for check() and uncheck() actions.
@@ -553,17 +634,147 @@ impl MouseOptionsBuilder {
 	}
 }
 
+/// Element state awaited by [`WaitForSelectorOptions`].
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-wait-for-selector>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WaitForSelectorState {
+	/// Present in the DOM
+	Attached,
+	/// Not present in the DOM
+	Detached,
+	/// Present with a non-empty bounding box and without `visibility: hidden`
+	#[default]
+	Visible,
+	/// Not present, or present without a bounding box or with `visibility: hidden`
+	Hidden,
+}
+
+/// Wait for selector options
+///
+/// Configuration options for wait_for_selector().
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-wait-for-selector>
+#[derive(Debug, Clone, Default)]
+pub struct WaitForSelectorOptions {
+	/// State to wait for (default: visible)
+	pub state: Option<WaitForSelectorState>,
+	/// Fail when the selector matches more than one element
+	pub strict: Option<bool>,
+	/// Maximum time in milliseconds
+	pub timeout: Option<f64>,
+}
+
+impl WaitForSelectorOptions {
+	/// Create a new builder for WaitForSelectorOptions
+	pub fn builder() -> WaitForSelectorOptionsBuilder {
+		WaitForSelectorOptionsBuilder::default()
+	}
+
+	/// Convert options to JSON value for protocol
+	pub(crate) fn to_json(&self) -> serde_json::Value {
+		let mut json = serde_json::json!({});
+
+		if let Some(state) = self.state {
+			json["state"] = serde_json::to_value(state).unwrap();
+		}
+
+		if let Some(strict) = self.strict {
+			json["strict"] = serde_json::json!(strict);
+		}
+
+		// Timeout is required in Playwright 1.56.1+
+		if let Some(timeout) = self.timeout {
+			json["timeout"] = serde_json::json!(timeout);
+		} else {
+			json["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+		}
+
+		json
+	}
+}
+
+/// Builder for WaitForSelectorOptions
+#[derive(Debug, Clone, Default)]
+pub struct WaitForSelectorOptionsBuilder {
+	state: Option<WaitForSelectorState>,
+	strict: Option<bool>,
+	timeout: Option<f64>,
+}
+
+impl WaitForSelectorOptionsBuilder {
+	/// Set the state to wait for
+	pub fn state(mut self, state: WaitForSelectorState) -> Self {
+		self.state = Some(state);
+		self
+	}
+
+	/// Fail when the selector matches more than one element
+	pub fn strict(mut self, strict: bool) -> Self {
+		self.strict = Some(strict);
+		self
+	}
+
+	/// Set timeout in milliseconds
+	pub fn timeout(mut self, timeout: f64) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Build the WaitForSelectorOptions
+	pub fn build(self) -> WaitForSelectorOptions {
+		WaitForSelectorOptions {
+			state: self.state,
+			strict: self.strict,
+			timeout: self.timeout,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::click::MouseButton;
 
+	#[test]
+	fn test_wait_for_selector_options_builder() {
+		let options = WaitForSelectorOptions::builder()
+			.state(WaitForSelectorState::Detached)
+			.strict(true)
+			.timeout(2500.0)
+			.build();
+
+		let json = options.to_json();
+		assert_eq!(json["state"], "detached");
+		assert_eq!(json["strict"], true);
+		assert_eq!(json["timeout"], 2500.0);
+	}
+
+	#[test]
+	fn test_wait_for_selector_options_defaults() {
+		let json = WaitForSelectorOptions::default().to_json();
+		assert!(json.get("state").is_none(), "server default (visible) applies");
+		assert!(json.get("strict").is_none());
+		assert_eq!(json["timeout"], pw_protocol::options::DEFAULT_TIMEOUT_MS);
+
+		for (state, name) in [
+			(WaitForSelectorState::Attached, "attached"),
+			(WaitForSelectorState::Visible, "visible"),
+			(WaitForSelectorState::Hidden, "hidden"),
+		] {
+			let json = WaitForSelectorOptions::builder().state(state).build().to_json();
+			assert_eq!(json["state"], name);
+		}
+	}
+
 	#[test]
 	fn test_fill_options_builder() {
-		let options = FillOptions::builder().force(true).timeout(5000.0).build();
+		let options = FillOptions::builder().force(true).no_wait_after(true).timeout(5000.0).build();
 
 		let json = options.to_json();
 		assert_eq!(json["force"], true);
+		assert_eq!(json["noWaitAfter"], true);
 		assert_eq!(json["timeout"], 5000.0);
 	}
 
@@ -576,6 +787,15 @@ mod tests {
 		assert_eq!(json["timeout"], 3000.0);
 	}
 
+	#[test]
+	fn test_press_sequentially_options_builder() {
+		let options = PressSequentiallyOptions::builder().delay(25.0).build();
+
+		let json = options.to_json();
+		assert_eq!(json["delay"], 25.0);
+		assert_eq!(json["timeout"], pw_protocol::options::DEFAULT_TIMEOUT_MS);
+	}
+
 	#[test]
 	fn test_check_options_builder() {
 		let options = CheckOptions::builder()
@@ -430,10 +430,150 @@ impl Expectation {
 		}
 	}
 
+	/// Asserts that the element has attribute `name` with exactly `expected` as its value.
+	///
+	/// This assertion will retry until the attribute has the value or timeout.
+	/// A missing attribute never matches, so `expected = ""` only passes for an
+	/// attribute that is present but empty, such as a bare `disabled`.
+	///
+	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-attribute>
+	pub async fn to_have_attribute(self, name: &str, expected: &str) -> Result<()> {
+		let start = std::time::Instant::now();
+		let selector = self.locator.selector().to_string();
+
+		loop {
+			let actual = self.locator.get_attribute(name).await?;
+			let is_match = attribute_equals(actual.as_deref(), expected);
+
+			// Check if condition matches (with negation support)
+			let matches = if self.negate { !is_match } else { is_match };
+
+			if matches {
+				return Ok(());
+			}
+
+			// Check timeout
+			if start.elapsed() >= self.timeout {
+				let message = if self.negate {
+					format!(
+						"Expected element '{}' NOT to have attribute {}='{}', but it did after {:?}",
+						selector, name, expected, self.timeout
+					)
+				} else {
+					format!(
+						"Expected element '{}' to have attribute {}='{}', but had {} after {:?}",
+						selector,
+						name,
+						expected,
+						describe_attribute(actual.as_deref()),
+						self.timeout
+					)
+				};
+				return Err(pw_runtime::Error::AssertionTimeout(message));
+			}
+
+			// Wait before next poll
+			tokio::time::sleep(self.poll_interval).await;
+		}
+	}
+
+	/// Asserts that the element has attribute `name` with a value matching the regex pattern.
+	///
+	/// This assertion will retry until the attribute matches the pattern or timeout.
+	/// A missing attribute never matches, even a pattern that accepts the empty string.
+	pub async fn to_have_attribute_regex(self, name: &str, pattern: &str) -> Result<()> {
+		let start = std::time::Instant::now();
+		let selector = self.locator.selector().to_string();
+		let re = regex::Regex::new(pattern).map_err(|e| pw_runtime::Error::InvalidArgument(format!("Invalid regex: {}", e)))?;
+
+		loop {
+			let actual = self.locator.get_attribute(name).await?;
+			let is_match = actual.as_deref().is_some_and(|value| re.is_match(value));
+
+			// Check if condition matches (with negation support)
+			let matches = if self.negate { !is_match } else { is_match };
+
+			if matches {
+				return Ok(());
+			}
+
+			// Check timeout
+			if start.elapsed() >= self.timeout {
+				let message = if self.negate {
+					format!(
+						"Expected element '{}' NOT to have attribute {} matching pattern '{}', but it did after {:?}",
+						selector, name, pattern, self.timeout
+					)
+				} else {
+					format!(
+						"Expected element '{}' to have attribute {} matching pattern '{}', but had {} after {:?}",
+						selector,
+						name,
+						pattern,
+						describe_attribute(actual.as_deref()),
+						self.timeout
+					)
+				};
+				return Err(pw_runtime::Error::AssertionTimeout(message));
+			}
+
+			// Wait before next poll
+			tokio::time::sleep(self.poll_interval).await;
+		}
+	}
+
+	/// Asserts that the locator matches exactly `expected` elements.
+	///
+	/// This assertion will retry until the count equals `expected` or timeout,
+	/// so it waits for lists that are still being rendered.
+	///
+	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-count>
+	pub async fn to_have_count(self, expected: usize) -> Result<()> {
+		let start = std::time::Instant::now();
+		let selector = self.locator.selector().to_string();
+
+		loop {
+			let actual = self.locator.count().await?;
+
+			// Check if condition matches (with negation support)
+			let matches = if self.negate { actual != expected } else { actual == expected };
+
+			if matches {
+				return Ok(());
+			}
+
+			// Check timeout
+			if start.elapsed() >= self.timeout {
+				let message = if self.negate {
+					format!(
+						"Expected locator '{}' NOT to match {}, but it did after {:?}",
+						selector,
+						count_elements(expected),
+						self.timeout
+					)
+				} else {
+					format!(
+						"Expected locator '{}' to match {}, found {} after {:?}",
+						selector,
+						count_elements(expected),
+						actual,
+						self.timeout
+					)
+				};
+				return Err(pw_runtime::Error::AssertionTimeout(message));
+			}
+
+			// Wait before next poll
+			tokio::time::sleep(self.poll_interval).await;
+		}
+	}
+
 	/// Asserts that the element is enabled.
 	///
 	/// This assertion will retry until the element is enabled or timeout.
-	/// An element is enabled if it does not have the "disabled" attribute.
+	/// An element is disabled when it is a form control with the `disabled`
+	/// attribute, sits inside a disabled `<fieldset>`, or has `aria-disabled="true"`;
+	/// everything else is enabled.
 	///
 	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-enabled>
 	pub async fn to_be_enabled(self) -> Result<()> {
@@ -468,17 +608,39 @@ impl Expectation {
 	/// Asserts that the element is disabled.
 	///
 	/// This assertion will retry until the element is disabled or timeout.
-	/// An element is disabled if it has the "disabled" attribute.
+	/// See [`to_be_enabled`](Self::to_be_enabled) for what counts as disabled.
 	///
 	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-disabled>
 	pub async fn to_be_disabled(self) -> Result<()> {
-		// to_be_disabled is the opposite of to_be_enabled
-		// Use negation to reuse the enabled logic
-		let negated = Expectation {
-			negate: !self.negate, // Flip negation
-			..self
-		};
-		negated.to_be_enabled().await
+		let start = std::time::Instant::now();
+		let selector = self.locator.selector().to_string();
+
+		loop {
+			let is_disabled = !self.locator.is_enabled().await?;
+
+			// Check if condition matches (with negation support)
+			let matches = if self.negate { !is_disabled } else { is_disabled };
+
+			if matches {
+				return Ok(());
+			}
+
+			// Check timeout
+			if start.elapsed() >= self.timeout {
+				let message = if self.negate {
+					format!(
+						"Expected element '{}' NOT to be disabled, but it was disabled after {:?}",
+						selector, self.timeout
+					)
+				} else {
+					format!("Expected element '{}' to be disabled, but it was enabled after {:?}", selector, self.timeout)
+				};
+				return Err(pw_runtime::Error::AssertionTimeout(message));
+			}
+
+			// Wait before next poll
+			tokio::time::sleep(self.poll_interval).await;
+		}
 	}
 
 	/// Asserts that the checkbox or radio button is checked.
@@ -605,3 +767,42 @@ impl Expectation {
 		}
 	}
 }
+
+/// Compares an attribute value, treating a missing attribute as a non-match.
+fn attribute_equals(actual: Option<&str>, expected: &str) -> bool {
+	actual == Some(expected)
+}
+
+/// Formats an element count for assertion failure messages, e.g. `1 element` or `3 elements`.
+fn count_elements(count: usize) -> String {
+	if count == 1 { "1 element".to_string() } else { format!("{count} elements") }
+}
+
+/// Describes an attribute value for assertion failure messages.
+fn describe_attribute(actual: Option<&str>) -> String {
+	match actual {
+		Some(value) => format!("'{value}'"),
+		None => "no such attribute".to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn missing_attribute_differs_from_empty_value() {
+		assert!(attribute_equals(Some(""), ""));
+		assert!(!attribute_equals(None, ""));
+		assert!(attribute_equals(Some("true"), "true"));
+		assert_eq!(describe_attribute(Some("")), "''");
+		assert_eq!(describe_attribute(None), "no such attribute");
+	}
+
+	#[test]
+	fn count_elements_pluralizes() {
+		assert_eq!(count_elements(0), "0 elements");
+		assert_eq!(count_elements(1), "1 element");
+		assert_eq!(count_elements(3), "3 elements");
+	}
+}
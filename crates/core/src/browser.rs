@@ -1,20 +1,25 @@
 //! Browser protocol object.
 //!
 //! [`Browser`] represents a launched browser process and provides context/page
-//! creation plus lifecycle operations (`close`, connection state tracking).
+//! creation plus lifecycle operations (`close`, connection state tracking,
+//! disconnect notification).
 //!
 //! Instances are created by [`crate::BrowserType::launch`] and backed by a
 //! runtime channel owner.
 
+use std::future::Future;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use indexmap::IndexMap;
+use parking_lot::Mutex;
 use pw_runtime::Result;
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use serde::Deserialize;
 use serde_json::Value;
 
+use crate::handlers::{HandlerEntry, HandlerFn, HandlerFuture, HandlerMap, Subscription, next_handler_id};
 use crate::{BrowserContext, Page};
 
 /// Browser represents a browser instance.
@@ -60,6 +65,8 @@ pub struct Browser {
 	version: String,
 	name: String,
 	is_connected: Arc<AtomicBool>,
+	/// Handlers run once when the browser disconnects.
+	disconnected_handlers: HandlerMap<()>,
 }
 
 impl Browser {
@@ -91,11 +98,27 @@ impl Browser {
 			.ok_or_else(|| pw_runtime::Error::ProtocolError("Browser initializer missing 'name' field".to_string()))?
 			.to_string();
 
+		let is_connected = Arc::new(AtomicBool::new(true));
+		let disconnected_handlers: HandlerMap<()> = Arc::new(Mutex::new(IndexMap::new()));
+
+		// The server never sends `close` when the driver dies, so also watch the
+		// connection itself. Weak references keep the task from pinning the browser.
+		let mut closed = base.connection().closed();
+		let weak_connected = Arc::downgrade(&is_connected);
+		let weak_handlers = Arc::downgrade(&disconnected_handlers);
+		tokio::spawn(async move {
+			let _ = closed.wait_for(|closed| *closed).await;
+			if let (Some(is_connected), Some(handlers)) = (weak_connected.upgrade(), weak_handlers.upgrade()) {
+				mark_disconnected(&is_connected, &handlers);
+			}
+		});
+
 		Ok(Self {
 			base,
 			version,
 			name,
-			is_connected: Arc::new(AtomicBool::new(true)),
+			is_connected,
+			disconnected_handlers,
 		})
 	}
 
@@ -125,6 +148,27 @@ impl Browser {
 		self.is_connected.load(Ordering::SeqCst)
 	}
 
+	/// Registers a handler that runs once when the browser disconnects.
+	///
+	/// Fires when [`close`](Self::close) completes, when the browser process
+	/// exits or crashes, or when the connection to the Playwright driver drops.
+	/// Handlers registered after the disconnect never run.
+	/// Returns a [`Subscription`] that unregisters the handler when dropped.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browser#browser-event-disconnected>
+	pub fn on_disconnected<F, Fut>(&self, handler: F) -> Subscription
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<()>> + Send + 'static,
+	{
+		let id = next_handler_id();
+		let handler: HandlerFn<()> = Arc::new(move |()| -> HandlerFuture { Box::pin(handler()) });
+
+		self.disconnected_handlers.lock().insert(id, HandlerEntry { id, meta: (), handler });
+
+		Subscription::from_handler_map(id, &self.disconnected_handlers)
+	}
+
 	/// Returns the channel for sending protocol messages
 	///
 	/// Used internally for sending RPC calls to the browser.
@@ -301,7 +345,7 @@ impl ChannelOwner for Browser {
 	}
 
 	fn dispose(&self, reason: pw_runtime::channel_owner::DisposeReason) {
-		self.is_connected.store(false, Ordering::SeqCst);
+		mark_disconnected(&self.is_connected, &self.disconnected_handlers);
 		self.base.dispose(reason)
 	}
 
@@ -318,8 +362,8 @@ impl ChannelOwner for Browser {
 	}
 
 	fn on_event(&self, method: &str, params: Value) {
-		if method == "disconnected" {
-			self.is_connected.store(false, Ordering::SeqCst);
+		if method == "close" || method == "disconnected" {
+			mark_disconnected(&self.is_connected, &self.disconnected_handlers);
 		}
 		self.base.on_event(method, params)
 	}
@@ -329,6 +373,25 @@ impl ChannelOwner for Browser {
 	}
 }
 
+/// Flips the browser to disconnected and runs its disconnect handlers, once.
+fn mark_disconnected(is_connected: &AtomicBool, handlers: &HandlerMap<()>) {
+	if !is_connected.swap(false, Ordering::SeqCst) {
+		return;
+	}
+
+	let handlers: Vec<_> = handlers.lock().values().map(|e| (e.id, e.handler.clone())).collect();
+	if handlers.is_empty() {
+		return;
+	}
+	tokio::spawn(async move {
+		for (id, handler) in handlers {
+			if let Err(e) = handler(()).await {
+				tracing::error!(error = %e, handler_id = id, "Disconnected handler error");
+			}
+		}
+	});
+}
+
 impl std::fmt::Debug for Browser {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Browser")
@@ -339,8 +402,83 @@ impl std::fmt::Debug for Browser {
 	}
 }
 
-// Note: Browser testing is done via integration tests since it requires:
-// - A real Connection with object registry
-// - Protocol messages from the server
-// - BrowserType.launch() to create Browser objects
-// See: crates/playwright-core/tests/browser_launch_integration.rs (Phase 2 Slice 3)
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use pw_runtime::{Connection, ConnectionConfig, PipeTransport};
+	use serde_json::json;
+	use tokio::io::duplex;
+	use tokio::sync::mpsc;
+
+	use super::*;
+	use crate::root::Root;
+
+	fn test_browser(connection: &Arc<Connection>) -> Browser {
+		let root: Arc<dyn ChannelOwner> = Arc::new(Root::new(Arc::clone(connection) as Arc<dyn pw_runtime::ConnectionLike>));
+		Browser::new(
+			root,
+			"Browser".to_string(),
+			Arc::from("browser@test"),
+			json!({ "version": "131.0.6778.33", "name": "chromium" }),
+		)
+		.unwrap()
+	}
+
+	fn counting_handler(browser: &Browser) -> (Subscription, mpsc::UnboundedReceiver<()>) {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let subscription = browser.on_disconnected(move || {
+			let tx = tx.clone();
+			async move {
+				let _ = tx.send(());
+				Ok(())
+			}
+		});
+		(subscription, rx)
+	}
+
+	async fn expect_fired(rx: &mut mpsc::UnboundedReceiver<()>) {
+		tokio::time::timeout(Duration::from_secs(5), rx.recv())
+			.await
+			.expect("disconnect handler should run")
+			.unwrap();
+	}
+
+	#[tokio::test]
+	async fn close_event_fires_disconnect_handler_once() {
+		let (stdin_write, _stdin_read) = duplex(1024);
+		let (_stdout_write, stdout_read) = duplex(1024);
+		let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+		let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx), ConnectionConfig::default()));
+
+		let browser = test_browser(&connection);
+		assert_eq!(browser.version(), "131.0.6778.33");
+		let (_subscription, mut fired) = counting_handler(&browser);
+
+		browser.on_event("close", json!({}));
+		expect_fired(&mut fired).await;
+		assert!(!browser.is_connected());
+
+		browser.on_event("close", json!({}));
+		tokio::time::sleep(Duration::from_millis(50)).await;
+		assert!(fired.try_recv().is_err(), "handler should run only once");
+	}
+
+	#[tokio::test]
+	async fn dropped_connection_fires_disconnect_handler() {
+		let (stdin_write, _stdin_read) = duplex(1024);
+		let (stdout_write, stdout_read) = duplex(1024);
+		let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+		let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx), ConnectionConfig::default()));
+
+		let browser = test_browser(&connection);
+		let (_subscription, mut fired) = counting_handler(&browser);
+
+		let runner = Arc::clone(&connection);
+		tokio::spawn(async move { runner.run().await });
+		drop(stdout_write);
+
+		expect_fired(&mut fired).await;
+		assert!(!browser.is_connected());
+	}
+}
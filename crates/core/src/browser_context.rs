@@ -8,16 +8,18 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use pw_runtime::Result;
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
 
-use crate::Page;
 use crate::cookie::{ClearCookiesOptions, Cookie, StorageState, StorageStateOptions};
 use crate::tracing::Tracing;
+use crate::{Page, ResponseObject};
 
 /// Options for [`BrowserContext::route_from_har`].
 #[derive(Debug, Clone, Default)]
@@ -109,6 +111,18 @@ impl HarNotFound {
 #[derive(Clone)]
 pub struct BrowserContext {
 	base: ChannelOwnerImpl,
+	/// Response GUIDs from `response` events, resolved in arrival order.
+	response_queue: mpsc::UnboundedSender<String>,
+	/// Resolved responses broadcast to [`BrowserContext::response_events`] subscribers.
+	response_tx: broadcast::Sender<ResponseObject>,
+	/// Console messages with their page GUID, forwarded to pages in arrival order.
+	console_queue: mpsc::UnboundedSender<(String, crate::ConsoleMessage)>,
+	/// Uncaught page exceptions with their page GUID, forwarded in arrival order.
+	page_error_queue: mpsc::UnboundedSender<(String, crate::PageError)>,
+	/// Page GUIDs from `page` events, forwarded to their openers in arrival order.
+	page_queue: mpsc::UnboundedSender<String>,
+	/// Whether [`BrowserContext::capture_console`] subscribed to `console` events.
+	console_enabled: Arc<AtomicBool>,
 }
 
 impl BrowserContext {
@@ -130,10 +144,86 @@ impl BrowserContext {
 	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
 
-		let context = Self { base };
+		let (response_tx, _) = broadcast::channel(256);
+		let (response_queue, mut queued_responses) = mpsc::unbounded_channel::<String>();
+		let (console_queue, mut queued_console) = mpsc::unbounded_channel::<(String, crate::ConsoleMessage)>();
+		let (page_error_queue, mut queued_page_errors) = mpsc::unbounded_channel::<(String, crate::PageError)>();
+		let (page_queue, mut queued_pages) = mpsc::unbounded_channel::<String>();
+		let context = Self {
+			base,
+			response_queue,
+			response_tx: response_tx.clone(),
+			console_queue,
+			page_error_queue,
+			page_queue,
+			console_enabled: Arc::new(AtomicBool::new(false)),
+		};
+
+		// Forward console messages on a single task so each page logs them
+		// in the order the server emitted the events.
+		let connection = context.connection();
+		tokio::spawn(async move {
+			while let Some((page_guid, message)) = queued_console.recv().await {
+				let Ok(page_arc) = connection.get_object(&page_guid).await else {
+					continue;
+				};
+				if let Some(page) = page_arc.downcast_ref::<Page>() {
+					page.dispatch_console(message);
+				}
+			}
+		});
+
+		let connection = context.connection();
+		tokio::spawn(async move {
+			while let Some((page_guid, error)) = queued_page_errors.recv().await {
+				let Ok(page_arc) = connection.get_object(&page_guid).await else {
+					continue;
+				};
+				if let Some(page) = page_arc.downcast_ref::<Page>() {
+					page.record_page_error(error);
+				}
+			}
+		});
+
+		// Popups are announced as context pages; forward each one to its opener
+		// on a single task so popups reach the opener in the order they opened.
+		let connection = context.connection();
+		tokio::spawn(async move {
+			while let Some(page_guid) = queued_pages.recv().await {
+				let Ok(page_arc) = connection.get_object(&page_guid).await else {
+					continue;
+				};
+				let Some(page) = page_arc.downcast_ref::<Page>().cloned() else {
+					continue;
+				};
+				let Some(opener_guid) = page.opener_guid() else {
+					continue;
+				};
+				let Ok(opener_arc) = connection.get_object(opener_guid).await else {
+					continue;
+				};
+				if let Some(opener) = opener_arc.downcast_ref::<Page>() {
+					opener.dispatch_popup(page);
+				}
+			}
+		});
+
+		// Resolve response objects on a single task so subscribers observe
+		// them in the order the server emitted the events.
+		let connection = context.connection();
+		tokio::spawn(async move {
+			while let Some(guid) = queued_responses.recv().await {
+				let Ok(response_arc) = connection.get_object(&guid).await else {
+					continue;
+				};
+				if let Some(response) = response_arc.downcast_ref::<ResponseObject>() {
+					let _ = response_tx.send(response.clone());
+				}
+			}
+		});
 
 		// Enable dialog event subscription
-		// Dialog events need to be explicitly subscribed to via updateSubscription command
+		// This event needs to be explicitly subscribed to via updateSubscription command
 		let channel = context.channel().clone();
 		tokio::spawn(async move {
 			let _ = channel
@@ -157,6 +247,61 @@ impl BrowserContext {
 		self.base.channel()
 	}
 
+	/// Starts delivering console messages to this context's pages.
+	///
+	/// Pages only see [`ConsoleMessage`](crate::ConsoleMessage)s logged after
+	/// the first call; later calls return immediately.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-console>
+	pub async fn capture_console(&self) -> Result<()> {
+		if self.console_enabled.swap(true, Ordering::AcqRel) {
+			return Ok(());
+		}
+		let subscribed = self
+			.channel()
+			.send_no_result(
+				"updateSubscription",
+				serde_json::json!({
+					"event": "console",
+					"enabled": true
+				}),
+			)
+			.await;
+		if subscribed.is_err() {
+			self.console_enabled.store(false, Ordering::Release);
+		}
+		subscribed
+	}
+
+	/// Subscribes to every response received by pages in this context.
+	///
+	/// Responses arrive in protocol order, so a main-frame navigation yields
+	/// each redirect hop before the final document response. Only responses
+	/// emitted after subscribing are delivered. The server stops sending
+	/// `response` events once the last [`ResponseEvents`] is dropped.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-response>
+	pub async fn response_events(&self) -> Result<ResponseEvents> {
+		let receiver = self.response_tx.subscribe();
+		self.set_response_subscription(true).await?;
+		Ok(ResponseEvents {
+			receiver,
+			context: self.clone(),
+		})
+	}
+
+	async fn set_response_subscription(&self, enabled: bool) -> Result<()> {
+		self.channel()
+			.send_no_result(
+				"updateSubscription",
+				serde_json::json!({
+					"event": "response",
+					"enabled": enabled
+				}),
+			)
+			.await
+	}
+
 	/// Returns all pages in this browser context.
 	///
 	/// This returns all currently open pages (tabs) within this context.
@@ -227,6 +372,14 @@ impl BrowserContext {
 		self.channel().send_no_result("close", serde_json::json!({})).await
 	}
 
+	/// Pauses automation and opens the Playwright Inspector.
+	///
+	/// Resolves once the user resumes from the inspector. A headless browser
+	/// has no inspector to resume from, so the call never returns there.
+	pub async fn pause(&self) -> Result<()> {
+		self.channel().send_no_result("pause", serde_json::json!({})).await
+	}
+
 	/// Adds cookies to the browser context.
 	///
 	/// Cookies can be specified with either a domain or a URL. If URL is provided,
@@ -638,6 +791,45 @@ impl BrowserContext {
 	}
 }
 
+/// Responses received by a [`BrowserContext`], returned by [`BrowserContext::response_events`].
+///
+/// Dropping the last one turns the context's `response` subscription back off.
+pub struct ResponseEvents {
+	receiver: broadcast::Receiver<ResponseObject>,
+	context: BrowserContext,
+}
+
+impl ResponseEvents {
+	/// Receives the next response, see [`broadcast::Receiver::recv`].
+	pub async fn recv(&mut self) -> std::result::Result<ResponseObject, broadcast::error::RecvError> {
+		self.receiver.recv().await
+	}
+}
+
+impl Drop for ResponseEvents {
+	fn drop(&mut self) {
+		// This receiver still counts until the fields drop.
+		if self.context.response_tx.receiver_count() > 1 {
+			return;
+		}
+		let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+			return;
+		};
+		let context = self.context.clone();
+		runtime.spawn(async move {
+			if context.response_tx.receiver_count() == 0 {
+				let _ = context.set_response_subscription(false).await;
+			}
+		});
+	}
+}
+
+impl std::fmt::Debug for ResponseEvents {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ResponseEvents").field("context", &self.context.guid()).finish()
+	}
+}
+
 impl pw_runtime::channel_owner::private::Sealed for BrowserContext {}
 
 impl ChannelOwner for BrowserContext {
@@ -683,6 +875,34 @@ impl ChannelOwner for BrowserContext {
 
 	fn on_event(&self, method: &str, params: Value) {
 		match method {
+			"response" => {
+				if self.response_tx.receiver_count() == 0 {
+					return;
+				}
+				if let Some(response_guid) = params.get("response").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) {
+					let _ = self.response_queue.send(response_guid.to_string());
+				}
+			}
+			"page" => {
+				if let Some(page_guid) = params.get("page").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) {
+					let _ = self.page_queue.send(page_guid.to_string());
+				}
+			}
+			"console" => {
+				// Console events come to BrowserContext with the originating page
+				// Event format: {type, text, location, page: {guid: "..."}}
+				if let Some(page_guid) = params.get("page").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) {
+					let _ = self.console_queue.send((page_guid.to_string(), crate::ConsoleMessage::from_protocol(&params)));
+				}
+			}
+			"pageError" => {
+				// Uncaught exceptions also arrive on the context: {error: SerializedError, page: {guid}}
+				let Some(page_guid) = params.get("page").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) else {
+					return;
+				};
+				let error = crate::PageError::from_protocol(params.get("error").unwrap_or(&Value::Null));
+				let _ = self.page_error_queue.send((page_guid.to_string(), error));
+			}
 			"dialog" => {
 				// Dialog events come to BrowserContext, need to forward to the associated Page
 				// Event format: {dialog: {guid: "..."}}
@@ -741,7 +961,7 @@ impl std::fmt::Debug for BrowserContext {
 /// Viewport dimensions for browser context.
 ///
 /// See: <https://playwright.dev/docs/api/class-browser#browser-new-context>
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Viewport {
 	/// Page width in pixels
 	pub width: u32,
@@ -752,7 +972,7 @@ pub struct Viewport {
 /// Geolocation coordinates.
 ///
 /// See: <https://playwright.dev/docs/api/class-browser#browser-new-context>
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Geolocation {
 	/// Latitude between -90 and 90
 	pub latitude: f64,
@@ -842,6 +1062,10 @@ pub struct BrowserContextOptions {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub color_scheme: Option<String>,
 
+	/// Emulates 'prefers-reduced-motion' media feature ("reduce", "no-preference")
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reduced_motion: Option<String>,
+
 	/// Whether the viewport supports touch events
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub has_touch: Option<bool>,
@@ -934,6 +1158,7 @@ pub struct BrowserContextOptionsBuilder {
 	geolocation: Option<Geolocation>,
 	permissions: Option<Vec<String>>,
 	color_scheme: Option<String>,
+	reduced_motion: Option<String>,
 	has_touch: Option<bool>,
 	is_mobile: Option<bool>,
 	javascript_enabled: Option<bool>,
@@ -1007,6 +1232,12 @@ impl BrowserContextOptionsBuilder {
 		self
 	}
 
+	/// Sets the reduced motion preference
+	pub fn reduced_motion(mut self, reduced_motion: String) -> Self {
+		self.reduced_motion = Some(reduced_motion);
+		self
+	}
+
 	/// Sets whether the viewport supports touch events
 	pub fn has_touch(mut self, has_touch: bool) -> Self {
 		self.has_touch = Some(has_touch);
@@ -1161,6 +1392,7 @@ impl BrowserContextOptionsBuilder {
 			geolocation: self.geolocation,
 			permissions: self.permissions,
 			color_scheme: self.color_scheme,
+			reduced_motion: self.reduced_motion,
 			has_touch: self.has_touch,
 			is_mobile: self.is_mobile,
 			javascript_enabled: self.javascript_enabled,
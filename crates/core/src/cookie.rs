@@ -76,6 +76,12 @@ pub struct Cookie {
 	/// URL to infer domain and path from. Either url or domain must be specified.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub url: Option<String>,
+
+	/// Top-level site a partitioned (CHIPS) cookie is keyed to, e.g. `"https://example.com"`.
+	///
+	/// See: https://developer.mozilla.org/en-US/docs/Web/Privacy/Guides/Privacy_sandbox/Partitioned_cookies
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub partition_key: Option<String>,
 }
 
 impl Cookie {
@@ -97,6 +103,7 @@ impl Cookie {
 			secure: None,
 			same_site: None,
 			url: None,
+			partition_key: None,
 		}
 	}
 
@@ -118,6 +125,7 @@ impl Cookie {
 			secure: None,
 			same_site: None,
 			url: Some(url.into()),
+			partition_key: None,
 		}
 	}
 
@@ -150,6 +158,12 @@ impl Cookie {
 		self.same_site = Some(same_site);
 		self
 	}
+
+	/// Partitions the cookie under the given top-level site
+	pub fn partition_key(mut self, partition_key: impl Into<String>) -> Self {
+		self.partition_key = Some(partition_key.into());
+		self
+	}
 }
 
 /// Options for clearing cookies.
@@ -275,6 +289,37 @@ impl StorageState {
 		let content = serde_json::to_string_pretty(self).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 		std::fs::write(path, content)
 	}
+
+	/// Merges `other` into this state, with `other` winning conflicts.
+	///
+	/// Cookies are keyed by name, domain, path and partition key; a cookie from `other`
+	/// replaces the matching one in place. Origins are keyed by origin, and
+	/// their localStorage entries by name.
+	pub fn merge(&mut self, other: StorageState) {
+		for cookie in other.cookies {
+			let existing = self
+				.cookies
+				.iter_mut()
+				.find(|c| c.name == cookie.name && c.domain == cookie.domain && c.path == cookie.path && c.partition_key == cookie.partition_key);
+			match existing {
+				Some(slot) => *slot = cookie,
+				None => self.cookies.push(cookie),
+			}
+		}
+
+		for origin in other.origins {
+			let Some(target) = self.origins.iter_mut().find(|o| o.origin == origin.origin) else {
+				self.origins.push(origin);
+				continue;
+			};
+			for entry in origin.local_storage {
+				match target.local_storage.iter_mut().find(|e| e.name == entry.name) {
+					Some(slot) => *slot = entry,
+					None => target.local_storage.push(entry),
+				}
+			}
+		}
+	}
 }
 
 /// Options for the storage_state() method.
@@ -344,6 +389,23 @@ mod tests {
 		assert!(json.contains("\"sameSite\":\"Lax\""));
 	}
 
+	#[test]
+	fn test_chrome_cookie_with_partition_key() {
+		let json = r#"{"name":"__Host-pk","value":"v1","domain":"widgets.example.net","path":"/","expires":1798761600.5,"httpOnly":true,"secure":true,"sameSite":"Lax","partitionKey":"https://example.com"}"#;
+		let cookie: Cookie = serde_json::from_str(json).unwrap();
+		assert_eq!(cookie.same_site, Some(SameSite::Lax));
+		assert_eq!(cookie.partition_key.as_deref(), Some("https://example.com"));
+
+		let value = serde_json::to_value(&cookie).unwrap();
+		assert_eq!(value["partitionKey"], "https://example.com");
+		assert!(
+			serde_json::to_value(Cookie::new("a", "b", "example.com"))
+				.unwrap()
+				.get("partitionKey")
+				.is_none()
+		);
+	}
+
 	#[test]
 	fn test_same_site_serialization() {
 		assert_eq!(serde_json::to_string(&SameSite::None).unwrap(), "\"None\"");
@@ -392,4 +454,62 @@ mod tests {
 		assert_eq!(restored.cookies[0].name, "session");
 		assert_eq!(restored.cookies[0].http_only, Some(true));
 	}
+
+	#[test]
+	fn test_storage_state_merge_keeps_partitions_apart() {
+		let mut state = StorageState::with_cookies(vec![Cookie::new("sid", "a", ".example.net").partition_key("https://a.com")]);
+		state.merge(StorageState::with_cookies(vec![
+			Cookie::new("sid", "b", ".example.net").partition_key("https://b.com"),
+			Cookie::new("sid", "a2", ".example.net").partition_key("https://a.com"),
+		]));
+
+		let values: Vec<&str> = state.cookies.iter().map(|c| c.value.as_str()).collect();
+		assert_eq!(values, ["a2", "b"]);
+	}
+
+	#[test]
+	fn test_storage_state_merge_last_wins() {
+		let mut state = StorageState {
+			cookies: vec![
+				Cookie::new("session", "old", ".example.com"),
+				Cookie::new("session", "other-path", ".example.com").path("/admin"),
+			],
+			origins: vec![OriginState {
+				origin: "https://example.com".into(),
+				local_storage: vec![
+					LocalStorageEntry {
+						name: "token".into(),
+						value: "old".into(),
+					},
+					LocalStorageEntry {
+						name: "theme".into(),
+						value: "dark".into(),
+					},
+				],
+			}],
+		};
+
+		state.merge(StorageState {
+			cookies: vec![Cookie::new("session", "new", ".example.com"), Cookie::new("sid", "x", ".other.com")],
+			origins: vec![
+				OriginState {
+					origin: "https://example.com".into(),
+					local_storage: vec![LocalStorageEntry {
+						name: "token".into(),
+						value: "new".into(),
+					}],
+				},
+				OriginState {
+					origin: "https://other.com".into(),
+					local_storage: Vec::new(),
+				},
+			],
+		});
+
+		let values: Vec<_> = state.cookies.iter().map(|c| c.value.as_str()).collect();
+		assert_eq!(values, ["new", "other-path", "x"]);
+		assert_eq!(state.origins.len(), 2);
+		let storage: Vec<_> = state.origins[0].local_storage.iter().map(|e| (e.name.as_str(), e.value.as_str())).collect();
+		assert_eq!(storage, [("token", "new"), ("theme", "dark")]);
+	}
 }
@@ -3,16 +3,43 @@
 //! [`ElementHandle`] represents a concrete DOM node reference returned from
 //! selector queries and supports element-scoped operations.
 //!
-//! This module currently focuses on screenshot capture and protocol plumbing.
+//! This module currently focuses on screenshot capture, scrolling, geometry,
+//! and protocol plumbing.
 
 use std::sync::Arc;
 
 use base64::Engine;
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Element bounds in CSS pixels, relative to the main frame's viewport.
+///
+/// Offsets of enclosing iframes (including cross-origin ones) are already
+/// applied, so the box can be fed straight into mouse or screenshot clip
+/// coordinates.
+///
+/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct BoundingBox {
+	/// X coordinate of the top-left corner.
+	pub x: f64,
+	/// Y coordinate of the top-left corner.
+	pub y: f64,
+	/// Width of the element.
+	pub width: f64,
+	/// Height of the element.
+	pub height: f64,
+}
+
+impl BoundingBox {
+	/// Returns the center point of the box as `(x, y)`.
+	pub fn center(&self) -> (f64, f64) {
+		(self.x + self.width / 2.0, self.y + self.height / 2.0)
+	}
+}
+
 /// ElementHandle represents a DOM element in the page.
 ///
 /// ElementHandles are created via `page.query_selector()` or `frame.query_selector()`.
@@ -82,6 +109,50 @@ impl ElementHandle {
 
 		Ok(bytes)
 	}
+
+	/// Returns the element's bounding box, or [`None`] if the element is not
+	/// rendered (e.g. `display: none` or detached).
+	///
+	/// The box is computed by the browser rather than via
+	/// `getBoundingClientRect`, so elements inside iframes report positions
+	/// relative to the top-level page.
+	///
+	/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
+	pub async fn bounding_box(&self) -> Result<Option<BoundingBox>> {
+		#[derive(Deserialize)]
+		struct BoundingBoxResponse {
+			value: Option<BoundingBox>,
+		}
+
+		let response: BoundingBoxResponse = self.base.channel().send("boundingBox", serde_json::json!({})).await?;
+		Ok(response.value)
+	}
+
+	/// Scrolls the element into view unless it is already completely visible.
+	///
+	/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-scroll-into-view-if-needed>
+	pub async fn scroll_into_view_if_needed(&self) -> Result<()> {
+		self.base
+			.channel()
+			.send_no_result(
+				"scrollIntoViewIfNeeded",
+				serde_json::json!({
+					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+				}),
+			)
+			.await
+	}
+
+	/// Releases the element on the browser side.
+	///
+	/// The handle keeps its DOM node alive until released or until the page
+	/// navigates. This is Playwright's `dispose`; the name avoids clashing with
+	/// [`ChannelOwner::dispose`], which only updates the local object registry.
+	///
+	/// See: <https://playwright.dev/docs/api/class-jshandle#js-handle-dispose>
+	pub async fn release(&self) -> Result<()> {
+		self.base.channel().send_no_result("dispose", serde_json::json!({})).await
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for ElementHandle {}
@@ -141,3 +212,33 @@ impl std::fmt::Debug for ElementHandle {
 		f.debug_struct("ElementHandle").field("guid", &self.guid()).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bounding_box_center_is_midpoint() {
+		let bbox = BoundingBox {
+			x: 40.0,
+			y: 60.0,
+			width: 100.0,
+			height: 50.0,
+		};
+		assert_eq!(bbox.center(), (90.0, 85.0));
+	}
+
+	#[test]
+	fn bounding_box_deserializes_protocol_rect() {
+		let bbox: BoundingBox = serde_json::from_value(serde_json::json!({"x": 8.5, "y": 16.0, "width": 120.0, "height": 24.0})).unwrap();
+		assert_eq!(
+			bbox,
+			BoundingBox {
+				x: 8.5,
+				y: 16.0,
+				width: 120.0,
+				height: 24.0
+			}
+		);
+	}
+}
@@ -6,7 +6,9 @@
 //! [`crate::Page`] owns the main frame and forwards many high-level methods to
 //! this module.
 
-use std::sync::Arc;
+use std::collections::BTreeSet;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
@@ -14,8 +16,10 @@ use pw_runtime::{Error, Result};
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use tokio::sync::{broadcast, watch};
 
-use crate::page::{GotoOptions, Response};
+use crate::handlers::RouteMatcher;
+use crate::page::{GotoOptions, Response, WaitUntil};
 
 /// Frame represents a frame within a page.
 ///
@@ -28,6 +32,87 @@ use crate::page::{GotoOptions, Response};
 #[derive(Clone)]
 pub struct Frame {
 	base: ChannelOwnerImpl,
+	/// URL of the last committed navigation.
+	url: Arc<RwLock<String>>,
+	/// Committed navigations, broadcast to [`Frame::navigations`] subscribers.
+	navigation_tx: broadcast::Sender<FrameNavigation>,
+	/// Lifecycle events (`load`, `domcontentloaded`, `networkidle`) reached by the current document.
+	load_states: Arc<watch::Sender<BTreeSet<String>>>,
+}
+
+/// A navigation committed by a [`Frame`].
+///
+/// See <https://playwright.dev/docs/api/class-page#page-event-frame-navigated>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameNavigation {
+	/// URL the frame navigated to.
+	pub url: String,
+	/// Whether a new document was loaded; `false` for fragment and History API navigations.
+	pub new_document: bool,
+	/// Error text when the navigation was aborted.
+	pub error: Option<String>,
+}
+
+/// URL pattern accepted by [`Frame::wait_for_url`] and [`Page::wait_for_url`](crate::Page::wait_for_url).
+#[derive(Debug, Clone)]
+pub enum UrlPattern {
+	/// Glob matched against the whole URL, e.g. `**/checkout/*`.
+	Glob(String),
+	/// Regular expression searched anywhere in the URL.
+	Regex(regex::Regex),
+}
+
+impl UrlPattern {
+	/// Compiles a regular expression pattern.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InvalidArgument`] if `pattern` is not a valid regex.
+	pub fn regex(pattern: &str) -> Result<Self> {
+		regex::Regex::new(pattern)
+			.map(Self::Regex)
+			.map_err(|e| Error::InvalidArgument(format!("Invalid regex: {e}")))
+	}
+
+	/// Returns `true` if `url` matches this pattern.
+	pub fn is_match(&self, url: &str) -> bool {
+		match self {
+			Self::Glob(glob) => RouteMatcher::new(glob).is_match(url),
+			Self::Regex(re) => re.is_match(url),
+		}
+	}
+}
+
+impl From<&str> for UrlPattern {
+	fn from(glob: &str) -> Self {
+		Self::Glob(glob.to_string())
+	}
+}
+
+impl From<regex::Regex> for UrlPattern {
+	fn from(re: regex::Regex) -> Self {
+		Self::Regex(re)
+	}
+}
+
+impl std::fmt::Display for UrlPattern {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Glob(glob) => f.write_str(glob),
+			Self::Regex(re) => write!(f, "/{}/", re.as_str()),
+		}
+	}
+}
+
+impl FrameNavigation {
+	/// Parses the params of a frame `navigated` event.
+	fn from_event(params: &Value) -> Option<Self> {
+		Some(Self {
+			url: params.get("url")?.as_str()?.to_string(),
+			new_document: params.get("newDocument").is_some_and(|doc| !doc.is_null()),
+			error: params.get("error").and_then(|e| e.as_str()).map(str::to_string),
+		})
+	}
 }
 
 impl Frame {
@@ -36,9 +121,107 @@ impl Frame {
 	/// This is called by the object factory when the server sends a `__create__` message
 	/// for a Frame object.
 	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
+		let load_states = initializer["loadStates"]
+			.as_array()
+			.map(|states| states.iter().filter_map(|s| s.as_str()).map(str::to_string).collect())
+			.unwrap_or_default();
+		let url = Arc::new(RwLock::new(initializer["url"].as_str().unwrap_or("about:blank").to_string()));
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
+		let (navigation_tx, _) = broadcast::channel(64);
 
-		Ok(Self { base })
+		Ok(Self {
+			base,
+			url,
+			navigation_tx,
+			load_states: Arc::new(watch::Sender::new(load_states)),
+		})
+	}
+
+	/// Returns the URL of the last committed navigation.
+	///
+	/// See <https://playwright.dev/docs/api/class-frame#frame-url>
+	pub fn url(&self) -> String {
+		self.url.read().unwrap_or_else(|e| e.into_inner()).clone()
+	}
+
+	/// Waits until the frame's URL matches `pattern`, then for the `load` state.
+	///
+	/// Resolves immediately if the current URL already matches. Otherwise each
+	/// committed navigation is checked, including History API and fragment
+	/// changes made by single-page apps. Returns the matching URL.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Timeout`] when `timeout` elapses first.
+	///
+	/// See <https://playwright.dev/docs/api/class-frame#frame-wait-for-url>
+	pub async fn wait_for_url(&self, pattern: impl Into<UrlPattern>, timeout: Duration) -> Result<String> {
+		let pattern = pattern.into();
+		let deadline = tokio::time::Instant::now() + timeout;
+		let mut navigations = self.navigations();
+
+		let current = self.url();
+		let url = if pattern.is_match(&current) {
+			current
+		} else {
+			tokio::time::timeout_at(deadline, async {
+				loop {
+					match navigations.recv().await {
+						Ok(navigation) if navigation.error.is_none() && pattern.is_match(&navigation.url) => return Ok(navigation.url),
+						Ok(_) => continue,
+						Err(broadcast::error::RecvError::Lagged(n)) => {
+							tracing::warn!(dropped = n, "Navigation receiver lagged");
+						}
+						Err(broadcast::error::RecvError::Closed) => return Err(Error::ChannelClosed),
+					}
+				}
+			})
+			.await
+			.map_err(|_| Error::Timeout(format!("Timeout {}ms exceeded waiting for URL matching '{pattern}'", timeout.as_millis())))??
+		};
+
+		let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+		self.wait_for_load_state(WaitUntil::Load, remaining).await?;
+		Ok(url)
+	}
+
+	/// Returns a broadcast receiver for navigations committed from now on.
+	///
+	/// Subscribe before triggering the action that navigates so the event
+	/// cannot be missed.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-event-frame-navigated>
+	pub fn navigations(&self) -> broadcast::Receiver<FrameNavigation> {
+		self.navigation_tx.subscribe()
+	}
+
+	/// Waits until the current document reaches `state`.
+	///
+	/// Resolves immediately if the state was already reached. [`WaitUntil::Commit`]
+	/// is always satisfied once a document exists.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Timeout`] when `timeout` elapses first.
+	///
+	/// See <https://playwright.dev/docs/api/class-frame#frame-wait-for-load-state>
+	pub async fn wait_for_load_state(&self, state: WaitUntil, timeout: Duration) -> Result<()> {
+		if state == WaitUntil::Commit {
+			return Ok(());
+		}
+
+		let mut states = self.load_states.subscribe();
+		tokio::time::timeout(timeout, states.wait_for(|reached| reached.contains(state.as_str())))
+			.await
+			.map_err(|_| {
+				Error::Timeout(format!(
+					"Timeout {}ms exceeded waiting for load state '{}'",
+					timeout.as_millis(),
+					state.as_str()
+				))
+			})?
+			.map(|_| ())
+			.map_err(|_| Error::ChannelClosed)
 	}
 
 	/// Returns the channel for sending protocol messages
@@ -97,32 +280,7 @@ impl Frame {
 		if let Some(response_ref) = goto_result.response {
 			let response_arc = self.connection().wait_for_object(&response_ref.guid, std::time::Duration::from_secs(1)).await?;
 
-			let initializer = response_arc.initializer();
-			let status = initializer["status"]
-				.as_u64()
-				.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing status".to_string()))? as u16;
-
-			let headers = initializer["headers"]
-				.as_array()
-				.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing headers".to_string()))?
-				.iter()
-				.filter_map(|h| {
-					let name = h["name"].as_str()?;
-					let value = h["value"].as_str()?;
-					Some((name.to_string(), value.to_string()))
-				})
-				.collect();
-
-			Ok(Some(Response {
-				url: initializer["url"]
-					.as_str()
-					.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing url".to_string()))?
-					.to_string(),
-				status,
-				status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
-				ok: (200..300).contains(&status),
-				headers,
-			}))
+			Ok(Some(Response::from_object(&response_arc)?))
 		} else {
 			Ok(None)
 		}
@@ -142,8 +300,6 @@ impl Frame {
 	}
 
 	/// Returns the first element matching the selector, or None if not found.
-	/// Playwright may encode the handle in either `element`, `handle`, or directly as
-	/// the response object depending on transport shape.
 	///
 	/// See: <https://playwright.dev/docs/api/class-frame#frame-query-selector>
 	pub async fn query_selector(&self, selector: &str) -> Result<Option<Arc<crate::ElementHandle>>> {
@@ -157,6 +313,39 @@ impl Frame {
 			)
 			.await?;
 
+		self.element_from_response(&response).await
+	}
+
+	/// Waits for the first element matching `selector` to reach a state.
+	///
+	/// Returns the element for `attached` and `visible`, and [`None`] for
+	/// `detached` and `hidden`, where there may be no element to return.
+	/// The default state is `visible`. Call [`ElementHandle::release`] on a
+	/// returned element that is no longer needed.
+	///
+	/// [`ElementHandle::release`]: crate::ElementHandle::release
+	///
+	/// # Errors
+	///
+	/// Returns a timeout error if the state is not reached within the timeout.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-selector>
+	pub async fn wait_for_selector(&self, selector: &str, options: Option<crate::WaitForSelectorOptions>) -> Result<Option<Arc<crate::ElementHandle>>> {
+		let mut params = serde_json::json!({ "selector": selector });
+		let opts_json = options.unwrap_or_default().to_json();
+		if let (Some(obj), Some(opts_obj)) = (params.as_object_mut(), opts_json.as_object()) {
+			obj.extend(opts_obj.clone());
+		}
+
+		let response: serde_json::Value = self.channel().send("waitForSelector", params).await?;
+		self.element_from_response(&response).await
+	}
+
+	/// Resolves the element handle carried by a selector query response.
+	///
+	/// Playwright may encode the handle in either `element`, `handle`, or directly as
+	/// the response object depending on transport shape.
+	async fn element_from_response(&self, response: &serde_json::Value) -> Result<Option<Arc<crate::ElementHandle>>> {
 		if response.as_object().map(|o| o.is_empty()).unwrap_or(true) {
 			return Ok(None);
 		}
@@ -166,7 +355,7 @@ impl Frame {
 		} else if let Some(elem) = response.get("handle") {
 			elem
 		} else {
-			&response
+			response
 		};
 
 		if element_value.is_null() {
@@ -584,6 +773,28 @@ impl Frame {
 		self.channel().send_no_result("press", params).await
 	}
 
+	/// Types text into the element one character at a time.
+	pub(crate) async fn locator_press_sequentially(&self, selector: &str, text: &str, options: Option<crate::PressSequentiallyOptions>) -> Result<()> {
+		let mut params = serde_json::json!({
+			"selector": selector,
+			"text": text,
+			"strict": true
+		});
+
+		if let Some(opts) = options {
+			let opts_json = opts.to_json();
+			if let Some(obj) = params.as_object_mut() {
+				if let Some(opts_obj) = opts_json.as_object() {
+					obj.extend(opts_obj.clone());
+				}
+			}
+		} else {
+			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+		}
+
+		self.channel().send_no_result("type", params).await
+	}
+
 	pub(crate) async fn locator_check(&self, selector: &str, options: Option<crate::CheckOptions>) -> Result<()> {
 		let mut params = serde_json::json!({
 			"selector": selector,
@@ -1018,6 +1229,80 @@ impl Frame {
 		serde_json::from_value(json_value).map_err(|e| Error::ProtocolError(format!("Failed to deserialize evaluate result: {}", e)))
 	}
 
+	/// Calls the JavaScript function `expression` with the first element matching
+	/// `selector` and an optional JSON `arg`, returning the result as JSON.
+	///
+	/// This is the internal implementation used by [`crate::Locator::evaluate`].
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ElementNotFound`] if nothing matches `selector`, or
+	/// [`Error::Remote`] if the function throws.
+	pub(crate) async fn locator_evaluate(&self, selector: &str, expression: &str, arg: Option<&Value>) -> Result<Value> {
+		let params = serde_json::json!({
+			"selector": selector,
+			"expression": expression,
+			"isFunction": true,
+			"arg": {
+				"value": Self::json_to_protocol_value(arg.unwrap_or(&Value::Null)),
+				"handles": []
+			},
+			"strict": false
+		});
+
+		#[derive(Deserialize)]
+		struct EvaluateResult {
+			value: Value,
+		}
+
+		let result: EvaluateResult = self
+			.channel()
+			.send("evalOnSelector", params)
+			.await
+			.map_err(|err| element_not_found_for(err, selector))?;
+		Self::protocol_value_to_json(&result.value)
+	}
+
+	/// Calls the JavaScript function `expression` with the array of all elements
+	/// matching `selector` (possibly empty), returning the result as JSON.
+	///
+	/// This is the internal implementation used by [`crate::Locator::evaluate_all`].
+	pub(crate) async fn locator_evaluate_all(&self, selector: &str, expression: &str) -> Result<Value> {
+		let params = serde_json::json!({
+			"selector": selector,
+			"expression": expression,
+			"isFunction": true,
+			"arg": {
+				"value": {"v": "undefined"},
+				"handles": []
+			}
+		});
+
+		#[derive(Deserialize)]
+		struct EvaluateResult {
+			value: Value,
+		}
+
+		let result: EvaluateResult = self.channel().send("evalOnSelectorAll", params).await?;
+		Self::protocol_value_to_json(&result.value)
+	}
+
+	/// Converts standard JSON to the Playwright protocol value format.
+	///
+	/// Inverse of [`Self::protocol_value_to_json`] for the JSON subset.
+	fn json_to_protocol_value(value: &Value) -> Value {
+		match value {
+			Value::Null => serde_json::json!({"v": "null"}),
+			Value::Bool(b) => serde_json::json!({"b": b}),
+			Value::Number(n) => serde_json::json!({"n": n}),
+			Value::String(s) => serde_json::json!({"s": s}),
+			Value::Array(items) => serde_json::json!({"a": items.iter().map(Self::json_to_protocol_value).collect::<Vec<_>>()}),
+			Value::Object(map) => serde_json::json!({
+				"o": map.iter().map(|(k, v)| serde_json::json!({"k": k, "v": Self::json_to_protocol_value(v)})).collect::<Vec<_>>()
+			}),
+		}
+	}
+
 	/// Converts Playwright protocol value format to standard JSON.
 	///
 	/// Playwright wraps JavaScript values in a specific format for serialization:
@@ -1129,9 +1414,32 @@ impl ChannelOwner for Frame {
 		self.base.remove_child(guid)
 	}
 
-	fn on_event(&self, _method: &str, _params: Value) {
-		// Frame-specific event handling is intentionally deferred until dedicated
-		// subscriptions are exposed on the public API.
+	fn on_event(&self, method: &str, params: Value) {
+		match method {
+			"navigated" => {
+				if let Some(navigation) = FrameNavigation::from_event(&params) {
+					if navigation.error.is_none() {
+						if let Ok(mut url) = self.url.write() {
+							*url = navigation.url.clone();
+						}
+					}
+					let _ = self.navigation_tx.send(navigation);
+				}
+			}
+			"loadstate" => {
+				let add = params.get("add").and_then(|v| v.as_str());
+				let remove = params.get("remove").and_then(|v| v.as_str());
+				self.load_states.send_modify(|states| {
+					if let Some(state) = add {
+						states.insert(state.to_string());
+					}
+					if let Some(state) = remove {
+						states.remove(state);
+					}
+				});
+			}
+			_ => {}
+		}
 	}
 
 	fn was_collected(&self) -> bool {
@@ -1144,3 +1452,88 @@ impl std::fmt::Debug for Frame {
 		f.debug_struct("Frame").field("guid", &self.guid()).finish()
 	}
 }
+
+/// Maps the server's "no element matches" failure to [`Error::ElementNotFound`].
+fn element_not_found_for(err: Error, selector: &str) -> Error {
+	let no_match = match &err {
+		Error::Remote { message, .. } | Error::ProtocolError(message) => message.contains("Failed to find element matching selector"),
+		_ => false,
+	};
+	if no_match {
+		Error::ElementNotFound(format!("No element matches locator '{selector}'"))
+	} else {
+		err
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn remote_no_match_maps_to_element_not_found() {
+		let remote = Error::Remote {
+			name: "Error".into(),
+			message: "Failed to find element matching selector \"#missing\"".into(),
+			stack: None,
+		};
+		let err = element_not_found_for(remote, "#missing");
+		assert!(matches!(&err, Error::ElementNotFound(msg) if msg.contains("#missing")), "{err:?}");
+
+		let thrown = Error::Remote {
+			name: "Error".into(),
+			message: "boom".into(),
+			stack: None,
+		};
+		assert!(matches!(element_not_found_for(thrown, "#x"), Error::Remote { .. }));
+	}
+
+	#[test]
+	fn json_to_protocol_value_wraps_each_type() {
+		let value = json!({"name": "pw", "tags": [1, true, null]});
+		assert_eq!(
+			Frame::json_to_protocol_value(&value),
+			json!({"o": [
+				{"k": "name", "v": {"s": "pw"}},
+				{"k": "tags", "v": {"a": [{"n": 1}, {"b": true}, {"v": "null"}]}}
+			]})
+		);
+	}
+
+	#[test]
+	fn frame_navigation_from_event() {
+		let navigation = FrameNavigation::from_event(&json!({"url": "https://example.com/?q=2", "name": "", "newDocument": {}})).unwrap();
+		assert_eq!(navigation.url, "https://example.com/?q=2");
+		assert!(navigation.new_document);
+		assert_eq!(navigation.error, None);
+
+		let same_document = FrameNavigation::from_event(&json!({"url": "https://example.com/#top", "name": ""})).unwrap();
+		assert!(!same_document.new_document);
+
+		let aborted = FrameNavigation::from_event(&json!({"url": "https://example.com/", "error": "net::ERR_ABORTED"})).unwrap();
+		assert_eq!(aborted.error.as_deref(), Some("net::ERR_ABORTED"));
+
+		assert_eq!(FrameNavigation::from_event(&json!({})), None);
+	}
+
+	#[test]
+	fn url_pattern_matches_glob_and_regex() {
+		let glob = UrlPattern::from("**/checkout/*");
+		assert!(glob.is_match("https://shop.example.com/checkout/payment"));
+		assert!(!glob.is_match("https://shop.example.com/cart"));
+
+		let re = UrlPattern::regex(r"/orders/\d+$").unwrap();
+		assert!(re.is_match("https://shop.example.com/orders/42"));
+		assert!(!re.is_match("https://shop.example.com/orders/new"));
+		assert_eq!(re.to_string(), r"//orders/\d+$/");
+	}
+
+	#[test]
+	fn json_to_protocol_value_round_trips() {
+		let value = json!({"nested": {"list": ["a", 2.5, false]}, "empty": []});
+		let wrapped = Frame::json_to_protocol_value(&value);
+		assert_eq!(Frame::protocol_value_to_json(&wrapped).unwrap(), value);
+	}
+}
@@ -95,7 +95,7 @@ pub struct LaunchOptions {
 }
 
 /// Filter or disable default browser arguments
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IgnoreDefaultArgs {
 	/// Ignore all default arguments
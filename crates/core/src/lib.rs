@@ -174,6 +174,7 @@ pub mod launch_options;
 pub mod locator;
 pub mod mouse;
 pub mod page;
+pub mod pdf;
 pub mod playwright;
 pub mod request;
 pub mod response;
@@ -187,12 +188,15 @@ pub mod video;
 pub use accessibility::{
 	Accessibility, AccessibilityNode, AccessibilitySnapshotOptions, AccessibilitySnapshotOptionsBuilder, AccessibilityValue, CheckedState, PressedState,
 };
-pub use action_options::{CheckOptions, FillOptions, HoverOptions, KeyboardOptions, MouseOptions, PressOptions, SelectOptions};
+pub use action_options::{
+	CheckOptions, FillOptions, HoverOptions, KeyboardOptions, MouseOptions, PressOptions, PressSequentiallyOptions, SelectOptions, WaitForSelectorOptions,
+	WaitForSelectorOptionsBuilder, WaitForSelectorState,
+};
 // Re-export assertions
 pub use assertions::{Expectation, expect};
 pub use browser::Browser;
 pub use browser_context::{
-	BrowserContext, BrowserContextOptions, BrowserContextOptionsBuilder, Geolocation, HarContentPolicy, HarMode, HarNotFound, HarStartOptions,
+	BrowserContext, BrowserContextOptions, BrowserContextOptionsBuilder, Geolocation, HarContentPolicy, HarMode, HarNotFound, HarStartOptions, ResponseEvents,
 	RouteFromHarOptions, Viewport,
 };
 pub use browser_type::{BrowserType, ConnectOverCDPResult, LaunchedServer};
@@ -200,23 +204,24 @@ pub use click::{ClickOptions, KeyboardModifier, MouseButton, Position};
 pub use cookie::{ClearCookiesOptions, Cookie, LocalStorageEntry, OriginState, SameSite, StorageState, StorageStateOptions};
 pub use dialog::Dialog;
 pub use download::Download;
-pub use element_handle::ElementHandle;
+pub use element_handle::{BoundingBox, ElementHandle};
 pub use events::{ConsoleSubscription, EventStream, EventWaiter};
 pub use file_payload::{FilePayload, FilePayloadBuilder};
-pub use frame::Frame;
+pub use frame::{Frame, FrameNavigation, UrlPattern};
 // Re-export initialization function
 pub use init::initialize_playwright;
 pub use keyboard::Keyboard;
 pub use launch_options::{IgnoreDefaultArgs, LaunchOptions, ProxySettings};
-pub use locator::Locator;
+pub use locator::{GetByRoleOptions, GetByRoleOptionsBuilder, Locator};
 pub use mouse::Mouse;
-pub use page::{ConsoleLocation, ConsoleMessage, ConsoleMessageKind, GotoOptions, Page, Response, Subscription, WaitUntil};
+pub use page::{ConsoleLocation, ConsoleMessage, ConsoleMessageKind, GotoOptions, Page, PageError, Response, Subscription, WaitUntil};
+pub use pdf::{PdfFormat, PdfMargin, PdfOptions, PdfOptionsBuilder};
 pub use playwright::Playwright;
 pub use request::Request;
-pub use response::ResponseObject;
+pub use response::{ResponseObject, SecurityDetails};
 pub use root::Root;
 pub use route::{ContinueOptions, ContinueOptionsBuilder, FulfillOptions, FulfillOptionsBuilder, Route};
-pub use screenshot::{ScreenshotClip, ScreenshotOptions, ScreenshotType};
+pub use screenshot::{ScreenshotAnimations, ScreenshotClip, ScreenshotOptions, ScreenshotType};
 pub use select_option::SelectOption;
 pub use tracing::{Tracing, TracingStartChunkOptions, TracingStartOptions, TracingStartOptionsBuilder, TracingStopOptions};
 pub use video::Video;
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use pw_runtime::Result;
+use pw_runtime::{Error, Result};
 
 use crate::Frame;
 
@@ -121,6 +121,10 @@ impl Locator {
 
 	/// Creates a locator for the nth matching element (0-indexed).
 	///
+	/// Negative indices count from the end, so `-1` is the last match. When
+	/// an action times out because `index` is beyond the current matches, it
+	/// fails with [`Error::IndexOutOfRange`] reporting the match count instead.
+	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-nth>
 	pub fn nth(&self, index: i32) -> Locator {
 		Locator::new(Arc::clone(&self.frame), format!("{} >> nth={}", self.selector, index))
@@ -133,6 +137,74 @@ impl Locator {
 		Locator::new(Arc::clone(&self.frame), format!("{} >> {}", self.selector, selector))
 	}
 
+	/// Creates a locator matching elements of either this locator or `other`.
+	///
+	/// Useful when a page ships one of several UI variants. Both locators must
+	/// belong to the same frame; the result resolves in this locator's frame.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-or>
+	pub fn or(&self, other: &Locator) -> Locator {
+		Locator::new(Arc::clone(&self.frame), internal_selector(&self.selector, "or", &other.selector))
+	}
+
+	/// Creates a locator matching only elements that satisfy both this locator and `other`.
+	///
+	/// Narrows ambiguous matches, e.g. a `button` that also carries a given
+	/// `data-testid`. Both locators must belong to the same frame.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-and>
+	pub fn and(&self, other: &Locator) -> Locator {
+		Locator::new(Arc::clone(&self.frame), internal_selector(&self.selector, "and", &other.selector))
+	}
+
+	/// Creates a sub-locator for elements with ARIA `role` within this locator's subtree.
+	///
+	/// See [`Page::get_by_role`](crate::Page::get_by_role) for how `options` narrow the match.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-role>
+	pub fn get_by_role(&self, role: &str, options: Option<GetByRoleOptions>) -> Locator {
+		self.locator(&options.unwrap_or_default().selector(role))
+	}
+
+	/// Resolves the locator to a handle for its first matching element.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ElementNotFound`](pw_runtime::Error::ElementNotFound) if nothing matches.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-element-handle>
+	pub async fn element_handle(&self) -> Result<Arc<crate::ElementHandle>> {
+		let handle = self.frame.query_selector(&self.selector).await?;
+		let found = handle.ok_or_else(|| Error::ElementNotFound(format!("No element matches locator '{}'", self.selector)));
+		self.explain_miss(found).await
+	}
+
+	/// Calls the JavaScript function `function_body` with the first matching
+	/// element as its first argument and `arg` as its second, returning the
+	/// result as JSON.
+	///
+	/// ```ignore
+	/// let href = page.locator("a.next").await.evaluate("el => el.href", None).await?;
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ElementNotFound`](pw_runtime::Error::ElementNotFound) if nothing matches.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate>
+	pub async fn evaluate(&self, function_body: &str, arg: Option<serde_json::Value>) -> Result<serde_json::Value> {
+		self.explain_miss(self.frame.locator_evaluate(&self.selector, function_body, arg.as_ref()).await)
+			.await
+	}
+
+	/// Calls the JavaScript function `function_body` with the array of all
+	/// matching elements (empty when nothing matches), returning the result as JSON.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate-all>
+	pub async fn evaluate_all(&self, function_body: &str) -> Result<serde_json::Value> {
+		self.frame.locator_evaluate_all(&self.selector, function_body).await
+	}
+
 	/// Returns the number of elements matching this locator.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-count>
@@ -144,28 +216,38 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-text-content>
 	pub async fn text_content(&self) -> Result<Option<String>> {
-		self.frame.locator_text_content(&self.selector).await
+		self.explain_miss(self.frame.locator_text_content(&self.selector).await).await
+	}
+
+	/// Returns the `textContent` of every matching element in document order.
+	///
+	/// Elements without text yield an empty string; no match yields an empty vector.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-all-text-contents>
+	pub async fn all_text_contents(&self) -> Result<Vec<String>> {
+		let value = self.evaluate_all("ee => ee.map(e => e.textContent || '')").await?;
+		serde_json::from_value(value).map_err(|e| Error::ProtocolError(format!("allTextContents returned non-string values: {e}")))
 	}
 
 	/// Returns the inner text of the element (visible text).
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-inner-text>
 	pub async fn inner_text(&self) -> Result<String> {
-		self.frame.locator_inner_text(&self.selector).await
+		self.explain_miss(self.frame.locator_inner_text(&self.selector).await).await
 	}
 
 	/// Returns the inner HTML of the element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-inner-html>
 	pub async fn inner_html(&self) -> Result<String> {
-		self.frame.locator_inner_html(&self.selector).await
+		self.explain_miss(self.frame.locator_inner_html(&self.selector).await).await
 	}
 
 	/// Returns the value of the specified attribute.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-get-attribute>
 	pub async fn get_attribute(&self, name: &str) -> Result<Option<String>> {
-		self.frame.locator_get_attribute(&self.selector, name).await
+		self.explain_miss(self.frame.locator_get_attribute(&self.selector, name).await).await
 	}
 
 	/// Returns whether the element is visible.
@@ -209,35 +291,48 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-click>
 	pub async fn click(&self, options: Option<crate::ClickOptions>) -> Result<()> {
-		self.frame.locator_click(&self.selector, options).await
+		self.explain_miss(self.frame.locator_click(&self.selector, options).await).await
 	}
 
 	/// Double clicks the element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-dblclick>
 	pub async fn dblclick(&self, options: Option<crate::ClickOptions>) -> Result<()> {
-		self.frame.locator_dblclick(&self.selector, options).await
+		self.explain_miss(self.frame.locator_dblclick(&self.selector, options).await).await
 	}
 
 	/// Fills the element with text.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-fill>
 	pub async fn fill(&self, text: &str, options: Option<crate::FillOptions>) -> Result<()> {
-		self.frame.locator_fill(&self.selector, text, options).await
+		self.explain_miss(self.frame.locator_fill(&self.selector, text, options).await).await
 	}
 
 	/// Clears the element's value.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-clear>
 	pub async fn clear(&self, options: Option<crate::FillOptions>) -> Result<()> {
-		self.frame.locator_clear(&self.selector, options).await
+		self.explain_miss(self.frame.locator_clear(&self.selector, options).await).await
 	}
 
 	/// Presses a key on the element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-press>
 	pub async fn press(&self, key: &str, options: Option<crate::PressOptions>) -> Result<()> {
-		self.frame.locator_press(&self.selector, key, options).await
+		self.explain_miss(self.frame.locator_press(&self.selector, key, options).await).await
+	}
+
+	/// Focuses the element and types `text` one character at a time.
+	///
+	/// Unlike [`fill`](Self::fill), each character sends `keydown`, `keypress`/`input`
+	/// and `keyup` events, as [`Keyboard::type_text`](crate::Keyboard::type_text) does.
+	/// Use it for inputs that react to individual keystrokes, such as
+	/// autocomplete fields and masked inputs.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-press-sequentially>
+	pub async fn press_sequentially(&self, text: &str, options: Option<crate::PressSequentiallyOptions>) -> Result<()> {
+		self.explain_miss(self.frame.locator_press_sequentially(&self.selector, text, options).await)
+			.await
 	}
 
 	/// Ensures the checkbox or radio button is checked.
@@ -246,7 +341,7 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-check>
 	pub async fn check(&self, options: Option<crate::CheckOptions>) -> Result<()> {
-		self.frame.locator_check(&self.selector, options).await
+		self.explain_miss(self.frame.locator_check(&self.selector, options).await).await
 	}
 
 	/// Ensures the checkbox is unchecked.
@@ -255,7 +350,7 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-uncheck>
 	pub async fn uncheck(&self, options: Option<crate::CheckOptions>) -> Result<()> {
-		self.frame.locator_uncheck(&self.selector, options).await
+		self.explain_miss(self.frame.locator_uncheck(&self.selector, options).await).await
 	}
 
 	/// Sets the checkbox or radio button to the specified checked state.
@@ -272,14 +367,14 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-hover>
 	pub async fn hover(&self, options: Option<crate::HoverOptions>) -> Result<()> {
-		self.frame.locator_hover(&self.selector, options).await
+		self.explain_miss(self.frame.locator_hover(&self.selector, options).await).await
 	}
 
 	/// Returns the value of the input, textarea, or select element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-input-value>
 	pub async fn input_value(&self, _options: Option<()>) -> Result<String> {
-		self.frame.locator_input_value(&self.selector).await
+		self.explain_miss(self.frame.locator_input_value(&self.selector).await).await
 	}
 
 	/// Selects one or more options in a select element.
@@ -288,7 +383,8 @@ impl Locator {
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-select-option>
 	pub async fn select_option(&self, value: impl Into<crate::SelectOption>, options: Option<crate::SelectOptions>) -> Result<Vec<String>> {
-		self.frame.locator_select_option(&self.selector, value.into(), options).await
+		self.explain_miss(self.frame.locator_select_option(&self.selector, value.into(), options).await)
+			.await
 	}
 
 	/// Selects multiple options in a select element.
@@ -302,35 +398,77 @@ impl Locator {
 		options: Option<crate::SelectOptions>,
 	) -> Result<Vec<String>> {
 		let select_options: Vec<crate::SelectOption> = values.iter().map(|v| v.clone().into()).collect();
-		self.frame.locator_select_option_multiple(&self.selector, select_options, options).await
+		self.explain_miss(self.frame.locator_select_option_multiple(&self.selector, select_options, options).await)
+			.await
 	}
 
 	/// Sets the file path(s) to upload to a file input element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files(&self, file: &std::path::PathBuf, _options: Option<()>) -> Result<()> {
-		self.frame.locator_set_input_files(&self.selector, file).await
+		self.explain_miss(self.frame.locator_set_input_files(&self.selector, file).await).await
 	}
 
 	/// Sets multiple file paths to upload to a file input element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files_multiple(&self, files: &[&std::path::PathBuf], _options: Option<()>) -> Result<()> {
-		self.frame.locator_set_input_files_multiple(&self.selector, files).await
+		self.explain_miss(self.frame.locator_set_input_files_multiple(&self.selector, files).await)
+			.await
 	}
 
 	/// Sets a file to upload using FilePayload (explicit name, mimeType, buffer).
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files_payload(&self, file: crate::FilePayload, _options: Option<()>) -> Result<()> {
-		self.frame.locator_set_input_files_payload(&self.selector, file).await
+		self.explain_miss(self.frame.locator_set_input_files_payload(&self.selector, file).await).await
 	}
 
 	/// Sets multiple files to upload using FilePayload.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files_payload_multiple(&self, files: &[crate::FilePayload], _options: Option<()>) -> Result<()> {
-		self.frame.locator_set_input_files_payload_multiple(&self.selector, files).await
+		self.explain_miss(self.frame.locator_set_input_files_payload_multiple(&self.selector, files).await)
+			.await
+	}
+
+	/// Scrolls the element into view unless it is already completely visible.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ElementNotFound`](pw_runtime::Error::ElementNotFound) if nothing matches.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-scroll-into-view-if-needed>
+	pub async fn scroll_into_view_if_needed(&self) -> Result<()> {
+		self.element_handle().await?.scroll_into_view_if_needed().await
+	}
+
+	/// Waits until the element this locator resolves to reaches a state
+	/// (default: `visible`).
+	///
+	/// Runs in strict mode, so it fails if the locator matches more than one
+	/// element.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-wait-for>
+	pub async fn wait_for(&self, options: Option<crate::WaitForSelectorOptions>) -> Result<()> {
+		let mut options = options.unwrap_or_default();
+		options.strict = Some(true);
+		if let Some(element) = self.frame.wait_for_selector(&self.selector, Some(options)).await? {
+			element.release().await?;
+		}
+		Ok(())
+	}
+
+	/// Returns the bounding box of the first matching element in page
+	/// coordinates, or [`None`] if it is not rendered.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::ElementNotFound`](pw_runtime::Error::ElementNotFound) if nothing matches.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-bounding-box>
+	pub async fn bounding_box(&self) -> Result<Option<crate::BoundingBox>> {
+		self.element_handle().await?.bounding_box().await
 	}
 
 	/// Takes a screenshot of the element and returns the image bytes.
@@ -341,19 +479,230 @@ impl Locator {
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-screenshot>
 	pub async fn screenshot(&self, options: Option<crate::ScreenshotOptions>) -> Result<Vec<u8>> {
 		// Query for the element using strict mode (should return exactly one)
-		let element = self
-			.frame
-			.query_selector(&self.selector)
-			.await?
-			.ok_or_else(|| pw_runtime::Error::ElementNotFound(format!("Element not found: {}", self.selector)))?;
+		let element = self.frame.query_selector(&self.selector).await?;
+		let element = element.ok_or_else(|| Error::ElementNotFound(format!("Element not found: {}", self.selector)));
+		let element = self.explain_miss(element).await?;
 
 		// Delegate to ElementHandle.screenshot()
 		element.screenshot(options).await
 	}
 }
 
+impl Locator {
+	/// Turns a miss on an out-of-range `nth` locator into [`Error::IndexOutOfRange`].
+	///
+	/// Playwright waits for an `nth=` match that can never appear and
+	/// eventually times out; counting the base selector after the failure
+	/// tells the caller why. Other results pass through unchanged.
+	async fn explain_miss<T>(&self, result: Result<T>) -> Result<T> {
+		let err = match result {
+			Err(err) if err.is_timeout() || matches!(err, Error::ElementNotFound(_)) => err,
+			other => return other,
+		};
+		let Some((base, index)) = split_nth(&self.selector) else {
+			return Err(err);
+		};
+		match self.frame.locator_count(base).await {
+			Ok(count) if !nth_in_range(index, count) => Err(Error::IndexOutOfRange {
+				selector: base.to_string(),
+				index,
+				count,
+			}),
+			_ => Err(err),
+		}
+	}
+}
+
 impl std::fmt::Debug for Locator {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Locator").field("selector", &self.selector).finish()
 	}
 }
+
+/// Splits a trailing `>> nth=<index>` off `selector`.
+fn split_nth(selector: &str) -> Option<(&str, i32)> {
+	let (base, index) = selector.rsplit_once(">> nth=")?;
+	Some((base.trim_end(), index.trim().parse().ok()?))
+}
+
+/// Whether `index` (negative counts from the end) addresses one of `count` elements.
+fn nth_in_range(index: i32, count: usize) -> bool {
+	let count = i64::try_from(count).unwrap_or(i64::MAX);
+	let index = i64::from(index);
+	if index < 0 { count + index >= 0 } else { index < count }
+}
+
+/// Get-by-role options
+///
+/// Narrows [`Page::get_by_role`](crate::Page::get_by_role) by accessible name
+/// and ARIA state. Unset options match any value.
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-get-by-role>
+#[derive(Debug, Clone, Default)]
+pub struct GetByRoleOptions {
+	/// Accessible name to match, case-insensitive substring unless `exact`
+	pub name: Option<String>,
+	/// Match `name` exactly and case-sensitively
+	pub exact: Option<bool>,
+	/// Match checkboxes and radios by `aria-checked` or native checked state
+	pub checked: Option<bool>,
+	/// Match toggle buttons by `aria-pressed`
+	pub pressed: Option<bool>,
+	/// Match headings, list items, rows and tree items by level
+	pub level: Option<u32>,
+}
+
+impl GetByRoleOptions {
+	/// Create a new builder for GetByRoleOptions
+	pub fn builder() -> GetByRoleOptionsBuilder {
+		GetByRoleOptionsBuilder::default()
+	}
+
+	/// Builds the `internal:role=` selector for `role`, with attributes in Playwright's order.
+	pub(crate) fn selector(&self, role: &str) -> String {
+		let mut selector = format!("internal:role={role}");
+		if let Some(checked) = self.checked {
+			selector.push_str(&format!("[checked={checked}]"));
+		}
+		if let Some(level) = self.level {
+			selector.push_str(&format!("[level={level}]"));
+		}
+		if let Some(name) = &self.name {
+			let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+			let flag = if self.exact.unwrap_or(false) { 's' } else { 'i' };
+			selector.push_str(&format!("[name=\"{escaped}\"{flag}]"));
+		}
+		if let Some(pressed) = self.pressed {
+			selector.push_str(&format!("[pressed={pressed}]"));
+		}
+		selector
+	}
+}
+
+/// Builder for GetByRoleOptions
+#[derive(Debug, Clone, Default)]
+pub struct GetByRoleOptionsBuilder {
+	name: Option<String>,
+	exact: Option<bool>,
+	checked: Option<bool>,
+	pressed: Option<bool>,
+	level: Option<u32>,
+}
+
+impl GetByRoleOptionsBuilder {
+	/// Set the accessible name to match
+	pub fn name(mut self, name: impl Into<String>) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	/// Match the name exactly and case-sensitively
+	pub fn exact(mut self, exact: bool) -> Self {
+		self.exact = Some(exact);
+		self
+	}
+
+	/// Match checked or unchecked elements
+	pub fn checked(mut self, checked: bool) -> Self {
+		self.checked = Some(checked);
+		self
+	}
+
+	/// Match pressed or unpressed toggle buttons
+	pub fn pressed(mut self, pressed: bool) -> Self {
+		self.pressed = Some(pressed);
+		self
+	}
+
+	/// Set the heading or nesting level to match
+	pub fn level(mut self, level: u32) -> Self {
+		self.level = Some(level);
+		self
+	}
+
+	/// Build the GetByRoleOptions
+	pub fn build(self) -> GetByRoleOptions {
+		GetByRoleOptions {
+			name: self.name,
+			exact: self.exact,
+			checked: self.checked,
+			pressed: self.pressed,
+			level: self.level,
+		}
+	}
+}
+
+/// Builds a Playwright `internal:<kind>` combinator; the right side is JSON-quoted.
+fn internal_selector(selector: &str, kind: &str, other: &str) -> String {
+	format!("{selector} >> internal:{kind}={}", serde_json::Value::from(other))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn role_selector_orders_and_escapes_options() {
+		assert_eq!(GetByRoleOptions::default().selector("button"), "internal:role=button");
+		assert_eq!(
+			GetByRoleOptions::builder().name("Submit").build().selector("button"),
+			r#"internal:role=button[name="Submit"i]"#
+		);
+		let options = GetByRoleOptions::builder()
+			.name(r#"Say "hi" \ bye"#)
+			.exact(true)
+			.level(2)
+			.checked(false)
+			.pressed(true)
+			.build();
+		assert_eq!(
+			options.selector("heading"),
+			r#"internal:role=heading[checked=false][level=2][name="Say \"hi\" \\ bye"s][pressed=true]"#
+		);
+	}
+
+	#[test]
+	fn or_selector_quotes_alternative() {
+		assert_eq!(internal_selector("#new", "or", "#old"), r##"#new >> internal:or="#old""##);
+		assert_eq!(
+			internal_selector("button", "or", r#"text="Sign in""#),
+			r#"button >> internal:or="text=\"Sign in\"""#
+		);
+	}
+
+	#[test]
+	fn and_selector_intersects_with_quoted_side() {
+		assert_eq!(
+			internal_selector("button", "and", "[data-testid=save]"),
+			r#"button >> internal:and="[data-testid=save]""#
+		);
+	}
+
+	#[test]
+	fn split_nth_extracts_base_and_index() {
+		assert_eq!(split_nth("li >> nth=10"), Some(("li", 10)));
+		assert_eq!(split_nth("ul >> li >> nth=-1"), Some(("ul >> li", -1)));
+		assert_eq!(split_nth("li"), None);
+		assert_eq!(split_nth("li >> nth=0 >> span"), None);
+	}
+
+	#[test]
+	fn nth_in_range_normalizes_negative_indices() {
+		assert!(nth_in_range(2, 3));
+		assert!(!nth_in_range(10, 3));
+		assert!(nth_in_range(-1, 3));
+		assert!(nth_in_range(-3, 3));
+		assert!(!nth_in_range(-4, 3));
+		assert!(!nth_in_range(0, 0));
+	}
+
+	#[test]
+	fn index_out_of_range_reports_count() {
+		let err = Error::IndexOutOfRange {
+			selector: "li".into(),
+			index: 10,
+			count: 3,
+		};
+		assert_eq!(err.to_string(), "index 10 out of range (count 3) for selector 'li'");
+	}
+}
@@ -42,4 +42,14 @@ impl Page {
 	pub async fn evaluate_typed<T: serde::de::DeserializeOwned>(&self, expression: &str) -> Result<T> {
 		self.main_frame().await?.frame_evaluate_expression_typed(expression).await
 	}
+
+	/// Registers a script evaluated in every new document before page scripts run.
+	///
+	/// The script applies to subsequent navigations of this page (including
+	/// child frames); it does not run against the currently loaded document.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-add-init-script>
+	pub async fn add_init_script(&self, script: &str) -> Result<()> {
+		self.channel().send_no_result("addInitScript", serde_json::json!({ "source": script })).await
+	}
 }
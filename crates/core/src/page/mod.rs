@@ -3,9 +3,12 @@
 mod eval;
 mod input;
 mod page_events;
+mod pdf;
 mod routing;
 mod screenshot;
 
+use std::collections::VecDeque;
+use std::future::Future;
 use std::sync::{Arc, RwLock};
 
 use indexmap::IndexMap;
@@ -15,11 +18,12 @@ use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnecti
 use pw_runtime::{Error, Result};
 use serde::Deserialize;
 use serde_json::Value;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc};
 
+use crate::frame::{FrameNavigation, UrlPattern};
 pub use crate::handlers::Subscription;
 use crate::handlers::{HandlerMap, RouteMeta};
-use crate::{Dialog, Download, Route};
+use crate::{Dialog, Download, ResponseObject, Route};
 
 /// A browser tab or window within a [`BrowserContext`](crate::BrowserContext).
 ///
@@ -31,6 +35,8 @@ pub struct Page {
 	url: Arc<RwLock<String>>,
 	/// GUID of the main frame.
 	main_frame_guid: Arc<str>,
+	/// Viewport configured on the owning context (`None` for `noViewport` contexts).
+	viewport_size: Arc<RwLock<Option<(u32, u32)>>>,
 	/// Route handlers for network interception (with compiled matchers).
 	route_handlers: HandlerMap<Route, RouteMeta>,
 	/// Download event handlers.
@@ -39,6 +45,21 @@ pub struct Page {
 	dialog_handlers: HandlerMap<Dialog>,
 	/// Console message broadcast channel.
 	console_tx: broadcast::Sender<ConsoleMessage>,
+	/// Console messages not yet taken, oldest first, capped at [`CONSOLE_LOG_LIMIT`].
+	console_log: Arc<Mutex<VecDeque<ConsoleMessage>>>,
+	/// Uncaught exceptions not yet taken, oldest first, capped at [`PAGE_ERROR_LIMIT`].
+	page_errors: Arc<Mutex<VecDeque<PageError>>>,
+	/// `download` events, resolved and dispatched in arrival order.
+	download_queue: mpsc::UnboundedSender<QueuedDownload>,
+	/// Pages opened by this page, broadcast to [`Page::popups`] subscribers.
+	popup_tx: broadcast::Sender<Page>,
+}
+
+/// `download` event payload awaiting its artifact object.
+struct QueuedDownload {
+	artifact_guid: String,
+	url: String,
+	suggested_filename: String,
 }
 
 /// Console message from JavaScript `console.*` calls.
@@ -154,6 +175,80 @@ impl std::fmt::Display for ConsoleMessageKind {
 	}
 }
 
+impl ConsoleMessage {
+	/// Parses a protocol console payload (`type`, `text`, `location`).
+	pub(crate) fn from_protocol(value: &Value) -> Self {
+		let kind = value
+			.get("type")
+			.and_then(|v| v.as_str())
+			.map(ConsoleMessageKind::from_str)
+			.unwrap_or(ConsoleMessageKind::Log);
+
+		let text = value.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string();
+
+		let location = value.get("location").and_then(|loc| {
+			Some(ConsoleLocation {
+				url: loc.get("url")?.as_str()?.to_string(),
+				line_number: loc.get("lineNumber")?.as_u64()? as u32,
+				column_number: loc.get("columnNumber")?.as_u64()? as u32,
+			})
+		});
+
+		Self { kind, text, location }
+	}
+}
+
+/// Most console messages a page buffers for [`Page::take_console_messages`].
+const CONSOLE_LOG_LIMIT: usize = 1000;
+
+/// Most uncaught exceptions a page buffers for [`Page::page_errors`].
+const PAGE_ERROR_LIMIT: usize = 100;
+
+/// Uncaught exception thrown by page JavaScript.
+///
+/// See <https://playwright.dev/docs/api/class-page#page-event-page-error>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageError {
+	/// Error name, such as `TypeError`.
+	pub name: String,
+	/// Error message.
+	pub message: String,
+	/// JavaScript stack trace, when the error carried one.
+	pub stack: Option<String>,
+}
+
+impl PageError {
+	/// Parses a protocol `SerializedError` payload.
+	///
+	/// Thrown values that are not `Error` objects arrive as a serialized value
+	/// instead; their string form becomes the message.
+	pub(crate) fn from_protocol(value: &Value) -> Self {
+		let str_field = |v: &Value, key: &str| v.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+		match value.get("error") {
+			Some(error) => Self {
+				name: str_field(error, "name").unwrap_or_else(|| "Error".to_string()),
+				message: str_field(error, "message").unwrap_or_default(),
+				stack: str_field(error, "stack").filter(|s| !s.is_empty()),
+			},
+			None => {
+				// Primitive wrappers: {"s": ..}, {"n": ..}, {"b": ..} or {"v": "undefined"}.
+				let thrown = value.get("value");
+				let message = ["s", "n", "b", "v"]
+					.iter()
+					.find_map(|key| thrown.and_then(|v| v.get(key)))
+					.map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+					.unwrap_or_default();
+				Self {
+					name: String::new(),
+					message,
+					stack: None,
+				}
+			}
+		}
+	}
+}
+
 /// Source code location for a console message.
 #[derive(Debug, Clone)]
 pub struct ConsoleLocation {
@@ -174,6 +269,7 @@ impl Page {
 				.ok_or_else(|| pw_runtime::Error::ProtocolError("Page initializer missing 'mainFrame.guid' field".to_string()))?,
 		);
 
+		let viewport_size = Arc::new(RwLock::new(parse_viewport_size(&initializer["viewportSize"])));
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
 
 		let url = Arc::new(RwLock::new("about:blank".to_string()));
@@ -181,15 +277,39 @@ impl Page {
 		let download_handlers = Arc::new(Mutex::new(IndexMap::new()));
 		let dialog_handlers = Arc::new(Mutex::new(IndexMap::new()));
 		let (console_tx, _) = broadcast::channel(256);
+		let console_log = Arc::new(Mutex::new(VecDeque::new()));
+		let page_errors = Arc::new(Mutex::new(VecDeque::new()));
+		let (popup_tx, _) = broadcast::channel(16);
+
+		// Resolve downloads on a single task so handlers observe them in the
+		// order the server emitted the events.
+		let (download_queue, mut queued_downloads) = mpsc::unbounded_channel::<QueuedDownload>();
+		let connection = base.connection();
+		let handlers = Arc::clone(&download_handlers);
+		tokio::spawn(async move {
+			while let Some(queued) = queued_downloads.recv().await {
+				let Ok(artifact_arc) = connection.get_object(&queued.artifact_guid).await else {
+					tracing::error!(guid = %queued.artifact_guid, "Failed to get artifact object");
+					continue;
+				};
+				let download = Download::from_artifact(artifact_arc, queued.url, queued.suggested_filename);
+				page_events::dispatch_download(&handlers, download).await;
+			}
+		});
 
 		Ok(Self {
 			base,
 			url,
 			main_frame_guid,
+			viewport_size,
 			route_handlers,
 			download_handlers,
 			dialog_handlers,
 			console_tx,
+			console_log,
+			page_errors,
+			download_queue,
+			popup_tx,
 		})
 	}
 
@@ -207,6 +327,16 @@ impl Page {
 		Ok(frame.clone())
 	}
 
+	/// Returns the GUID of the page's main frame.
+	pub fn main_frame_guid(&self) -> &str {
+		&self.main_frame_guid
+	}
+
+	/// Returns the GUID of the page that opened this one, if it is a popup.
+	pub(crate) fn opener_guid(&self) -> Option<&str> {
+		self.base.initializer().get("opener")?.get("guid")?.as_str()
+	}
+
 	/// Returns the current URL (initially "about:blank").
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-url>
@@ -214,6 +344,19 @@ impl Page {
 		self.url.read().unwrap_or_else(|e| e.into_inner()).clone()
 	}
 
+	/// Returns the configured viewport as `(width, height)` in CSS pixels.
+	///
+	/// This is the size set on the browser context (or via a later viewport
+	/// change), not a JS measurement: `window.innerWidth` includes scrollbars
+	/// while `document.documentElement.clientWidth` excludes them, and both can
+	/// drift from this value. Returns `None` when the context was created with
+	/// `noViewport`.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-viewport-size>
+	pub fn viewport_size(&self) -> Option<(u32, u32)> {
+		*self.viewport_size.read().unwrap_or_else(|e| e.into_inner())
+	}
+
 	/// Closes the page.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-close>
@@ -221,6 +364,20 @@ impl Page {
 		self.channel().send_no_result("close", serde_json::json!({})).await
 	}
 
+	/// Pauses automation and opens the Playwright Inspector.
+	///
+	/// Resolves once the user resumes from the inspector. Only meaningful in
+	/// headed mode; in headless mode the call never returns.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-pause>
+	pub async fn pause(&self) -> Result<()> {
+		let context = self
+			.parent()
+			.and_then(|parent| parent.downcast_ref::<crate::BrowserContext>().cloned())
+			.ok_or_else(|| Error::ProtocolError("Page has no parent BrowserContext".to_string()))?;
+		context.pause().await
+	}
+
 	/// Brings the page to the front (activates the tab).
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-bring-to-front>
@@ -259,6 +416,88 @@ impl Page {
 		Ok(response)
 	}
 
+	/// Runs `action` and waits for the main frame navigation it triggers.
+	///
+	/// The navigation listener is attached before `action` starts, so
+	/// navigations that commit while the action is still running are caught,
+	/// including same-URL reloads and History API changes that a before/after
+	/// URL comparison cannot see. Once a navigation commits, waits for
+	/// `wait_until` (default [`WaitUntil::Load`]). The timeout (default
+	/// [`DEFAULT_TIMEOUT_MS`](crate::DEFAULT_TIMEOUT_MS)) starts when `action`
+	/// completes.
+	///
+	/// # Errors
+	///
+	/// Returns the action's error if it fails, or [`Error::Timeout`] when no
+	/// navigation completes in time. An aborted navigation (for example a link
+	/// that turns into a download) is not an error: it is returned with
+	/// [`FrameNavigation::error`] set and without waiting for a load state.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-navigation>
+	pub async fn expect_navigation<F>(&self, action: F, options: Option<GotoOptions>) -> Result<FrameNavigation>
+	where
+		F: Future<Output = Result<()>>,
+	{
+		let options = options.unwrap_or_default();
+		let timeout = options
+			.timeout
+			.unwrap_or(std::time::Duration::from_millis(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64));
+		let wait_until = options.wait_until.unwrap_or(WaitUntil::Load);
+
+		let frame = self.main_frame().await?;
+		let mut navigations = frame.navigations();
+		action.await?;
+
+		let deadline = tokio::time::Instant::now() + timeout;
+		let navigation = tokio::time::timeout_at(deadline, async {
+			loop {
+				match navigations.recv().await {
+					Ok(navigation) => return Ok(navigation),
+					Err(broadcast::error::RecvError::Lagged(n)) => {
+						tracing::warn!(dropped = n, "Navigation receiver lagged");
+					}
+					Err(broadcast::error::RecvError::Closed) => return Err(Error::ChannelClosed),
+				}
+			}
+		})
+		.await
+		.map_err(|_| Error::Timeout(format!("Timeout {}ms exceeded waiting for navigation", timeout.as_millis())))??;
+
+		if navigation.error.is_some() {
+			return Ok(navigation);
+		}
+		if let Ok(mut page_url) = self.url.write() {
+			*page_url = navigation.url.clone();
+		}
+
+		let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+		frame.wait_for_load_state(wait_until, remaining).await?;
+
+		Ok(navigation)
+	}
+
+	/// Waits until the main frame's URL matches `pattern`, returning that URL.
+	///
+	/// Shorthand for [`Frame::wait_for_url`](crate::Frame::wait_for_url).
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-url>
+	pub async fn wait_for_url(&self, pattern: impl Into<UrlPattern>, timeout: std::time::Duration) -> Result<String> {
+		let url = self.main_frame().await?.wait_for_url(pattern, timeout).await?;
+		if let Ok(mut page_url) = self.url.write() {
+			*page_url = url.clone();
+		}
+		Ok(url)
+	}
+
+	/// Waits until the main frame's current document reaches `state`.
+	///
+	/// Shorthand for [`Frame::wait_for_load_state`](crate::Frame::wait_for_load_state).
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-load-state>
+	pub async fn wait_for_load_state(&self, state: WaitUntil, timeout: std::time::Duration) -> Result<()> {
+		self.main_frame().await?.wait_for_load_state(state, timeout).await
+	}
+
 	/// Returns the page's title.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-title>
@@ -276,6 +515,27 @@ impl Page {
 		crate::Locator::new(Arc::new(frame), selector.to_string())
 	}
 
+	/// Creates a locator for elements by ARIA role, accessible name, and state.
+	///
+	/// `role` is an ARIA role such as `"button"`, `"link"`, or `"heading"`,
+	/// matched against explicit `role` attributes and implicit roles alike.
+	/// Unless [`GetByRoleOptions::exact`](crate::GetByRoleOptions::exact) is
+	/// set, `name` matches a case-insensitive substring of the accessible name.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// use pw_rs::GetByRoleOptions;
+	///
+	/// let submit = page.get_by_role("button", Some(GetByRoleOptions::builder().name("Submit").build())).await;
+	/// submit.click(None).await?;
+	/// ```
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-get-by-role>
+	pub async fn get_by_role(&self, role: &str, options: Option<crate::GetByRoleOptions>) -> crate::Locator {
+		self.locator(&options.unwrap_or_default().selector(role)).await
+	}
+
 	/// Returns the keyboard for low-level control.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-keyboard>
@@ -297,6 +557,13 @@ impl Page {
 		crate::Accessibility::new(self.clone())
 	}
 
+	/// Captures the accessibility tree, optionally pruned and scoped to a root.
+	///
+	/// Shorthand for [`Accessibility::snapshot`](crate::Accessibility::snapshot).
+	pub async fn accessibility_snapshot(&self, options: Option<crate::AccessibilitySnapshotOptions>) -> Result<Option<crate::AccessibilityNode>> {
+		self.accessibility().snapshot(options).await
+	}
+
 	/// Returns the video handle if recording is enabled, or `None`.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-video>
@@ -349,33 +616,7 @@ impl Page {
 			// Wait for Response object - __create__ may arrive after the response
 			let response_arc = self.connection().wait_for_object(&response_ref.guid, std::time::Duration::from_secs(1)).await?;
 
-			let initializer = response_arc.initializer();
-
-			let status = initializer["status"]
-				.as_u64()
-				.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing status".to_string()))? as u16;
-
-			let headers = initializer["headers"]
-				.as_array()
-				.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing headers".to_string()))?
-				.iter()
-				.filter_map(|h| {
-					let name = h["name"].as_str()?;
-					let value = h["value"].as_str()?;
-					Some((name.to_string(), value.to_string()))
-				})
-				.collect();
-
-			let response = Response {
-				url: initializer["url"]
-					.as_str()
-					.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing url".to_string()))?
-					.to_string(),
-				status,
-				status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
-				ok: (200..300).contains(&status),
-				headers,
-			};
+			let response = Response::from_object(&response_arc)?;
 
 			// Update the page's URL
 			if let Ok(mut page_url) = self.url.write() {
@@ -398,6 +639,23 @@ impl Page {
 		frame.query_selector(selector).await
 	}
 
+	/// Waits for the first element matching `selector` to reach a state.
+	///
+	/// Shorthand for [`Frame::wait_for_selector`](crate::Frame::wait_for_selector).
+	///
+	/// ```ignore
+	/// use pw_rs::{WaitForSelectorOptions, WaitForSelectorState};
+	///
+	/// let options = WaitForSelectorOptions::builder().state(WaitForSelectorState::Detached).timeout(5000.0).build();
+	/// page.wait_for_selector(".spinner", Some(options)).await?;
+	/// ```
+	///
+	/// See: <https://playwright.dev/docs/api/class-page#page-wait-for-selector>
+	pub async fn wait_for_selector(&self, selector: &str, options: Option<crate::WaitForSelectorOptions>) -> Result<Option<Arc<crate::ElementHandle>>> {
+		let frame = self.main_frame().await?;
+		frame.wait_for_selector(selector, options).await
+	}
+
 	/// Returns all elements matching the selector.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-query-selector-all>
@@ -459,11 +717,18 @@ impl ChannelOwner for Page {
 					}
 				}
 			}
+			"viewportSizeChanged" => {
+				if let Ok(mut viewport_size) = self.viewport_size.write() {
+					*viewport_size = parse_viewport_size(&params["viewportSize"]);
+				}
+			}
 			"route" => {
 				let Some(route_guid) = params.get("route").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) else {
 					return;
 				};
 
+				// Routes are handled concurrently on purpose: a slow handler must not
+				// stall unrelated requests, and each route is independent.
 				let connection = self.connection();
 				let route_guid_owned = route_guid.to_string();
 				let self_clone = self.clone();
@@ -483,26 +748,14 @@ impl ChannelOwner for Page {
 				});
 			}
 			"download" => {
-				let url = params.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string();
-
-				let suggested_filename = params.get("suggestedFilename").and_then(|v| v.as_str()).unwrap_or("").to_string();
-
 				let Some(artifact_guid) = params.get("artifact").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) else {
 					return;
 				};
 
-				let connection = self.connection();
-				let artifact_guid_owned = artifact_guid.to_string();
-				let self_clone = self.clone();
-
-				tokio::spawn(async move {
-					let Ok(artifact_arc) = connection.get_object(&artifact_guid_owned).await else {
-						tracing::error!(guid = %artifact_guid_owned, "Failed to get artifact object");
-						return;
-					};
-
-					let download = Download::from_artifact(artifact_arc, url, suggested_filename);
-					self_clone.on_download_event(download).await;
+				let _ = self.download_queue.send(QueuedDownload {
+					artifact_guid: artifact_guid.to_string(),
+					url: params.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+					suggested_filename: params.get("suggestedFilename").and_then(|v| v.as_str()).unwrap_or("").to_string(),
 				});
 			}
 			"dialog" => {}
@@ -510,24 +763,13 @@ impl ChannelOwner for Page {
 				let Some(message_obj) = params.get("message") else {
 					return;
 				};
-
-				let kind = message_obj
-					.get("type")
-					.and_then(|v| v.as_str())
-					.map(ConsoleMessageKind::from_str)
-					.unwrap_or(ConsoleMessageKind::Log);
-
-				let text = message_obj.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string();
-
-				let location = message_obj.get("location").and_then(|loc| {
-					Some(ConsoleLocation {
-						url: loc.get("url")?.as_str()?.to_string(),
-						line_number: loc.get("lineNumber")?.as_u64()? as u32,
-						column_number: loc.get("columnNumber")?.as_u64()? as u32,
-					})
-				});
-
-				let _ = self.console_tx.send(ConsoleMessage { kind, text, location });
+				self.dispatch_console(ConsoleMessage::from_protocol(message_obj));
+			}
+			"pageError" => {
+				let Some(error) = params.get("error") else {
+					return;
+				};
+				self.record_page_error(PageError::from_protocol(error));
 			}
 			_ => {}
 		}
@@ -538,13 +780,20 @@ impl ChannelOwner for Page {
 	}
 }
 
+/// Parses a protocol `{ width, height }` viewport object.
+fn parse_viewport_size(value: &Value) -> Option<(u32, u32)> {
+	let width = value.get("width")?.as_u64()?;
+	let height = value.get("height")?.as_u64()?;
+	Some((u32::try_from(width).ok()?, u32::try_from(height).ok()?))
+}
+
 impl std::fmt::Debug for Page {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Page").field("guid", &self.guid()).field("url", &self.url()).finish()
 	}
 }
 
-/// Options for [`Page::goto`] and [`Page::reload`].
+/// Options for [`Page::goto`], [`Page::reload`] and [`Page::expect_navigation`].
 #[derive(Debug, Clone, Default)]
 pub struct GotoOptions {
 	/// Maximum operation time.
@@ -609,6 +858,7 @@ pub struct Response {
 	pub ok: bool,
 	/// Response headers.
 	pub headers: std::collections::HashMap<String, String>,
+	object: ResponseObject,
 }
 
 impl Response {
@@ -636,6 +886,47 @@ impl Response {
 	pub fn headers(&self) -> &std::collections::HashMap<String, String> {
 		&self.headers
 	}
+
+	/// Returns the protocol object behind this response, for details such as
+	/// its request, redirect chain, or TLS information.
+	pub fn object(&self) -> &ResponseObject {
+		&self.object
+	}
+
+	/// Builds a response from its protocol object as returned by `goto` or `reload`.
+	pub(crate) fn from_object(object: &Arc<dyn ChannelOwner>) -> Result<Self> {
+		let object = object
+			.downcast_ref::<ResponseObject>()
+			.cloned()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Response object, got {}", object.type_name())))?;
+		let initializer = object.initializer();
+		let status = initializer["status"]
+			.as_u64()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing status".to_string()))? as u16;
+
+		let headers = initializer["headers"]
+			.as_array()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing headers".to_string()))?
+			.iter()
+			.filter_map(|h| {
+				let name = h["name"].as_str()?;
+				let value = h["value"].as_str()?;
+				Some((name.to_string(), value.to_string()))
+			})
+			.collect();
+
+		Ok(Self {
+			url: initializer["url"]
+				.as_str()
+				.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing url".to_string()))?
+				.to_string(),
+			status,
+			status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
+			ok: (200..300).contains(&status),
+			headers,
+			object,
+		})
+	}
 }
 
 #[cfg(test)]
@@ -652,6 +943,13 @@ mod tests {
 		assert_eq!(ConsoleMessageKind::from_str("unknown"), ConsoleMessageKind::Other);
 	}
 
+	#[test]
+	fn test_parse_viewport_size() {
+		assert_eq!(parse_viewport_size(&serde_json::json!({ "width": 1280, "height": 720 })), Some((1280, 720)));
+		assert_eq!(parse_viewport_size(&Value::Null), None);
+		assert_eq!(parse_viewport_size(&serde_json::json!({ "width": 1280 })), None);
+	}
+
 	#[test]
 	fn test_console_message_kind_display() {
 		assert_eq!(format!("{}", ConsoleMessageKind::Log), "log");
@@ -680,6 +978,38 @@ mod tests {
 		assert_eq!(loc.column_number, 10);
 	}
 
+	#[test]
+	fn test_console_message_from_protocol() {
+		let msg = ConsoleMessage::from_protocol(&serde_json::json!({
+			"type": "error",
+			"text": "boom",
+			"location": { "url": "https://example.com/app.js", "lineNumber": 3, "columnNumber": 7 },
+			"page": { "guid": "page@1" }
+		}));
+
+		assert_eq!(msg.kind(), ConsoleMessageKind::Error);
+		assert_eq!(msg.text(), "boom");
+		let loc = msg.location().unwrap();
+		assert_eq!((loc.url.as_str(), loc.line_number, loc.column_number), ("https://example.com/app.js", 3, 7));
+
+		let bare = ConsoleMessage::from_protocol(&serde_json::json!({ "text": "hi" }));
+		assert_eq!(bare.kind(), ConsoleMessageKind::Log);
+		assert!(bare.location().is_none());
+	}
+
+	#[test]
+	fn test_page_error_from_protocol() {
+		let error = PageError::from_protocol(&serde_json::json!({
+			"error": { "name": "Error", "message": "boom", "stack": "Error: boom\n    at <anonymous>:1:26" }
+		}));
+		assert_eq!(error.name, "Error");
+		assert_eq!(error.message, "boom");
+		assert_eq!(error.stack.as_deref(), Some("Error: boom\n    at <anonymous>:1:26"));
+
+		let thrown = PageError::from_protocol(&serde_json::json!({ "value": { "s": "oops" } }));
+		assert_eq!((thrown.name.as_str(), thrown.message.as_str(), thrown.stack), ("", "oops", None));
+	}
+
 	#[test]
 	fn test_console_message_without_location() {
 		let msg = ConsoleMessage {
@@ -1,14 +1,15 @@
-//! Event handling methods for [`Page`] (download, dialog, console).
+//! Event handling methods for [`Page`] (download, dialog, console, page error, popup, response).
 
 use std::future::Future;
 use std::sync::Arc;
 
+use pw_runtime::channel_owner::ChannelOwner;
 use pw_runtime::{Error, Result};
 use tokio::sync::broadcast;
 
-use super::{ConsoleMessage, Page};
-use crate::handlers::{HandlerEntry, HandlerFn, HandlerFuture, Subscription, next_handler_id};
-use crate::{Dialog, Download};
+use super::{ConsoleMessage, Page, PageError};
+use crate::handlers::{HandlerEntry, HandlerFn, HandlerFuture, HandlerMap, Subscription, next_handler_id};
+use crate::{Dialog, Download, ResponseObject};
 
 impl Page {
 	/// Registers a download event handler.
@@ -52,13 +53,64 @@ impl Page {
 
 	/// Returns a broadcast receiver for console messages.
 	///
+	/// Messages flow once [`BrowserContext::capture_console`](crate::BrowserContext::capture_console)
+	/// has been called on the page's context.
+	///
 	/// See <https://playwright.dev/docs/api/class-page#page-event-console>
 	pub fn console_messages(&self) -> broadcast::Receiver<ConsoleMessage> {
 		self.console_tx.subscribe()
 	}
 
+	/// Drains the console messages logged since the page opened or since the
+	/// last call, oldest first.
+	///
+	/// Only the most recent 1000 messages are kept. Nothing is logged until
+	/// [`BrowserContext::capture_console`](crate::BrowserContext::capture_console)
+	/// has been called on the page's context.
+	pub fn take_console_messages(&self) -> Vec<ConsoleMessage> {
+		self.console_log.lock().drain(..).collect()
+	}
+
+	/// Records a console message and broadcasts it to subscribers.
+	pub(crate) fn dispatch_console(&self, message: ConsoleMessage) {
+		{
+			let mut log = self.console_log.lock();
+			if log.len() == super::CONSOLE_LOG_LIMIT {
+				log.pop_front();
+			}
+			log.push_back(message.clone());
+		}
+		let _ = self.console_tx.send(message);
+	}
+
+	/// Returns the uncaught exceptions thrown since the page opened or since the
+	/// last [`Page::take_page_errors`], oldest first.
+	///
+	/// Only the most recent 100 errors are kept.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-event-page-error>
+	pub fn page_errors(&self) -> Vec<PageError> {
+		self.page_errors.lock().iter().cloned().collect()
+	}
+
+	/// Drains the buffered uncaught exceptions, oldest first.
+	pub fn take_page_errors(&self) -> Vec<PageError> {
+		self.page_errors.lock().drain(..).collect()
+	}
+
+	/// Records an uncaught exception.
+	pub(crate) fn record_page_error(&self, error: PageError) {
+		let mut errors = self.page_errors.lock();
+		if errors.len() == super::PAGE_ERROR_LIMIT {
+			errors.pop_front();
+		}
+		errors.push_back(error);
+	}
+
 	/// Waits for a console message matching the predicate.
 	///
+	/// Enables console capture on the page's context first.
+	///
 	/// # Errors
 	///
 	/// Returns [`Error::Timeout`](pw_runtime::Error::Timeout) or
@@ -68,6 +120,7 @@ impl Page {
 		F: Fn(&ConsoleMessage) -> bool,
 	{
 		let mut rx = self.console_messages();
+		self.browser_context()?.capture_console().await?;
 
 		tokio::time::timeout(timeout, async move {
 			loop {
@@ -90,7 +143,9 @@ impl Page {
 	/// Registers a console message callback via a background task.
 	///
 	/// Returns a [`ConsoleSubscription`](crate::events::ConsoleSubscription) that
-	/// cancels the task when dropped.
+	/// cancels the task when dropped. The handler only sees messages once
+	/// [`BrowserContext::capture_console`](crate::BrowserContext::capture_console)
+	/// has been called on the page's context.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-event-console>
 	pub fn on_console<F>(&self, handler: F) -> crate::events::ConsoleSubscription
@@ -122,18 +177,107 @@ impl Page {
 		crate::events::ConsoleSubscription::new(cancel_tx)
 	}
 
-	/// Dispatches a download event to all registered handlers.
-	pub(super) async fn on_download_event(&self, download: Download) {
-		let handlers: Vec<_> = {
-			let map = self.download_handlers.lock();
-			map.values().map(|e| (e.id, e.handler.clone())).collect()
-		};
+	/// Returns a broadcast receiver for pages opened by this page.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-event-popup>
+	pub fn popups(&self) -> broadcast::Receiver<Page> {
+		self.popup_tx.subscribe()
+	}
 
-		for (id, handler) in handlers {
-			if let Err(e) = handler(download.clone()).await {
-				tracing::error!(error = %e, handler_id = id, "Download handler error");
+	/// Runs `action` and returns the popup page it opens.
+	///
+	/// The listener is attached before `action` starts, so popups opened while
+	/// the action is still running are caught. The returned page may not have
+	/// finished loading; use [`Page::wait_for_load_state`] before reading it.
+	/// The timeout (default [`DEFAULT_TIMEOUT_MS`](crate::DEFAULT_TIMEOUT_MS))
+	/// starts when `action` completes.
+	///
+	/// # Errors
+	///
+	/// Returns the action's error if it fails, or
+	/// [`Error::Timeout`](pw_runtime::Error::Timeout) when no popup opens in time.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-popup>
+	pub async fn expect_popup<F>(&self, action: F, timeout: Option<std::time::Duration>) -> Result<Page>
+	where
+		F: Future<Output = Result<()>>,
+	{
+		let timeout = timeout.unwrap_or(std::time::Duration::from_millis(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64));
+		let mut popups = self.popups();
+		action.await?;
+
+		tokio::time::timeout(timeout, async move {
+			loop {
+				match popups.recv().await {
+					Ok(popup) => return Ok(popup),
+					Err(broadcast::error::RecvError::Lagged(n)) => {
+						tracing::warn!(dropped = n, "Popup receiver lagged");
+					}
+					Err(broadcast::error::RecvError::Closed) => return Err(Error::ChannelClosed),
+				}
 			}
-		}
+		})
+		.await
+		.map_err(|_| Error::Timeout(format!("Timeout {}ms exceeded waiting for popup", timeout.as_millis())))?
+	}
+
+	/// Waits for a response to a request from this page that matches the predicate.
+	///
+	/// Only responses received after the call are considered. Requests from
+	/// any of the page's frames count; those from workers do not.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Timeout`](pw_runtime::Error::Timeout) or
+	/// [`Error::ChannelClosed`](pw_runtime::Error::ChannelClosed).
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-response>
+	pub async fn wait_for_response<F>(&self, predicate: F, timeout: std::time::Duration) -> Result<ResponseObject>
+	where
+		F: Fn(&ResponseObject) -> bool,
+	{
+		let mut responses = self.browser_context()?.response_events().await?;
+
+		tokio::time::timeout(timeout, async move {
+			loop {
+				match responses.recv().await {
+					Ok(response) if predicate(&response) && self.issued(&response).await => return Ok(response),
+					Ok(_) => continue,
+					Err(broadcast::error::RecvError::Lagged(n)) => {
+						tracing::warn!(dropped = n, "Response receiver lagged");
+					}
+					Err(broadcast::error::RecvError::Closed) => return Err(Error::ChannelClosed),
+				}
+			}
+		})
+		.await
+		.map_err(|_| Error::Timeout(format!("Timeout {}ms exceeded waiting for response", timeout.as_millis())))?
+	}
+
+	/// The context that owns this page and receives its context-level events.
+	fn browser_context(&self) -> Result<crate::BrowserContext> {
+		self.parent()
+			.and_then(|parent| parent.downcast_ref::<crate::BrowserContext>().cloned())
+			.ok_or_else(|| Error::ProtocolError("Page has no parent BrowserContext".to_string()))
+	}
+
+	/// Whether `response` answers a request issued by one of this page's frames.
+	async fn issued(&self, response: &ResponseObject) -> bool {
+		let Ok(request) = response.request().await else {
+			return false;
+		};
+		let Some(frame_guid) = request.frame_guid() else {
+			return false;
+		};
+		let Ok(frame) = self.connection().get_object(frame_guid).await else {
+			return false;
+		};
+		frame.parent().is_some_and(|parent| parent.guid() == self.guid())
+	}
+
+	/// Announces a page opened by this one (called by [`BrowserContext`](crate::BrowserContext)).
+	pub(crate) fn dispatch_popup(&self, popup: Page) {
+		let _ = self.popup_tx.send(popup);
 	}
 
 	/// Dispatches a dialog event to all registered handlers.
@@ -155,3 +299,20 @@ impl Page {
 		self.on_dialog_event(dialog).await;
 	}
 }
+
+/// Dispatches a download event to all registered handlers.
+///
+/// Takes the handler map rather than the [`Page`] so the download queue task
+/// does not keep the page (and with it the queue sender) alive.
+pub(super) async fn dispatch_download(download_handlers: &HandlerMap<Download>, download: Download) {
+	let handlers: Vec<_> = {
+		let map = download_handlers.lock();
+		map.values().map(|e| (e.id, e.handler.clone())).collect()
+	};
+
+	for (id, handler) in handlers {
+		if let Err(e) = handler(download.clone()).await {
+			tracing::error!(error = %e, handler_id = id, "Download handler error");
+		}
+	}
+}
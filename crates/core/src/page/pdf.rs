@@ -0,0 +1,29 @@
+//! PDF generation for [`Page`].
+
+use base64::Engine;
+use pw_runtime::Result;
+use serde::Deserialize;
+
+use super::Page;
+
+#[derive(Deserialize)]
+struct PdfResponse {
+	pdf: String,
+}
+
+impl Page {
+	/// Renders the page as a PDF and returns its bytes.
+	///
+	/// Only Chromium supports PDF generation; other browsers reject the call.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-pdf>
+	pub async fn pdf(&self, options: Option<crate::PdfOptions>) -> Result<Vec<u8>> {
+		let params = options.map(|o| o.to_json()).unwrap_or_else(|| serde_json::json!({}));
+
+		let response: PdfResponse = self.channel().send("pdf", params).await?;
+
+		base64::prelude::BASE64_STANDARD
+			.decode(&response.pdf)
+			.map_err(|e| pw_runtime::Error::ProtocolError(format!("decode pdf: {e}")))
+	}
+}
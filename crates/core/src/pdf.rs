@@ -0,0 +1,213 @@
+//! PDF option data types.
+//!
+//! This module defines the paper format, margin, and option structs for
+//! [`Page::pdf`](crate::Page::pdf), which only Chromium supports.
+//!
+//! Serialization matches Playwright's expected wire representation.
+
+use serde::Serialize;
+
+/// Paper format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum PdfFormat {
+	/// 210mm x 297mm
+	A4,
+	/// 8.5in x 11in
+	Letter,
+}
+
+/// Page margins
+///
+/// Values are CSS lengths with units, e.g. `"1cm"` or `"0.5in"`; unitless
+/// numbers are pixels.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PdfMargin {
+	/// Top margin
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top: Option<String>,
+	/// Right margin
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub right: Option<String>,
+	/// Bottom margin
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub bottom: Option<String>,
+	/// Left margin
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub left: Option<String>,
+}
+
+impl PdfMargin {
+	/// The same margin on all four sides
+	pub fn uniform(margin: impl Into<String>) -> Self {
+		let margin = margin.into();
+		Self {
+			top: Some(margin.clone()),
+			right: Some(margin.clone()),
+			bottom: Some(margin.clone()),
+			left: Some(margin),
+		}
+	}
+}
+
+/// PDF options
+///
+/// # Examples
+///
+/// ```ignore
+/// use pw_rs::{PdfFormat, PdfMargin, PdfOptions};
+///
+/// let options = PdfOptions::builder()
+///     .format(PdfFormat::Letter)
+///     .landscape(true)
+///     .print_background(true)
+///     .margin(PdfMargin::uniform("1cm"))
+///     .build();
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-pdf>
+#[derive(Debug, Clone, Default)]
+pub struct PdfOptions {
+	/// Paper format (Playwright defaults to Letter)
+	pub format: Option<PdfFormat>,
+	/// Landscape orientation
+	pub landscape: Option<bool>,
+	/// Print background graphics
+	pub print_background: Option<bool>,
+	/// Rendering scale, between 0.1 and 2
+	pub scale: Option<f64>,
+	/// Page margins
+	pub margin: Option<PdfMargin>,
+}
+
+impl PdfOptions {
+	/// Create a new builder for PdfOptions
+	pub fn builder() -> PdfOptionsBuilder {
+		PdfOptionsBuilder::default()
+	}
+
+	/// Convert options to JSON value for protocol
+	pub(crate) fn to_json(&self) -> serde_json::Value {
+		let mut json = serde_json::json!({});
+
+		if let Some(format) = &self.format {
+			json["format"] = serde_json::to_value(format).unwrap();
+		}
+
+		if let Some(landscape) = self.landscape {
+			json["landscape"] = serde_json::json!(landscape);
+		}
+
+		if let Some(print_background) = self.print_background {
+			json["printBackground"] = serde_json::json!(print_background);
+		}
+
+		if let Some(scale) = self.scale {
+			json["scale"] = serde_json::json!(scale);
+		}
+
+		if let Some(margin) = &self.margin {
+			json["margin"] = serde_json::to_value(margin).unwrap();
+		}
+
+		json
+	}
+}
+
+/// Builder for PdfOptions
+#[derive(Debug, Clone, Default)]
+pub struct PdfOptionsBuilder {
+	format: Option<PdfFormat>,
+	landscape: Option<bool>,
+	print_background: Option<bool>,
+	scale: Option<f64>,
+	margin: Option<PdfMargin>,
+}
+
+impl PdfOptionsBuilder {
+	/// Set the paper format
+	pub fn format(mut self, format: PdfFormat) -> Self {
+		self.format = Some(format);
+		self
+	}
+
+	/// Use landscape orientation
+	pub fn landscape(mut self, landscape: bool) -> Self {
+		self.landscape = Some(landscape);
+		self
+	}
+
+	/// Print background graphics
+	pub fn print_background(mut self, print_background: bool) -> Self {
+		self.print_background = Some(print_background);
+		self
+	}
+
+	/// Set the rendering scale (0.1 to 2)
+	pub fn scale(mut self, scale: f64) -> Self {
+		self.scale = Some(scale);
+		self
+	}
+
+	/// Set page margins
+	pub fn margin(mut self, margin: PdfMargin) -> Self {
+		self.margin = Some(margin);
+		self
+	}
+
+	/// Build the PdfOptions
+	pub fn build(self) -> PdfOptions {
+		PdfOptions {
+			format: self.format,
+			landscape: self.landscape,
+			print_background: self.print_background,
+			scale: self.scale,
+			margin: self.margin,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_pdf_format_serialization() {
+		assert_eq!(serde_json::to_string(&PdfFormat::A4).unwrap(), "\"A4\"");
+		assert_eq!(serde_json::to_string(&PdfFormat::Letter).unwrap(), "\"Letter\"");
+	}
+
+	#[test]
+	fn test_default_options_are_empty() {
+		assert_eq!(PdfOptions::default().to_json(), serde_json::json!({}));
+	}
+
+	#[test]
+	fn test_builder_multiple_options() {
+		let options = PdfOptions::builder()
+			.format(PdfFormat::A4)
+			.landscape(true)
+			.print_background(true)
+			.scale(0.8)
+			.margin(PdfMargin {
+				top: Some("1cm".into()),
+				..Default::default()
+			})
+			.build();
+
+		let json = options.to_json();
+		assert_eq!(json["format"], "A4");
+		assert_eq!(json["landscape"], true);
+		assert_eq!(json["printBackground"], true);
+		assert_eq!(json["scale"], 0.8);
+		assert_eq!(json["margin"], serde_json::json!({ "top": "1cm" }));
+	}
+
+	#[test]
+	fn test_uniform_margin() {
+		let json = serde_json::to_value(PdfMargin::uniform("0.5in")).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({ "top": "0.5in", "right": "0.5in", "bottom": "0.5in", "left": "0.5in" })
+		);
+	}
+}
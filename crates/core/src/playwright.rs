@@ -11,7 +11,7 @@ use parking_lot::Mutex;
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use pw_runtime::connection::ConnectionLike;
-use pw_runtime::{PlaywrightServer, Result};
+use pw_runtime::{PipeTransport, PlaywrightServer, Result, TransportFactory, TransportParts};
 use serde_json::Value;
 
 use crate::BrowserType;
@@ -92,31 +92,37 @@ impl Playwright {
 	/// * Protocol initialization fails
 	/// * Server doesn't respond within timeout (30s)
 	pub async fn launch() -> Result<Self> {
+		Self::launch_with_config(pw_runtime::ConnectionConfig::default()).await
+	}
+
+	/// Launches Playwright like [`launch`](Self::launch), with explicit connection options.
+	///
+	/// Set [`ConnectionConfig::collect_stats`](pw_runtime::ConnectionConfig::collect_stats)
+	/// to read request timings back through [`connection_stats`](Self::connection_stats).
+	///
+	/// With [`ConnectionConfig::reconnect`](pw_runtime::ConnectionConfig::reconnect)
+	/// set, a driver that exits unexpectedly is relaunched. Browsers, contexts
+	/// and pages from the lost driver fail with
+	/// [`Error::ConnectionLost`](pw_runtime::Error::ConnectionLost), while this
+	/// handle and its browser types keep working against the new driver.
+	pub async fn launch_with_config(config: pw_runtime::ConnectionConfig) -> Result<Self> {
 		use pw_runtime::connection::Connection;
-		use pw_runtime::{PipeTransport, PlaywrightServer};
 
 		// 1. Launch Playwright server
 		tracing::debug!("Launching Playwright server");
 		let mut server = PlaywrightServer::launch().await?;
 
-		// 2. Take stdio streams from server process
-		let stdin = server
-			.process
-			.stdin
-			.take()
-			.ok_or_else(|| pw_runtime::Error::ServerError("Failed to get server stdin".to_string()))?;
-
-		let stdout = server
-			.process
-			.stdout
-			.take()
-			.ok_or_else(|| pw_runtime::Error::ServerError("Failed to get server stdout".to_string()))?;
-
-		// 3. Create transport and connection
+		// 2. Create transport and connection over the server's stdio
 		tracing::debug!("Creating transport and connection");
-		let (transport, message_rx) = PipeTransport::new(stdin, stdout);
-		let parts = transport.into_transport_parts(message_rx);
-		let connection: Arc<Connection> = Arc::new(Connection::new(parts));
+		let parts = stdio_transport(&mut server)?;
+		let reconnect = config.reconnect.is_some();
+		let connection: Arc<Connection> = Arc::new(Connection::new(parts, config));
+		let server = Arc::new(Mutex::new(Some(server)));
+
+		// 3. Relaunch the driver if it exits while reconnect is enabled
+		if reconnect {
+			connection.set_transport_factory(relaunch_driver(Arc::clone(&server)))?;
+		}
 
 		// 4. Spawn connection message loop in background
 		let conn_for_loop: Arc<Connection> = Arc::clone(&connection);
@@ -141,7 +147,7 @@ impl Playwright {
 			chromium: Arc::clone(&playwright.chromium),
 			firefox: Arc::clone(&playwright.firefox),
 			webkit: Arc::clone(&playwright.webkit),
-			server: Arc::new(Mutex::new(Some(server))),
+			server,
 			keep_server_running: false,
 			owns_server: true,
 		})
@@ -150,12 +156,12 @@ impl Playwright {
 	/// Connect to a running Playwright driver over WebSocket.
 	pub async fn connect_ws(ws_url: &str) -> Result<Self> {
 		use pw_runtime::WebSocketTransport;
-		use pw_runtime::connection::Connection;
+		use pw_runtime::connection::{Connection, ConnectionConfig};
 
 		tracing::debug!(%ws_url, "Connecting to Playwright driver via websocket");
 		let (transport, message_rx) = WebSocketTransport::connect(ws_url).await?;
 		let parts = transport.into_transport_parts(message_rx);
-		let connection: Arc<Connection> = Arc::new(Connection::new(parts));
+		let connection: Arc<Connection> = Arc::new(Connection::new(parts, ConnectionConfig::default()));
 
 		let conn_for_loop: Arc<Connection> = Arc::clone(&connection);
 		tokio::spawn(async move {
@@ -179,6 +185,13 @@ impl Playwright {
 		})
 	}
 
+	/// Request counts and latencies for the driver connection.
+	///
+	/// Returns `None` unless launched with stats collection enabled.
+	pub fn connection_stats(&self) -> Option<pw_runtime::ConnectionStats> {
+		self.base.connection().stats()
+	}
+
 	/// Connect to a running Playwright driver on localhost.
 	pub async fn connect_daemon(port: u16) -> Result<Self> {
 		Self::connect_ws(&format!("ws://127.0.0.1:{}", port)).await
@@ -279,6 +292,8 @@ impl Playwright {
 	///
 	/// Returns an error if the server shutdown fails.
 	pub async fn shutdown(&self) -> Result<()> {
+		self.base.connection().shutdown();
+
 		// Take server from mutex without holding the lock across await
 		let server = self.server.lock().take();
 		if let Some(server) = server {
@@ -289,6 +304,43 @@ impl Playwright {
 	}
 }
 
+/// Takes the server's stdio pipes and wraps them in a transport.
+fn stdio_transport(server: &mut PlaywrightServer) -> Result<TransportParts> {
+	let stdin = server
+		.process
+		.stdin
+		.take()
+		.ok_or_else(|| pw_runtime::Error::ServerError("Failed to get server stdin".to_string()))?;
+
+	let stdout = server
+		.process
+		.stdout
+		.take()
+		.ok_or_else(|| pw_runtime::Error::ServerError("Failed to get server stdout".to_string()))?;
+
+	let (transport, message_rx) = PipeTransport::new(stdin, stdout);
+	Ok(transport.into_transport_parts(message_rx))
+}
+
+/// Builds a transport factory that launches a fresh driver process.
+///
+/// The new process replaces the one in `server`, so shutdown and drop stop
+/// whichever driver is current; the lost one is killed in case it lingers.
+fn relaunch_driver(server: Arc<Mutex<Option<PlaywrightServer>>>) -> TransportFactory {
+	Arc::new(move || {
+		let server = Arc::clone(&server);
+		Box::pin(async move {
+			tracing::debug!("Relaunching Playwright server");
+			let mut next = PlaywrightServer::launch().await?;
+			let parts = stdio_transport(&mut next)?;
+			if let Some(mut lost) = server.lock().replace(next) {
+				let _ = lost.process.start_kill();
+			}
+			Ok(parts)
+		})
+	})
+}
+
 impl pw_runtime::channel_owner::private::Sealed for Playwright {}
 
 impl ChannelOwner for Playwright {
@@ -356,6 +408,7 @@ impl Drop for Playwright {
 
 		if let Some(mut server) = self.server.lock().take() {
 			tracing::debug!("Drop: Force-killing Playwright server");
+			self.base.connection().shutdown();
 
 			// We can't call async shutdown in Drop, so use blocking kill
 			// This is less graceful but ensures the process terminates
@@ -9,6 +9,7 @@ use std::sync::Arc;
 
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::Deserialize;
 use serde_json::Value;
 
 /// Request represents an HTTP request during navigation.
@@ -61,6 +62,60 @@ impl Request {
 	pub fn is_navigation_request(&self) -> bool {
 		self.resource_type() == "document"
 	}
+
+	/// Returns the GUID of the frame that issued this request, if known.
+	///
+	/// Compare against [`Page::main_frame_guid`](crate::Page::main_frame_guid)
+	/// to pick out top-level navigations.
+	pub fn frame_guid(&self) -> Option<&str> {
+		self.initializer().get("frame").and_then(|f| f.get("guid")).and_then(|g| g.as_str())
+	}
+
+	/// Returns the request that was redirected to this one, if any.
+	///
+	/// Following it repeatedly walks a redirect chain back to the original request.
+	///
+	/// See: <https://playwright.dev/docs/api/class-request#request-redirected-from>
+	pub async fn redirected_from(&self) -> Result<Option<Request>> {
+		let Some(guid) = self.initializer().get("redirectedFrom").and_then(|r| r.get("guid")).and_then(|g| g.as_str()) else {
+			return Ok(None);
+		};
+
+		let request_arc = self.connection().get_object(guid).await?;
+		request_arc
+			.downcast_ref::<Request>()
+			.cloned()
+			.map(Some)
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Request object, got {}", request_arc.type_name())))
+	}
+
+	/// Returns the response to this request, or `None` if it failed without one.
+	///
+	/// See: <https://playwright.dev/docs/api/class-request#request-response>
+	pub async fn response(&self) -> Result<Option<crate::ResponseObject>> {
+		#[derive(Deserialize)]
+		struct ResponseResult {
+			response: Option<ResponseReference>,
+		}
+
+		#[derive(Deserialize)]
+		struct ResponseReference {
+			#[serde(deserialize_with = "pw_runtime::connection::deserialize_arc_str")]
+			guid: Arc<str>,
+		}
+
+		let result: ResponseResult = self.channel().send("response", serde_json::json!({})).await?;
+		let Some(response_ref) = result.response else {
+			return Ok(None);
+		};
+
+		let response_arc = self.connection().wait_for_object(&response_ref.guid, std::time::Duration::from_secs(1)).await?;
+		response_arc
+			.downcast_ref::<crate::ResponseObject>()
+			.cloned()
+			.map(Some)
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Response object, got {}", response_arc.type_name())))
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for Request {}
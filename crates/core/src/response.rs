@@ -9,8 +9,34 @@ use std::sync::Arc;
 
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// TLS details of a response served over HTTPS.
+///
+/// Validity bounds are Unix timestamps in seconds.
+///
+/// See: <https://playwright.dev/docs/api/class-response#response-security-details>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityDetails {
+	/// Common Name of the certificate issuer.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub issuer: Option<String>,
+	/// Negotiated protocol (e.g. "TLS 1.3").
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub protocol: Option<String>,
+	/// Common Name of the certificate subject.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub subject_name: Option<String>,
+	/// Start of certificate validity.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub valid_from: Option<f64>,
+	/// End of certificate validity.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub valid_to: Option<f64>,
+}
+
 /// Response represents an HTTP response from a navigation operation.
 ///
 /// Response objects are not created directly - they are returned from
@@ -32,6 +58,64 @@ impl ResponseObject {
 
 		Ok(Self { base })
 	}
+
+	/// Returns the response URL.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-url>
+	pub fn url(&self) -> &str {
+		self.initializer().get("url").and_then(|v| v.as_str()).unwrap_or("")
+	}
+
+	/// Returns the HTTP status code.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-status>
+	pub fn status(&self) -> u16 {
+		self.initializer().get("status").and_then(|v| v.as_u64()).unwrap_or(0) as u16
+	}
+
+	/// Returns the first value of header `name` (case-insensitive), if present.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-header-value>
+	pub fn header_value(&self, name: &str) -> Option<&str> {
+		self.initializer()
+			.get("headers")?
+			.as_array()?
+			.iter()
+			.find(|h| h.get("name").and_then(|n| n.as_str()).is_some_and(|n| n.eq_ignore_ascii_case(name)))?
+			.get("value")?
+			.as_str()
+	}
+
+	/// Returns TLS details, or `None` for responses not served over HTTPS.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-security-details>
+	pub async fn security_details(&self) -> Result<Option<SecurityDetails>> {
+		#[derive(Deserialize)]
+		struct SecurityDetailsResponse {
+			value: Option<SecurityDetails>,
+		}
+
+		let response: SecurityDetailsResponse = self.channel().send("securityDetails", serde_json::json!({})).await?;
+		Ok(response.value)
+	}
+
+	/// Resolves the [`Request`](crate::Request) this response answers.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-request>
+	pub async fn request(&self) -> Result<crate::Request> {
+		let guid = self
+			.initializer()
+			.get("request")
+			.and_then(|r| r.get("guid"))
+			.and_then(|g| g.as_str())
+			.ok_or_else(|| pw_runtime::Error::ProtocolError("Response missing 'request.guid'".to_string()))?;
+
+		let request_arc = self.connection().get_object(guid).await?;
+		request_arc
+			.downcast_ref::<crate::Request>()
+			.cloned()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Request object, got {}", request_arc.type_name())))
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for ResponseObject {}
@@ -91,3 +175,24 @@ impl std::fmt::Debug for ResponseObject {
 		f.debug_struct("ResponseObject").field("guid", &self.guid()).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn security_details_deserializes_protocol_shape() {
+		let details: SecurityDetails = serde_json::from_value(serde_json::json!({
+			"issuer": "R3",
+			"protocol": "TLS 1.3",
+			"subjectName": "example.com",
+			"validFrom": 1700000000,
+			"validTo": 1710000000
+		}))
+		.unwrap();
+
+		assert_eq!(details.issuer.as_deref(), Some("R3"));
+		assert_eq!(details.subject_name.as_deref(), Some("example.com"));
+		assert_eq!(details.valid_to, Some(1710000000.0));
+	}
+}
@@ -25,6 +25,21 @@ pub enum ScreenshotType {
 	Jpeg,
 }
 
+/// Animation handling during screenshot capture
+///
+/// With [`ScreenshotAnimations::Disabled`], Playwright fast-forwards finite
+/// CSS animations and transitions to their end state and cancels infinite
+/// ones (resetting them to their initial state) for the duration of the
+/// capture. Animations resume afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotAnimations {
+	/// Stop animations while capturing
+	Disabled,
+	/// Leave animations running (Playwright default)
+	Allow,
+}
+
 /// Clip region for screenshot
 ///
 /// Specifies a rectangular region to capture.
@@ -100,6 +115,8 @@ pub struct ScreenshotOptions {
 	pub clip: Option<ScreenshotClip>,
 	/// Hide default white background (PNG only)
 	pub omit_background: Option<bool>,
+	/// Animation handling while capturing
+	pub animations: Option<ScreenshotAnimations>,
 	/// Screenshot timeout in milliseconds
 	pub timeout: Option<f64>,
 }
@@ -134,6 +151,10 @@ impl ScreenshotOptions {
 			json["omitBackground"] = serde_json::json!(omit_background);
 		}
 
+		if let Some(animations) = &self.animations {
+			json["animations"] = serde_json::to_value(animations).unwrap();
+		}
+
 		// Timeout is required in Playwright 1.56.1+
 		if let Some(timeout) = self.timeout {
 			json["timeout"] = serde_json::json!(timeout);
@@ -155,6 +176,7 @@ pub struct ScreenshotOptionsBuilder {
 	full_page: Option<bool>,
 	clip: Option<ScreenshotClip>,
 	omit_background: Option<bool>,
+	animations: Option<ScreenshotAnimations>,
 	timeout: Option<f64>,
 }
 
@@ -191,6 +213,12 @@ impl ScreenshotOptionsBuilder {
 		self
 	}
 
+	/// Set animation handling while capturing
+	pub fn animations(mut self, animations: ScreenshotAnimations) -> Self {
+		self.animations = Some(animations);
+		self
+	}
+
 	/// Set screenshot timeout in milliseconds
 	pub fn timeout(mut self, timeout: f64) -> Self {
 		self.timeout = Some(timeout);
@@ -205,6 +233,7 @@ impl ScreenshotOptionsBuilder {
 			full_page: self.full_page,
 			clip: self.clip,
 			omit_background: self.omit_background,
+			animations: self.animations,
 			timeout: self.timeout,
 		}
 	}
@@ -262,6 +291,15 @@ mod tests {
 		assert_eq!(json["omitBackground"], true);
 	}
 
+	#[test]
+	fn test_builder_animations_disabled() {
+		let options = ScreenshotOptions::builder().animations(ScreenshotAnimations::Disabled).build();
+
+		let json = options.to_json();
+		assert_eq!(json["animations"], "disabled");
+		assert!(ScreenshotOptions::default().to_json().get("animations").is_none());
+	}
+
 	#[test]
 	fn test_builder_multiple_options() {
 		let options = ScreenshotOptions::builder()
@@ -0,0 +1,34 @@
+//! Browser tests for accessibility snapshots rooted at a locator.
+
+use pw_rs::{AccessibilityNode, AccessibilitySnapshotOptions, Playwright};
+
+const PAGE: &str = "data:text/html,<h1>Account</h1><form><label>Email <input></label><button>Sign up</button></form>";
+
+fn roles(node: &AccessibilityNode, out: &mut Vec<String>) {
+	out.push(node.role.clone());
+	for child in node.children.iter().flatten() {
+		roles(child, out);
+	}
+}
+
+#[tokio::test]
+async fn root_locator_scopes_snapshot_and_reports_missing_root() {
+	let playwright = Playwright::launch().await.expect("launch playwright");
+	let browser = playwright.chromium().launch().await.expect("launch chromium");
+	let page = browser.new_page().await.unwrap();
+	page.goto(PAGE, None).await.unwrap();
+
+	let options = AccessibilitySnapshotOptions::builder().root_locator(page.locator("form").await).build();
+	let tree = page.accessibility_snapshot(Some(options)).await.unwrap().expect("form subtree");
+	let mut found = Vec::new();
+	roles(&tree, &mut found);
+	assert!(found.iter().any(|role| role == "textbox"), "missing textbox: {found:?}");
+	assert!(found.iter().any(|role| role == "button"), "missing button: {found:?}");
+	assert!(!found.iter().any(|role| role == "heading"), "heading outside the root: {found:?}");
+
+	let options = AccessibilitySnapshotOptions::builder().root_locator(page.locator("#missing").await).build();
+	let err = page.accessibility_snapshot(Some(options)).await.unwrap_err();
+	assert!(matches!(err, pw_rs::Error::ElementNotFound(_)), "unexpected error: {err}");
+
+	browser.close().await.unwrap();
+}
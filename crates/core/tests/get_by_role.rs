@@ -0,0 +1,33 @@
+//! Browser tests for role-based locators.
+
+use pw_rs::{GetByRoleOptions, Playwright};
+
+const FORM: &str = "data:text/html,\
+	<section aria-label='Profile'>\
+		<label>Email <input type='email'></label>\
+		<button onclick=\"document.querySelector('output').textContent = 'saved'\">Save</button>\
+		<button onclick=\"document.querySelector('output').textContent = 'draft'\">Save draft</button>\
+	</section>\
+	<section aria-label='Danger zone'><button>Save</button></section>\
+	<output></output>";
+
+#[tokio::test]
+async fn get_by_role_fills_and_clicks_matching_elements() {
+	let playwright = Playwright::launch().await.expect("launch playwright");
+	let browser = playwright.chromium().launch().await.expect("launch chromium");
+	let page = browser.new_page().await.unwrap();
+	page.goto(FORM, None).await.unwrap();
+
+	let email = page.get_by_role("textbox", Some(GetByRoleOptions::builder().name("Email").build())).await;
+	email.fill("ada@example.com", None).await.unwrap();
+	assert_eq!(email.input_value(None).await.unwrap(), "ada@example.com");
+
+	// Scoped to the profile section, an exact name picks "Save" over "Save draft".
+	let profile = page.get_by_role("region", Some(GetByRoleOptions::builder().name("Profile").build())).await;
+	let save = profile.get_by_role("button", Some(GetByRoleOptions::builder().name("Save").exact(true).build()));
+	assert_eq!(save.count().await.unwrap(), 1);
+	save.click(None).await.unwrap();
+	assert_eq!(page.locator("output").await.text_content().await.unwrap().as_deref(), Some("saved"));
+
+	browser.close().await.unwrap();
+}
@@ -143,6 +143,7 @@ impl ExtensionCookie {
 			secure: Some(self.secure),
 			same_site: Some(self.parse_same_site()),
 			url: None,
+			partition_key: None,
 		}
 	}
 
@@ -59,6 +59,10 @@ pub struct Cookie {
 	/// URL to infer domain and path from
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub url: Option<String>,
+
+	/// Top-level site this partitioned (CHIPS) cookie is keyed to
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub partition_key: Option<String>,
 }
 
 impl Cookie {
@@ -74,6 +78,7 @@ impl Cookie {
 			secure: None,
 			same_site: None,
 			url: None,
+			partition_key: None,
 		}
 	}
 
@@ -89,6 +94,7 @@ impl Cookie {
 			secure: None,
 			same_site: None,
 			url: Some(url.into()),
+			partition_key: None,
 		}
 	}
 
@@ -121,6 +127,12 @@ impl Cookie {
 		self.same_site = Some(same_site);
 		self
 	}
+
+	/// Partitions the cookie under the given top-level site.
+	pub fn partition_key(mut self, partition_key: impl Into<String>) -> Self {
+		self.partition_key = Some(partition_key.into());
+		self
+	}
 }
 
 /// Options for clearing cookies.
@@ -266,4 +278,46 @@ mod tests {
 		let restored: StorageState = serde_json::from_str(&json).unwrap();
 		assert_eq!(restored.cookies.len(), 1);
 	}
+
+	#[test]
+	fn test_chrome_cookie_with_same_site_and_partition_key() {
+		let json = r#"{
+			"name": "__Host-pk",
+			"value": "v1",
+			"domain": "widgets.example.net",
+			"path": "/",
+			"expires": 1798761600.5,
+			"httpOnly": true,
+			"secure": true,
+			"sameSite": "Lax",
+			"partitionKey": "https://example.com"
+		}"#;
+		let cookie: Cookie = serde_json::from_str(json).unwrap();
+		assert_eq!(cookie.same_site, Some(SameSite::Lax));
+		assert_eq!(cookie.partition_key.as_deref(), Some("https://example.com"));
+
+		let value = serde_json::to_value(&cookie).unwrap();
+		assert_eq!(value["sameSite"], "Lax");
+		assert_eq!(value["partitionKey"], "https://example.com");
+		assert!(value.get("partition_key").is_none());
+	}
+
+	#[test]
+	fn test_storage_state_file_keeps_same_site_and_partition_key() {
+		let path = std::env::temp_dir().join(format!("pw-protocol-cookie-{}.json", std::process::id()));
+		let state = StorageState::with_cookies(vec![
+			Cookie::new("sid", "abc", ".example.com")
+				.same_site(SameSite::None)
+				.secure(true)
+				.partition_key("https://example.com"),
+		]);
+
+		state.to_file(&path).unwrap();
+		let restored = StorageState::from_file(&path);
+		let _ = std::fs::remove_file(&path);
+
+		let cookie = &restored.unwrap().cookies[0];
+		assert_eq!(cookie.same_site, Some(SameSite::None));
+		assert_eq!(cookie.partition_key.as_deref(), Some("https://example.com"));
+	}
 }
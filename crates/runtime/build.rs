@@ -26,6 +26,7 @@ mod dir_names {
 
 fn main() {
 	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rustc-env=PLAYWRIGHT_DRIVER_VERSION={}", PLAYWRIGHT_VERSION);
 
 	let drivers_dir = get_drivers_dir();
 	let platform = detect_platform();
@@ -479,7 +480,6 @@ fn set_executable_if_shebang(path: &Path) -> io::Result<()> {
 /// Emits compile-time environment variables for driver paths.
 fn set_output_env_vars(driver_dir: &Path, platform: &str, drivers_dir: &Path) {
 	println!("cargo:rustc-env=PLAYWRIGHT_DRIVER_DIR={}", driver_dir.display());
-	println!("cargo:rustc-env=PLAYWRIGHT_DRIVER_VERSION={}", PLAYWRIGHT_VERSION);
 	println!("cargo:rustc-env=PLAYWRIGHT_DRIVER_PLATFORM={}", platform);
 	println!("cargo:rustc-env=PLAYWRIGHT_DRIVERS_DIR={}", drivers_dir.display());
 
@@ -76,6 +76,12 @@ pub trait ChannelOwner: private::Sealed + DowncastSync {
 	fn remove_child(&self, guid: &str);
 
 	/// Handles a protocol event sent to this object.
+	///
+	/// The connection calls this synchronously from its single dispatch loop,
+	/// so events for one GUID arrive in the order the server sent them.
+	/// Implementations must not block, and any async follow-up that handlers
+	/// can observe should go through one queue per object rather than a task
+	/// per event, or that order is lost.
 	fn on_event(&self, method: &str, params: Value);
 
 	/// Returns true if this object was garbage collected.
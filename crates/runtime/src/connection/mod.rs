@@ -16,27 +16,54 @@
 //! 5. Message loop receives response from transport
 //! 6. Response is correlated by ID and sent via oneshot channel
 //! 7. Client receives result
+//!
+//! # Reconnecting
+//!
+//! When the transport closes, every request still awaiting a response fails
+//! with [`Error::ConnectionLost`]. With a [`ReconnectPolicy`] in the
+//! [`ConnectionConfig`] and a [`TransportFactory`] installed through
+//! [`Connection::set_transport_factory`], an unexpected close (one not
+//! preceded by [`Connection::shutdown`]) instead builds a new transport,
+//! retrying with exponential backoff.
+//!
+//! Objects from the lost server are disposed and the root object's
+//! `initialize` handshake is replayed, so the new server recreates the
+//! protocol object tree. An old object whose type and `name` match exactly
+//! one object of that tree (the `Playwright` object and its browser types,
+//! for example) is rebound to it: requests sent through the old handle go to
+//! the new GUID. Requests to any other object of the lost server fail with
+//! [`Error::ConnectionLost`].
+//!
+//! # Stats
+//!
+//! With [`ConnectionConfig::collect_stats`] set, the connection counts
+//! requests in flight and records each method's latency; read them with
+//! [`Connection::stats`].
 
 mod object_store;
+mod stats;
 #[cfg(test)]
 mod tests;
 
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 use std::time::Duration;
 
 use dashmap::DashMap;
 pub use object_store::ObjectStore;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::sync::{Mutex as TokioMutex, mpsc, oneshot};
+use stats::StatsRecorder;
+pub use stats::{ConnectionStats, LATENCY_BUCKETS_MS, MethodStats};
+use tokio::sync::{Mutex as TokioMutex, mpsc, oneshot, watch};
 
 use crate::channel_owner::{ChannelOwner, DisposeReason, ParentOrConnection};
 use crate::error::{Error, Result};
-use crate::transport::{Transport, TransportParts, TransportReceiver};
+use crate::transport::TransportParts;
 
 /// Trait defining the interface that ChannelOwner needs from a Connection
 ///
@@ -66,6 +93,20 @@ pub trait ConnectionLike: Send + Sync {
 	///
 	/// Uses notification-based waiting rather than polling for efficiency.
 	fn wait_for_object(&self, guid: &str, timeout: Duration) -> AsyncChannelOwnerResult<'_>;
+
+	/// Subscribe to the connection-close signal
+	///
+	/// The value flips to `true` once the server's message stream ends, e.g.
+	/// because the driver process exited or the transport dropped.
+	fn closed(&self) -> watch::Receiver<bool>;
+
+	/// Request counts and latencies, if the connection collects them
+	fn stats(&self) -> Option<ConnectionStats> {
+		None
+	}
+
+	/// Mark the coming close as intended, so it does not trigger a reconnect
+	fn shutdown(&self) {}
 }
 
 /// Type alias for complex async return type
@@ -224,6 +265,52 @@ pub enum Message {
 
 type CallbackMap = Arc<DashMap<u32, oneshot::Sender<Result<Value>>>>;
 
+/// Builds a replacement transport, e.g. by relaunching the driver.
+pub type TransportFactory = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<TransportParts>> + Send>> + Send + Sync>;
+
+/// How [`Connection::run`] recovers when the transport closes unexpectedly.
+///
+/// Takes effect only once a [`TransportFactory`] is installed with
+/// [`Connection::set_transport_factory`]; without one the connection closes.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+	/// Attempts before giving up and closing the connection.
+	pub max_attempts: u32,
+	/// Delay before the first attempt, doubled after each failed one.
+	pub initial_backoff: Duration,
+	/// Upper bound on the delay between attempts.
+	pub max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+	/// Makes 3 attempts, backing off from 250ms up to 5s.
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			initial_backoff: Duration::from_millis(250),
+			max_backoff: Duration::from_secs(5),
+		}
+	}
+}
+
+impl ReconnectPolicy {
+	/// Delay before the 0-based `attempt`.
+	fn backoff(&self, attempt: u32) -> Duration {
+		self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_backoff)
+	}
+}
+
+/// Options for [`Connection::new`].
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionConfig {
+	/// Reconnects after an unexpected transport close. `None` (the default)
+	/// ends the connection instead.
+	pub reconnect: Option<ReconnectPolicy>,
+	/// Counts in-flight requests and records per-method latency for
+	/// [`Connection::stats`]. Off by default.
+	pub collect_stats: bool,
+}
+
 /// RAII guard ensuring callback cleanup when a request future is dropped.
 struct CancelGuard {
 	id: u32,
@@ -281,30 +368,72 @@ pub struct Connection {
 	last_id: AtomicU32,
 	callbacks: CallbackMap,
 	outbound_tx: mpsc::UnboundedSender<Value>,
-	transport_sender: Arc<TokioMutex<Option<Box<dyn Transport>>>>,
-	message_rx: Arc<TokioMutex<Option<mpsc::UnboundedReceiver<Value>>>>,
-	transport_receiver: Arc<TokioMutex<Option<Box<dyn TransportReceiver>>>>,
+	transport: Arc<TokioMutex<Option<TransportParts>>>,
 	outbound_rx: Arc<TokioMutex<Option<mpsc::UnboundedReceiver<Value>>>>,
 	objects: Arc<ObjectStore>,
 	factory: OnceLock<Arc<dyn ObjectFactory>>,
+	closed: watch::Sender<bool>,
+	reconnect: Option<ReconnectPolicy>,
+	transport_factory: OnceLock<TransportFactory>,
+	shutting_down: AtomicBool,
+	/// Root object (GUID `""`), kept for replaying the handshake after a reconnect.
+	root: Mutex<Option<Arc<dyn ChannelOwner>>>,
+	/// Method and params last sent to the root object.
+	handshake: Mutex<Option<(String, Value)>>,
+	/// GUIDs of objects from a lost server, mapped to the object they were
+	/// rebound to, or `None` when requests to them must fail.
+	retired: DashMap<Arc<str>, Option<Arc<str>>>,
+	/// Present only when [`ConnectionConfig::collect_stats`] is set.
+	stats: Option<StatsRecorder>,
+}
+
+/// Identifies an object across servers: its type and initializer `name`.
+type RebindKey = (String, Option<String>);
+
+fn rebind_key(object: &dyn ChannelOwner) -> RebindKey {
+	let name = object.initializer().get("name").and_then(Value::as_str).map(str::to_string);
+	(object.type_name().to_string(), name)
 }
 
 impl Connection {
-	pub fn new(parts: TransportParts) -> Self {
+	pub fn new(parts: TransportParts, config: ConnectionConfig) -> Self {
 		let (outbound_tx, outbound_rx) = mpsc::unbounded_channel();
 		Self {
 			last_id: AtomicU32::new(0),
 			callbacks: Arc::new(DashMap::new()),
 			outbound_tx,
-			transport_sender: Arc::new(TokioMutex::new(Some(parts.sender))),
-			message_rx: Arc::new(TokioMutex::new(Some(parts.message_rx))),
-			transport_receiver: Arc::new(TokioMutex::new(Some(parts.receiver))),
+			transport: Arc::new(TokioMutex::new(Some(parts))),
 			outbound_rx: Arc::new(TokioMutex::new(Some(outbound_rx))),
 			objects: Arc::new(ObjectStore::new()),
 			factory: OnceLock::new(),
+			closed: watch::Sender::new(false),
+			stats: config.collect_stats.then(StatsRecorder::default),
+			reconnect: config.reconnect,
+			transport_factory: OnceLock::new(),
+			shutting_down: AtomicBool::new(false),
+			root: Mutex::new(None),
+			handshake: Mutex::new(None),
+			retired: DashMap::new(),
 		}
 	}
 
+	/// Marks the coming transport close as intended, so it does not trigger a reconnect.
+	///
+	/// Also releases the root object retained for handshake replay, which
+	/// holds a reference back to this connection.
+	pub fn shutdown(&self) {
+		self.shutting_down.store(true, Ordering::SeqCst);
+		self.root.lock().take();
+	}
+
+	/// Snapshot of request counts and latencies.
+	///
+	/// Returns `None` unless the connection was created with
+	/// [`ConnectionConfig::collect_stats`].
+	pub fn stats(&self) -> Option<ConnectionStats> {
+		self.stats.as_ref().map(StatsRecorder::snapshot)
+	}
+
 	/// Sets the object factory for creating protocol objects.
 	///
 	/// Returns an error if called more than once.
@@ -314,8 +443,18 @@ impl Connection {
 			.map_err(|_| Error::ProtocolError("set_factory can only be called once".into()))
 	}
 
+	/// Sets the factory used to rebuild the transport under a [`ReconnectPolicy`].
+	///
+	/// Returns an error if called more than once.
+	pub fn set_transport_factory(&self, factory: TransportFactory) -> Result<()> {
+		self.transport_factory
+			.set(factory)
+			.map_err(|_| Error::ProtocolError("set_transport_factory can only be called once".into()))
+	}
+
 	/// Sends a message to the Playwright server and awaits the response.
 	pub async fn send_message(&self, guid: &str, method: &str, params: Value) -> Result<Value> {
+		let guid = self.resolve_guid(guid)?;
 		let id = self.last_id.fetch_add(1, Ordering::SeqCst);
 
 		tracing::debug!("Sending message: id={}, guid='{}', method='{}'", id, guid, method);
@@ -323,11 +462,16 @@ impl Connection {
 		let (tx, rx) = oneshot::channel();
 		self.callbacks.insert(id, tx);
 
+		if guid.is_empty() && self.reconnect.is_some() {
+			*self.handshake.lock() = Some((method.to_string(), params.clone()));
+		}
+
 		let guard = CancelGuard::new(id, Arc::clone(&self.callbacks));
+		let in_flight = self.stats.as_ref().map(StatsRecorder::start);
 
 		let request = Request {
 			id,
-			guid: Arc::from(guid),
+			guid,
 			method: method.to_string(),
 			params,
 			metadata: Metadata::now(),
@@ -343,24 +487,28 @@ impl Connection {
 
 		tracing::debug!("Awaiting response for ID {}", id);
 
-		ResponseFuture { rx, guard }.await
+		let result = ResponseFuture { rx, guard }.await;
+		if let Some(in_flight) = in_flight {
+			in_flight.finish(method);
+		}
+		result
 	}
 
 	/// Run the message dispatch loop
+	///
+	/// Messages are dispatched one at a time in receive order, and each
+	/// event's [`ChannelOwner::on_event`] returns before the next message is
+	/// looked at. Responses and events therefore never overtake each other,
+	/// and events for a given GUID reach their owner in server order.
+	///
+	/// Returns once the transport closes and no reconnect follows.
 	pub async fn run(self: &Arc<Self>) {
-		let transport_receiver = self
-			.transport_receiver
+		let mut parts = self
+			.transport
 			.lock()
 			.await
 			.take()
-			.expect("run() can only be called once - transport receiver already taken");
-
-		let mut transport_sender = self
-			.transport_sender
-			.lock()
-			.await
-			.take()
-			.expect("run() can only be called once - transport sender already taken");
+			.expect("run() can only be called once - transport already taken");
 
 		let mut outbound_rx = self
 			.outbound_rx
@@ -369,28 +517,68 @@ impl Connection {
 			.take()
 			.expect("run() can only be called once - outbound receiver already taken");
 
+		loop {
+			let Some(returned_rx) = self.pump(parts, outbound_rx).await else {
+				break;
+			};
+			outbound_rx = returned_rx;
+			self.fail_pending();
+
+			if self.shutting_down.load(Ordering::SeqCst) {
+				break;
+			}
+			let Some(policy) = &self.reconnect else {
+				break;
+			};
+			let Some(factory) = self.transport_factory.get() else {
+				tracing::warn!("Transport closed; reconnect is enabled but no transport factory is set");
+				break;
+			};
+			let Some(next) = self.reconnect(policy, factory).await else {
+				break;
+			};
+			parts = next;
+			self.reset_objects();
+		}
+
+		self.fail_pending();
+		self.root.lock().take();
+		self.closed.send_replace(true);
+	}
+
+	/// Dispatches messages from one transport until its server stream ends.
+	///
+	/// Returns the outbound queue for reuse, or `None` if the writer task died.
+	async fn pump(self: &Arc<Self>, parts: TransportParts, mut outbound_rx: mpsc::UnboundedReceiver<Value>) -> Option<mpsc::UnboundedReceiver<Value>> {
+		let TransportParts {
+			sender: mut transport_sender,
+			receiver: transport_receiver,
+			mut message_rx,
+		} = parts;
+
 		let reader_handle = tokio::spawn(async move {
 			if let Err(e) = transport_receiver.run().await {
 				tracing::error!("Transport read error: {}", e);
 			}
 		});
 
+		let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
 		let writer_handle = tokio::spawn(async move {
-			while let Some(message) = outbound_rx.recv().await {
-				if let Err(e) = transport_sender.send(message).await {
-					tracing::error!("Transport write error: {}", e);
-					break;
+			loop {
+				tokio::select! {
+					_ = &mut stop_rx => break,
+					message = outbound_rx.recv() => {
+						let Some(message) = message else { break };
+						if let Err(e) = transport_sender.send(message).await {
+							tracing::error!("Transport write error: {}", e);
+							break;
+						}
+					}
 				}
 			}
+			outbound_rx
 		});
 
-		let mut message_rx = self
-			.message_rx
-			.lock()
-			.await
-			.take()
-			.expect("run() can only be called once - message receiver already taken");
-
 		while let Some(message_value) = message_rx.recv().await {
 			match serde_json::from_value::<Message>(message_value) {
 				Ok(message) => {
@@ -404,8 +592,116 @@ impl Connection {
 			}
 		}
 
+		let _ = stop_tx.send(());
 		let _ = reader_handle.await;
-		let _ = writer_handle.await;
+		writer_handle.await.ok()
+	}
+
+	/// Fails every request still awaiting a response with [`Error::ConnectionLost`].
+	fn fail_pending(&self) {
+		let ids: Vec<u32> = self.callbacks.iter().map(|entry| *entry.key()).collect();
+		for id in ids {
+			if let Some((_, callback)) = self.callbacks.remove(&id) {
+				let _ = callback.send(Err(Error::ConnectionLost));
+			}
+		}
+	}
+
+	/// Builds a new transport, backing off between failed attempts.
+	async fn reconnect(&self, policy: &ReconnectPolicy, factory: &TransportFactory) -> Option<TransportParts> {
+		for attempt in 0..policy.max_attempts {
+			tokio::time::sleep(policy.backoff(attempt)).await;
+			if self.shutting_down.load(Ordering::SeqCst) {
+				return None;
+			}
+			match factory().await {
+				Ok(parts) => {
+					tracing::info!(attempt = attempt + 1, "Reconnected to Playwright server");
+					return Some(parts);
+				}
+				Err(e) => tracing::warn!(attempt = attempt + 1, "Reconnect failed: {}", e),
+			}
+		}
+		tracing::error!(attempts = policy.max_attempts, "Giving up reconnecting to Playwright server");
+		None
+	}
+
+	/// Disposes objects from the lost server and replays the root handshake.
+	///
+	/// The replay runs in its own task: its response arrives through the
+	/// dispatch loop, which resumes once this returns. Once it completes,
+	/// old objects are rebound to their counterparts in the new tree.
+	fn reset_objects(self: &Arc<Self>) {
+		let mut lost = Vec::new();
+		for object in self.objects.snapshot() {
+			let guid: Arc<str> = Arc::from(object.guid());
+			if guid.is_empty() {
+				continue;
+			}
+			lost.push((rebind_key(object.as_ref()), Arc::clone(&guid)));
+			self.retired.insert(Arc::clone(&guid), None);
+			object.dispose(DisposeReason::Closed);
+			self.objects.remove(&guid);
+		}
+
+		let root = self.root.lock().clone();
+		let handshake = self.handshake.lock().clone();
+		let (Some(root), Some((method, params))) = (root, handshake) else {
+			return;
+		};
+		self.objects.insert(Arc::from(""), root);
+
+		let connection = Arc::clone(self);
+		tokio::spawn(async move {
+			match connection.send_message("", &method, params).await {
+				Ok(_) => {
+					tracing::debug!("Replayed {} handshake after reconnect", method);
+					connection.rebind(&lost);
+				}
+				Err(e) => tracing::error!("Handshake replay after reconnect failed: {}", e),
+			}
+			connection.objects.remove("");
+		});
+	}
+
+	/// Points each lost object at the one current object sharing its [`RebindKey`].
+	///
+	/// Keys shared by several lost or current objects are ambiguous and stay retired.
+	fn rebind(&self, lost: &[(RebindKey, Arc<str>)]) {
+		let current: Vec<(RebindKey, Arc<str>)> = self
+			.objects
+			.snapshot()
+			.iter()
+			.filter(|object| !object.guid().is_empty())
+			.map(|object| (rebind_key(object.as_ref()), Arc::from(object.guid())))
+			.collect();
+
+		for (key, old) in lost {
+			if lost.iter().filter(|(other, _)| other == key).count() != 1 {
+				continue;
+			}
+			let mut matches = current.iter().filter(|(other, _)| other == key);
+			if let (Some((_, new)), None) = (matches.next(), matches.next()) {
+				tracing::debug!("Rebound {} to {}", old, new);
+				self.retired.insert(Arc::clone(old), Some(Arc::clone(new)));
+			}
+		}
+	}
+
+	/// Maps `guid` through any rebinding after a reconnect.
+	///
+	/// Fails with [`Error::ConnectionLost`] for objects of a lost server that
+	/// were not rebound.
+	fn resolve_guid(&self, guid: &str) -> Result<Arc<str>> {
+		let mut guid: Arc<str> = Arc::from(guid);
+		loop {
+			let next = self.retired.get(&*guid).map(|entry| entry.value().clone());
+			match next {
+				None => return Ok(guid),
+				Some(Some(rebound)) => guid = rebound,
+				Some(None) => return Err(Error::ConnectionLost),
+			}
+		}
 	}
 
 	/// Dispatch an incoming message (test-only public version)
@@ -564,6 +860,10 @@ impl ConnectionLike for Connection {
 
 	fn register_object(&self, guid: Arc<str>, object: Arc<dyn ChannelOwner>) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
 		Box::pin(async move {
+			if guid.is_empty() && self.reconnect.is_some() && !self.shutting_down.load(Ordering::SeqCst) {
+				*self.root.lock() = Some(Arc::clone(&object));
+			}
+			self.retired.remove(&*guid);
 			self.objects.insert(guid, object);
 		})
 	}
@@ -600,4 +900,16 @@ impl ConnectionLike for Connection {
 		let guid_owned = guid.to_string();
 		Box::pin(async move { self.objects.wait_for(&guid_owned, timeout).await })
 	}
+
+	fn closed(&self) -> watch::Receiver<bool> {
+		self.closed.subscribe()
+	}
+
+	fn stats(&self) -> Option<ConnectionStats> {
+		Connection::stats(self)
+	}
+
+	fn shutdown(&self) {
+		Connection::shutdown(self)
+	}
 }
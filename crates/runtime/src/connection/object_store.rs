@@ -45,6 +45,11 @@ impl ObjectStore {
 		self.objects.remove(&Arc::from(guid) as &Arc<str>);
 	}
 
+	/// Returns every registered object.
+	pub fn snapshot(&self) -> Vec<Arc<dyn ChannelOwner>> {
+		self.objects.iter().map(|entry| entry.value().clone()).collect()
+	}
+
 	/// Synchronous lookup.
 	pub fn try_get(&self, guid: &str) -> Option<Arc<dyn ChannelOwner>> {
 		self.objects.get(&Arc::from(guid) as &Arc<str>).map(|r| r.value().clone())
@@ -0,0 +1,192 @@
+//! Opt-in request counters for [`Connection`](super::Connection).
+//!
+//! Enabled with [`ConnectionConfig::collect_stats`](super::ConnectionConfig::collect_stats).
+//! A disabled connection keeps no recorder, so the only cost left in
+//! `send_message` is an `Option` check.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Upper bounds of the latency histogram buckets, in milliseconds.
+///
+/// [`MethodStats::histogram`] has one more bucket than this, counting slower requests.
+pub const LATENCY_BUCKETS_MS: [u64; 6] = [1, 10, 50, 100, 500, 1000];
+
+/// Snapshot returned by [`Connection::stats`](super::Connection::stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionStats {
+	/// Requests sent and still awaiting a response.
+	pub in_flight: usize,
+	/// Completed requests per protocol method, slowest total first.
+	pub methods: Vec<MethodStats>,
+}
+
+impl ConnectionStats {
+	/// Completed requests across all methods.
+	pub fn requests(&self) -> u64 {
+		self.methods.iter().map(|m| m.count).sum()
+	}
+
+	/// Adds `other`'s counts into this snapshot, e.g. to total several connections.
+	pub fn merge(&mut self, other: &ConnectionStats) {
+		self.in_flight += other.in_flight;
+		for theirs in &other.methods {
+			match self.methods.iter_mut().find(|m| m.method == theirs.method) {
+				Some(ours) => ours.merge(theirs),
+				None => self.methods.push(theirs.clone()),
+			}
+		}
+		sort_by_total(&mut self.methods);
+	}
+}
+
+/// Latencies recorded for one protocol method, such as `goto`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodStats {
+	pub method: String,
+	pub count: u64,
+	pub total: Duration,
+	pub max: Duration,
+	/// Request counts per [`LATENCY_BUCKETS_MS`] bucket, then one for slower requests.
+	pub histogram: [u64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl MethodStats {
+	fn new(method: &str) -> Self {
+		Self {
+			method: method.to_string(),
+			count: 0,
+			total: Duration::ZERO,
+			max: Duration::ZERO,
+			histogram: [0; LATENCY_BUCKETS_MS.len() + 1],
+		}
+	}
+
+	/// Average latency, or zero before any request completed.
+	pub fn mean(&self) -> Duration {
+		match u32::try_from(self.count) {
+			Ok(0) => Duration::ZERO,
+			Ok(count) => self.total / count,
+			Err(_) => Duration::from_secs_f64(self.total.as_secs_f64() / self.count as f64),
+		}
+	}
+
+	fn record(&mut self, latency: Duration) {
+		self.count += 1;
+		self.total += latency;
+		self.max = self.max.max(latency);
+		let millis = latency.as_millis();
+		let bucket = LATENCY_BUCKETS_MS
+			.iter()
+			.position(|&bound| millis <= u128::from(bound))
+			.unwrap_or(LATENCY_BUCKETS_MS.len());
+		self.histogram[bucket] += 1;
+	}
+
+	fn merge(&mut self, other: &MethodStats) {
+		self.count += other.count;
+		self.total += other.total;
+		self.max = self.max.max(other.max);
+		for (ours, theirs) in self.histogram.iter_mut().zip(other.histogram) {
+			*ours += theirs;
+		}
+	}
+}
+
+fn sort_by_total(methods: &mut [MethodStats]) {
+	methods.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.method.cmp(&b.method)));
+}
+
+/// Live counters behind [`ConnectionStats`].
+#[derive(Debug, Default)]
+pub(crate) struct StatsRecorder {
+	in_flight: AtomicUsize,
+	methods: Mutex<HashMap<String, MethodStats>>,
+}
+
+impl StatsRecorder {
+	/// Counts a request as in flight until the returned guard is finished or dropped.
+	pub(crate) fn start(&self) -> InFlight<'_> {
+		self.in_flight.fetch_add(1, Ordering::Relaxed);
+		InFlight {
+			recorder: self,
+			started: Instant::now(),
+		}
+	}
+
+	pub(crate) fn snapshot(&self) -> ConnectionStats {
+		let mut methods: Vec<MethodStats> = self.methods.lock().values().cloned().collect();
+		sort_by_total(&mut methods);
+		ConnectionStats {
+			in_flight: self.in_flight.load(Ordering::Relaxed),
+			methods,
+		}
+	}
+}
+
+/// A request counted by [`StatsRecorder::start`].
+///
+/// Dropping it without [`finish`](Self::finish), as when the request future
+/// is cancelled, only clears the in-flight count.
+pub(crate) struct InFlight<'a> {
+	recorder: &'a StatsRecorder,
+	started: Instant,
+}
+
+impl InFlight<'_> {
+	/// Records the request's latency under `method`.
+	pub(crate) fn finish(self, method: &str) {
+		let latency = self.started.elapsed();
+		let mut methods = self.recorder.methods.lock();
+		if let Some(stats) = methods.get_mut(method) {
+			stats.record(latency);
+			return;
+		}
+		let mut stats = MethodStats::new(method);
+		stats.record(latency);
+		methods.insert(method.to_string(), stats);
+	}
+}
+
+impl Drop for InFlight<'_> {
+	fn drop(&mut self) {
+		self.recorder.in_flight.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn record_fills_histogram_buckets() {
+		let mut stats = MethodStats::new("goto");
+		for millis in [0, 5, 10, 75, 2000] {
+			stats.record(Duration::from_millis(millis));
+		}
+		assert_eq!(stats.count, 5);
+		assert_eq!(stats.max, Duration::from_secs(2));
+		assert_eq!(stats.mean(), Duration::from_millis(418));
+		assert_eq!(stats.histogram, [1, 2, 0, 1, 0, 0, 1]);
+	}
+
+	#[test]
+	fn merge_sums_matching_methods() {
+		let recorder = StatsRecorder::default();
+		recorder.start().finish("click");
+		recorder.start().finish("goto");
+		let pending = recorder.start();
+
+		let mut total = recorder.snapshot();
+		assert_eq!(total.in_flight, 1);
+		drop(pending);
+		total.merge(&recorder.snapshot());
+
+		assert_eq!(total.in_flight, 1);
+		assert_eq!(total.requests(), 4);
+		assert!(total.methods.iter().all(|m| m.count == 2));
+	}
+}
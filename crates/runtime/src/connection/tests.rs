@@ -1,9 +1,11 @@
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
-use tokio::io::duplex;
+use tokio::io::{AsyncWriteExt, duplex};
 
 use super::*;
+use crate::channel::Channel;
+use crate::channel_owner::ChannelOwnerImpl;
 use crate::transport::PipeTransport;
 
 fn create_test_connection() -> (Connection, tokio::io::DuplexStream, tokio::io::DuplexStream) {
@@ -12,7 +14,7 @@ fn create_test_connection() -> (Connection, tokio::io::DuplexStream, tokio::io::
 
 	let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
 	let parts = transport.into_transport_parts(message_rx);
-	let connection = Connection::new(parts);
+	let connection = Connection::new(parts, ConnectionConfig::default());
 
 	(connection, stdin_read, stdout_write)
 }
@@ -143,3 +145,348 @@ fn test_error_type_parsing() {
 		_ => panic!("Expected Remote error"),
 	}
 }
+
+/// Protocol object recording every event dispatched to it.
+struct RecordingOwner {
+	base: ChannelOwnerImpl,
+	events: parking_lot::Mutex<Vec<(String, Value)>>,
+}
+
+impl crate::channel_owner::private::Sealed for RecordingOwner {}
+
+impl ChannelOwner for RecordingOwner {
+	fn guid(&self) -> &str {
+		self.base.guid()
+	}
+
+	fn type_name(&self) -> &str {
+		self.base.type_name()
+	}
+
+	fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+		self.base.parent()
+	}
+
+	fn connection(&self) -> Arc<dyn ConnectionLike> {
+		self.base.connection()
+	}
+
+	fn initializer(&self) -> &Value {
+		self.base.initializer()
+	}
+
+	fn channel(&self) -> &Channel {
+		self.base.channel()
+	}
+
+	fn dispose(&self, reason: DisposeReason) {
+		self.base.dispose(reason)
+	}
+
+	fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+		self.base.adopt(child)
+	}
+
+	fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+		self.base.add_child(guid, child)
+	}
+
+	fn remove_child(&self, guid: &str) {
+		self.base.remove_child(guid)
+	}
+
+	fn on_event(&self, method: &str, params: Value) {
+		self.events.lock().push((method.to_string(), params));
+	}
+
+	fn was_collected(&self) -> bool {
+		self.base.was_collected()
+	}
+}
+
+async fn write_frame(stream: &mut tokio::io::DuplexStream, message: &Value) {
+	let bytes = serde_json::to_vec(message).unwrap();
+	stream.write_all(&(bytes.len() as u32).to_le_bytes()).await.unwrap();
+	stream.write_all(&bytes).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_events_reach_owner_in_server_order() {
+	let (connection, _stdin_read, mut stdout_write) = create_test_connection();
+	let connection = Arc::new(connection);
+
+	let owner = Arc::new(RecordingOwner {
+		base: ChannelOwnerImpl::new(
+			ParentOrConnection::Connection(Arc::clone(&connection) as Arc<dyn ConnectionLike>),
+			"Page".to_string(),
+			Arc::from("page@ordered"),
+			Value::Null,
+		),
+		events: parking_lot::Mutex::new(Vec::new()),
+	});
+	connection.objects.insert(Arc::from("page@ordered"), owner.clone());
+
+	let runner = Arc::clone(&connection);
+	let run_handle = tokio::spawn(async move { runner.run().await });
+
+	// Leave requests in flight so their responses interleave with the events.
+	let pending: Vec<_> = (0..3)
+		.map(|_| {
+			let connection = Arc::clone(&connection);
+			tokio::spawn(async move { connection.send_message("page@ordered", "noop", Value::Null).await })
+		})
+		.collect();
+	while connection.callbacks.len() < pending.len() {
+		tokio::task::yield_now().await;
+	}
+
+	const EVENTS: usize = 60;
+	let mut next_response = 0;
+	for i in 0..EVENTS {
+		let method = if i == EVENTS - 1 { "load" } else { "console" };
+		write_frame(
+			&mut stdout_write,
+			&serde_json::json!({ "guid": "page@ordered", "method": method, "params": { "seq": i } }),
+		)
+		.await;
+		if i % 20 == 10 && next_response < pending.len() {
+			write_frame(&mut stdout_write, &serde_json::json!({ "id": next_response, "result": {} })).await;
+			next_response += 1;
+		}
+	}
+
+	for handle in pending {
+		tokio::time::timeout(Duration::from_secs(5), handle).await.unwrap().unwrap().unwrap();
+	}
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while owner.events.lock().len() < EVENTS {
+			tokio::time::sleep(Duration::from_millis(5)).await;
+		}
+	})
+	.await
+	.expect("owner should receive every event");
+
+	let events = owner.events.lock();
+	let seqs: Vec<u64> = events.iter().map(|(_, params)| params["seq"].as_u64().unwrap()).collect();
+	assert_eq!(seqs, (0..EVENTS as u64).collect::<Vec<_>>());
+	assert_eq!(events.last().unwrap().0, "load");
+
+	run_handle.abort();
+}
+
+#[tokio::test]
+async fn test_closed_signal_fires_when_transport_ends() {
+	let (connection, _stdin_read, stdout_write) = create_test_connection();
+	let connection = Arc::new(connection);
+	let mut closed = connection.closed();
+	assert!(!*closed.borrow());
+
+	let runner = Arc::clone(&connection);
+	tokio::spawn(async move { runner.run().await });
+
+	drop(stdout_write);
+	tokio::time::timeout(std::time::Duration::from_secs(5), closed.wait_for(|closed| *closed))
+		.await
+		.expect("closed signal should fire once the server stream ends")
+		.unwrap();
+}
+
+async fn read_frame(stream: &mut tokio::io::DuplexStream) -> Value {
+	use tokio::io::AsyncReadExt;
+
+	let mut len = [0u8; 4];
+	stream.read_exact(&mut len).await.unwrap();
+	let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+	stream.read_exact(&mut bytes).await.unwrap();
+	serde_json::from_slice(&bytes).unwrap()
+}
+
+fn recording_owner(connection: &Arc<Connection>, type_name: &str, guid: &str, initializer: Value) -> Arc<RecordingOwner> {
+	Arc::new(RecordingOwner {
+		base: ChannelOwnerImpl::new(
+			ParentOrConnection::Connection(Arc::clone(connection) as Arc<dyn ConnectionLike>),
+			type_name.to_string(),
+			Arc::from(guid),
+			initializer,
+		),
+		events: parking_lot::Mutex::new(Vec::new()),
+	})
+}
+
+fn reconnecting_config() -> ConnectionConfig {
+	ConnectionConfig {
+		reconnect: Some(ReconnectPolicy {
+			initial_backoff: Duration::from_millis(1),
+			..ReconnectPolicy::default()
+		}),
+		..ConnectionConfig::default()
+	}
+}
+
+#[tokio::test]
+async fn test_pending_request_fails_when_transport_ends() {
+	let (connection, _stdin_read, stdout_write) = create_test_connection();
+	let connection = Arc::new(connection);
+
+	let runner = Arc::clone(&connection);
+	tokio::spawn(async move { runner.run().await });
+
+	let sender = Arc::clone(&connection);
+	let pending = tokio::spawn(async move { sender.send_message("page@gone", "noop", Value::Null).await });
+	while connection.callbacks.is_empty() {
+		tokio::task::yield_now().await;
+	}
+
+	drop(stdout_write);
+	let result = tokio::time::timeout(Duration::from_secs(5), pending).await.unwrap().unwrap();
+	assert!(matches!(result, Err(Error::ConnectionLost)), "unexpected result: {result:?}");
+}
+
+#[tokio::test]
+async fn test_stats_count_in_flight_and_completed_requests() {
+	let (stdin_read, stdin_write) = duplex(1024);
+	let (stdout_read, stdout_write) = duplex(1024);
+	let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+	let config = ConnectionConfig {
+		collect_stats: true,
+		..ConnectionConfig::default()
+	};
+	let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx), config));
+
+	let runner = Arc::clone(&connection);
+	tokio::spawn(async move { runner.run().await });
+
+	let (mut stdin_read, mut stdout_write) = (stdin_read, stdout_write);
+	let sender = Arc::clone(&connection);
+	let pending = tokio::spawn(async move { sender.send_message("page@stats", "goto", Value::Null).await });
+	let request = read_frame(&mut stdin_read).await;
+	assert_eq!(connection.stats().unwrap().in_flight, 1);
+
+	write_frame(&mut stdout_write, &serde_json::json!({ "id": request["id"], "result": {} })).await;
+	pending.await.unwrap().unwrap();
+
+	let stats = connection.stats().unwrap();
+	assert_eq!(stats.in_flight, 0);
+	assert_eq!(stats.requests(), 1);
+	assert_eq!(stats.methods[0].method, "goto");
+	assert_eq!(stats.methods[0].histogram.iter().sum::<u64>(), 1);
+}
+
+#[test]
+fn test_stats_disabled_by_default() {
+	let (connection, _, _) = create_test_connection();
+	assert!(connection.stats().is_none());
+}
+
+#[test]
+fn test_reconnect_backoff_doubles_up_to_max() {
+	let policy = ReconnectPolicy::default();
+	assert_eq!(policy.backoff(0), Duration::from_millis(250));
+	assert_eq!(policy.backoff(1), Duration::from_millis(500));
+	assert_eq!(policy.backoff(3), Duration::from_secs(2));
+	assert_eq!(policy.backoff(10), Duration::from_secs(5));
+	assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_reconnect_replays_handshake_and_rebinds_objects() {
+	let (stdin_read, stdin_write) = duplex(1024);
+	let (stdout_read, stdout_write) = duplex(1024);
+	let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+	let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx), reconnecting_config()));
+
+	let (next_stdin_read, next_stdin_write) = duplex(1024);
+	let (next_stdout_read, next_stdout_write) = duplex(1024);
+	let (next_transport, next_message_rx) = PipeTransport::new(next_stdin_write, next_stdout_read);
+	let next_parts = Arc::new(parking_lot::Mutex::new(Some(next_transport.into_transport_parts(next_message_rx))));
+	connection
+		.set_transport_factory(Arc::new(move || {
+			let parts = next_parts.lock().take();
+			Box::pin(async move { parts.ok_or(Error::ChannelClosed) })
+		}))
+		.unwrap();
+
+	let root = recording_owner(&connection, "Root", "", Value::Null);
+	connection.register_object(Arc::from(""), root).await;
+	let chromium = recording_owner(&connection, "BrowserType", "browser-type@old", serde_json::json!({ "name": "chromium" }));
+	connection.register_object(Arc::from("browser-type@old"), chromium).await;
+	let page = recording_owner(&connection, "Page", "page@old", Value::Null);
+	connection.register_object(Arc::from("page@old"), page).await;
+
+	let runner = Arc::clone(&connection);
+	tokio::spawn(async move { runner.run().await });
+
+	let (mut stdin_read, mut stdout_write) = (stdin_read, stdout_write);
+	let sender = Arc::clone(&connection);
+	let initialize = tokio::spawn(async move { sender.send_message("", "initialize", serde_json::json!({ "sdkLanguage": "rust" })).await });
+	let request = read_frame(&mut stdin_read).await;
+	write_frame(&mut stdout_write, &serde_json::json!({ "id": request["id"], "result": {} })).await;
+	initialize.await.unwrap().unwrap();
+
+	drop(stdout_write);
+
+	let (mut next_stdin_read, mut next_stdout_write) = (next_stdin_read, next_stdout_write);
+	let replayed = tokio::time::timeout(Duration::from_secs(5), read_frame(&mut next_stdin_read))
+		.await
+		.expect("handshake should be replayed on the new transport");
+	assert_eq!(replayed["guid"], "");
+	assert_eq!(replayed["method"], "initialize");
+	assert_eq!(replayed["params"]["sdkLanguage"], "rust");
+	assert!(
+		connection.objects.try_get("page@old").is_none(),
+		"objects from the lost server should be disposed"
+	);
+	assert!(!*connection.closed().borrow());
+
+	// The new server recreates the browser type under a fresh GUID before answering.
+	let next_chromium = recording_owner(&connection, "BrowserType", "browser-type@new", serde_json::json!({ "name": "chromium" }));
+	connection.register_object(Arc::from("browser-type@new"), next_chromium).await;
+	write_frame(&mut next_stdout_write, &serde_json::json!({ "id": replayed["id"], "result": {} })).await;
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while connection.objects.try_get("").is_some() {
+			tokio::time::sleep(Duration::from_millis(5)).await;
+		}
+	})
+	.await
+	.expect("root should be unregistered once the replay completes");
+
+	let sender = Arc::clone(&connection);
+	let launch = tokio::spawn(async move { sender.send_message("browser-type@old", "launch", Value::Null).await });
+	let request = read_frame(&mut next_stdin_read).await;
+	assert_eq!(request["guid"], "browser-type@new", "rebound objects should address their new GUID");
+	write_frame(&mut next_stdout_write, &serde_json::json!({ "id": request["id"], "result": {} })).await;
+	launch.await.unwrap().unwrap();
+
+	let stale = connection.send_message("page@old", "goto", Value::Null).await;
+	assert!(matches!(stale, Err(Error::ConnectionLost)), "unexpected result: {stale:?}");
+}
+
+#[tokio::test]
+async fn test_shutdown_closes_without_reconnecting() {
+	let (stdin_read, stdin_write) = duplex(1024);
+	let (stdout_read, stdout_write) = duplex(1024);
+	let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+	let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx), reconnecting_config()));
+
+	let attempts = Arc::new(AtomicU32::new(0));
+	let counter = Arc::clone(&attempts);
+	connection
+		.set_transport_factory(Arc::new(move || {
+			counter.fetch_add(1, Ordering::SeqCst);
+			Box::pin(async { Err(Error::ChannelClosed) })
+		}))
+		.unwrap();
+
+	let runner = Arc::clone(&connection);
+	tokio::spawn(async move { runner.run().await });
+
+	connection.shutdown();
+	drop(stdout_write);
+	drop(stdin_read);
+	let mut closed = connection.closed();
+	tokio::time::timeout(Duration::from_secs(5), closed.wait_for(|closed| *closed))
+		.await
+		.expect("shutdown should close instead of reconnecting")
+		.unwrap();
+	assert_eq!(attempts.load(Ordering::SeqCst), 0);
+}
@@ -10,6 +10,9 @@ use tracing::warn;
 
 use crate::error::{Error, Result};
 
+/// Playwright driver version pinned by the build script.
+pub const DRIVER_VERSION: &str = env!("PLAYWRIGHT_DRIVER_VERSION");
+
 /// Get the path to the Playwright driver executable
 ///
 /// This function attempts to locate the Playwright driver in the following order:
@@ -75,6 +75,10 @@ pub enum Error {
 	#[error("Channel closed unexpectedly")]
 	ChannelClosed,
 
+	/// The transport closed while the request awaited its response.
+	#[error("Connection to Playwright server lost")]
+	ConnectionLost,
+
 	/// Invalid argument provided to method.
 	#[error("Invalid argument: {0}")]
 	InvalidArgument(String),
@@ -83,6 +87,10 @@ pub enum Error {
 	#[error("Element not found: selector '{0}'")]
 	ElementNotFound(String),
 
+	/// An `nth` locator index beyond the elements its base selector matched.
+	#[error("index {index} out of range (count {count}) for selector '{selector}'")]
+	IndexOutOfRange { selector: String, index: i32, count: usize },
+
 	/// Assertion timeout (expect API).
 	#[error("Assertion timeout: {0}")]
 	AssertionTimeout(String),
@@ -122,4 +130,66 @@ impl Error {
 			_ => false,
 		}
 	}
+
+	/// Returns true if a navigation was aborted because another navigation replaced it.
+	///
+	/// Matches the messages Playwright surfaces when a page redirects (e.g. via
+	/// `location.replace`) before the awaited lifecycle event fires: Chromium's
+	/// "interrupted by another navigation" and WebKit's "Navigation interrupted
+	/// by another one".
+	pub fn is_navigation_interrupted(&self) -> bool {
+		let Error::Remote { message, .. } = self else {
+			return false;
+		};
+		["interrupted by another navigation", "Navigation interrupted by another one"]
+			.iter()
+			.any(|needle| message.contains(needle))
+	}
+
+	/// Returns true if the browser aborted a navigation without saying why.
+	///
+	/// Chromium's `net::ERR_ABORTED` and Firefox's `NS_BINDING_ABORTED` also
+	/// report client-side redirects, but equally downloads, `204` responses and
+	/// stopped loads. Callers must check the page actually moved elsewhere
+	/// before treating one as a redirect.
+	pub fn is_navigation_aborted(&self) -> bool {
+		let Error::Remote { message, .. } = self else {
+			return false;
+		};
+		["net::ERR_ABORTED", "NS_BINDING_ABORTED"].iter().any(|needle| message.contains(needle))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn remote(message: &str) -> Error {
+		Error::Remote {
+			name: "Error".to_string(),
+			message: message.to_string(),
+			stack: None,
+		}
+	}
+
+	#[test]
+	fn navigation_interrupted_matches_engine_messages() {
+		assert!(remote("Navigation to \"https://a.test/\" is interrupted by another navigation to \"https://b.test/\"").is_navigation_interrupted());
+		assert!(remote("Navigation interrupted by another one").is_navigation_interrupted());
+	}
+
+	#[test]
+	fn bare_aborts_are_not_interruptions() {
+		for message in ["net::ERR_ABORTED at https://a.test/", "NS_BINDING_ABORTED"] {
+			assert!(remote(message).is_navigation_aborted());
+			assert!(!remote(message).is_navigation_interrupted());
+		}
+		assert!(!remote("Navigation interrupted by another one").is_navigation_aborted());
+	}
+
+	#[test]
+	fn navigation_interrupted_rejects_other_errors() {
+		assert!(!remote("net::ERR_NAME_NOT_RESOLVED at https://missing.test/").is_navigation_interrupted());
+		assert!(!Error::Timeout("interrupted by another navigation".to_string()).is_navigation_interrupted());
+	}
 }
@@ -47,8 +47,11 @@ pub mod transport;
 // Re-export key types at crate root
 pub use channel::Channel;
 pub use channel_owner::{ChannelOwner, ChannelOwnerImpl, DisposeReason, ParentOrConnection};
-pub use connection::{AsyncChannelOwnerResult, Connection, ConnectionLike, Event, Message, Metadata, ObjectFactory, ObjectStore, Request, Response};
-pub use driver::{TestRunnerPaths, get_driver_executable, get_test_runner_paths};
+pub use connection::{
+	AsyncChannelOwnerResult, Connection, ConnectionConfig, ConnectionLike, ConnectionStats, Event, LATENCY_BUCKETS_MS, Message, Metadata, MethodStats,
+	ObjectFactory, ObjectStore, ReconnectPolicy, Request, Response, TransportFactory,
+};
+pub use driver::{DRIVER_VERSION, TestRunnerPaths, get_driver_executable, get_test_runner_paths};
 pub use error::{Error, Result};
 pub use playwright_server::PlaywrightServer;
 pub use process::{pid_is_alive, port_available};